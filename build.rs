@@ -19,7 +19,14 @@
  */
 
 fn main() {
-    glib_build_tools::compile_resources(&["src"], "src/nutmeg.gresource.xml", "nutmeg.gresource");
+    // The GResource bundle is only needed by the GTK front end.
+    if std::env::var_os("CARGO_FEATURE_GUI").is_some() {
+        glib_build_tools::compile_resources(
+            &["src"],
+            "src/nutmeg.gresource.xml",
+            "nutmeg.gresource",
+        );
+    }
 
     // Ensure config.rs exists (normally generated by Meson, but necessary for bare cargo builds)
     let config_path = std::path::Path::new("src/config.rs");
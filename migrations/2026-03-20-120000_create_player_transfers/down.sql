@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_player_transfers_player;
+DROP TABLE player_transfers;
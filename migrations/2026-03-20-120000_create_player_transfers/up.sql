@@ -0,0 +1,18 @@
+-- Transfer history of individual players (transfersplayer endpoint).
+CREATE TABLE player_transfers (
+    transfer_id      INTEGER NOT NULL,
+    player_id        INTEGER NOT NULL,
+    download_id      INTEGER NOT NULL,
+    deadline         TEXT NOT NULL,
+    buyer_team_id    INTEGER NOT NULL,
+    buyer_team_name  TEXT NOT NULL,
+    seller_team_id   INTEGER NOT NULL,
+    seller_team_name TEXT NOT NULL,
+    price            INTEGER NOT NULL,
+    tsi              INTEGER,
+    PRIMARY KEY (transfer_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_player_transfers_player
+    ON player_transfers (player_id, download_id DESC);
@@ -0,0 +1,10 @@
+-- Events in a player's history (playerevents endpoint).
+CREATE TABLE player_events (
+    player_id      INTEGER NOT NULL,
+    download_id    INTEGER NOT NULL,
+    event_date     TEXT NOT NULL,
+    event_type_id  INTEGER NOT NULL,
+    event_text     TEXT NOT NULL,
+    PRIMARY KEY (player_id, download_id, event_date),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
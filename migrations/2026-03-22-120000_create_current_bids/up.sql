@@ -0,0 +1,15 @@
+-- Active transfer bids of the team (currentbids endpoint), both players the
+-- team is bidding on ('buying') and its own listed players ('selling').
+CREATE TABLE current_bids (
+    player_id         INTEGER NOT NULL,
+    download_id       INTEGER NOT NULL,
+    side              TEXT NOT NULL,
+    transfer_id       INTEGER,
+    player_name       TEXT NOT NULL,
+    highest_bid       INTEGER,
+    deadline          TEXT NOT NULL,
+    bidder_team_id    INTEGER,
+    bidder_team_name  TEXT,
+    PRIMARY KEY (player_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
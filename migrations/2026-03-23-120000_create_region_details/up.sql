@@ -0,0 +1,13 @@
+-- Region statistics and weather (regiondetails endpoint).
+CREATE TABLE region_details (
+    region_id           INTEGER NOT NULL,
+    download_id         INTEGER NOT NULL,
+    league_id           INTEGER NOT NULL,
+    region_name         TEXT NOT NULL,
+    weather_id          INTEGER,
+    tomorrow_weather_id INTEGER,
+    number_of_users     INTEGER,
+    number_of_online    INTEGER,
+    PRIMARY KEY (region_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
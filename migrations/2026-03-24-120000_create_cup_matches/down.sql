@@ -0,0 +1 @@
+DROP TABLE cup_matches;
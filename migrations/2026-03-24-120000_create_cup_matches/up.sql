@@ -0,0 +1,17 @@
+-- Cup fixtures (cupmatches endpoint).
+CREATE TABLE cup_matches (
+    match_id        INTEGER NOT NULL,
+    download_id     INTEGER NOT NULL,
+    cup_id          INTEGER NOT NULL,
+    season          INTEGER,
+    cup_round       INTEGER,
+    match_date      TEXT NOT NULL,
+    home_team_id    INTEGER NOT NULL,
+    home_team_name  TEXT NOT NULL,
+    away_team_id    INTEGER NOT NULL,
+    away_team_name  TEXT NOT NULL,
+    home_goals      INTEGER,
+    away_goals      INTEGER,
+    PRIMARY KEY (match_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,15 @@
+-- Skill-up history (trainingevents endpoint). Events never change once
+-- they happened, so they are keyed on the event itself rather than the
+-- download that first saw them.
+CREATE TABLE training_events (
+    player_id   INTEGER NOT NULL,
+    event_date  TEXT NOT NULL,
+    skill_id    INTEGER NOT NULL,
+    download_id INTEGER NOT NULL,
+    old_level   INTEGER NOT NULL,
+    new_level   INTEGER NOT NULL,
+    season      INTEGER,
+    match_round INTEGER,
+    PRIMARY KEY (player_id, event_date, skill_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+DROP TABLE arena_details;
@@ -0,0 +1,20 @@
+-- Arena seating (arenadetails endpoint). The expanded_* columns are only
+-- set while an expansion is under construction.
+CREATE TABLE arena_details (
+    arena_id           INTEGER NOT NULL,
+    download_id        INTEGER NOT NULL,
+    arena_name         TEXT NOT NULL,
+    team_id            INTEGER,
+    team_name          TEXT,
+    terraces           INTEGER NOT NULL,
+    basic              INTEGER NOT NULL,
+    roof               INTEGER NOT NULL,
+    vip                INTEGER NOT NULL,
+    expansion_date     TEXT,
+    expanded_terraces  INTEGER,
+    expanded_basic     INTEGER,
+    expanded_roof      INTEGER,
+    expanded_vip       INTEGER,
+    PRIMARY KEY (arena_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,6 @@
+-- Small key/value store for UI state that must survive restarts and
+-- database refreshes, such as the last selected team.
+CREATE TABLE preferences (
+    key    TEXT PRIMARY KEY NOT NULL,
+    value  TEXT NOT NULL
+);
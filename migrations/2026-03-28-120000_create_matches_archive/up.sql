@@ -0,0 +1,18 @@
+-- Past matches of a team as returned by matchesarchive, kept apart from
+-- `matches` so the archive can be read per team and date range.
+CREATE TABLE matches_archive (
+    team_id            INTEGER NOT NULL,
+    match_id           INTEGER NOT NULL,
+    download_id        INTEGER NOT NULL,
+    home_team_id       INTEGER NOT NULL,
+    home_team_name     TEXT NOT NULL,
+    away_team_id       INTEGER NOT NULL,
+    away_team_name     TEXT NOT NULL,
+    match_date         TEXT NOT NULL,
+    match_type         INTEGER NOT NULL,
+    match_context_id   INTEGER,
+    home_goals         INTEGER,
+    away_goals         INTEGER,
+    PRIMARY KEY (team_id, match_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
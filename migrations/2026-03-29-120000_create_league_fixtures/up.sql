@@ -0,0 +1,18 @@
+-- Season schedule of a series (leaguefixtures endpoint). Goals stay NULL
+-- until a match has been played; later fetches overwrite earlier ones.
+CREATE TABLE league_fixtures (
+    lku_id          INTEGER NOT NULL,
+    season          INTEGER NOT NULL,
+    round           INTEGER NOT NULL,
+    home_team_id    INTEGER NOT NULL,
+    match_id        INTEGER NOT NULL,
+    download_id     INTEGER NOT NULL,
+    home_team_name  TEXT NOT NULL,
+    away_team_id    INTEGER NOT NULL,
+    away_team_name  TEXT NOT NULL,
+    match_date      TEXT NOT NULL,
+    home_goals      INTEGER,
+    away_goals      INTEGER,
+    PRIMARY KEY (lku_id, season, round, home_team_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
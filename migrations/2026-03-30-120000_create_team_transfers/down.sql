@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_team_transfers_team;
+DROP TABLE team_transfers;
@@ -0,0 +1,22 @@
+-- Transfer history of a team (transfersteam endpoint). transfer_type is the
+-- CHPP code: 'B' for a purchase, 'S' for a sale.
+CREATE TABLE team_transfers (
+    transfer_id      INTEGER NOT NULL,
+    team_id          INTEGER NOT NULL,
+    download_id      INTEGER NOT NULL,
+    deadline         TEXT NOT NULL,
+    transfer_type    TEXT NOT NULL,
+    player_id        INTEGER NOT NULL,
+    player_name      TEXT NOT NULL,
+    buyer_team_id    INTEGER NOT NULL,
+    buyer_team_name  TEXT NOT NULL,
+    seller_team_id   INTEGER NOT NULL,
+    seller_team_name TEXT NOT NULL,
+    price            INTEGER NOT NULL,
+    tsi              INTEGER,
+    PRIMARY KEY (transfer_id, team_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_team_transfers_team
+    ON team_transfers (team_id, download_id DESC);
@@ -0,0 +1,12 @@
+-- Hattrick's own denominations (translations endpoint), cached per language.
+-- item_type is 'skill_level', 'specialty' or 'position'; item_id is the code
+-- the text names. Later fetches overwrite earlier ones.
+CREATE TABLE translations (
+    language_id INTEGER NOT NULL,
+    item_type   TEXT NOT NULL,
+    item_id     INTEGER NOT NULL,
+    download_id INTEGER NOT NULL,
+    text        TEXT NOT NULL,
+    PRIMARY KEY (language_id, item_type, item_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
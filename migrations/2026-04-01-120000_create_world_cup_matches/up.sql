@@ -0,0 +1,20 @@
+-- World Cup matches (worldcup endpoint), with the group each belongs to.
+-- Goals stay NULL until a match has been played; later fetches overwrite
+-- earlier ones.
+CREATE TABLE world_cup_matches (
+    cup_id             INTEGER NOT NULL,
+    season             INTEGER NOT NULL,
+    match_id           INTEGER NOT NULL,
+    download_id        INTEGER NOT NULL,
+    match_round        INTEGER NOT NULL,
+    cup_series_unit_id INTEGER NOT NULL,
+    home_team_id       INTEGER NOT NULL,
+    home_team_name     TEXT NOT NULL,
+    away_team_id       INTEGER NOT NULL,
+    away_team_name     TEXT NOT NULL,
+    match_date         TEXT NOT NULL,
+    home_goals         INTEGER,
+    away_goals         INTEGER,
+    PRIMARY KEY (cup_id, season, match_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_hof_players_team;
+DROP TABLE hof_players;
@@ -0,0 +1,26 @@
+-- Hall of Fame players of a team (hofplayers endpoint), with peak skills.
+CREATE TABLE hof_players (
+    player_id        INTEGER NOT NULL,
+    download_id      INTEGER NOT NULL,
+    team_id          INTEGER NOT NULL,
+    first_name       TEXT NOT NULL,
+    nick_name        TEXT,
+    last_name        TEXT NOT NULL,
+    age              INTEGER NOT NULL,
+    arrival_date     TEXT,
+    hof_date         TEXT NOT NULL,
+    hof_age          INTEGER,
+    expert_type      INTEGER NOT NULL,
+    keeper_skill     INTEGER,
+    playmaker_skill  INTEGER,
+    scorer_skill     INTEGER,
+    passing_skill    INTEGER,
+    winger_skill     INTEGER,
+    defender_skill   INTEGER,
+    set_pieces_skill INTEGER,
+    PRIMARY KEY (player_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_hof_players_team
+    ON hof_players (team_id, download_id DESC);
@@ -0,0 +1,15 @@
+-- The user's bookmarks (bookmarks endpoint). bookmark_type is CHPP's
+-- BookmarkTypeID and target_id the id of the bookmarked object. Each sync
+-- replaces the whole set.
+CREATE TABLE bookmarks (
+    bookmark_type INTEGER NOT NULL,
+    target_id     INTEGER NOT NULL,
+    bookmark_id   INTEGER NOT NULL,
+    download_id   INTEGER NOT NULL,
+    text          TEXT NOT NULL,
+    text2         TEXT,
+    target_id2    INTEGER,
+    comment       TEXT,
+    PRIMARY KEY (bookmark_type, target_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_challenges_team;
+DROP TABLE challenges;
@@ -0,0 +1,21 @@
+-- Friendly challenges of a team (challenges endpoint). side is 'sent' for
+-- challenges the team sent and 'received' for offers from other teams.
+CREATE TABLE challenges (
+    training_match_id INTEGER NOT NULL,
+    download_id       INTEGER NOT NULL,
+    team_id           INTEGER NOT NULL,
+    is_weekend        BOOLEAN NOT NULL,
+    side              TEXT NOT NULL,
+    match_time        TEXT NOT NULL,
+    friendly_type     INTEGER NOT NULL,
+    opponent_team_id  INTEGER NOT NULL,
+    opponent_name     TEXT NOT NULL,
+    arena_id          INTEGER,
+    arena_name        TEXT,
+    is_agreed         BOOLEAN NOT NULL,
+    PRIMARY KEY (training_match_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_challenges_team
+    ON challenges (team_id, is_weekend, download_id DESC);
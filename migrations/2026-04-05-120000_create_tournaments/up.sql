@@ -0,0 +1,20 @@
+-- Tournaments the user takes part in (tournamentlist / tournamentdetails).
+-- One row per tournament; a later fetch replaces the earlier one.
+CREATE TABLE tournaments (
+    tournament_id          INTEGER PRIMARY KEY NOT NULL,
+    download_id            INTEGER NOT NULL,
+    name                   TEXT NOT NULL,
+    tournament_type        INTEGER NOT NULL,
+    season                 INTEGER NOT NULL,
+    logo_url               TEXT,
+    trophy_type            INTEGER,
+    number_of_teams        INTEGER NOT NULL,
+    number_of_groups       INTEGER NOT NULL,
+    last_match_round       INTEGER,
+    first_match_round_date TEXT,
+    next_match_round_date  TEXT,
+    is_matches_ongoing     BOOLEAN NOT NULL,
+    creator_user_id        INTEGER,
+    creator_login_name     TEXT,
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,14 @@
+-- Achievements of a Hattrick user (achievements endpoint). One row per
+-- achievement type; a later fetch replaces the earlier one.
+CREATE TABLE achievements (
+    user_id          INTEGER NOT NULL,
+    achievement_id   INTEGER NOT NULL,
+    download_id      INTEGER NOT NULL,
+    title            TEXT NOT NULL,
+    category_id      INTEGER NOT NULL,
+    points           INTEGER NOT NULL,
+    event_date       TEXT NOT NULL,
+    number_of_events INTEGER,
+    PRIMARY KEY (user_id, achievement_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,18 @@
+-- Supporter relationships (supporters endpoint). direction is 'supporter'
+-- for teams supporting owner_id (a team) and 'supported' for teams that
+-- owner_id (a user) supports.
+CREATE TABLE supporters (
+    owner_id         INTEGER NOT NULL,
+    direction        TEXT NOT NULL,
+    team_id          INTEGER NOT NULL,
+    download_id      INTEGER NOT NULL,
+    team_name        TEXT NOT NULL,
+    user_id          INTEGER NOT NULL,
+    login_name       TEXT NOT NULL,
+    league_id        INTEGER,
+    league_name      TEXT,
+    league_unit_id   INTEGER,
+    league_unit_name TEXT,
+    PRIMARY KEY (owner_id, direction, team_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+DROP TABLE national_teams;
@@ -0,0 +1,12 @@
+-- National team staff roles of a user (User/NationalTeams in teamdetails).
+-- role is NationalTeamStaffType: 1 = coach, 2 = assistant.
+CREATE TABLE national_teams (
+    user_id       INTEGER NOT NULL,
+    nt_team_id    INTEGER NOT NULL,
+    download_id   INTEGER NOT NULL,
+    nt_team_name  TEXT,
+    league_id     INTEGER,
+    role          INTEGER,
+    PRIMARY KEY (user_id, nt_team_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
@@ -0,0 +1,16 @@
+-- Team finances (economy endpoint), one row per team per download so cash
+-- can be followed over time. Amounts are in CHPP's base currency (SEK).
+CREATE TABLE economy (
+    team_id               INTEGER NOT NULL,
+    download_id           INTEGER NOT NULL,
+    cash                  BIGINT NOT NULL,
+    expected_cash         BIGINT,
+    fan_club_size         INTEGER,
+    income_spectators     BIGINT,
+    income_sponsors       BIGINT,
+    income_sum            BIGINT,
+    costs_sum             BIGINT,
+    expected_weeks_total  BIGINT,
+    PRIMARY KEY (team_id, download_id),
+    FOREIGN KEY (download_id) REFERENCES downloads(id) ON DELETE CASCADE
+);
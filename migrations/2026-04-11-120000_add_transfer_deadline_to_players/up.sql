@@ -0,0 +1 @@
+ALTER TABLE players ADD COLUMN transfer_deadline TEXT;
@@ -60,6 +60,12 @@ mod imp {
 
             info!("Application activated");
 
+            if let Err(e) = crate::config::validate_consumer_credentials() {
+                error!("Missing CHPP consumer credentials: {}", e);
+                application.show_not_configured(&e);
+                return;
+            }
+
             // Check for first run (empty database)
             let db_manager = crate::db::manager::DbManager::new();
             let is_first_run = match db_manager.has_users() {
@@ -145,6 +151,31 @@ impl NutmegApplication {
         });
     }
 
+    /// Explain that the build lacks CHPP credentials, then quit: nothing
+    /// past this point can work without them.
+    fn show_not_configured(&self, err: &crate::config::ConfigError) {
+        #[allow(deprecated)]
+        let dialog = gtk::MessageDialog::builder()
+            .application(self)
+            .modal(true)
+            .message_type(gtk::MessageType::Error)
+            .buttons(gtk::ButtonsType::Close)
+            .text(gettext("App not configured"))
+            .secondary_text(format!(
+                "{}\n\n{}",
+                gettext("Set HT_CONSUMER_KEY and HT_CONSUMER_SECRET and restart Nutmeg."),
+                err
+            ))
+            .build();
+        let app = self.clone();
+        #[allow(deprecated)]
+        dialog.connect_response(move |dialog, _| {
+            dialog.close();
+            app.quit();
+        });
+        dialog.present();
+    }
+
     fn setup_gactions(&self) {
         let quit_action = gio::ActionEntry::builder("quit")
             .activate(move |app: &Self, _, _| app.quit())
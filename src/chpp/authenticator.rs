@@ -24,10 +24,10 @@ use oauth_1a::{ClientId, ClientSecret, OAuthData, SigningKey, Token};
 use std::env;
 use std::io::{self, Write};
 
+use crate::chpp::client::{ChppClient, HattrickClient};
 use crate::chpp::oauth::{
     create_oauth_context, exchange_verification_code, request_token, OauthSettings,
 };
-use crate::chpp::request::team_details_request;
 
 // This file is useful to do a full end to end test of the CHPP OAuth flow.
 
@@ -151,7 +151,8 @@ pub fn perform_cli_auth() -> glib::ExitCode {
 
     // Execute async request (reuse runtime)
 
-    match rt.block_on(team_details_request(data, key, Some(281726))) {
+    let client = HattrickClient::new();
+    match rt.block_on(client.team_details(data, key, Some(281726))) {
         Ok(data) => {
             println!("Successfully retrieved team details!");
             // println!("{:#?}", data);
@@ -171,11 +172,7 @@ pub fn perform_cli_auth() -> glib::ExitCode {
                     &access_secret,
                 );
 
-                match rt.block_on(crate::chpp::request::players_request(
-                    data2,
-                    key2,
-                    Some(team_id),
-                )) {
+                match rt.block_on(client.players(data2, key2, Some(team_id))) {
                     Ok(players_data) => {
                         println!("Successfully retrieved players!");
                         let team_w_players = &players_data.Team;
@@ -18,22 +18,35 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-use crate::error::NutmegError;
 use crate::chpp::model::{
-    AvatarsData, HattrickData, LeagueDetailsData, MatchDetailsData, MatchLineupData,
-    MatchesArchiveData, MatchesData, Player, PlayersData, StaffListData, WorldDetails,
+    Achievements, AllianceDetails, AllianceSearchResults, ArenaDetails, AvatarsData, Bookmarks,
+    Challenges, CupMatches, CurrentBids, Economy, HattrickData, HofPlayers, LeagueDetailsData,
+    LeagueFixtures, LiveActionType, LiveData, ManagerCompendium, MatchDetailsData, MatchLineupData,
+    MatchesArchiveData, MatchesData, Player, PlayerEvents, PlayerTransfers, PlayersData,
+    RegionDetails, SearchResults, SearchType, StaffListData, Supporters, TeamTransfers,
+    TournamentDetails, TournamentList, TrainingEvents, TransferSearchCriteria,
+    TransferSearchResults, Translations, WorldCup, WorldDetails, WorldLanguages,
 };
 use crate::chpp::oauth::{OAuthData, SigningKey};
 use crate::chpp::request::{
-    league_details_request, match_details_request, match_lineup_request, matches_archive_request,
-    matches_request, player_details_request, players_request, team_details_request,
-    world_details_request,
+    http_client, league_details_request, match_details_request, match_lineup_request,
+    matches_archive_request, matches_request, player_details_request, players_request,
+    team_details_request, world_details_request, DEFAULT_REQUEST_TIMEOUT,
 };
+use crate::error::NutmegError;
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::time::Duration;
 
 #[async_trait]
 pub trait ChppClient: Send + Sync {
-    async fn world_details(&self, data: OAuthData, key: SigningKey) -> Result<WorldDetails, NutmegError>;
+    /// With `league_id` set, only that league is returned.
+    async fn world_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        league_id: Option<u32>,
+    ) -> Result<WorldDetails, NutmegError>;
 
     async fn team_details(
         &self,
@@ -89,8 +102,8 @@ pub trait ChppClient: Send + Sync {
         data: OAuthData,
         key: SigningKey,
         team_id: Option<u32>,
-        first_match_date: Option<String>,
-        last_match_date: Option<String>,
+        first_match_date: Option<NaiveDate>,
+        last_match_date: Option<NaiveDate>,
     ) -> Result<MatchesArchiveData, NutmegError>;
 
     async fn match_details(
@@ -109,20 +122,219 @@ pub trait ChppClient: Send + Sync {
         team_id: u32,
         source_system: &str,
     ) -> Result<MatchLineupData, NutmegError>;
+
+    async fn alliances(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        search_name: &str,
+    ) -> Result<AllianceSearchResults, NutmegError>;
+
+    async fn alliance_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        alliance_id: u32,
+    ) -> Result<AllianceDetails, NutmegError>;
+
+    async fn transfers_player(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        player_id: u32,
+    ) -> Result<PlayerTransfers, NutmegError>;
+
+    async fn player_events(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        player_id: u32,
+    ) -> Result<PlayerEvents, NutmegError>;
+
+    async fn current_bids(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+    ) -> Result<CurrentBids, NutmegError>;
+
+    async fn search(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        search_type: SearchType,
+        query: &str,
+    ) -> Result<SearchResults, NutmegError>;
+
+    async fn region_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        region_id: u32,
+    ) -> Result<RegionDetails, NutmegError>;
+
+    async fn world_languages(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+    ) -> Result<WorldLanguages, NutmegError>;
+
+    async fn manager_compendium(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        user_id: Option<u32>,
+    ) -> Result<ManagerCompendium, NutmegError>;
+
+    async fn cup_matches(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<CupMatches, NutmegError>;
+
+    async fn training_events(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        player_id: u32,
+    ) -> Result<TrainingEvents, NutmegError>;
+
+    async fn arena_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        arena_id: Option<u32>,
+    ) -> Result<ArenaDetails, NutmegError>;
+
+    async fn economy(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<Economy, NutmegError>;
+
+    async fn league_fixtures(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        league_level_unit_id: u32,
+        season: Option<u32>,
+    ) -> Result<LeagueFixtures, NutmegError>;
+
+    async fn transfers_team(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: u32,
+        page: u32,
+    ) -> Result<TeamTransfers, NutmegError>;
+
+    async fn translations(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        language_id: u32,
+    ) -> Result<Translations, NutmegError>;
+
+    async fn world_cup(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        cup_id: u32,
+        season: Option<u32>,
+    ) -> Result<WorldCup, NutmegError>;
+
+    async fn hof_players(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<HofPlayers, NutmegError>;
+
+    async fn bookmarks(&self, data: OAuthData, key: SigningKey) -> Result<Bookmarks, NutmegError>;
+
+    async fn challenges(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: u32,
+        is_weekend: bool,
+    ) -> Result<Challenges, NutmegError>;
+
+    async fn tournament_list(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        user_id: Option<u32>,
+    ) -> Result<TournamentList, NutmegError>;
+
+    async fn tournament_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        tournament_id: u32,
+    ) -> Result<TournamentDetails, NutmegError>;
+
+    async fn achievements(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        user_id: Option<u32>,
+    ) -> Result<Achievements, NutmegError>;
+
+    async fn supporters(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<Supporters, NutmegError>;
+
+    async fn live(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        match_ids: &[u32],
+        action_type: LiveActionType,
+    ) -> Result<LiveData, NutmegError>;
+
+    async fn transfer_search(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        criteria: TransferSearchCriteria,
+    ) -> Result<TransferSearchResults, NutmegError>;
 }
 
-pub struct HattrickClient;
+pub struct HattrickClient {
+    /// Shared by every request, so connections are pooled and each request
+    /// gets the client's timeout.
+    http: reqwest::Client,
+}
 
 impl HattrickClient {
     pub fn new() -> Self {
-        Self
+        Self::with_timeout(DEFAULT_REQUEST_TIMEOUT)
+    }
+
+    /// A client whose requests give up after `timeout`, so a stalled
+    /// connection surfaces as a `Network` error instead of hanging a sync.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        // Building only fails when the TLS backend cannot be initialised,
+        // where `reqwest::Client::new` would panic as well.
+        let http = http_client(timeout).expect("Failed to initialise the HTTP client");
+        Self { http }
     }
 }
 
 #[async_trait]
 impl ChppClient for HattrickClient {
-    async fn world_details(&self, data: OAuthData, key: SigningKey) -> Result<WorldDetails, NutmegError> {
-        world_details_request(data, key).await
+    async fn world_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        league_id: Option<u32>,
+    ) -> Result<WorldDetails, NutmegError> {
+        world_details_request(&self.http, data, key, league_id).await
     }
 
     async fn team_details(
@@ -131,7 +343,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         team_id: Option<u32>,
     ) -> Result<HattrickData, NutmegError> {
-        team_details_request(data, key, team_id).await
+        team_details_request(&self.http, data, key, team_id).await
     }
 
     async fn players(
@@ -140,7 +352,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         team_id: Option<u32>,
     ) -> Result<PlayersData, NutmegError> {
-        players_request(data, key, team_id).await
+        players_request(&self.http, data, key, team_id).await
     }
 
     async fn player_details(
@@ -149,7 +361,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         player_id: u32,
     ) -> Result<Player, NutmegError> {
-        player_details_request(data, key, player_id).await
+        player_details_request(&self.http, data, key, player_id).await
     }
 
     async fn avatars(
@@ -158,7 +370,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         team_id: Option<u32>,
     ) -> Result<AvatarsData, NutmegError> {
-        crate::chpp::request::avatars_request(data, key, team_id).await
+        crate::chpp::request::avatars_request(&self.http, data, key, team_id).await
     }
 
     async fn league_details(
@@ -167,7 +379,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         league_level_unit_id: u32,
     ) -> Result<LeagueDetailsData, NutmegError> {
-        league_details_request(data, key, league_level_unit_id).await
+        league_details_request(&self.http, data, key, league_level_unit_id).await
     }
 
     async fn matches(
@@ -176,7 +388,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         team_id: Option<u32>,
     ) -> Result<MatchesData, NutmegError> {
-        matches_request(data, key, team_id).await
+        matches_request(&self.http, data, key, team_id).await
     }
 
     async fn staff_list(
@@ -185,7 +397,7 @@ impl ChppClient for HattrickClient {
         key: SigningKey,
         team_id: Option<u32>,
     ) -> Result<StaffListData, NutmegError> {
-        crate::chpp::request::staff_list_request(data, key, team_id).await
+        crate::chpp::request::staff_list_request(&self.http, data, key, team_id).await
     }
 
     async fn matches_archive(
@@ -193,10 +405,18 @@ impl ChppClient for HattrickClient {
         data: OAuthData,
         key: SigningKey,
         team_id: Option<u32>,
-        first_match_date: Option<String>,
-        last_match_date: Option<String>,
+        first_match_date: Option<NaiveDate>,
+        last_match_date: Option<NaiveDate>,
     ) -> Result<MatchesArchiveData, NutmegError> {
-        matches_archive_request(data, key, team_id, first_match_date, last_match_date).await
+        matches_archive_request(
+            &self.http,
+            data,
+            key,
+            team_id,
+            first_match_date,
+            last_match_date,
+        )
+        .await
     }
 
     async fn match_details(
@@ -206,7 +426,7 @@ impl ChppClient for HattrickClient {
         match_id: u32,
         source_system: &str,
     ) -> Result<MatchDetailsData, NutmegError> {
-        match_details_request(data, key, match_id, source_system).await
+        match_details_request(&self.http, data, key, match_id, source_system).await
     }
 
     async fn match_lineup(
@@ -217,6 +437,277 @@ impl ChppClient for HattrickClient {
         team_id: u32,
         source_system: &str,
     ) -> Result<MatchLineupData, NutmegError> {
-        match_lineup_request(data, key, match_id, team_id, source_system).await
+        match_lineup_request(&self.http, data, key, match_id, team_id, source_system).await
+    }
+
+    async fn alliances(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        search_name: &str,
+    ) -> Result<AllianceSearchResults, NutmegError> {
+        crate::chpp::request::alliances_request(&self.http, data, key, search_name).await
+    }
+
+    async fn alliance_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        alliance_id: u32,
+    ) -> Result<AllianceDetails, NutmegError> {
+        crate::chpp::request::alliance_details_request(&self.http, data, key, alliance_id).await
+    }
+
+    async fn transfers_player(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        player_id: u32,
+    ) -> Result<PlayerTransfers, NutmegError> {
+        crate::chpp::request::transfers_player_request(&self.http, data, key, player_id).await
+    }
+
+    async fn player_events(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        player_id: u32,
+    ) -> Result<PlayerEvents, NutmegError> {
+        crate::chpp::request::player_events_request(&self.http, data, key, player_id).await
+    }
+
+    async fn current_bids(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+    ) -> Result<CurrentBids, NutmegError> {
+        crate::chpp::request::current_bids_request(&self.http, data, key).await
+    }
+
+    async fn search(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        search_type: SearchType,
+        query: &str,
+    ) -> Result<SearchResults, NutmegError> {
+        crate::chpp::request::search_request(&self.http, data, key, search_type, query).await
+    }
+
+    async fn region_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        region_id: u32,
+    ) -> Result<RegionDetails, NutmegError> {
+        crate::chpp::request::region_details_request(&self.http, data, key, region_id).await
+    }
+
+    async fn world_languages(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+    ) -> Result<WorldLanguages, NutmegError> {
+        crate::chpp::request::world_languages_request(&self.http, data, key).await
+    }
+
+    async fn manager_compendium(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        user_id: Option<u32>,
+    ) -> Result<ManagerCompendium, NutmegError> {
+        crate::chpp::request::manager_compendium_request(&self.http, data, key, user_id).await
+    }
+
+    async fn cup_matches(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<CupMatches, NutmegError> {
+        crate::chpp::request::cup_matches_request(&self.http, data, key, team_id).await
+    }
+
+    async fn training_events(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        player_id: u32,
+    ) -> Result<TrainingEvents, NutmegError> {
+        crate::chpp::request::training_events_request(&self.http, data, key, player_id).await
+    }
+
+    async fn arena_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        arena_id: Option<u32>,
+    ) -> Result<ArenaDetails, NutmegError> {
+        crate::chpp::request::arena_details_request(&self.http, data, key, arena_id).await
+    }
+
+    async fn economy(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<Economy, NutmegError> {
+        crate::chpp::request::economy_request(&self.http, data, key, team_id).await
+    }
+
+    async fn league_fixtures(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        league_level_unit_id: u32,
+        season: Option<u32>,
+    ) -> Result<LeagueFixtures, NutmegError> {
+        crate::chpp::request::league_fixtures_request(
+            &self.http,
+            data,
+            key,
+            league_level_unit_id,
+            season,
+        )
+        .await
+    }
+
+    async fn transfers_team(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: u32,
+        page: u32,
+    ) -> Result<TeamTransfers, NutmegError> {
+        crate::chpp::request::transfers_team_request(&self.http, data, key, team_id, page).await
+    }
+
+    async fn translations(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        language_id: u32,
+    ) -> Result<Translations, NutmegError> {
+        crate::chpp::request::translations_request(&self.http, data, key, language_id).await
+    }
+
+    async fn world_cup(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        cup_id: u32,
+        season: Option<u32>,
+    ) -> Result<WorldCup, NutmegError> {
+        crate::chpp::request::world_cup_request(&self.http, data, key, cup_id, season).await
+    }
+
+    async fn hof_players(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<HofPlayers, NutmegError> {
+        crate::chpp::request::hof_players_request(&self.http, data, key, team_id).await
+    }
+
+    async fn bookmarks(&self, data: OAuthData, key: SigningKey) -> Result<Bookmarks, NutmegError> {
+        crate::chpp::request::bookmarks_request(&self.http, data, key).await
+    }
+
+    async fn challenges(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: u32,
+        is_weekend: bool,
+    ) -> Result<Challenges, NutmegError> {
+        crate::chpp::request::challenges_request(&self.http, data, key, team_id, is_weekend).await
+    }
+
+    async fn tournament_list(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        user_id: Option<u32>,
+    ) -> Result<TournamentList, NutmegError> {
+        crate::chpp::request::tournament_list_request(&self.http, data, key, user_id).await
+    }
+
+    async fn tournament_details(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        tournament_id: u32,
+    ) -> Result<TournamentDetails, NutmegError> {
+        crate::chpp::request::tournament_details_request(&self.http, data, key, tournament_id).await
+    }
+
+    async fn achievements(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        user_id: Option<u32>,
+    ) -> Result<Achievements, NutmegError> {
+        crate::chpp::request::achievements_request(&self.http, data, key, user_id).await
+    }
+
+    async fn supporters(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        team_id: Option<u32>,
+    ) -> Result<Supporters, NutmegError> {
+        crate::chpp::request::supporters_request(&self.http, data, key, team_id).await
+    }
+
+    async fn live(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        match_ids: &[u32],
+        action_type: LiveActionType,
+    ) -> Result<LiveData, NutmegError> {
+        crate::chpp::request::live_request(&self.http, data, key, match_ids, action_type).await
+    }
+
+    async fn transfer_search(
+        &self,
+        data: OAuthData,
+        key: SigningKey,
+        criteria: TransferSearchCriteria,
+    ) -> Result<TransferSearchResults, NutmegError> {
+        crate::chpp::request::transfer_search_request(&self.http, data, key, criteria).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_with_timeout_applies_to_the_client_requests() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        // Accept the connection, then never answer.
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        });
+
+        let client = HattrickClient::with_timeout(Duration::from_millis(200));
+        let started = std::time::Instant::now();
+        let result = client
+            .http
+            .get(format!("http://{}/chppxml.ashx", addr))
+            .send()
+            .await;
+        let elapsed = started.elapsed();
+
+        assert!(result
+            .expect_err("a stalled request should not succeed")
+            .is_timeout());
+        assert!(elapsed >= Duration::from_millis(200), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
     }
 }
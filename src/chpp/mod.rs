@@ -22,7 +22,12 @@ static CHPP_OAUTH_REQUEST_TOKEN_URL: &str = "https://chpp.hattrick.org/oauth/req
 static CHPP_OAUTH_AUTH_URL: &str = "https://chpp.hattrick.org/oauth/authorize.aspx";
 static CHPP_OAUTH_ACCESS_TOKEN_URL: &str = "https://chpp.hattrick.org/oauth/access_token.ashx";
 static CHPP_URL: &str = "https://chpp.hattrick.org/chppxml.ashx";
-static NUTMEG_USER_AGENT: &str = "Nutmeg/v1.0";
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The `User-Agent` sent with CHPP requests, tracking the crate version.
+pub fn user_agent() -> String {
+    format!("Nutmeg/{}", VERSION)
+}
 
 //pub mod authenticator;
 pub mod client;
@@ -40,6 +45,27 @@ mod tests_teamid;
 pub use client::ChppClient;
 pub use oauth::create_oauth_context;
 pub use oauth::exchange_verification_code;
+pub use oauth::exchange_verification_code_async;
+pub use oauth::get_authorization_url_async;
 pub use oauth::get_request_token_url;
+pub use oauth::normalize_verification_code;
+pub use oauth::ChppScope;
 pub use oauth::OauthSettings;
 pub use retry::retry_with_default_config;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_agent_carries_dotted_version() {
+        let agent = user_agent();
+        let version = agent
+            .strip_prefix("Nutmeg/")
+            .expect("user agent should start with Nutmeg/");
+        let parts: Vec<&str> = version.split('.').collect();
+        assert!(parts.len() >= 2, "{}", agent);
+        assert!(parts[0].parse::<u32>().is_ok(), "{}", agent);
+        assert!(parts[1].parse::<u32>().is_ok(), "{}", agent);
+    }
+}
@@ -426,6 +426,28 @@ pub struct User {
     pub LastLoginDate: String,
     #[serde(deserialize_with = "deserialize_bool")]
     pub HasManagerLicense: bool,
+    #[serde(default)]
+    pub NationalTeams: Option<NationalTeams>,
+}
+
+/// A national team the user is on the staff of.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct NationalTeam {
+    pub NationalTeamID: u32,
+    pub NationalTeamName: Option<String>,
+    pub LeagueID: Option<u32>,
+    /// Staff role: 1 = coach, 2 = assistant.
+    pub NationalTeamStaffType: Option<u32>,
+}
+
+/// NT roles of a user; Hattrick sends a self-closing `<NationalTeams />`
+/// for users without any.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct NationalTeams {
+    #[serde(rename = "NationalTeam", default)]
+    pub NationalTeams: Vec<NationalTeam>,
 }
 
 #[allow(non_snake_case)]
@@ -456,6 +478,33 @@ pub struct League {
     pub LeagueSystemId: Option<u32>,
 }
 
+const HT_WEEKS_PER_SEASON: i64 = 16;
+
+/// The league's current season and week (1-16) at `now`.
+///
+/// `Season` and `MatchRound` describe the league as of `synced_at`, the
+/// time of the download they were saved by. From there the week moves on
+/// every Monday at midnight, Hattrick time, and the season after week 16.
+/// `None` without world details for the league.
+pub fn current_season_week(
+    league: &League,
+    synced_at: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<(u32, u32)> {
+    use crate::utils::hattrick_time::hattrick_week;
+
+    let (Some(season), Some(round)) = (league.Season, league.MatchRound) else {
+        return None;
+    };
+
+    let elapsed = (hattrick_week(now) - hattrick_week(synced_at)).max(0);
+    let weeks = (round as i64).clamp(1, HT_WEEKS_PER_SEASON) - 1 + elapsed;
+    let season = season as i64 + weeks / HT_WEEKS_PER_SEASON;
+    let week = weeks % HT_WEEKS_PER_SEASON + 1;
+
+    u32::try_from(season).ok().map(|s| (s, week as u32))
+}
+
 #[allow(non_snake_case)]
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct Country {
@@ -669,6 +718,36 @@ pub struct Player {
     pub LastMatch: Option<LastMatch>,
     #[serde(default, deserialize_with = "deserialize_empty_tag_is_none")]
     pub GenderID: Option<u32>,
+    /// Sale details, sent by playerdetails while the player is transfer listed.
+    #[serde(default)]
+    pub TransferDetails: Option<PlayerTransferDetails>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerTransferDetails {
+    #[serde(default)]
+    pub AskingPrice: Option<u32>,
+    /// End of the auction, `YYYY-MM-DD HH:MM:SS` in Hattrick time.
+    pub Deadline: String,
+    #[serde(default)]
+    pub HighestBid: Option<u32>,
+}
+
+// A player is identified by PlayerID alone: snapshots of the same player
+// differ in skills, TSI and so on, but are still the same player.
+impl PartialEq for Player {
+    fn eq(&self, other: &Self) -> bool {
+        self.PlayerID == other.PlayerID
+    }
+}
+
+impl Eq for Player {}
+
+impl std::hash::Hash for Player {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.PlayerID.hash(state);
+    }
 }
 
 impl Player {
@@ -715,8 +794,8 @@ impl Player {
                 if o.CareerHattricks.is_none() && self.CareerHattricks.is_some() {
                     o.CareerHattricks = self.CareerHattricks;
                 }
-                if o.CareerHattricks.is_none() && self.CareerHattricks.is_some() {
-                    o.CareerHattricks = self.CareerHattricks;
+                if o.CareerAssists.is_none() && self.CareerAssists.is_some() {
+                    o.CareerAssists = self.CareerAssists;
                 }
                 if o.Specialty.is_none() && self.Specialty.is_some() {
                     o.Specialty = self.Specialty;
@@ -727,10 +806,6 @@ impl Player {
                 if o.CountryID.is_none() && self.CountryID.is_some() {
                     o.CountryID = self.CountryID;
                 }
-                // Set country ID to native country ID if country ID is not present.
-                if o.CountryID.is_none() && o.NativeCountryID.is_some() {
-                    o.CountryID = o.NativeCountryID;
-                }
                 // National team stats
                 if o.Caps.is_none() && self.Caps.is_some() {
                     o.Caps = self.Caps;
@@ -774,15 +849,29 @@ impl Player {
                 if o.AssistsCurrentTeam.is_none() && self.AssistsCurrentTeam.is_some() {
                     o.AssistsCurrentTeam = self.AssistsCurrentTeam;
                 }
-                if o.CareerAssists.is_none() && self.CareerAssists.is_some() {
-                    o.CareerAssists = self.CareerAssists;
-                }
                 if o.GenderID.is_none() && self.GenderID.is_some() {
                     o.GenderID = self.GenderID;
                 }
+                if o.TransferDetails.is_none() && self.TransferDetails.is_some() {
+                    o.TransferDetails = self.TransferDetails.clone();
+                }
                 if o.NativeCountryFlag.is_none() && self.NativeCountryFlag.is_some() {
                     o.NativeCountryFlag = self.NativeCountryFlag.clone();
                 }
+                if o.Flag.is_none() && self.Flag.is_some() {
+                    o.Flag = self.Flag.clone();
+                }
+                if o.AvatarBlob.is_none() && self.AvatarBlob.is_some() {
+                    o.AvatarBlob = self.AvatarBlob.clone();
+                }
+                if o.PlayerSkills.is_none() && self.PlayerSkills.is_some() {
+                    o.PlayerSkills = self.PlayerSkills.clone();
+                }
+                // Set country ID to native country ID if country ID is not present.
+                // Done last so a native country ID from either source is used.
+                if o.CountryID.is_none() && o.NativeCountryID.is_some() {
+                    o.CountryID = o.NativeCountryID;
+                }
 
                 o
             }
@@ -792,6 +881,15 @@ impl Player {
             }
         }
     }
+
+    /// End of the player's auction, when transfer listed with a known
+    /// deadline. CHPP gives the deadline in Hattrick (Swedish) time.
+    pub fn transfer_deadline(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        if !self.TransferListed {
+            return None;
+        }
+        crate::utils::hattrick_time::parse_hattrick_time(&self.TransferDetails.as_ref()?.Deadline)
+    }
 }
 
 #[allow(non_snake_case)]
@@ -867,6 +965,13 @@ pub struct Team {
     #[serde(deserialize_with = "deserialize_option_bool", default)]
     pub PossibleToChallengeWeekend: Option<bool>,
     pub GenderID: Option<u8>,
+    pub Guestbook: Option<Guestbook>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Guestbook {
+    pub NumberOfGuestbookItems: u32,
 }
 
 #[allow(non_snake_case)]
@@ -1174,6 +1279,7 @@ pub struct ChppErrorResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::load_fixture;
     use serde_xml_rs::from_str;
 
     #[test]
@@ -1435,72 +1541,9 @@ mod tests {
     }
     #[test]
     fn test_deserialize_players_data() {
-        let xml = r#"
-        <HattrickData>
-            <Team>
-                <TeamID>3000</TeamID>
-                <TeamName>Test Team C</TeamName>
-                <ShortTeamName>Testers C</ShortTeamName>
-                <PlayerList>
-                    <Player>
-                        <PlayerID>40001</PlayerID>
-                        <FirstName>John</FirstName>
-                        <LastName>Doe</LastName>
-                        <PlayerNumber>1</PlayerNumber>
-                        <Age>20</Age>
-                        <AgeDays>10</AgeDays>
-                        <TSI>1500</TSI>
-                        <PlayerForm>6</PlayerForm>
-                        <Statement></Statement>
-                        <Experience>3</Experience>
-                        <Loyalty>5</Loyalty>
-                        <MotherClubBonus>True</MotherClubBonus>
-                        <Leadership>5</Leadership>
-                        <Salary>1000</Salary>
-                        <IsAbroad>False</IsAbroad>
-                        <Agreeability>3</Agreeability>
-                        <Aggressiveness>3</Aggressiveness>
-                        <Honesty>3</Honesty>
-                        <LeagueGoals>0</LeagueGoals>
-                        <CupGoals>0</CupGoals>
-                        <FriendliesGoals>0</FriendliesGoals>
-                        <CareerGoals>0</CareerGoals>
-                        <CareerHattricks>0</CareerHattricks>
-                        <Specialty>0</Specialty>
-                        <TransferListed>False</TransferListed>
-                        <NationalTeamID>0</NationalTeamID>
-                        <CountryID>1</CountryID>
-                        <Caps>0</Caps>
-                        <CapsU20>0</CapsU20>
-                        <Cards>0</Cards>
-                        <InjuryLevel>-1</InjuryLevel>
-                    </Player>
-                    <Player>
-                        <PlayerID>40002</PlayerID>
-                        <FirstName>Jane</FirstName>
-                        <LastName>Smith</LastName>
-                        <PlayerNumber>10</PlayerNumber>
-                        <Age>25</Age>
-                        <TSI>5000</TSI>
-                        <PlayerForm>7</PlayerForm>
-                        <Experience>5</Experience>
-                        <Loyalty>10</Loyalty>
-                        <MotherClubBonus>False</MotherClubBonus>
-                        <Leadership>3</Leadership>
-                        <Salary>5000</Salary>
-                        <IsAbroad>True</IsAbroad>
-                        <Agreeability>3</Agreeability>
-                        <Aggressiveness>3</Aggressiveness>
-                        <Honesty>3</Honesty>
-                        <TransferListed>True</TransferListed>
-                        <CountryID>2</CountryID>
-                    </Player>
-                </PlayerList>
-            </Team>
-        </HattrickData>
-        "#;
+        let xml = load_fixture("players.xml");
 
-        let res: PlayersData = from_str(xml).expect("Failed to deserialize players data");
+        let res: PlayersData = from_str(&xml).expect("Failed to deserialize players data");
         assert_eq!(res.Team.TeamID, "3000");
         assert_eq!(res.Team.TeamName, "Test Team C");
 
@@ -1526,225 +1569,65 @@ mod tests {
 
     #[test]
     fn test_teams_with_announcements_deserialization() {
-        let xml = r#"<HattrickData>
-      <FileName>teamdetails.xml</FileName>
-      <Version>3.7</Version>
-      <UserID>6992417</UserID>
-      <FetchedDate>2026-02-01 18:12:26</FetchedDate>
-      <User>
-        <UserID>6992417</UserID>
-        <Language>
-          <LanguageID>2</LanguageID>
-          <LanguageName>English (UK)</LanguageName>
-        </Language>
-        <SupporterTier>gold</SupporterTier>
-        <Loginname>tychobrailleur</Loginname>
-        <Name>HIDDEN</Name>
-        <ICQ></ICQ>
-        <SignupDate>2019-10-24 20:19:39</SignupDate>
-        <ActivationDate>2019-10-24 20:20:00</ActivationDate>
-        <LastLoginDate>2026-02-01 18:04:54</LastLoginDate>
-        <HasManagerLicense>True</HasManagerLicense>
-        <NationalTeams />
-      </User>
-      <Teams>
-        <Team>
-          <TeamID>1000</TeamID>
-          <TeamName>Test Team A</TeamName>
-          <ShortTeamName>A</ShortTeamName>
-          <IsPrimaryClub>True</IsPrimaryClub>
-          <FoundedDate>2019-10-24 20:20:00</FoundedDate>
-          <IsDeactivated>False</IsDeactivated>
-          <Arena>
-            <ArenaID>1001</ArenaID>
-            <ArenaName>Armadillo Arena</ArenaName>
-          </Arena>
-          <League>
-            <LeagueID>21</LeagueID>
-            <LeagueName>Ireland</LeagueName>
-          </League>
-          <Country>
-            <CountryID>16</CountryID>
-            <CountryName>Ireland</CountryName>
-          </Country>
-          <Region>
-            <RegionID>627</RegionID>
-            <RegionName>Wicklow</RegionName>
-          </Region>
-          <Trainer>
-            <PlayerID>456835710</PlayerID>
-          </Trainer>
-          <HomePage></HomePage>
-          <DressURI>//res.hattrick.org/kits/29/283/2827/2826328/matchKitSmall.png</DressURI>
-          <DressAlternateURI>//res.hattrick.org/kits/29/283/2827/2826330/matchKitSmall.png</DressAlternateURI>
-          <LeagueLevelUnit>
-            <LeagueLevelUnitID>8806</LeagueLevelUnitID>
-            <LeagueLevelUnitName>IV.32</LeagueLevelUnitName>
-            <LeagueLevel>4</LeagueLevel>
-          </LeagueLevelUnit>
-          <BotStatus>
-            <IsBot>False</IsBot>
-          </BotStatus>
-          <Cup />
-
-          <PowerRating>
-            <GlobalRanking>45701</GlobalRanking>
-            <LeagueRanking>148</LeagueRanking>
-            <RegionRanking>7</RegionRanking>
-            <PowerRating>936</PowerRating>
-          </PowerRating>
-          <FriendlyTeamID />
-          <NumberOfVictories />
-          <NumberOfUndefeated />
-          <TeamRank />
-          <Fanclub>
-            <FanclubID>673567</FanclubID>
-            <FanclubName>Anteaters Ultras</FanclubName>
-            <FanclubSize>2765</FanclubSize>
-          </Fanclub>
-          <LogoURL>//res.hattrick.org/teamlogo/3/29/281/280747/280747.png</LogoURL>
-          <Guestbook>
-            <NumberOfGuestbookItems>0</NumberOfGuestbookItems>
-          </Guestbook>
-          <PressAnnouncement>
-            <Subject>The Bogdan Controversy</Subject>
-            <Body>There was a certain amount of stupor within the Pangolins ranks when it was discovered in training camp today that there actually was a player whose first name was “Bogdan” on the team.
-
-    “Come on lads” the player involved, [playerid=434668244]Tărtăreanu[/playerid], said “I have been here for 6 bloody seasons, how does it come as a surprise now??”
-
-    The management team, still in shock, and busy sifting through piles of papers, refused to comment.</Body>
-            <SendDate>2023-10-10 18:13:00</SendDate>
-          </PressAnnouncement>
-          <TeamColors>
-            <BackgroundColor>288032</BackgroundColor>
-            <Color>ffffff</Color>
-          </TeamColors>
-          <YouthTeamID>0</YouthTeamID>
-          <YouthTeamName></YouthTeamName>
-          <NumberOfVisits>0</NumberOfVisits>
-          <TrophyList>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>76</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8806</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.32</LeagueLevelUnitName>
-              <GainedDate>2024-09-07 01:51:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>75</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8806</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.32</LeagueLevelUnitName>
-              <GainedDate>2024-05-18 01:52:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>74</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8806</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.32</LeagueLevelUnitName>
-              <GainedDate>2024-01-27 01:51:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>73</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8806</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.32</LeagueLevelUnitName>
-              <GainedDate>2023-10-07 01:51:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>70</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8826</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.52</LeagueLevelUnitName>
-              <GainedDate>2022-11-05 01:52:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>68</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8810</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.36</LeagueLevelUnitName>
-              <GainedDate>2022-03-26 01:51:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>67</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8810</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.36</LeagueLevelUnitName>
-              <GainedDate>2021-12-04 01:51:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>65</TrophySeason>
-              <LeagueLevel>4</LeagueLevel>
-              <LeagueLevelUnitId>8789</LeagueLevelUnitId>
-              <LeagueLevelUnitName>IV.15</LeagueLevelUnitName>
-              <GainedDate>2021-04-24 02:18:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/iv.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-            <Trophy>
-              <TrophyTypeId>17</TrophyTypeId>
-              <TrophySeason>61</TrophySeason>
-              <LeagueLevel>5</LeagueLevel>
-              <LeagueLevelUnitId>34587</LeagueLevelUnitId>
-              <LeagueLevelUnitName>V.5</LeagueLevelUnitName>
-              <GainedDate>2020-02-01 02:44:00</GainedDate>
-              <ImageUrl>/App_Themes/Standard/Images/Trophies/v.png</ImageUrl>
-              <CupLeagueLevel></CupLeagueLevel>
-              <CupLevel></CupLevel>
-              <CupLevelIndex></CupLevelIndex>
-            </Trophy>
-          </TrophyList>
-          <PossibleToChallengeMidweek>False</PossibleToChallengeMidweek>
-          <PossibleToChallengeWeekend>False</PossibleToChallengeWeekend>
-        </Team>
-      </Teams>
-    </HattrickData>"#;
-
-        let res: HattrickData = from_str(xml).expect("Failed to deserialize HattrickData data");
+        let xml = load_fixture("teamdetails_announcements.xml");
+
+        let res: HattrickData = from_str(&xml).expect("Failed to deserialize HattrickData data");
         let team_data = &res.Teams.Teams[0];
 
         assert_eq!(team_data.TeamID, "1000");
         assert_eq!(team_data.TeamName, "Test Team A");
         assert_eq!(team_data.IsPrimaryClub, Some(true));
         assert_eq!(team_data.IsDeactivated, Some(false));
+
+        assert_eq!(
+            team_data
+                .Guestbook
+                .as_ref()
+                .map(|g| g.NumberOfGuestbookItems),
+            Some(0)
+        );
+
+        let national_teams = res
+            .User
+            .NationalTeams
+            .expect("Empty NationalTeams is present");
+        assert!(national_teams.NationalTeams.is_empty());
+    }
+
+    #[test]
+    fn test_user_national_teams_deserialization() {
+        let xml = r#"<User>
+            <UserID>100</UserID>
+            <Name>Coach</Name>
+            <Loginname>coach</Loginname>
+            <SupporterTier>none</SupporterTier>
+            <SignupDate>2010-01-01</SignupDate>
+            <ActivationDate>2010-01-01</ActivationDate>
+            <LastLoginDate>2010-01-01</LastLoginDate>
+            <HasManagerLicense>True</HasManagerLicense>
+            <NationalTeams>
+                <NationalTeam Index="0">
+                    <NationalTeamStaffType>1</NationalTeamStaffType>
+                    <NationalTeamID>3015</NationalTeamID>
+                    <NationalTeamName>Ireland</NationalTeamName>
+                    <LeagueID>21</LeagueID>
+                </NationalTeam>
+                <NationalTeam Index="1">
+                    <NationalTeamStaffType>2</NationalTeamStaffType>
+                    <NationalTeamID>3115</NationalTeamID>
+                    <NationalTeamName>Ireland U21</NationalTeamName>
+                    <LeagueID>21</LeagueID>
+                </NationalTeam>
+            </NationalTeams>
+        </User>"#;
+
+        let user: User = from_str(xml).expect("Failed to deserialize User");
+        let teams = user.NationalTeams.unwrap().NationalTeams;
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams[0].NationalTeamID, 3015);
+        assert_eq!(teams[0].NationalTeamStaffType, Some(1));
+        assert_eq!(teams[1].NationalTeamName.as_deref(), Some("Ireland U21"));
+        assert_eq!(teams[1].LeagueID, Some(21));
     }
 
     // Leaving this as sanity check, this what I used to debug the empty tag issue...
@@ -1822,6 +1705,7 @@ mod tests {
             MatchesCurrentTeam: None,
             GoalsCurrentTeam: None,
             AssistsCurrentTeam: None,
+            TransferDetails: None,
             CareerAssists: None,
             GenderID: Some(1),
             NativeCountryFlag: None,
@@ -1884,6 +1768,7 @@ mod tests {
             MatchesCurrentTeam: None,
             GoalsCurrentTeam: None,
             AssistsCurrentTeam: None,
+            TransferDetails: None,
             CareerAssists: None,
             GenderID: Some(1),
             NativeCountryFlag: None,
@@ -1907,6 +1792,85 @@ mod tests {
         assert_eq!(merged.CapsU20, Some(10)); // From basic
     }
 
+    #[test]
+    fn test_merge_player_data_fills_every_optional_field() {
+        let basic = Player {
+            PlayerID: 1,
+            PlayerNumber: Some(7),
+            AgeDays: Some(12),
+            Statement: Some("Hi".to_string()),
+            ReferencePlayerID: Some(2),
+            LeagueGoals: Some(1),
+            CupGoals: Some(2),
+            FriendliesGoals: Some(3),
+            CareerGoals: Some(4),
+            CareerHattricks: Some(5),
+            CareerAssists: Some(6),
+            Specialty: Some(1),
+            NationalTeamID: Some(3000),
+            Caps: Some(8),
+            CapsU20: Some(9),
+            Cards: Some(1),
+            InjuryLevel: Some(0),
+            AvatarBlob: Some(vec![1, 2, 3]),
+            Flag: Some("🇮🇪".to_string()),
+            ArrivalDate: Some("2024-01-01 00:00:00".to_string()),
+            PlayerCategoryId: Some(6),
+            MotherClub: Some(MotherClub::default()),
+            NativeCountryID: Some(5),
+            NativeLeagueID: Some(21),
+            NativeLeagueName: Some("Ireland".to_string()),
+            NativeCountryFlag: Some("🇮🇪".to_string()),
+            MatchesCurrentTeam: Some(10),
+            GoalsCurrentTeam: Some(11),
+            AssistsCurrentTeam: Some(12),
+            LastMatch: Some(LastMatch::default()),
+            GenderID: Some(2),
+            TransferDetails: Some(PlayerTransferDetails::default()),
+            ..Default::default()
+        };
+        let detailed = Player {
+            PlayerID: 1,
+            ..Default::default()
+        };
+
+        let merged = basic.merge_player_data(Some(detailed));
+
+        assert_eq!(merged.PlayerNumber, Some(7));
+        assert_eq!(merged.AgeDays, Some(12));
+        assert_eq!(merged.Statement.as_deref(), Some("Hi"));
+        assert_eq!(merged.ReferencePlayerID, Some(2));
+        assert_eq!(merged.LeagueGoals, Some(1));
+        assert_eq!(merged.CupGoals, Some(2));
+        assert_eq!(merged.FriendliesGoals, Some(3));
+        assert_eq!(merged.CareerGoals, Some(4));
+        assert_eq!(merged.CareerHattricks, Some(5));
+        assert_eq!(merged.CareerAssists, Some(6));
+        assert_eq!(merged.Specialty, Some(1));
+        assert_eq!(merged.NationalTeamID, Some(3000));
+        assert_eq!(merged.Caps, Some(8));
+        assert_eq!(merged.CapsU20, Some(9));
+        assert_eq!(merged.Cards, Some(1));
+        assert_eq!(merged.InjuryLevel, Some(0));
+        assert_eq!(merged.AvatarBlob, Some(vec![1, 2, 3]));
+        assert_eq!(merged.Flag.as_deref(), Some("🇮🇪"));
+        assert_eq!(merged.ArrivalDate.as_deref(), Some("2024-01-01 00:00:00"));
+        assert_eq!(merged.PlayerCategoryId, Some(6));
+        assert!(merged.MotherClub.is_some());
+        assert_eq!(merged.NativeCountryID, Some(5));
+        assert_eq!(merged.NativeLeagueID, Some(21));
+        assert_eq!(merged.NativeLeagueName.as_deref(), Some("Ireland"));
+        assert_eq!(merged.NativeCountryFlag.as_deref(), Some("🇮🇪"));
+        assert_eq!(merged.MatchesCurrentTeam, Some(10));
+        assert_eq!(merged.GoalsCurrentTeam, Some(11));
+        assert_eq!(merged.AssistsCurrentTeam, Some(12));
+        assert!(merged.LastMatch.is_some());
+        assert_eq!(merged.GenderID, Some(2));
+        assert!(merged.TransferDetails.is_some());
+        // No CountryID anywhere, so the native country ID is used
+        assert_eq!(merged.CountryID, Some(5));
+    }
+
     #[test]
     fn test_merge_player_data_without_detailed() {
         use crate::chpp::model::Player;
@@ -1957,6 +1921,7 @@ mod tests {
             MatchesCurrentTeam: None,
             GoalsCurrentTeam: None,
             AssistsCurrentTeam: None,
+            TransferDetails: None,
             CareerAssists: None,
             GenderID: Some(1),
             NativeCountryFlag: None,
@@ -1972,130 +1937,2853 @@ mod tests {
         assert_eq!(merged.Statement, basic.Statement);
         assert!(merged.PlayerSkills.is_none());
     }
-}
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct Layer {
-    #[serde(rename = "x")]
-    pub x: i32,
-    #[serde(rename = "y")]
-    pub y: i32,
-    #[serde(rename = "Image")]
-    pub image: String,
-}
+    #[test]
+    fn test_player_identity_is_player_id() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        let earlier = Player {
+            PlayerID: 42,
+            TSI: 1200,
+            ..Default::default()
+        };
+        let later = Player {
+            PlayerID: 42,
+            TSI: 1500,
+            ..Default::default()
+        };
+        assert_eq!(earlier, later);
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct Avatar {
-    #[serde(rename = "BackgroundImage")]
-    pub background_image: String,
-    #[serde(rename = "Layer", default)]
-    pub layers: Vec<Layer>,
-}
+        let hash = |p: &Player| {
+            let mut hasher = DefaultHasher::new();
+            p.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&earlier), hash(&later));
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct AvatarPlayer {
-    #[serde(rename = "PlayerID")]
-    pub player_id: u32,
-    #[serde(rename = "Avatar")]
-    pub avatar: Avatar,
-}
+        let other = Player {
+            PlayerID: 43,
+            TSI: 1200,
+            ..Default::default()
+        };
+        assert_ne!(earlier, other);
 
-#[derive(Deserialize, Serialize, Debug, Clone, Default)]
-pub struct AvatarsPlayers {
-    #[serde(rename = "Player", default)]
-    pub players: Vec<AvatarPlayer>,
-}
+        let unique: HashSet<Player> = [earlier, later, other].into_iter().collect();
+        assert_eq!(unique.len(), 2);
+    }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct AvatarsTeam {
-    #[serde(rename = "TeamId")]
-    pub team_id: u32,
-    #[serde(rename = "Players", default)]
-    pub players: AvatarsPlayers,
-}
+    #[test]
+    fn test_transfer_deadline_of_listed_player() {
+        let xml = r#"
+        <TransferDetails>
+            <AskingPrice>250000</AskingPrice>
+            <Deadline>2024-03-03 18:30:00</Deadline>
+            <HighestBid>0</HighestBid>
+        </TransferDetails>
+        "#;
+        let details: PlayerTransferDetails =
+            from_str(xml).expect("Failed to deserialize transfer details");
+        assert_eq!(details.AskingPrice, Some(250000));
+
+        let listed = Player {
+            TransferListed: true,
+            TransferDetails: Some(details),
+            ..Default::default()
+        };
+        // 18:30 CET
+        assert_eq!(
+            listed.transfer_deadline().map(|d| d.to_rfc3339()),
+            Some("2024-03-03T17:30:00+00:00".to_string())
+        );
+
+        let summer = Player {
+            TransferDetails: Some(PlayerTransferDetails {
+                Deadline: "2024-07-14 18:30:00".to_string(),
+                ..Default::default()
+            }),
+            ..listed.clone()
+        };
+        // 18:30 CEST
+        assert_eq!(
+            summer.transfer_deadline().map(|d| d.to_rfc3339()),
+            Some("2024-07-14T16:30:00+00:00".to_string())
+        );
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct AvatarsData {
-    #[serde(rename = "FileName")]
-    pub file_name: String,
-    #[serde(rename = "Version")]
-    pub version: String,
-    #[serde(rename = "UserID")]
-    pub user_id: u32,
-    #[serde(rename = "Team")]
-    pub team: AvatarsTeam,
-}
+        let delisted = Player {
+            TransferListed: false,
+            ..listed.clone()
+        };
+        assert_eq!(delisted.transfer_deadline(), None);
+    }
 
-#[allow(non_snake_case)]
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct Staff {
-    pub StaffId: u32,
-    pub StaffType: u32,
-    pub StaffLevel: u32,
-    pub HiredDate: String,
-    pub Cost: u32,
-    pub Name: String,
-    #[serde(default)]
-    pub HofPlayerId: Option<u32>,
-}
+    #[test]
+    fn test_deserialize_alliance_search() {
+        let xml = r#"
+            <HattrickData>
+                <Alliances>
+                    <Alliance>
+                        <AllianceID>1001</AllianceID>
+                        <AllianceName>Irish Football Federation</AllianceName>
+                        <AllianceDescription>For Irish managers</AllianceDescription>
+                    </Alliance>
+                    <Alliance>
+                        <AllianceID>1002</AllianceID>
+                        <AllianceName>Irish Youth Academy</AllianceName>
+                        <AllianceDescription />
+                    </Alliance>
+                </Alliances>
+                <PageIndex>0</PageIndex>
+                <Pages>1</Pages>
+            </HattrickData>
+        "#;
+        let results: AllianceSearchResults = from_str(xml).expect("Failed to parse alliances");
+        assert_eq!(results.Alliances.Alliances.len(), 2);
+        assert_eq!(results.Alliances.Alliances[0].AllianceID, 1001);
+        assert_eq!(
+            results.Alliances.Alliances[1].AllianceName,
+            "Irish Youth Academy"
+        );
+        assert_eq!(results.Pages, Some(1));
+    }
 
-#[allow(non_snake_case)]
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct StaffTrainer {
-    pub TrainerId: u32,
-    pub Name: String,
-    #[serde(default)]
-    pub Age: Option<u32>,
-    #[serde(default)]
-    pub AgeDays: Option<u32>,
-    #[serde(default)]
-    pub ContractDate: Option<String>,
-    #[serde(default)]
-    pub Cost: Option<u32>,
-    #[serde(default)]
-    pub CountryID: Option<u32>,
-    #[serde(default)]
-    pub TrainerType: Option<u32>,
-    #[serde(default)]
-    pub Leadership: Option<u32>,
-    #[serde(default)]
-    pub TrainerSkillLevel: Option<u32>,
-    #[serde(default)]
-    pub TrainerStatus: Option<u32>,
-}
+    #[test]
+    fn test_deserialize_alliance_details() {
+        let xml = r#"
+            <HattrickData>
+                <Alliance>
+                    <AllianceID>1001</AllianceID>
+                    <AllianceName>Irish Football Federation</AllianceName>
+                    <Abbreviation>IFF</Abbreviation>
+                    <Description>For Irish managers</Description>
+                    <TopRole>President</TopRole>
+                    <TopUserID>4242</TopUserID>
+                    <TopLoginname>prez</TopLoginname>
+                    <CreationDate>2005-03-01 10:00:00</CreationDate>
+                    <NumberOfMembers>356</NumberOfMembers>
+                    <UserRole>
+                        <RoleID>7</RoleID>
+                        <RoleName>Member</RoleName>
+                    </UserRole>
+                </Alliance>
+            </HattrickData>
+        "#;
+        let details: AllianceDetails = from_str(xml).expect("Failed to parse alliance details");
+        let alliance = details.Alliance;
+        assert_eq!(alliance.AllianceID, 1001);
+        assert_eq!(alliance.Abbreviation.as_deref(), Some("IFF"));
+        assert_eq!(alliance.NumberOfMembers, Some(356));
+        let role = alliance.UserRole.expect("Expected a user role");
+        assert_eq!(role.RoleID, 7);
+        assert_eq!(role.RoleName, "Member");
+    }
 
-#[allow(non_snake_case)]
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct StaffMembersWrapper {
-    #[serde(rename = "Staff", default)]
-    pub staff: Vec<Staff>,
-}
+    #[test]
+    fn test_deserialize_player_transfers() {
+        let xml = r#"
+            <HattrickData>
+                <Transfers>
+                    <Player>
+                        <PlayerID>123456</PlayerID>
+                    </Player>
+                    <Transfer>
+                        <TransferID>9001</TransferID>
+                        <Deadline>2023-04-02 19:45:00</Deadline>
+                        <Buyer>
+                            <BuyerTeamID>54321</BuyerTeamID>
+                            <BuyerTeamName>Nutmeg FC</BuyerTeamName>
+                        </Buyer>
+                        <Seller>
+                            <SellerTeamID>11111</SellerTeamID>
+                            <SellerTeamName>Old Club</SellerTeamName>
+                        </Seller>
+                        <Price>1250000</Price>
+                        <TSI>4200</TSI>
+                    </Transfer>
+                    <Transfer>
+                        <TransferID>8000</TransferID>
+                        <Deadline>2021-11-20 12:00:00</Deadline>
+                        <Buyer>
+                            <BuyerTeamID>11111</BuyerTeamID>
+                            <BuyerTeamName>Old Club</BuyerTeamName>
+                        </Buyer>
+                        <Seller>
+                            <SellerTeamID>22222</SellerTeamID>
+                            <SellerTeamName>Youth Club</SellerTeamName>
+                        </Seller>
+                        <Price>8500</Price>
+                    </Transfer>
+                </Transfers>
+            </HattrickData>
+        "#;
+        let data: PlayerTransfers = from_str(xml).expect("Failed to parse player transfers");
+        let list = data.Transfers;
+        assert_eq!(list.Player.map(|p| p.PlayerID), Some(123456));
+        assert_eq!(list.Transfers.len(), 2);
+
+        let latest = &list.Transfers[0];
+        assert_eq!(latest.TransferID, 9001);
+        assert_eq!(latest.Buyer.BuyerTeamName, "Nutmeg FC");
+        assert_eq!(latest.Seller.SellerTeamID, 11111);
+        assert_eq!(latest.Price, 1_250_000);
+        assert_eq!(latest.TSI, Some(4200));
+
+        assert_eq!(list.Transfers[1].Price, 8500);
+        assert_eq!(list.Transfers[1].TSI, None);
+    }
 
-#[allow(non_snake_case)]
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct StaffList {
-    #[serde(default)]
-    pub Trainer: Option<StaffTrainer>,
-    #[serde(default)]
-    pub StaffMembers: Option<StaffMembersWrapper>,
-    #[serde(default)]
-    pub TotalStaffMembers: Option<u32>,
-    #[serde(default)]
-    pub TotalCost: Option<u32>,
-}
+    #[test]
+    fn test_deserialize_player_events() {
+        let xml = r#"
+            <HattrickData>
+                <Player>
+                    <PlayerID>123456</PlayerID>
+                    <PlayerEvents>
+                        <PlayerEvent>
+                            <EventDate>2024-02-10 14:00:00</EventDate>
+                            <PlayerEventTypeID>5</PlayerEventTypeID>
+                            <EventText>Scored a hattrick against [teamid=11111]</EventText>
+                        </PlayerEvent>
+                        <PlayerEvent>
+                            <EventDate>2023-08-01 09:30:00</EventDate>
+                            <PlayerEventTypeID>1</PlayerEventTypeID>
+                            <EventText>Signed from [teamid=22222]</EventText>
+                        </PlayerEvent>
+                    </PlayerEvents>
+                </Player>
+            </HattrickData>
+        "#;
+        let data: PlayerEvents = from_str(xml).expect("Failed to parse player events");
+        assert_eq!(data.Player.PlayerID, 123456);
+        let events = &data.Player.PlayerEvents.Events;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].EventDate, "2024-02-10 14:00:00");
+        assert_eq!(events[0].PlayerEventTypeID, 5);
+        assert_eq!(
+            events[0].EventText,
+            "Scored a hattrick against [teamid=11111]"
+        );
+        assert_eq!(events[1].PlayerEventTypeID, 1);
+    }
 
-#[allow(non_snake_case)]
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct StaffListData {
-    #[serde(rename = "FileName")]
-    pub file_name: String,
-    #[serde(rename = "Version")]
-    pub version: String,
-    #[serde(rename = "UserID")]
-    pub user_id: u32,
-    #[serde(rename = "FetchedDate", default)]
-    pub fetched_date: Option<String>,
-    #[serde(rename = "StaffList")]
-    pub staff_list: StaffList,
+    #[test]
+    fn test_deserialize_current_bids() {
+        let xml = r#"
+            <HattrickData>
+                <TeamID>54321</TeamID>
+                <BiddingPlayers>
+                    <BidItem>
+                        <TransferID>7001</TransferID>
+                        <PlayerID>111</PlayerID>
+                        <PlayerName>Bob Buyme</PlayerName>
+                        <HighestBid>150000</HighestBid>
+                        <Deadline>2024-03-02 20:00:00</Deadline>
+                        <BidderTeam>
+                            <TeamID>54321</TeamID>
+                            <TeamName>Nutmeg FC</TeamName>
+                        </BidderTeam>
+                    </BidItem>
+                </BiddingPlayers>
+                <SellingPlayers>
+                    <BidItem>
+                        <TransferID>7002</TransferID>
+                        <PlayerID>222</PlayerID>
+                        <PlayerName>Sam Sellme</PlayerName>
+                        <HighestBid>42000</HighestBid>
+                        <Deadline>2024-03-03 18:30:00</Deadline>
+                        <BidderTeam>
+                            <TeamID>99999</TeamID>
+                            <TeamName>Rival United</TeamName>
+                        </BidderTeam>
+                    </BidItem>
+                    <BidItem>
+                        <PlayerID>333</PlayerID>
+                        <PlayerName>No Bids</PlayerName>
+                        <Deadline>2024-03-04 10:00:00</Deadline>
+                    </BidItem>
+                </SellingPlayers>
+            </HattrickData>
+        "#;
+        let bids: CurrentBids = from_str(xml).expect("Failed to parse current bids");
+        assert_eq!(bids.TeamID, Some(54321));
+
+        assert_eq!(bids.BiddingPlayers.Items.len(), 1);
+        let buying = &bids.BiddingPlayers.Items[0];
+        assert_eq!(buying.PlayerID, 111);
+        assert_eq!(buying.HighestBid, Some(150000));
+        assert_eq!(
+            buying.BidderTeam.as_ref().map(|t| t.TeamName.as_str()),
+            Some("Nutmeg FC")
+        );
+
+        assert_eq!(bids.SellingPlayers.Items.len(), 2);
+        let selling = &bids.SellingPlayers.Items[0];
+        assert_eq!(selling.Deadline, "2024-03-03 18:30:00");
+        assert_eq!(selling.BidderTeam.as_ref().map(|t| t.TeamID), Some(99999));
+        let unbid = &bids.SellingPlayers.Items[1];
+        assert_eq!(unbid.HighestBid, None);
+        assert!(unbid.BidderTeam.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_team_search() {
+        let xml = r#"
+            <HattrickData>
+                <SearchParams>
+                    <SearchType>4</SearchType>
+                    <SearchString>Nutmeg</SearchString>
+                </SearchParams>
+                <PageIndex>0</PageIndex>
+                <Pages>1</Pages>
+                <SearchResults>
+                    <Result>
+                        <ResultID>54321</ResultID>
+                        <ResultName>Nutmeg FC</ResultName>
+                        <LeagueID>21</LeagueID>
+                        <LeagueName>Ireland</LeagueName>
+                    </Result>
+                    <Result>
+                        <ResultID>54322</ResultID>
+                        <ResultName>Nutmeg Rovers</ResultName>
+                        <LeagueID>5</LeagueID>
+                        <LeagueName>France</LeagueName>
+                    </Result>
+                </SearchResults>
+            </HattrickData>
+        "#;
+        let results: SearchResults = from_str(xml).expect("Failed to parse team search");
+        assert_eq!(
+            results.SearchParams.and_then(|p| p.SearchType),
+            Some(SearchType::Team.code())
+        );
+        let rows = &results.SearchResults.Results;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].ResultID, 54321);
+        assert_eq!(rows[0].ResultName, "Nutmeg FC");
+        assert_eq!(rows[1].LeagueName.as_deref(), Some("France"));
+    }
+
+    #[test]
+    fn test_deserialize_player_search() {
+        let xml = r#"
+            <HattrickData>
+                <SearchParams>
+                    <SearchType>0</SearchType>
+                    <SearchString>Doe</SearchString>
+                </SearchParams>
+                <PageIndex>0</PageIndex>
+                <Pages>1</Pages>
+                <SearchResults>
+                    <Result>
+                        <ResultID>123456</ResultID>
+                        <ResultName>John Doe</ResultName>
+                    </Result>
+                </SearchResults>
+            </HattrickData>
+        "#;
+        let results: SearchResults = from_str(xml).expect("Failed to parse player search");
+        let rows = &results.SearchResults.Results;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].ResultID, 123456);
+        assert_eq!(rows[0].ResultName, "John Doe");
+        assert_eq!(rows[0].LeagueID, None);
+    }
+
+    #[test]
+    fn test_deserialize_empty_search() {
+        let xml = r#"
+            <HattrickData>
+                <SearchParams>
+                    <SearchType>4</SearchType>
+                    <SearchString>zzzz</SearchString>
+                </SearchParams>
+                <PageIndex>0</PageIndex>
+                <Pages>0</Pages>
+                <SearchResults />
+            </HattrickData>
+        "#;
+        let results: SearchResults = from_str(xml).expect("Failed to parse empty search");
+        assert!(results.SearchResults.Results.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_region_details() {
+        let xml = r#"
+            <HattrickData>
+                <League>
+                    <LeagueID>21</LeagueID>
+                    <LeagueName>Ireland</LeagueName>
+                    <Region>
+                        <RegionID>1040</RegionID>
+                        <RegionName>Dublin</RegionName>
+                        <WeatherID>0</WeatherID>
+                        <TomorrowWeatherID>3</TomorrowWeatherID>
+                        <NumberOfUsers>412</NumberOfUsers>
+                        <NumberOfOnline>17</NumberOfOnline>
+                    </Region>
+                </League>
+            </HattrickData>
+        "#;
+        let details: RegionDetails = from_str(xml).expect("Failed to parse region details");
+        assert_eq!(details.League.LeagueID, 21);
+        let region = details.League.Region;
+        assert_eq!(region.RegionID, 1040);
+        assert_eq!(region.RegionName, "Dublin");
+        assert_eq!(region.WeatherID, Some(0));
+        assert_eq!(region.TomorrowWeatherID, Some(3));
+        assert_eq!(region.NumberOfUsers, Some(412));
+        assert_eq!(region.NumberOfOnline, Some(17));
+    }
+
+    #[test]
+    fn test_deserialize_world_languages() {
+        let xml = r#"
+            <HattrickData>
+                <FileName>worldlanguages.xml</FileName>
+                <Version>1.2</Version>
+                <LanguageList>
+                    <Language>
+                        <LanguageID>1</LanguageID>
+                        <LanguageName>Svenska</LanguageName>
+                    </Language>
+                    <Language>
+                        <LanguageID>2</LanguageID>
+                        <LanguageName>English (UK)</LanguageName>
+                    </Language>
+                    <Language>
+                        <LanguageID>5</LanguageID>
+                        <LanguageName>Français</LanguageName>
+                    </Language>
+                </LanguageList>
+            </HattrickData>
+        "#;
+        let data: WorldLanguages = from_str(xml).expect("Failed to parse world languages");
+        let languages = &data.LanguageList.Languages;
+        assert_eq!(languages.len(), 3);
+        assert_eq!(languages[0].LanguageID, 1);
+        assert_eq!(languages[1].LanguageName, "English (UK)");
+        assert_eq!(languages[2].LanguageName, "Français");
+    }
+
+    #[test]
+    fn test_deserialize_manager_compendium() {
+        let xml = r#"
+            <HattrickData>
+                <FileName>managercompendium.xml</FileName>
+                <Version>1.6</Version>
+                <Manager>
+                    <UserId>13537902</UserId>
+                    <Loginname>tycho</Loginname>
+                    <SupporterTier>gold</SupporterTier>
+                    <LastLogins>
+                        <LoginTime>2026-03-22 19:03:11</LoginTime>
+                        <LoginTime>2026-03-21 08:45:02</LoginTime>
+                    </LastLogins>
+                    <Teams>
+                        <Team>
+                            <TeamId>54321</TeamId>
+                            <TeamName>Nutmeg FC</TeamName>
+                            <League>
+                                <LeagueId>21</LeagueId>
+                                <LeagueName>Ireland</LeagueName>
+                                <Season>92</Season>
+                            </League>
+                            <LeagueLevelUnit>
+                                <LeagueLevelUnitId>3001</LeagueLevelUnitId>
+                                <LeagueLevelUnitName>IV.12</LeagueLevelUnitName>
+                                <LeagueLevel>4</LeagueLevel>
+                            </LeagueLevelUnit>
+                        </Team>
+                        <Team>
+                            <TeamId>54322</TeamId>
+                            <TeamName>Nutmeg Reserves</TeamName>
+                        </Team>
+                    </Teams>
+                    <NationalTeamCoach>
+                        <NationalTeam>
+                            <NationalTeamId>3021</NationalTeamId>
+                            <NationalTeamName>Ireland U21</NationalTeamName>
+                        </NationalTeam>
+                    </NationalTeamCoach>
+                    <Avatar>
+                        <BackgroundImage>/Img/Avatar/backgrounds/bg_blue.png</BackgroundImage>
+                        <Layer x="9" y="10">
+                            <Image>/Img/Avatar/bodies/bd1_s.png</Image>
+                        </Layer>
+                    </Avatar>
+                </Manager>
+            </HattrickData>
+        "#;
+        let compendium: ManagerCompendium =
+            from_str(xml).expect("Failed to parse manager compendium");
+        let manager = compendium.Manager;
+        assert_eq!(manager.UserId, 13537902);
+        assert_eq!(manager.Loginname, "tycho");
+        assert_eq!(manager.SupporterTier, SupporterTier::Gold);
+        assert_eq!(manager.last_login(), Some("2026-03-22 19:03:11"));
+        assert_eq!(manager.team_ids(), vec![54321, 54322]);
+
+        let unit = manager.Teams.Teams[0].LeagueLevelUnit.as_ref().unwrap();
+        assert_eq!(unit.LeagueLevelUnitId, 3001);
+        assert!(manager.Teams.Teams[1].League.is_none());
+
+        assert_eq!(manager.NationalTeamCoach.NationalTeams.len(), 1);
+        assert_eq!(
+            manager.NationalTeamCoach.NationalTeams[0].NationalTeamName,
+            "Ireland U21"
+        );
+        assert!(manager.NationalTeamAssistant.NationalTeams.is_empty());
+
+        let avatar = manager.Avatar.expect("Missing avatar");
+        assert_eq!(avatar.layers.len(), 1);
+        assert_eq!(avatar.layers[0].x, 9);
+    }
+
+    #[test]
+    fn test_deserialize_cup_matches() {
+        let xml = r#"
+            <HattrickData>
+                <FileName>cupmatches.xml</FileName>
+                <Version>1.4</Version>
+                <Cup>
+                    <CupID>137</CupID>
+                    <CupName>Irish Cup</CupName>
+                    <Season>92</Season>
+                    <CupRound>4</CupRound>
+                    <MatchList>
+                        <Match>
+                            <MatchID>735112233</MatchID>
+                            <MatchDate>2026-03-17 20:00:00</MatchDate>
+                            <HomeTeam>
+                                <HomeTeamId>54321</HomeTeamId>
+                                <HomeTeamName>Nutmeg FC</HomeTeamName>
+                            </HomeTeam>
+                            <AwayTeam>
+                                <AwayTeamId>98765</AwayTeamId>
+                                <AwayTeamName>Galway United</AwayTeamName>
+                            </AwayTeam>
+                            <MatchResult>
+                                <HomeGoals>3</HomeGoals>
+                                <AwayGoals>1</AwayGoals>
+                            </MatchResult>
+                        </Match>
+                        <Match>
+                            <MatchID>735112234</MatchID>
+                            <MatchDate>2026-03-24 20:00:00</MatchDate>
+                            <HomeTeam>
+                                <HomeTeamId>11111</HomeTeamId>
+                                <HomeTeamName>Cork City</HomeTeamName>
+                            </HomeTeam>
+                            <AwayTeam>
+                                <AwayTeamId>54321</AwayTeamId>
+                                <AwayTeamName>Nutmeg FC</AwayTeamName>
+                            </AwayTeam>
+                        </Match>
+                    </MatchList>
+                </Cup>
+            </HattrickData>
+        "#;
+        let cup_matches: CupMatches = from_str(xml).expect("Failed to parse cup matches");
+        let cup = cup_matches.Cup;
+        assert_eq!(cup.CupID, 137);
+        assert_eq!(cup.CupName.as_deref(), Some("Irish Cup"));
+        assert_eq!(cup.CupRound, Some(4));
+        assert_eq!(cup.MatchList.Matches.len(), 2);
+
+        let played = &cup.MatchList.Matches[0];
+        assert_eq!(played.HomeTeam.HomeTeamId, 54321);
+        assert_eq!(played.AwayTeam.AwayTeamName, "Galway United");
+        let result = played.MatchResult.as_ref().expect("Missing result");
+        assert_eq!(result.HomeGoals, Some(3));
+        assert_eq!(result.AwayGoals, Some(1));
+
+        assert!(cup.MatchList.Matches[1].MatchResult.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_training_events() {
+        let xml = r#"
+            <HattrickData>
+                <FileName>trainingevents.xml</FileName>
+                <Version>1.3</Version>
+                <Player>
+                    <PlayerID>400123456</PlayerID>
+                    <TrainingEvents>
+                        <TrainingEvent>
+                            <EventDate>2026-01-08 06:00:00</EventDate>
+                            <SkillID>4</SkillID>
+                            <OldLevel>6</OldLevel>
+                            <NewLevel>7</NewLevel>
+                            <Season>91</Season>
+                            <MatchRound>14</MatchRound>
+                        </TrainingEvent>
+                        <TrainingEvent>
+                            <EventDate>2026-02-12 06:00:00</EventDate>
+                            <SkillID>4</SkillID>
+                            <OldLevel>7</OldLevel>
+                            <NewLevel>8</NewLevel>
+                            <Season>92</Season>
+                            <MatchRound>2</MatchRound>
+                        </TrainingEvent>
+                        <TrainingEvent>
+                            <EventDate>2026-02-12 06:00:00</EventDate>
+                            <SkillID>8</SkillID>
+                            <OldLevel>5</OldLevel>
+                            <NewLevel>6</NewLevel>
+                        </TrainingEvent>
+                    </TrainingEvents>
+                </Player>
+            </HattrickData>
+        "#;
+        let events: TrainingEvents = from_str(xml).expect("Failed to parse training events");
+        assert_eq!(events.Player.PlayerID, 400123456);
+        let list = &events.Player.TrainingEvents.Events;
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].SkillID, 4);
+        assert_eq!(list[0].OldLevel, 6);
+        assert_eq!(list[0].NewLevel, 7);
+        assert_eq!(list[0].Season, Some(91));
+        assert_eq!(list[1].NewLevel, 8);
+        assert_eq!(list[2].SkillID, 8);
+        assert_eq!(list[2].MatchRound, None);
+    }
+
+    #[test]
+    fn test_deserialize_arena_details() {
+        let xml = r#"
+            <HattrickData>
+                <FileName>arenadetails.xml</FileName>
+                <Version>1.7</Version>
+                <Arena>
+                    <ArenaID>54321</ArenaID>
+                    <ArenaName>Nutmeg Park</ArenaName>
+                    <Team>
+                        <TeamID>54321</TeamID>
+                        <TeamName>Nutmeg FC</TeamName>
+                    </Team>
+                    <CurrentCapacity>
+                        <RebuiltDate Available="True">2025-11-02 10:00:00</RebuiltDate>
+                        <Terraces>9000</Terraces>
+                        <Basic>3500</Basic>
+                        <Roof>1200</Roof>
+                        <VIP>300</VIP>
+                        <Total>14000</Total>
+                    </CurrentCapacity>
+                    <ExpandedCapacity Available="True">
+                        <ExpansionDate>2026-03-30 10:00:00</ExpansionDate>
+                        <Terraces>1000</Terraces>
+                        <Basic>0</Basic>
+                        <Roof>500</Roof>
+                        <VIP>0</VIP>
+                        <Total>1500</Total>
+                    </ExpandedCapacity>
+                </Arena>
+            </HattrickData>
+        "#;
+        let details: ArenaDetails = from_str(xml).expect("Failed to parse arena details");
+        let arena = &details.Arena;
+        assert_eq!(arena.ArenaID, 54321);
+        assert_eq!(arena.ArenaName, "Nutmeg Park");
+        assert_eq!(arena.Team.as_ref().unwrap().TeamName, "Nutmeg FC");
+        assert_eq!(arena.CurrentCapacity.Terraces, 9000);
+        assert_eq!(arena.CurrentCapacity.VIP, 300);
+        assert!(arena.is_expanding());
+        let expanded = arena.ExpandedCapacity.as_ref().unwrap();
+        assert_eq!(
+            expanded.ExpansionDate.as_deref(),
+            Some("2026-03-30 10:00:00")
+        );
+        assert_eq!(expanded.total(), 1500);
+        assert_eq!(total_capacity(&details), 14000);
+    }
+
+    #[test]
+    fn test_total_capacity() {
+        let mut details = ArenaDetails::default();
+        assert_eq!(total_capacity(&details), 0);
+        assert!(!details.Arena.is_expanding());
+
+        details.Arena.CurrentCapacity = ArenaCapacity {
+            Terraces: 12000,
+            Basic: 0,
+            Roof: 2500,
+            VIP: 450,
+            ExpansionDate: None,
+        };
+        assert_eq!(total_capacity(&details), 14950);
+
+        details.Arena.ExpandedCapacity = Some(ArenaCapacity::default());
+        assert!(!details.Arena.is_expanding());
+    }
+
+    #[test]
+    fn test_deserialize_matches_archive() {
+        let xml = load_fixture("matchesarchive.xml");
+        let res: MatchesArchiveData =
+            from_str(&xml).expect("Failed to deserialize matches archive");
+
+        assert_eq!(res.Team.TeamID, "1000");
+        assert_eq!(
+            res.Team.FirstMatchDate.as_deref(),
+            Some("2026-01-05 00:00:00")
+        );
+        let matches = &res.Team.MatchList.Matches;
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].MatchID, 700001);
+        assert_eq!(matches[0].HomeTeam.HomeTeamID, "1000");
+        assert_eq!(matches[0].HomeGoals, Some(2));
+        assert_eq!(matches[1].MatchType, 3);
+        assert_eq!(matches[1].AwayTeam.AwayTeamName, "Test Team A");
+        assert_eq!(matches[1].CupLevel, Some(1));
+    }
+
+    #[test]
+    fn test_deserialize_league_fixtures() {
+        let xml = load_fixture("leaguefixtures.xml");
+        let res: LeagueFixtures = from_str(&xml).expect("Failed to deserialize league fixtures");
+
+        assert_eq!(res.LeagueLevelUnitID, 3001);
+        assert_eq!(res.LeagueLevelUnitName.as_deref(), Some("IV.12"));
+        assert_eq!(res.Season, 92);
+        // Eight teams play each other twice: 14 rounds of 4 matches.
+        assert_eq!(res.Matches.len(), 56);
+        assert!(res.Matches.iter().all(|m| (1..=14).contains(&m.MatchRound)));
+
+        let first = &res.Matches[0];
+        assert_eq!(first.MatchRound, 1);
+        assert_eq!(first.HomeTeam.HomeTeamID, 1000);
+        assert_eq!(first.AwayTeam.AwayTeamName, "Test Team H");
+        assert_eq!(first.HomeGoals, Some(2));
+
+        let last = res.Matches.last().unwrap();
+        assert_eq!(last.MatchRound, 14);
+        assert_eq!(last.HomeGoals, None);
+        assert_eq!(last.AwayGoals, None);
+    }
+
+    #[test]
+    fn test_deserialize_team_transfers() {
+        let xml = load_fixture("transfersteam.xml");
+        let data: TeamTransfers = from_str(&xml).expect("Failed to parse team transfers");
+        assert_eq!(data.Team.TeamID, 54321);
+        assert_eq!(data.Stats.as_ref().map(|s| s.NumberOfSales), Some(2));
+
+        let list = &data.Transfers;
+        assert_eq!(list.PageIndex, 0);
+        assert_eq!(list.Pages, 2);
+        assert_eq!(list.Transfers.len(), 4);
+
+        let sale = &list.Transfers[0];
+        assert_eq!(sale.TransferID, 400120);
+        assert_eq!(sale.TransferType, TransferType::Sell);
+        assert_eq!(sale.Player.PlayerName, "Liam Byrne");
+        assert_eq!(sale.Buyer.BuyerTeamName, "Rival United");
+        assert_eq!(sale.Price, 2_100_000);
+        assert_eq!(sale.TSI, Some(18230));
+
+        assert_eq!(list.Transfers[1].TransferType, TransferType::Buy);
+        assert_eq!(list.Transfers[3].TSI, None);
+    }
+
+    #[test]
+    fn test_net_transfer_balance() {
+        let transfer = |kind: TransferType, price: u32| Transfer {
+            TransferType: kind,
+            Price: price,
+            ..Default::default()
+        };
+        assert_eq!(net_transfer_balance(&[]), 0);
+        let transfers = vec![
+            transfer(TransferType::Sell, 2_100_000),
+            transfer(TransferType::Buy, 950_000),
+            transfer(TransferType::Buy, 300_000),
+            transfer(TransferType::Sell, 210_000),
+        ];
+        assert_eq!(net_transfer_balance(&transfers), 1_060_000);
+        assert_eq!(
+            net_transfer_balance(&[transfer(TransferType::Buy, 5_000)]),
+            -5_000
+        );
+    }
+
+    #[test]
+    fn test_deserialize_translations() {
+        let xml = load_fixture("translations.xml");
+        let data: Translations = from_str(&xml).expect("Failed to parse translations");
+        let texts = &data.Texts;
+        assert_eq!(texts.LanguageID, 5);
+        assert_eq!(texts.LanguageName, "Français");
+
+        assert_eq!(texts.SkillLevels.Levels.len(), 21);
+        assert_eq!(texts.SkillLevels.Levels[0].Value, 0);
+        assert_eq!(texts.SkillLevels.Levels[0].Text, "inexistant");
+        assert_eq!(texts.SkillLevels.Levels[20].Text, "divin");
+
+        let support = texts.PlayerSpecialties.Items.last().unwrap();
+        assert_eq!(support.Value, 8);
+        assert_eq!(support.Text, "Soutien");
+
+        assert_eq!(texts.MatchPositions.Items.len(), 14);
+        assert_eq!(texts.MatchPositions.Items[0].Value, 100);
+        assert_eq!(texts.MatchPositions.Items[0].Text, "Gardien");
+    }
+
+    #[test]
+    fn test_deserialize_world_cup_groups() {
+        let xml = load_fixture("worldcup.xml");
+        let wc: WorldCup = from_str(&xml).expect("Failed to parse world cup");
+        assert_eq!(wc.CupID, 137);
+        assert_eq!(wc.Season, 91);
+        assert_eq!(wc.MatchRound, Some(2));
+        assert_eq!(wc.groups(), vec![101, 102]);
+
+        let teams = &wc.WorldCupScores.Teams;
+        assert_eq!(teams.len(), 8);
+        assert_eq!(teams[0].TeamName, "Ireland");
+        assert_eq!(teams[0].CupSeriesUnitName, "Group A");
+        assert_eq!(teams[0].Points, 6);
+
+        let matches = &wc.Matches.Matches;
+        assert_eq!(matches.len(), 12);
+        assert_eq!(matches[0].CupSeriesUnitID, 101);
+        assert_eq!(matches[0].HomeTeam.HomeTeamName, "Ireland");
+        assert_eq!(matches[0].HomeGoals, Some(2));
+        let unplayed = matches.iter().filter(|m| m.HomeGoals.is_none()).count();
+        assert_eq!(unplayed, 4);
+    }
+
+    #[test]
+    fn test_group_standings_sorted_by_points() {
+        let xml = load_fixture("worldcup.xml");
+        let wc: WorldCup = from_str(&xml).expect("Failed to parse world cup");
+        assert_eq!(
+            group_standings(&wc, 101),
+            vec![(3001, 6), (3004, 4), (3002, 1), (3003, 0)]
+        );
+        // Sverige and Canada are level on points; Canada's goal difference is better
+        assert_eq!(
+            group_standings(&wc, 102),
+            vec![(3011, 6), (3013, 3), (3014, 1), (3012, 1)]
+        );
+        assert!(group_standings(&wc, 999).is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_hof_players() {
+        let xml = load_fixture("hofplayers.xml");
+        let hof: HofPlayers = from_str(&xml).expect("Failed to parse hof players");
+        assert_eq!(hof.TeamID, 54321);
+
+        let players = &hof.PlayerList.Players;
+        assert_eq!(players.len(), 2);
+        let keeper = &players[0];
+        assert_eq!(keeper.PlayerId, 310001);
+        assert_eq!(keeper.NickName.as_deref(), Some("The Wall"));
+        assert_eq!(keeper.HofDate, "2025-06-14 08:00:00");
+        assert_eq!(keeper.HofAge, Some(37));
+        assert_eq!(keeper.ExpertType, 1);
+        assert_eq!(keeper.KeeperSkill, Some(17));
+        assert_eq!(keeper.SetPiecesSkill, Some(14));
+
+        assert_eq!(players[1].NickName, None);
+        assert_eq!(players[1].PlaymakerSkill, Some(16));
+    }
+
+    #[test]
+    fn test_deserialize_bookmarks_of_mixed_types() {
+        let xml = load_fixture("bookmarks.xml");
+        let data: Bookmarks = from_str(&xml).expect("Failed to parse bookmarks");
+        let bookmarks = &data.BookmarkList.Bookmarks;
+        assert_eq!(bookmarks.len(), 4);
+
+        let types: Vec<BookmarkType> = bookmarks.iter().map(|b| b.bookmark_type()).collect();
+        assert_eq!(
+            types,
+            vec![
+                BookmarkType::Team,
+                BookmarkType::Player,
+                BookmarkType::Match,
+                BookmarkType::League
+            ]
+        );
+
+        let team = &bookmarks[0];
+        assert_eq!(team.ObjectID, 99999);
+        assert_eq!(team.Text, "Rival United");
+        assert_eq!(team.Text2, None);
+        assert_eq!(team.Comment.as_deref(), Some("Next cup opponent"));
+
+        let player = &bookmarks[1];
+        assert_eq!(player.ObjectID, 470002);
+        assert_eq!(player.Text2.as_deref(), Some("Calcio Verde"));
+        assert_eq!(player.ObjectID2, Some(88888));
+        assert_eq!(player.Comment, None);
+
+        assert_eq!(bookmarks[2].ObjectID, 700123);
+        assert_eq!(BookmarkType::from_id(42), BookmarkType::Other(42));
+    }
+
+    #[test]
+    fn test_deserialize_challenges() {
+        let xml = load_fixture("challenges.xml");
+        let data: Challenges = from_str(&xml).expect("Failed to parse challenges");
+        assert_eq!(data.Team.TeamID, 54321);
+
+        let sent = &data.Team.ChallengesByMe.Challenges;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].Opponent.TeamName, "Rival United");
+        assert_eq!(
+            sent[0].Arena.as_ref().map(|a| a.ArenaName.as_str()),
+            Some("Rival Park")
+        );
+        assert!(sent[0].IsAgreed);
+
+        let offers = &data.Team.OffersByOthers.Challenges;
+        assert_eq!(offers.len(), 2);
+        assert_eq!(offers[0].FriendlyType, 1);
+        assert!(!offers[0].IsAgreed);
+        assert!(offers[1].Arena.is_none());
+
+        assert_eq!(data.arranged().map(|c| c.TrainingMatchID), Some(81001));
+    }
+
+    #[test]
+    fn test_can_challenge_reads_team_flags() {
+        let team = Team {
+            PossibleToChallengeMidweek: Some(true),
+            PossibleToChallengeWeekend: Some(false),
+            ..Default::default()
+        };
+        assert!(can_challenge(&team, false));
+        assert!(!can_challenge(&team, true));
+
+        let unknown = Team::default();
+        assert!(!can_challenge(&unknown, false));
+        assert!(!can_challenge(&unknown, true));
+    }
+
+    #[test]
+    fn test_deserialize_tournament_list() {
+        let xml = load_fixture("tournamentlist.xml");
+        let data: TournamentList = from_str(&xml).expect("Failed to parse tournamentlist");
+        let tournaments = &data.TournamentList.Tournaments;
+        assert_eq!(tournaments.len(), 2);
+
+        let league = &tournaments[0];
+        assert_eq!(league.TournamentId, 52001);
+        assert_eq!(league.Name, "Friday Night League");
+        assert_eq!(league.TournamentType, 3);
+        assert_eq!(league.Season, 91);
+        assert!(league.IsMatchesOngoing);
+        assert_eq!(league.Creator.as_ref().unwrap().Loginname, "organiser");
+
+        let cup = &tournaments[1];
+        assert_eq!(cup.TournamentType, 4);
+        assert_eq!(cup.NumberOfGroups, 4);
+        assert!(!cup.IsMatchesOngoing);
+    }
+
+    #[test]
+    fn test_deserialize_tournament_details() {
+        let xml = load_fixture("tournamentdetails.xml");
+        let data: TournamentDetails = from_str(&xml).expect("Failed to parse tournamentdetails");
+        let t = &data.Tournament;
+        assert_eq!(t.TournamentId, 52001);
+        assert_eq!(t.Name, "Friday Night League");
+        assert_eq!(t.Season, 91);
+        assert_eq!(t.NumberOfTeams, 8);
+        assert_eq!(t.LastMatchRound, Some(7));
+        assert_eq!(t.NextMatchRoundDate.as_deref(), Some("2026-03-13 20:00:00"));
+    }
+
+    #[test]
+    fn test_deserialize_achievements() {
+        let xml = load_fixture("achievements.xml");
+        let data: Achievements = from_str(&xml).expect("Failed to parse achievements");
+        assert_eq!(data.UserID, 1234567);
+        assert_eq!(data.MaxPoints, Some(3290));
+
+        let list = &data.AchievementList.Achievements;
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].AchievementTypeID, 1);
+        assert_eq!(list[0].CategoryID, 1);
+        assert_eq!(list[0].Points, 50);
+        assert_eq!(list[0].EventDate, "2025-11-22 16:45:00");
+        assert!(!list[0].MultiLevel);
+        assert!(list[1].MultiLevel);
+        assert_eq!(list[1].NumberOfEvents, Some(3));
+    }
+
+    #[test]
+    fn test_deserialize_supporters_both_directions() {
+        let xml = load_fixture("supporters.xml");
+        let data: Supporters = from_str(&xml).expect("Failed to parse supporters");
+        assert_eq!(data.Team.as_ref().unwrap().TeamId, 54321);
+
+        let supported = &data.SupportedTeams.as_ref().unwrap().Teams;
+        assert_eq!(supported.len(), 2);
+        assert_eq!(supported[0].TeamId, 60001);
+        assert_eq!(supported[0].LoginName, "neighbour");
+        assert_eq!(supported[1].LeagueLevelUnitName.as_deref(), Some("III.3"));
+        assert_eq!(data.supported_count(), 2);
+
+        let supporters = &data.MySupporters.as_ref().unwrap().Teams;
+        assert_eq!(supporters.len(), 1);
+        assert_eq!(supporters[0].TeamName, "Tiny Athletic");
+        assert_eq!(data.supporter_count(), 1);
+
+        assert_eq!(Supporters::default().supporter_count(), 0);
+    }
+
+    #[test]
+    fn test_deserialize_live_match_in_progress() {
+        let xml = load_fixture("live.xml");
+        let data: LiveData = from_str(&xml).expect("Failed to parse live");
+        assert_eq!(data.MatchList.Matches.len(), 1);
+
+        let m = &data.MatchList.Matches[0];
+        assert_eq!(m.MatchID, 700123);
+        assert_eq!(m.HomeTeam.HomeTeamName, "Nutmeg FC");
+        assert_eq!((m.HomeGoals, m.AwayGoals), (Some(1), Some(1)));
+        assert_eq!(m.NextEventMinute, Some(41));
+
+        let events = m.events();
+        assert_eq!(events.len(), 3);
+        let minutes: Vec<u32> = events.iter().map(|e| e.Minute).collect();
+        assert_eq!(minutes, vec![0, 12, 33]);
+        assert_eq!(events[1].Index, Some(1));
+        assert_eq!(events[1].EventTypeID, 101);
+        assert_eq!(events[1].SubjectTeamID, Some(54321));
+        assert!(events[2].EventText.contains("equaliser"));
+    }
+
+    #[test]
+    fn test_live_action_type_params() {
+        assert_eq!(LiveActionType::ViewAll.as_param(), "viewAll");
+        assert_eq!(LiveActionType::AddMatch.as_param(), "addMatch");
+        assert!(!LiveActionType::ViewNew.is_subscription_change());
+        assert!(LiveActionType::ClearAll.is_subscription_change());
+    }
+
+    #[test]
+    fn test_deserialize_transfer_search_results() {
+        let xml = load_fixture("transfersearch.xml");
+        let data: TransferSearchResults = from_str(&xml).expect("Failed to parse transfersearch");
+        assert_eq!(data.ItemCount, 2);
+        assert_eq!(data.PageSize, 25);
+
+        let results = &data.TransferResults.Results;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].PlayerId, 481001);
+        assert_eq!(results[0].LastName, "Brennan");
+        assert_eq!(results[0].AskingPrice, 450000);
+        assert_eq!(results[0].Deadline, "2026-03-11 18:30:00");
+        assert_eq!(results[0].Details.as_ref().unwrap().Specialty, Some(2));
+        assert_eq!(results[1].NickName.as_deref(), Some("Nico"));
+        assert_eq!(results[1].HighestBid, Some(0));
+    }
+
+    #[test]
+    fn test_transfer_search_criteria_query_params() {
+        use crate::rating::types::PlayerSkill;
+
+        let criteria = TransferSearchCriteria::new(17, 21)
+            .min_skill(PlayerSkill::Playmaking, 7)
+            .min_skill(PlayerSkill::Form, 6)
+            .min_skill(PlayerSkill::Passing, 5)
+            .min_skill(PlayerSkill::Playmaking, 8)
+            .specialty(1)
+            .price_range(None, Some(500000));
+
+        let expected: Vec<(String, String)> = [
+            ("ageMin", "17"),
+            ("ageDaysMin", "0"),
+            ("ageMax", "21"),
+            ("ageDaysMax", "111"),
+            ("skillType1", "8"),
+            ("minSkillValue1", "8"),
+            ("maxSkillValue1", "20"),
+            ("skillType2", "7"),
+            ("minSkillValue2", "5"),
+            ("maxSkillValue2", "20"),
+            ("specialty", "1"),
+            ("priceMax", "500000"),
+            ("pageIndex", "0"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        assert_eq!(criteria.query_params(), expected);
+    }
+
+    #[test]
+    fn test_current_season_week() {
+        use chrono::{TimeZone, Utc};
+
+        let ireland = League {
+            LeagueID: 21,
+            Season: Some(76),
+            SeasonOffset: Some(-17),
+            MatchRound: Some(7),
+            ..Default::default()
+        };
+        // Synced on a Friday.
+        let synced = Utc.with_ymd_and_hms(2026, 1, 30, 12, 0, 0).unwrap();
+        assert_eq!(current_season_week(&ireland, synced, synced), Some((76, 7)));
+
+        // The week rolls over at midnight Swedish time on Monday.
+        let sunday = Utc.with_ymd_and_hms(2026, 2, 1, 22, 59, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2026, 2, 1, 23, 0, 0).unwrap();
+        assert_eq!(current_season_week(&ireland, synced, sunday), Some((76, 7)));
+        assert_eq!(current_season_week(&ireland, synced, monday), Some((76, 8)));
+
+        // In summer time the week starts an hour earlier in UTC.
+        let summer_sync = Utc.with_ymd_and_hms(2026, 7, 10, 12, 0, 0).unwrap();
+        let summer_sunday = Utc.with_ymd_and_hms(2026, 7, 12, 21, 59, 0).unwrap();
+        let summer_monday = Utc.with_ymd_and_hms(2026, 7, 12, 22, 0, 0).unwrap();
+        assert_eq!(
+            current_season_week(&ireland, summer_sync, summer_sunday),
+            Some((76, 7))
+        );
+        assert_eq!(
+            current_season_week(&ireland, summer_sync, summer_monday),
+            Some((76, 8))
+        );
+
+        // Ten weeks on, week 7 is week 1 of the next season.
+        let later = synced + chrono::Duration::weeks(10);
+        assert_eq!(current_season_week(&ireland, synced, later), Some((77, 1)));
+    }
+
+    #[test]
+    fn test_current_season_week_without_world_details() {
+        let at = chrono::Utc::now();
+        let league = League {
+            LeagueID: 1,
+            ..Default::default()
+        };
+        assert_eq!(current_season_week(&league, at, at), None);
+
+        let no_round = League {
+            Season: Some(93),
+            ..league.clone()
+        };
+        assert_eq!(current_season_week(&no_round, at, at), None);
+    }
+
+    #[test]
+    fn test_deserialize_economy() {
+        let xml = r#"
+            <HattrickData>
+                <FileName>economy.xml</FileName>
+                <Version>1.4</Version>
+                <Team>
+                    <TeamID>54321</TeamID>
+                    <TeamName>Nutmeg FC</TeamName>
+                    <Cash>1523400</Cash>
+                    <ExpectedCash>1601200</ExpectedCash>
+                    <SponsorsPopularity Available="True">5</SponsorsPopularity>
+                    <SupportersPopularity Available="True">6</SupportersPopularity>
+                    <FanClubSize>1842</FanClubSize>
+                    <IncomeSpectators>412000</IncomeSpectators>
+                    <IncomeSponsors>255000</IncomeSponsors>
+                    <IncomeSum>690000</IncomeSum>
+                    <CostsPlayers>380000</CostsPlayers>
+                    <CostsSum>612200</CostsSum>
+                    <ExpectedWeeksTotal>77800</ExpectedWeeksTotal>
+                </Team>
+            </HattrickData>
+        "#;
+        let economy: Economy = from_str(xml).expect("Failed to parse economy");
+        let team = &economy.Team;
+        assert_eq!(team.TeamID, 54321);
+        assert_eq!(team.Cash, 1523400);
+        assert_eq!(team.ExpectedCash, Some(1601200));
+        assert_eq!(team.FanClubSize, Some(1842));
+        assert_eq!(team.IncomeSpectators, Some(412000));
+        assert_eq!(team.IncomeSum, Some(690000));
+        assert_eq!(team.CostsSum, Some(612200));
+        assert_eq!(team.ExpectedWeeksTotal, Some(77800));
+    }
+
+    #[test]
+    fn test_deserialize_economy_negative_cash() {
+        let xml = r#"
+            <HattrickData>
+                <Team>
+                    <TeamID>1</TeamID>
+                    <TeamName>Overdrawn</TeamName>
+                    <Cash>-250000</Cash>
+                </Team>
+            </HattrickData>
+        "#;
+        let economy: Economy = from_str(xml).expect("Failed to parse economy");
+        assert_eq!(economy.Team.Cash, -250000);
+        assert_eq!(economy.Team.ExpectedCash, None);
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Layer {
+    #[serde(rename = "x")]
+    pub x: i32,
+    #[serde(rename = "y")]
+    pub y: i32,
+    #[serde(rename = "Image")]
+    pub image: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Avatar {
+    #[serde(rename = "BackgroundImage")]
+    pub background_image: String,
+    #[serde(rename = "Layer", default)]
+    pub layers: Vec<Layer>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AvatarPlayer {
+    #[serde(rename = "PlayerID")]
+    pub player_id: u32,
+    #[serde(rename = "Avatar")]
+    pub avatar: Avatar,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AvatarsPlayers {
+    #[serde(rename = "Player", default)]
+    pub players: Vec<AvatarPlayer>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AvatarsTeam {
+    #[serde(rename = "TeamId")]
+    pub team_id: u32,
+    #[serde(rename = "Players", default)]
+    pub players: AvatarsPlayers,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AvatarsData {
+    #[serde(rename = "FileName")]
+    pub file_name: String,
+    #[serde(rename = "Version")]
+    pub version: String,
+    #[serde(rename = "UserID")]
+    pub user_id: u32,
+    #[serde(rename = "Team")]
+    pub team: AvatarsTeam,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Staff {
+    pub StaffId: u32,
+    pub StaffType: u32,
+    pub StaffLevel: u32,
+    pub HiredDate: String,
+    pub Cost: u32,
+    pub Name: String,
+    #[serde(default)]
+    pub HofPlayerId: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaffTrainer {
+    pub TrainerId: u32,
+    pub Name: String,
+    #[serde(default)]
+    pub Age: Option<u32>,
+    #[serde(default)]
+    pub AgeDays: Option<u32>,
+    #[serde(default)]
+    pub ContractDate: Option<String>,
+    #[serde(default)]
+    pub Cost: Option<u32>,
+    #[serde(default)]
+    pub CountryID: Option<u32>,
+    #[serde(default)]
+    pub TrainerType: Option<u32>,
+    #[serde(default)]
+    pub Leadership: Option<u32>,
+    #[serde(default)]
+    pub TrainerSkillLevel: Option<u32>,
+    #[serde(default)]
+    pub TrainerStatus: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaffMembersWrapper {
+    #[serde(rename = "Staff", default)]
+    pub staff: Vec<Staff>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaffList {
+    #[serde(default)]
+    pub Trainer: Option<StaffTrainer>,
+    #[serde(default)]
+    pub StaffMembers: Option<StaffMembersWrapper>,
+    #[serde(default)]
+    pub TotalStaffMembers: Option<u32>,
+    #[serde(default)]
+    pub TotalCost: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StaffListData {
+    #[serde(rename = "FileName")]
+    pub file_name: String,
+    #[serde(rename = "Version")]
+    pub version: String,
+    #[serde(rename = "UserID")]
+    pub user_id: u32,
+    #[serde(rename = "FetchedDate", default)]
+    pub fetched_date: Option<String>,
+    #[serde(rename = "StaffList")]
+    pub staff_list: StaffList,
+}
+
+// Alliances (federations)
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AllianceSummary {
+    pub AllianceID: u32,
+    pub AllianceName: String,
+    #[serde(default)]
+    pub AllianceDescription: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AllianceList {
+    #[serde(rename = "Alliance", default)]
+    pub Alliances: Vec<AllianceSummary>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct AllianceSearchResults {
+    #[serde(default)]
+    pub Alliances: AllianceList,
+    #[serde(default)]
+    pub PageIndex: Option<u32>,
+    #[serde(default)]
+    pub Pages: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AllianceRole {
+    pub RoleID: u32,
+    pub RoleName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Alliance {
+    pub AllianceID: u32,
+    pub AllianceName: String,
+    #[serde(default)]
+    pub Abbreviation: Option<String>,
+    #[serde(default)]
+    pub Description: Option<String>,
+    #[serde(default)]
+    pub LogoURL: Option<String>,
+    #[serde(default)]
+    pub TopRole: Option<String>,
+    #[serde(default)]
+    pub TopUserID: Option<u32>,
+    #[serde(default)]
+    pub TopLoginname: Option<String>,
+    #[serde(default)]
+    pub CreationDate: Option<String>,
+    #[serde(default)]
+    pub NumberOfMembers: Option<u32>,
+    /// Role of the current user in this federation, if a member.
+    #[serde(default)]
+    pub UserRole: Option<AllianceRole>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct AllianceDetails {
+    pub Alliance: Alliance,
+}
+
+// Player transfer history
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransferBuyer {
+    pub BuyerTeamID: u32,
+    pub BuyerTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransferSeller {
+    pub SellerTeamID: u32,
+    pub SellerTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerTransfer {
+    pub TransferID: u32,
+    /// Deadline of the transfer, i.e. the date the player changed teams.
+    pub Deadline: String,
+    pub Buyer: TransferBuyer,
+    pub Seller: TransferSeller,
+    /// Price in the currency of the manager's league (an integer amount).
+    pub Price: u32,
+    #[serde(default)]
+    pub TSI: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransferredPlayer {
+    pub PlayerID: u32,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerTransferList {
+    #[serde(default)]
+    pub Player: Option<TransferredPlayer>,
+    #[serde(rename = "Transfer", default)]
+    pub Transfers: Vec<PlayerTransfer>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct PlayerTransfers {
+    #[serde(default)]
+    pub Transfers: PlayerTransferList,
+}
+
+// Player events
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerEvent {
+    pub EventDate: String,
+    pub PlayerEventTypeID: u32,
+    /// Event description, including Hattrick markup such as `[playerid=...]`.
+    #[serde(default)]
+    pub EventText: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerEventList {
+    #[serde(rename = "PlayerEvent", default)]
+    pub Events: Vec<PlayerEvent>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlayerEventsPlayer {
+    pub PlayerID: u32,
+    #[serde(default)]
+    pub PlayerEvents: PlayerEventList,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct PlayerEvents {
+    pub Player: PlayerEventsPlayer,
+}
+
+// Current transfer bids
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct BidderTeam {
+    pub TeamID: u32,
+    pub TeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct BidItem {
+    #[serde(default)]
+    pub TransferID: Option<u32>,
+    pub PlayerID: u32,
+    pub PlayerName: String,
+    /// Current highest bid, absent if nobody has bid yet.
+    #[serde(default)]
+    pub HighestBid: Option<u32>,
+    pub Deadline: String,
+    /// Team holding the highest bid.
+    #[serde(default)]
+    pub BidderTeam: Option<BidderTeam>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct BidItemList {
+    #[serde(rename = "BidItem", default)]
+    pub Items: Vec<BidItem>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct CurrentBids {
+    #[serde(default)]
+    pub TeamID: Option<u32>,
+    /// Players the team is bidding on.
+    #[serde(default)]
+    pub BiddingPlayers: BidItemList,
+    /// The team's own players on the transfer list.
+    #[serde(default)]
+    pub SellingPlayers: BidItemList,
+}
+
+// Search
+
+/// What the `search` endpoint looks up.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SearchType {
+    Player,
+    User,
+    Team,
+}
+
+impl SearchType {
+    /// Value of the CHPP `searchType` parameter.
+    pub fn code(&self) -> u32 {
+        match self {
+            SearchType::Player => 0,
+            SearchType::User => 1,
+            SearchType::Team => 4,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SearchResult {
+    pub ResultID: u32,
+    pub ResultName: String,
+    #[serde(default)]
+    pub LeagueID: Option<u32>,
+    #[serde(default)]
+    pub LeagueName: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SearchResultList {
+    #[serde(rename = "Result", default)]
+    pub Results: Vec<SearchResult>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SearchParams {
+    #[serde(default)]
+    pub SearchType: Option<u32>,
+    #[serde(default)]
+    pub SearchString: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct SearchResults {
+    #[serde(default)]
+    pub SearchParams: Option<SearchParams>,
+    #[serde(default)]
+    pub PageIndex: Option<u32>,
+    #[serde(default)]
+    pub Pages: Option<u32>,
+    #[serde(default)]
+    pub SearchResults: SearchResultList,
+}
+
+// Region details
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RegionInfo {
+    pub RegionID: u32,
+    pub RegionName: String,
+    /// Today's weather (0 = rain, 1 = overcast, 2 = partially cloudy, 3 = sunny).
+    #[serde(default)]
+    pub WeatherID: Option<u32>,
+    #[serde(default)]
+    pub TomorrowWeatherID: Option<u32>,
+    #[serde(default)]
+    pub NumberOfUsers: Option<u32>,
+    #[serde(default)]
+    pub NumberOfOnline: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RegionLeague {
+    pub LeagueID: u32,
+    pub LeagueName: String,
+    pub Region: RegionInfo,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct RegionDetails {
+    pub League: RegionLeague,
+}
+
+// World languages
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LanguageList {
+    #[serde(rename = "Language", default)]
+    pub Languages: Vec<Language>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct WorldLanguages {
+    #[serde(default)]
+    pub LanguageList: LanguageList,
+}
+
+// Manager compendium
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CompendiumLeague {
+    pub LeagueId: u32,
+    pub LeagueName: String,
+    #[serde(default)]
+    pub Season: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CompendiumLeagueLevelUnit {
+    pub LeagueLevelUnitId: u32,
+    pub LeagueLevelUnitName: String,
+    #[serde(default)]
+    pub LeagueLevel: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CompendiumTeam {
+    pub TeamId: u32,
+    pub TeamName: String,
+    #[serde(default)]
+    pub League: Option<CompendiumLeague>,
+    #[serde(default)]
+    pub LeagueLevelUnit: Option<CompendiumLeagueLevelUnit>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CompendiumTeamList {
+    #[serde(rename = "Team", default)]
+    pub Teams: Vec<CompendiumTeam>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CompendiumNationalTeam {
+    pub NationalTeamId: u32,
+    pub NationalTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CompendiumNationalTeamList {
+    #[serde(rename = "NationalTeam", default)]
+    pub NationalTeams: Vec<CompendiumNationalTeam>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LastLogins {
+    #[serde(rename = "LoginTime", default)]
+    pub LoginTimes: Vec<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Manager {
+    pub UserId: u32,
+    pub Loginname: String,
+    #[serde(default)]
+    pub SupporterTier: SupporterTier,
+    #[serde(default)]
+    pub LastLogins: LastLogins,
+    #[serde(default)]
+    pub Teams: CompendiumTeamList,
+    #[serde(default)]
+    pub NationalTeamCoach: CompendiumNationalTeamList,
+    #[serde(default)]
+    pub NationalTeamAssistant: CompendiumNationalTeamList,
+    #[serde(default)]
+    pub Avatar: Option<Avatar>,
+}
+
+impl Manager {
+    /// Most recent login time, as reported first by CHPP.
+    pub fn last_login(&self) -> Option<&str> {
+        self.LastLogins.LoginTimes.first().map(String::as_str)
+    }
+
+    /// IDs of every club the manager owns, in the order CHPP lists them.
+    pub fn team_ids(&self) -> Vec<u32> {
+        self.Teams
+            .Teams
+            .iter()
+            .map(|t| t.TeamId)
+            .filter(|&id| id != 0)
+            .collect()
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct ManagerCompendium {
+    pub Manager: Manager,
+}
+
+// Cup matches
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CupHomeTeam {
+    pub HomeTeamId: u32,
+    pub HomeTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CupAwayTeam {
+    pub AwayTeamId: u32,
+    pub AwayTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CupMatchResult {
+    #[serde(default)]
+    pub HomeGoals: Option<u32>,
+    #[serde(default)]
+    pub AwayGoals: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CupMatch {
+    pub MatchID: u32,
+    pub MatchDate: String,
+    pub HomeTeam: CupHomeTeam,
+    pub AwayTeam: CupAwayTeam,
+    /// Missing until the match has been played.
+    #[serde(default)]
+    pub MatchResult: Option<CupMatchResult>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CupMatchList {
+    #[serde(rename = "Match", default)]
+    pub Matches: Vec<CupMatch>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CupMatchesCup {
+    pub CupID: u32,
+    #[serde(default)]
+    pub CupName: Option<String>,
+    #[serde(default)]
+    pub Season: Option<u32>,
+    #[serde(default)]
+    pub CupRound: Option<u32>,
+    #[serde(default)]
+    pub MatchList: CupMatchList,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct CupMatches {
+    pub Cup: CupMatchesCup,
+}
+
+// Training events
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TrainingEvent {
+    pub EventDate: String,
+    /// Skill that changed, using the CHPP skill IDs.
+    pub SkillID: u32,
+    pub OldLevel: u32,
+    pub NewLevel: u32,
+    #[serde(default)]
+    pub Season: Option<u32>,
+    #[serde(default)]
+    pub MatchRound: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TrainingEventList {
+    #[serde(rename = "TrainingEvent", default)]
+    pub Events: Vec<TrainingEvent>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TrainingEventsPlayer {
+    pub PlayerID: u32,
+    #[serde(default)]
+    pub TrainingEvents: TrainingEventList,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct TrainingEvents {
+    pub Player: TrainingEventsPlayer,
+}
+
+// Economy
+
+/// Money amounts are in CHPP's base currency (SEK); cash can be negative.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct EconomyTeam {
+    pub TeamID: u32,
+    pub TeamName: String,
+    pub Cash: i64,
+    #[serde(default)]
+    pub ExpectedCash: Option<i64>,
+    #[serde(default)]
+    pub FanClubSize: Option<u32>,
+    #[serde(default)]
+    pub IncomeSpectators: Option<i64>,
+    #[serde(default)]
+    pub IncomeSponsors: Option<i64>,
+    #[serde(default)]
+    pub IncomeSum: Option<i64>,
+    #[serde(default)]
+    pub CostsSum: Option<i64>,
+    #[serde(default)]
+    pub ExpectedWeeksTotal: Option<i64>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct Economy {
+    pub Team: EconomyTeam,
+}
+
+// Arena details
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ArenaTeam {
+    pub TeamID: u32,
+    pub TeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ArenaCapacity {
+    #[serde(default)]
+    pub Terraces: u32,
+    #[serde(default)]
+    pub Basic: u32,
+    #[serde(default)]
+    pub Roof: u32,
+    #[serde(default)]
+    pub VIP: u32,
+    /// Only present on the expanded capacity while construction is running.
+    #[serde(default)]
+    pub ExpansionDate: Option<String>,
+}
+
+impl ArenaCapacity {
+    pub fn total(&self) -> u32 {
+        self.Terraces + self.Basic + self.Roof + self.VIP
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ArenaInfo {
+    pub ArenaID: u32,
+    pub ArenaName: String,
+    #[serde(default)]
+    pub Team: Option<ArenaTeam>,
+    #[serde(default)]
+    pub CurrentCapacity: ArenaCapacity,
+    #[serde(default)]
+    pub ExpandedCapacity: Option<ArenaCapacity>,
+}
+
+impl ArenaInfo {
+    /// Whether seats are currently being built or removed.
+    pub fn is_expanding(&self) -> bool {
+        self.ExpandedCapacity
+            .as_ref()
+            .is_some_and(|c| c.ExpansionDate.is_some() || c.total() > 0)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct ArenaDetails {
+    pub Arena: ArenaInfo,
+}
+
+/// Seats currently available in the arena, all sections together.
+pub fn total_capacity(a: &ArenaDetails) -> u32 {
+    a.Arena.CurrentCapacity.total()
+}
+
+// League fixtures
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct FixtureHomeTeam {
+    pub HomeTeamID: u32,
+    pub HomeTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct FixtureAwayTeam {
+    pub AwayTeamID: u32,
+    pub AwayTeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LeagueFixture {
+    pub MatchID: u32,
+    pub MatchRound: u32,
+    pub HomeTeam: FixtureHomeTeam,
+    pub AwayTeam: FixtureAwayTeam,
+    pub MatchDate: String,
+    /// Goals are only present once the match has been played.
+    #[serde(default)]
+    pub HomeGoals: Option<u32>,
+    #[serde(default)]
+    pub AwayGoals: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct LeagueFixtures {
+    pub LeagueLevelUnitID: u32,
+    #[serde(default)]
+    pub LeagueLevelUnitName: Option<String>,
+    pub Season: u32,
+    #[serde(rename = "Match", default)]
+    pub Matches: Vec<LeagueFixture>,
+}
+
+// Team transfer history
+
+/// Direction of a transfer seen from the team whose history was requested.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum TransferType {
+    #[default]
+    Buy,
+    Sell,
+}
+
+impl TransferType {
+    /// One-letter code used by CHPP, also how the type is stored.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TransferType::Buy => "B",
+            TransferType::Sell => "S",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "B" => Some(TransferType::Buy),
+            "S" => Some(TransferType::Sell),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        TransferType::from_code(s.trim())
+            .ok_or_else(|| serde::de::Error::custom(format!("Unknown TransferType: {}", s)))
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TeamTransferPlayer {
+    pub PlayerID: u32,
+    pub PlayerName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Transfer {
+    pub TransferID: u32,
+    pub Deadline: String,
+    pub TransferType: TransferType,
+    pub Player: TeamTransferPlayer,
+    pub Buyer: TransferBuyer,
+    pub Seller: TransferSeller,
+    pub Price: u32,
+    #[serde(default)]
+    pub TSI: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TeamTransferStats {
+    pub TotalSumOfBuys: i64,
+    pub TotalSumOfSales: i64,
+    pub NumberOfBuys: u32,
+    pub NumberOfSales: u32,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TeamTransferList {
+    /// Zero-based index of the returned page.
+    #[serde(default)]
+    pub PageIndex: u32,
+    #[serde(default)]
+    pub Pages: u32,
+    #[serde(rename = "Transfer", default)]
+    pub Transfers: Vec<Transfer>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransfersTeam {
+    pub TeamID: u32,
+    pub TeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct TeamTransfers {
+    pub Team: TransfersTeam,
+    #[serde(default)]
+    pub Stats: Option<TeamTransferStats>,
+    #[serde(default)]
+    pub Transfers: TeamTransferList,
+}
+
+/// Money made on the transfer market: sales count positively, purchases
+/// negatively.
+pub fn net_transfer_balance(transfers: &[Transfer]) -> i64 {
+    transfers
+        .iter()
+        .map(|t| match t.TransferType {
+            TransferType::Sell => t.Price as i64,
+            TransferType::Buy => -(t.Price as i64),
+        })
+        .sum()
+}
+
+// Translations
+
+/// A Hattrick denomination: the attribute `Value` is the code it names.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TranslationItem {
+    pub Value: u32,
+    #[serde(rename = "$value", default)]
+    pub Text: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SkillLevelList {
+    #[serde(rename = "Level", default)]
+    pub Levels: Vec<TranslationItem>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TranslationItemList {
+    #[serde(rename = "Item", default)]
+    pub Items: Vec<TranslationItem>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TranslationTexts {
+    pub LanguageID: u32,
+    #[serde(default)]
+    pub LanguageName: String,
+    #[serde(default)]
+    pub SkillLevels: SkillLevelList,
+    #[serde(default)]
+    pub PlayerSpecialties: TranslationItemList,
+    #[serde(default)]
+    pub MatchPositions: TranslationItemList,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct Translations {
+    pub Texts: TranslationTexts,
+}
+
+// World Cup
+
+pub type TeamId = u32;
+pub type Points = u32;
+
+/// A national team's line in its World Cup group.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct WorldCupTeam {
+    pub TeamID: TeamId,
+    pub TeamName: String,
+    /// The group the team plays in.
+    pub CupSeriesUnitID: u32,
+    #[serde(default)]
+    pub CupSeriesUnitName: String,
+    #[serde(default)]
+    pub MatchesPlayed: u32,
+    #[serde(default)]
+    pub GoalsFor: u32,
+    #[serde(default)]
+    pub GoalsAgainst: u32,
+    #[serde(default)]
+    pub Points: Points,
+    #[serde(default)]
+    pub Position: u32,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct WorldCupScores {
+    #[serde(rename = "Team", default)]
+    pub Teams: Vec<WorldCupTeam>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct WorldCupMatch {
+    pub MatchID: u32,
+    pub MatchRound: u32,
+    pub CupSeriesUnitID: u32,
+    pub HomeTeam: FixtureHomeTeam,
+    pub AwayTeam: FixtureAwayTeam,
+    pub MatchDate: String,
+    /// Goals are only present once the match has been played.
+    #[serde(default)]
+    pub HomeGoals: Option<u32>,
+    #[serde(default)]
+    pub AwayGoals: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct WorldCupMatchList {
+    #[serde(rename = "Match", default)]
+    pub Matches: Vec<WorldCupMatch>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct WorldCup {
+    pub CupID: u32,
+    pub Season: u32,
+    #[serde(default)]
+    pub MatchRound: Option<u32>,
+    #[serde(default)]
+    pub WorldCupScores: WorldCupScores,
+    #[serde(default)]
+    pub Matches: WorldCupMatchList,
+}
+
+impl WorldCup {
+    /// Ids of the groups in the cup, in ascending order.
+    pub fn groups(&self) -> Vec<u32> {
+        let mut groups: Vec<u32> = self
+            .WorldCupScores
+            .Teams
+            .iter()
+            .map(|t| t.CupSeriesUnitID)
+            .collect();
+        groups.sort_unstable();
+        groups.dedup();
+        groups
+    }
+}
+
+/// Table of a World Cup group, best team first. Teams level on points are
+/// separated by goal difference, then goals scored.
+pub fn group_standings(wc: &WorldCup, group: u32) -> Vec<(TeamId, Points)> {
+    let mut teams: Vec<&WorldCupTeam> = wc
+        .WorldCupScores
+        .Teams
+        .iter()
+        .filter(|t| t.CupSeriesUnitID == group)
+        .collect();
+    teams.sort_by_key(|t| {
+        let goal_difference = t.GoalsFor as i64 - t.GoalsAgainst as i64;
+        std::cmp::Reverse((t.Points, goal_difference, t.GoalsFor))
+    });
+    teams.into_iter().map(|t| (t.TeamID, t.Points)).collect()
+}
+
+// Hall of Fame
+
+/// A retired player in the club's Hall of Fame, with the skills they had at
+/// their peak.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct HofPlayer {
+    pub PlayerId: u32,
+    pub FirstName: String,
+    #[serde(default)]
+    pub NickName: Option<String>,
+    pub LastName: String,
+    pub Age: u32,
+    #[serde(default)]
+    pub ArrivalDate: Option<String>,
+    /// When the player was inducted into the Hall of Fame.
+    pub HofDate: String,
+    #[serde(default)]
+    pub HofAge: Option<u32>,
+    /// Non-zero when the player is an expert after retiring (e.g. a coach).
+    #[serde(default)]
+    pub ExpertType: u32,
+    #[serde(default)]
+    pub KeeperSkill: Option<u32>,
+    #[serde(default)]
+    pub PlaymakerSkill: Option<u32>,
+    #[serde(default)]
+    pub ScorerSkill: Option<u32>,
+    #[serde(default)]
+    pub PassingSkill: Option<u32>,
+    #[serde(default)]
+    pub WingerSkill: Option<u32>,
+    #[serde(default)]
+    pub DefenderSkill: Option<u32>,
+    #[serde(default)]
+    pub SetPiecesSkill: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct HofPlayerList {
+    #[serde(rename = "Player", default)]
+    pub Players: Vec<HofPlayer>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct HofPlayers {
+    pub TeamID: u32,
+    #[serde(default)]
+    pub PlayerList: HofPlayerList,
+}
+
+// Bookmarks
+
+/// What a bookmark points at, from its `BookmarkTypeID`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BookmarkType {
+    Team,
+    Player,
+    Match,
+    ConferencePost,
+    League,
+    YouthTeam,
+    YouthPlayer,
+    YouthMatch,
+    YouthLeague,
+    Other(u32),
+}
+
+impl BookmarkType {
+    pub fn from_id(id: u32) -> Self {
+        match id {
+            1 => BookmarkType::Team,
+            2 => BookmarkType::Player,
+            3 => BookmarkType::Match,
+            4 => BookmarkType::ConferencePost,
+            5 => BookmarkType::League,
+            6 => BookmarkType::YouthTeam,
+            7 => BookmarkType::YouthPlayer,
+            8 => BookmarkType::YouthMatch,
+            9 => BookmarkType::YouthLeague,
+            other => BookmarkType::Other(other),
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Bookmark {
+    pub BookmarkID: u32,
+    pub BookmarkTypeID: u32,
+    /// Name of the bookmarked object, e.g. the team or player name.
+    pub Text: String,
+    /// Extra detail depending on the type, e.g. a player's team.
+    #[serde(default)]
+    pub Text2: Option<String>,
+    /// Id of the bookmarked team, player, match...
+    pub ObjectID: u32,
+    #[serde(default)]
+    pub ObjectID2: Option<u32>,
+    #[serde(default)]
+    pub Comment: Option<String>,
+}
+
+impl Bookmark {
+    pub fn bookmark_type(&self) -> BookmarkType {
+        BookmarkType::from_id(self.BookmarkTypeID)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct BookmarkList {
+    #[serde(rename = "Bookmark", default)]
+    pub Bookmarks: Vec<Bookmark>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct Bookmarks {
+    #[serde(default)]
+    pub BookmarkList: BookmarkList,
+}
+
+// Friendly challenges
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ChallengeOpponent {
+    pub TeamID: u32,
+    pub TeamName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ChallengeArena {
+    pub ArenaID: u32,
+    pub ArenaName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Challenge {
+    pub TrainingMatchID: u32,
+    pub MatchTime: String,
+    /// 0 for normal friendlies, 1 for cup-rules friendlies.
+    #[serde(default)]
+    pub FriendlyType: u32,
+    pub Opponent: ChallengeOpponent,
+    #[serde(default)]
+    pub Arena: Option<ChallengeArena>,
+    /// Whether both teams agreed, i.e. the friendly is arranged.
+    #[serde(deserialize_with = "deserialize_bool", default)]
+    pub IsAgreed: bool,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ChallengeList {
+    #[serde(rename = "Challenge", default)]
+    pub Challenges: Vec<Challenge>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ChallengesTeam {
+    pub TeamID: u32,
+    pub TeamName: String,
+    /// Challenges the team sent.
+    #[serde(default)]
+    pub ChallengesByMe: ChallengeList,
+    /// Challenges other teams sent to this one.
+    #[serde(default)]
+    pub OffersByOthers: ChallengeList,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct Challenges {
+    pub Team: ChallengesTeam,
+}
+
+impl Challenges {
+    /// The agreed friendly, if one has been arranged.
+    pub fn arranged(&self) -> Option<&Challenge> {
+        self.Team
+            .ChallengesByMe
+            .Challenges
+            .iter()
+            .chain(self.Team.OffersByOthers.Challenges.iter())
+            .find(|c| c.IsAgreed)
+    }
+}
+
+/// Whether Hattrick still lets `team` be challenged for a weekend or a
+/// midweek friendly. Unknown flags read as not possible.
+pub fn can_challenge(team: &Team, weekend: bool) -> bool {
+    let flag = if weekend {
+        team.PossibleToChallengeWeekend
+    } else {
+        team.PossibleToChallengeMidweek
+    };
+    flag.unwrap_or(false)
+}
+
+// Tournaments
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TournamentCreator {
+    pub UserId: u32,
+    pub Loginname: String,
+}
+
+/// A user-created tournament, as listed by `tournamentlist` and described by
+/// `tournamentdetails`. `TournamentType` is the CHPP code, kept as sent.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Tournament {
+    pub TournamentId: u32,
+    pub Name: String,
+    pub TournamentType: u32,
+    pub Season: u32,
+    pub LogoUrl: Option<String>,
+    pub TrophyType: Option<u32>,
+    pub NumberOfTeams: u32,
+    pub NumberOfGroups: u32,
+    pub LastMatchRound: Option<u32>,
+    pub FirstMatchRoundDate: Option<String>,
+    pub NextMatchRoundDate: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_bool",
+        serialize_with = "serialize_bool"
+    )]
+    pub IsMatchesOngoing: bool,
+    pub Creator: Option<TournamentCreator>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TournamentEntries {
+    #[serde(rename = "Tournament", default)]
+    pub Tournaments: Vec<Tournament>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct TournamentList {
+    #[serde(default)]
+    pub TournamentList: TournamentEntries,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct TournamentDetails {
+    pub Tournament: Tournament,
+}
+
+// Achievements
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Achievement {
+    pub AchievementTypeID: u32,
+    pub AchievementTitle: String,
+    pub AchievementText: Option<String>,
+    pub CategoryID: u32,
+    pub EventDate: String,
+    pub Points: u32,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_bool",
+        serialize_with = "serialize_bool"
+    )]
+    pub MultiLevel: bool,
+    pub NumberOfEvents: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AchievementList {
+    #[serde(rename = "Achievement", default)]
+    pub Achievements: Vec<Achievement>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct Achievements {
+    pub UserID: u32,
+    pub MaxPoints: Option<u32>,
+    #[serde(default)]
+    pub AchievementList: AchievementList,
+}
+
+// Supporters
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SupportersTeam {
+    pub TeamId: u32,
+    pub TeamName: String,
+}
+
+/// A team on either side of a supporter relationship.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SupporterTeam {
+    pub UserId: u32,
+    pub LoginName: String,
+    pub TeamId: u32,
+    pub TeamName: String,
+    pub LeagueID: Option<u32>,
+    pub LeagueName: Option<String>,
+    pub LeagueLevelUnitID: Option<u32>,
+    pub LeagueLevelUnitName: Option<String>,
+}
+
+/// Teams the user supports (`<SupportedTeam>` items).
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SupportedTeamList {
+    pub TotalItems: Option<u32>,
+    pub MaxItems: Option<u32>,
+    #[serde(rename = "SupportedTeam", default)]
+    pub Teams: Vec<SupporterTeam>,
+}
+
+/// Teams supporting the team (`<SupporterTeam>` items).
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct MySupporterList {
+    pub TotalItems: Option<u32>,
+    pub MaxItems: Option<u32>,
+    #[serde(rename = "SupporterTeam", default)]
+    pub Teams: Vec<SupporterTeam>,
+}
+
+/// `supporters` document. CHPP fills `SupportedTeams` or `MySupporters`
+/// depending on the requested `actionType`.
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct Supporters {
+    pub Team: Option<SupportersTeam>,
+    pub SupportedTeams: Option<SupportedTeamList>,
+    pub MySupporters: Option<MySupporterList>,
+}
+
+impl Supporters {
+    /// Number of teams supporting the team; `TotalItems` when CHPP sends it,
+    /// which may exceed the listed page.
+    pub fn supporter_count(&self) -> u32 {
+        self.MySupporters
+            .as_ref()
+            .map(|l| l.TotalItems.unwrap_or(l.Teams.len() as u32))
+            .unwrap_or(0)
+    }
+
+    /// Number of teams the user supports.
+    pub fn supported_count(&self) -> u32 {
+        self.SupportedTeams
+            .as_ref()
+            .map(|l| l.TotalItems.unwrap_or(l.Teams.len() as u32))
+            .unwrap_or(0)
+    }
+}
+
+// Live match ticker
+
+/// `actionType` of a `live` request. `ViewAll` and `ViewNew` read the ticker
+/// of the subscribed matches; the others change the subscription list.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LiveActionType {
+    ViewAll,
+    ViewNew,
+    AddMatch,
+    DeleteMatch,
+    ClearAll,
+}
+
+impl LiveActionType {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            LiveActionType::ViewAll => "viewAll",
+            LiveActionType::ViewNew => "viewNew",
+            LiveActionType::AddMatch => "addMatch",
+            LiveActionType::DeleteMatch => "deleteMatch",
+            LiveActionType::ClearAll => "clearAll",
+        }
+    }
+
+    /// Whether the action changes the subscribed matches rather than reading
+    /// events.
+    pub fn is_subscription_change(&self) -> bool {
+        matches!(
+            self,
+            LiveActionType::AddMatch | LiveActionType::DeleteMatch | LiveActionType::ClearAll
+        )
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LiveEvent {
+    pub Index: Option<u32>,
+    pub Minute: u32,
+    pub SubjectPlayerID: Option<u32>,
+    pub SubjectTeamID: Option<u32>,
+    pub ObjectPlayerID: Option<u32>,
+    pub EventTypeID: u32,
+    pub EventVariation: Option<u32>,
+    pub EventText: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LiveEventList {
+    #[serde(rename = "Event", default)]
+    pub Events: Vec<LiveEvent>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LiveMatch {
+    pub MatchID: u32,
+    pub MatchDate: String,
+    pub SourceSystem: Option<String>,
+    pub HomeTeam: FixtureHomeTeam,
+    pub AwayTeam: FixtureAwayTeam,
+    pub HomeGoals: Option<u32>,
+    pub AwayGoals: Option<u32>,
+    #[serde(default)]
+    pub EventList: LiveEventList,
+    pub NextEventMinute: Option<u32>,
+}
+
+impl LiveMatch {
+    /// Events in ticker order.
+    pub fn events(&self) -> &[LiveEvent] {
+        &self.EventList.Events
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LiveMatchList {
+    #[serde(rename = "Match", default)]
+    pub Matches: Vec<LiveMatch>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct LiveData {
+    #[serde(default)]
+    pub MatchList: LiveMatchList,
+}
+
+// Transfer market search
+
+/// Most skill filters CHPP accepts in one `transfersearch` request.
+pub const MAX_TRANSFER_SEARCH_SKILLS: usize = 4;
+
+/// `skillType` code used by `transfersearch`; `None` for skills the market
+/// cannot be searched on.
+pub fn transfer_search_skill_type(skill: crate::rating::types::PlayerSkill) -> Option<u32> {
+    use crate::rating::types::PlayerSkill;
+    match skill {
+        PlayerSkill::Keeper => Some(1),
+        PlayerSkill::Stamina => Some(2),
+        PlayerSkill::SetPieces => Some(3),
+        PlayerSkill::Defending => Some(4),
+        PlayerSkill::Scoring => Some(5),
+        PlayerSkill::Winger => Some(6),
+        PlayerSkill::Passing => Some(7),
+        PlayerSkill::Playmaking => Some(8),
+        PlayerSkill::Experience => Some(11),
+        PlayerSkill::Form | PlayerSkill::Loyalty => None,
+    }
+}
+
+/// Transfer market query, built with `new` and the chained setters and
+/// turned into request parameters by `query_params`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransferSearchCriteria {
+    pub age_min: u32,
+    pub age_max: u32,
+    /// Minimum level per skill, at most `MAX_TRANSFER_SEARCH_SKILLS`.
+    pub min_skills: Vec<(crate::rating::types::PlayerSkill, u32)>,
+    pub specialty: Option<u32>,
+    pub price_min: Option<u32>,
+    pub price_max: Option<u32>,
+    pub page_index: u32,
+}
+
+impl TransferSearchCriteria {
+    pub fn new(age_min: u32, age_max: u32) -> Self {
+        TransferSearchCriteria {
+            age_min,
+            age_max,
+            ..Default::default()
+        }
+    }
+
+    /// Require at least `level` in `skill`. Setting the same skill twice
+    /// keeps the last level; skills beyond the CHPP limit or that cannot be
+    /// searched on are ignored.
+    pub fn min_skill(mut self, skill: crate::rating::types::PlayerSkill, level: u32) -> Self {
+        if transfer_search_skill_type(skill).is_none() {
+            return self;
+        }
+        if let Some(entry) = self.min_skills.iter_mut().find(|(s, _)| *s == skill) {
+            entry.1 = level;
+        } else if self.min_skills.len() < MAX_TRANSFER_SEARCH_SKILLS {
+            self.min_skills.push((skill, level));
+        }
+        self
+    }
+
+    pub fn specialty(mut self, specialty: u32) -> Self {
+        self.specialty = Some(specialty);
+        self
+    }
+
+    pub fn price_range(mut self, min: Option<u32>, max: Option<u32>) -> Self {
+        self.price_min = min;
+        self.price_max = max;
+        self
+    }
+
+    pub fn page(mut self, page_index: u32) -> Self {
+        self.page_index = page_index;
+        self
+    }
+
+    /// Query parameters for the request, in a stable order.
+    pub fn query_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("ageMin".to_string(), self.age_min.to_string()),
+            ("ageDaysMin".to_string(), "0".to_string()),
+            ("ageMax".to_string(), self.age_max.to_string()),
+            ("ageDaysMax".to_string(), "111".to_string()),
+        ];
+        for (i, (skill, level)) in self.min_skills.iter().enumerate() {
+            let Some(skill_type) = transfer_search_skill_type(*skill) else {
+                continue;
+            };
+            let n = i + 1;
+            params.push((format!("skillType{}", n), skill_type.to_string()));
+            params.push((format!("minSkillValue{}", n), level.to_string()));
+            // 20 is "divine", the top of the scale.
+            params.push((format!("maxSkillValue{}", n), "20".to_string()));
+        }
+        if let Some(specialty) = self.specialty {
+            params.push(("specialty".to_string(), specialty.to_string()));
+        }
+        if let Some(min) = self.price_min {
+            params.push(("priceMin".to_string(), min.to_string()));
+        }
+        if let Some(max) = self.price_max {
+            params.push(("priceMax".to_string(), max.to_string()));
+        }
+        params.push(("pageIndex".to_string(), self.page_index.to_string()));
+        params
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransferResultDetails {
+    pub Age: u32,
+    pub AgeDays: Option<u32>,
+    pub Salary: Option<u32>,
+    pub TSI: Option<u32>,
+    pub PlayerForm: Option<u32>,
+    pub Experience: Option<u32>,
+    pub Specialty: Option<u32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransferResult {
+    pub PlayerId: u32,
+    pub FirstName: String,
+    pub NickName: Option<String>,
+    pub LastName: String,
+    pub NativeCountryID: Option<u32>,
+    pub AskingPrice: u32,
+    pub Deadline: String,
+    pub HighestBid: Option<u32>,
+    pub Details: Option<TransferResultDetails>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TransferResultList {
+    #[serde(rename = "TransferResult", default)]
+    pub Results: Vec<TransferResult>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename = "HattrickData")]
+pub struct TransferSearchResults {
+    pub ItemCount: u32,
+    pub PageSize: u32,
+    pub PageIndex: u32,
+    #[serde(default)]
+    pub TransferResults: TransferResultList,
 }
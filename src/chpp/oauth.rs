@@ -18,10 +18,7 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-use std::{
-    cell::RefCell,
-    fmt::{Debug, Formatter},
-};
+use std::fmt::{Debug, Formatter};
 
 use http_types::{Method, Url};
 use log::info;
@@ -29,32 +26,75 @@ use oauth_1a::*;
 pub use oauth_1a::{OAuthData, SigningKey};
 
 use crate::error::NutmegError;
+use crate::chpp::request::{http_client, DEFAULT_REQUEST_TIMEOUT};
 use crate::chpp::CHPP_OAUTH_AUTH_URL;
 use crate::chpp::{CHPP_OAUTH_ACCESS_TOKEN_URL, CHPP_OAUTH_REQUEST_TOKEN_URL};
 
+/// Extended permissions that can be requested on top of CHPP's read access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChppScope {
+    SetMatchOrder,
+    ManageChallenges,
+    SetTraining,
+    PlaceBid,
+    ManageYouthPlayers,
+}
+
+impl ChppScope {
+    pub const ALL: [ChppScope; 5] = [
+        ChppScope::SetMatchOrder,
+        ChppScope::ManageChallenges,
+        ChppScope::SetTraining,
+        ChppScope::PlaceBid,
+        ChppScope::ManageYouthPlayers,
+    ];
+
+    /// Name of the scope in the `scope` parameter of the authorization URL.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChppScope::SetMatchOrder => "set_matchorder",
+            ChppScope::ManageChallenges => "manage_challenges",
+            ChppScope::SetTraining => "set_training",
+            ChppScope::PlaceBid => "place_bid",
+            ChppScope::ManageYouthPlayers => "manage_youthplayers",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ChppScope> {
+        Self::ALL.into_iter().find(|scope| scope.as_str() == name)
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct OauthSettings {
-    pub request_token: RefCell<String>,
-    pub oauth_secret_token: RefCell<String>,
-    pub nonce: RefCell<String>,
-    pub client_id: RefCell<String>,
-    pub client_secret: RefCell<String>,
+    pub request_token: String,
+    pub oauth_secret_token: String,
+    pub nonce: String,
+    pub client_id: String,
+    pub client_secret: String,
 }
 
 impl Debug for OauthSettings {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let binding = self.request_token.borrow_mut();
-        let tok: &str = binding.as_str();
-
-        f.debug_tuple(tok).finish()
+        // Secrets are deliberately left out.
+        f.debug_struct("OauthSettings")
+            .field("request_token", &self.request_token)
+            .field("client_id", &self.client_id)
+            .finish_non_exhaustive()
     }
 }
 
+/// Obtain a request token and build the Hattrick authorization URL.
+///
+/// `scopes` lists the extended permissions to ask the user for; leave it
+/// empty for read-only access. Returns the URL along with the settings
+/// holding the consumer credentials and the temporary request token, to pass
+/// to `exchange_verification_code`.
 pub fn get_request_token_url(
-    settings: &OauthSettings,
     consumer_key: &str,
     consumer_secret: &str,
-) -> Result<String, NutmegError> {
+    scopes: &[ChppScope],
+) -> Result<(String, OauthSettings), NutmegError> {
     let client_id = ClientId(consumer_key.to_string());
     let client_secret = ClientSecret(consumer_secret.to_string());
 
@@ -70,15 +110,12 @@ pub fn get_request_token_url(
     let initiate = Url::parse(CHPP_OAUTH_REQUEST_TOKEN_URL)
         .map_err(|e| NutmegError::Parse(format!("Invalid request token URL: {}", e)))?;
 
-    settings.client_secret.replace(consumer_secret.to_string());
-    settings.client_id.replace(consumer_key.to_string());
-
     let callback = "oob".to_owned();
     let req = SignableRequest::new(Method::Post, initiate.clone(), Default::default());
     let authorization = data.authorization(req, AuthorizationType::RequestToken { callback }, &key);
     info!("authorization: {}", authorization);
 
-    let client = reqwest::blocking::Client::new();
+    let client = blocking_client()?;
     let resp = client
         .post(initiate)
         .header("Authorization", authorization)
@@ -91,32 +128,23 @@ pub fn get_request_token_url(
     info!("---\n{}", resp);
     data.regen_nonce();
 
-    let token = receive_token(&mut data, &mut key, &resp).map_err(|e| {
-        NutmegError::Auth(format!(
-            "Failed to receive token: {}. Response: {}",
-            e, resp
-        ))
-    })?;
+    let (request_token, oauth_secret_token) = parse_token_response(&mut data, &mut key, &resp)?;
 
-    settings.request_token.replace(token.0.clone());
-    let token_secret = key
-        .token_secret
-        .ok_or_else(|| NutmegError::Auth("No token secret in key".to_string()))?;
-    match token_secret {
-        TokenSecret(s) => {
-            settings.oauth_secret_token.replace(s.clone());
-        }
-    }
+    let url = authorization_url(&request_token, scopes);
+    let settings = OauthSettings {
+        request_token,
+        oauth_secret_token,
+        client_id: consumer_key.to_string(),
+        client_secret: consumer_secret.to_string(),
+        ..Default::default()
+    };
 
-    Ok(format!(
-        "{}?oauth_token={}&scope=set_matchorder",
-        CHPP_OAUTH_AUTH_URL, &token.0
-    ))
+    Ok((url, settings))
 }
 
 #[allow(dead_code)]
 pub fn request_token(
-    settings: OauthSettings,
+    mut settings: OauthSettings,
     consumer_key: &str,
     consumer_secret: &str,
     verif_callback: fn(&str) -> i32,
@@ -136,15 +164,15 @@ pub fn request_token(
     let initiate = Url::parse(CHPP_OAUTH_REQUEST_TOKEN_URL)
         .map_err(|e| NutmegError::Parse(format!("Invalid request token URL: {}", e)))?;
 
-    settings.client_secret.replace(consumer_secret.to_string());
-    settings.client_id.replace(consumer_key.to_string());
+    settings.client_secret = consumer_secret.to_string();
+    settings.client_id = consumer_key.to_string();
 
     let callback = "oob".to_owned();
     let req = SignableRequest::new(Method::Post, initiate.clone(), Default::default());
     let authorization = data.authorization(req, AuthorizationType::RequestToken { callback }, &key);
     info!("authorization: {}", authorization);
 
-    let client = reqwest::blocking::Client::new();
+    let client = blocking_client()?;
     let resp = client
         .post(initiate)
         .header("Authorization", authorization)
@@ -162,35 +190,52 @@ pub fn request_token(
         .map_err(|e| NutmegError::Auth(format!("Failed to receive token: {}", e)))?;
     info!("---\n{}", token.0);
 
-    settings.request_token.replace(token.0.clone());
+    settings.request_token = token.0.clone();
     let token_secret = key
         .token_secret
         .ok_or_else(|| NutmegError::Auth("No token secret in key".to_string()))?;
     match token_secret {
         TokenSecret(s) => {
-            settings.oauth_secret_token.replace(s.clone());
+            settings.oauth_secret_token = s;
         }
     }
 
     // The callback needs to open the URL passed as an argument,
     // authenticate in Hattrick, and obtain the verification code.
-    verif_callback(&format!(
-        "{}?oauth_token={}&scope=set_matchorder",
-        CHPP_OAUTH_AUTH_URL, &token.0
-    ));
+    verif_callback(&authorization_url(&token.0, &[]));
 
     Ok(settings)
 }
 
+/// Clean up a verification code typed or pasted by the user.
+///
+/// Surrounding whitespace (including a trailing newline from a paste) is
+/// dropped. Hattrick codes are short alphanumeric strings, so anything else
+/// is rejected before contacting Hattrick.
+pub fn normalize_verification_code(raw: &str) -> Result<String, NutmegError> {
+    let code = raw.trim();
+    if code.is_empty() {
+        return Err(NutmegError::Auth(
+            "Please enter the verification code shown by Hattrick".to_string(),
+        ));
+    }
+    if code.len() > 64 || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(NutmegError::Auth(
+            "This does not look like a Hattrick verification code; copy it again from the Hattrick page".to_string(),
+        ));
+    }
+    Ok(code.to_string())
+}
+
 /// Exchange verification code for access token
 pub fn exchange_verification_code(
     verification_code: &str,
     settings: &OauthSettings,
 ) -> Result<(String, String), NutmegError> {
-    let consumer_key = settings.client_id.borrow().clone();
-    let consumer_secret = settings.client_secret.borrow().clone();
-    let request_token = settings.request_token.borrow().clone();
-    let oauth_secret_token = settings.oauth_secret_token.borrow().clone();
+    let consumer_key = settings.client_id.clone();
+    let consumer_secret = settings.client_secret.clone();
+    let request_token = settings.request_token.clone();
+    let oauth_secret_token = settings.oauth_secret_token.clone();
 
     if consumer_key.is_empty() || consumer_secret.is_empty() {
         return Err(NutmegError::Auth(
@@ -219,7 +264,7 @@ pub fn exchange_verification_code(
     };
     let authorization = data.authorization(req, access_type, &key);
 
-    let client = reqwest::blocking::Client::new();
+    let client = blocking_client()?;
     let resp = client
         .post(access_url)
         .header("Authorization", authorization)
@@ -245,6 +290,161 @@ pub fn exchange_verification_code(
     Ok((access_token, access_secret))
 }
 
+/// The `scope` parameter is left out altogether when no scopes are requested.
+fn authorization_url(request_token: &str, scopes: &[ChppScope]) -> String {
+    let mut url = format!("{}?oauth_token={}", CHPP_OAUTH_AUTH_URL, request_token);
+    if !scopes.is_empty() {
+        let names: Vec<&str> = scopes.iter().map(ChppScope::as_str).collect();
+        url.push_str("&scope=");
+        url.push_str(&names.join(","));
+    }
+    url
+}
+
+/// Extract the token and token secret from an OAuth token response
+/// (`oauth_token=...&oauth_token_secret=...`).
+fn parse_token_response(
+    data: &mut OAuthData,
+    key: &mut SigningKey,
+    resp: &str,
+) -> Result<(String, String), NutmegError> {
+    let token = receive_token(data, key, resp).map_err(|e| {
+        NutmegError::Auth(format!(
+            "Failed to receive token: {}. Response: {}",
+            e, resp
+        ))
+    })?;
+    let token_secret = match &key.token_secret {
+        Some(secret) => secret.0.clone(),
+        None => return Err(NutmegError::Auth("No token secret received".to_string())),
+    };
+    Ok((token.0, token_secret))
+}
+
+/// The OAuth handshake gets the same upper bound as data requests, so a
+/// stalled login fails instead of freezing the setup assistant.
+fn blocking_client() -> Result<reqwest::blocking::Client, NutmegError> {
+    reqwest::blocking::Client::builder()
+        .timeout(DEFAULT_REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| NutmegError::Network(format!("Failed to build HTTP client: {}", e)))
+}
+
+async fn post_signed(url: Url, authorization: String) -> Result<String, NutmegError> {
+    let client = http_client(DEFAULT_REQUEST_TIMEOUT)?;
+    client
+        .post(url)
+        .header("Authorization", authorization)
+        .header("Content-Length", "0")
+        .send()
+        .await
+        .map_err(|e| NutmegError::Network(format!("Failed to send request: {}", e)))?
+        .text()
+        .await
+        .map_err(|e| NutmegError::Network(format!("Failed to read text: {}", e)))
+}
+
+async fn fetch_request_token(
+    request_token_url: &str,
+    consumer_key: &str,
+    consumer_secret: &str,
+) -> Result<(String, String), NutmegError> {
+    let mut key = SigningKey::without_token(ClientSecret(consumer_secret.to_string()));
+    let mut data = OAuthData {
+        client_id: ClientId(consumer_key.to_string()),
+        token: None,
+        signature_method: SignatureMethod::HmacSha1,
+        nonce: Nonce::generate(),
+    };
+
+    let initiate = Url::parse(request_token_url)
+        .map_err(|e| NutmegError::Parse(format!("Invalid request token URL: {}", e)))?;
+
+    let callback = "oob".to_owned();
+    let req = SignableRequest::new(Method::Post, initiate.clone(), Default::default());
+    let authorization = data.authorization(req, AuthorizationType::RequestToken { callback }, &key);
+
+    let resp = post_signed(initiate, authorization).await?;
+    data.regen_nonce();
+
+    parse_token_response(&mut data, &mut key, &resp)
+}
+
+async fn fetch_access_token(
+    access_token_url: &str,
+    verification_code: &str,
+    consumer_key: String,
+    consumer_secret: String,
+    request_token: String,
+    request_token_secret: String,
+) -> Result<(String, String), NutmegError> {
+    let mut data = OAuthData {
+        client_id: ClientId(consumer_key),
+        token: Some(Token(request_token)),
+        signature_method: SignatureMethod::HmacSha1,
+        nonce: Nonce::generate(),
+    };
+    let mut key = SigningKey::with_token(
+        ClientSecret(consumer_secret),
+        TokenSecret(request_token_secret),
+    );
+
+    let access_url = Url::parse(access_token_url)
+        .map_err(|e| NutmegError::Parse(format!("Invalid URL: {}", e)))?;
+
+    let req = SignableRequest::new(Method::Post, access_url.clone(), Default::default());
+    let access_type = AuthorizationType::AccessToken {
+        verifier: verification_code.to_string(),
+    };
+    let authorization = data.authorization(req, access_type, &key);
+
+    let resp = post_signed(access_url, authorization).await?;
+    data.regen_nonce();
+
+    parse_token_response(&mut data, &mut key, &resp)
+}
+
+/// Non-blocking counterpart of `get_request_token_url`, for use from the UI.
+///
+/// Returns the authorization URL together with the temporary request token
+/// and its secret.
+pub async fn get_authorization_url_async(
+    consumer_key: &str,
+    consumer_secret: &str,
+    scopes: &[ChppScope],
+) -> Result<(String, String, String), NutmegError> {
+    let (token, secret) =
+        fetch_request_token(CHPP_OAUTH_REQUEST_TOKEN_URL, consumer_key, consumer_secret).await?;
+    Ok((authorization_url(&token, scopes), token, secret))
+}
+
+/// Non-blocking counterpart of `exchange_verification_code`.
+pub async fn exchange_verification_code_async(
+    verification_code: &str,
+    settings: &OauthSettings,
+) -> Result<(String, String), NutmegError> {
+    let consumer_key = settings.client_id.clone();
+    let consumer_secret = settings.client_secret.clone();
+    let request_token = settings.request_token.clone();
+    let oauth_secret_token = settings.oauth_secret_token.clone();
+
+    if consumer_key.is_empty() || consumer_secret.is_empty() {
+        return Err(NutmegError::Auth(
+            "Consumer key or secret missing in settings".to_string(),
+        ));
+    }
+
+    fetch_access_token(
+        CHPP_OAUTH_ACCESS_TOKEN_URL,
+        verification_code,
+        consumer_key,
+        consumer_secret,
+        request_token,
+        oauth_secret_token,
+    )
+    .await
+}
+
 pub fn create_oauth_context(
     consumer_key: &str,
     consumer_secret: &str,
@@ -291,12 +491,118 @@ mod tests {
         // HmacSha1 is the hardcoded signature method.
         matches!(data.signature_method, SignatureMethod::HmacSha1);
     }
+    /// Serve a single HTTP response with the given body on a local port.
+    async fn serve_once(body: &'static str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let mut request = Vec::new();
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            assert!(String::from_utf8_lossy(&request).contains("Authorization: OAuth"));
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        });
+        format!("http://{}/oauth/request_token.ashx", addr)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_request_token_async() {
+        let url = serve_once(
+            "oauth_token=req_token_123&oauth_token_secret=req_secret_456&oauth_callback_confirmed=true",
+        )
+        .await;
+
+        let (token, secret) = fetch_request_token(&url, "ckey", "csecret").await.unwrap();
+        assert_eq!(token, "req_token_123");
+        assert_eq!(secret, "req_secret_456");
+        assert_eq!(
+            authorization_url(&token, &[ChppScope::SetMatchOrder]),
+            format!(
+                "{}?oauth_token=req_token_123&scope=set_matchorder",
+                CHPP_OAUTH_AUTH_URL
+            )
+        );
+    }
+
+    #[test]
+    fn test_authorization_url_scopes() {
+        let url = authorization_url(
+            "tok",
+            &[ChppScope::SetMatchOrder, ChppScope::ManageChallenges],
+        );
+        assert!(url.ends_with("?oauth_token=tok&scope=set_matchorder,manage_challenges"));
+
+        let read_only = authorization_url("tok", &[]);
+        assert_eq!(
+            read_only,
+            format!("{}?oauth_token=tok", CHPP_OAUTH_AUTH_URL)
+        );
+        assert!(!read_only.contains("scope"));
+    }
+
+    #[test]
+    fn test_chpp_scope_names_round_trip() {
+        for scope in ChppScope::ALL {
+            assert_eq!(ChppScope::from_name(scope.as_str()), Some(scope));
+        }
+        assert_eq!(ChppScope::from_name("manage_team"), None);
+    }
+
+    #[test]
+    fn test_normalize_verification_code() {
+        assert_eq!(normalize_verification_code("Ab12Cd34").unwrap(), "Ab12Cd34");
+        assert_eq!(normalize_verification_code("  123456\n").unwrap(), "123456");
+
+        for raw in ["", "   ", "\n"] {
+            assert!(matches!(
+                normalize_verification_code(raw),
+                Err(NutmegError::Auth(_))
+            ));
+        }
+        for raw in ["12 34", "code=1234", "https://www.hattrick.org/"] {
+            assert!(matches!(
+                normalize_verification_code(raw),
+                Err(NutmegError::Auth(_))
+            ));
+        }
+        assert!(normalize_verification_code(&"a".repeat(65)).is_err());
+    }
+
     #[test]
     fn test_oauth_settings_default() {
         let settings = OauthSettings::default();
-        assert!(settings.request_token.borrow().is_empty());
-        assert!(settings.oauth_secret_token.borrow().is_empty());
-        assert!(settings.client_id.borrow().is_empty());
-        assert!(settings.client_secret.borrow().is_empty());
+        assert!(settings.request_token.is_empty());
+        assert!(settings.oauth_secret_token.is_empty());
+        assert!(settings.client_id.is_empty());
+        assert!(settings.client_secret.is_empty());
+    }
+
+    #[test]
+    fn test_oauth_settings_debug_hides_secrets() {
+        let settings = OauthSettings {
+            request_token: "rtok".to_string(),
+            oauth_secret_token: "rsecret".to_string(),
+            client_id: "ckey".to_string(),
+            client_secret: "csecret".to_string(),
+            ..Default::default()
+        };
+        let debug = format!("{:?}", settings);
+        assert!(debug.contains("rtok"));
+        assert!(!debug.contains("rsecret"));
+        assert!(!debug.contains("csecret"));
     }
 }
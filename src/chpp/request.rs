@@ -18,113 +18,168 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
+use chrono::NaiveDate;
 use http_types::{Method, Url};
 use log::{debug, info};
 use oauth_1a::*;
 use serde_xml_rs::from_str;
 use std::collections::BTreeMap;
+use std::time::Duration;
 
-use crate::error::NutmegError;
-use crate::chpp::metadata::ChppEndpoints;
+use crate::chpp::metadata::{ChppEndpoints, EndpointInfo};
 use crate::chpp::model::{
-    AvatarsData, ChppErrorResponse, HattrickData, LeagueDetailsData, MatchDetailsData,
-    MatchLineupData, MatchesArchiveData, MatchesData, Player, PlayerDetailsData, PlayersData,
-    StaffListData, WorldDetails,
+    Achievements, AllianceDetails, AllianceSearchResults, ArenaDetails, AvatarsData, Bookmarks,
+    Challenges, ChppErrorResponse, CupMatches, CurrentBids, Economy, HattrickData, HofPlayers,
+    LeagueDetailsData, LeagueFixtures, LiveActionType, LiveData, ManagerCompendium,
+    MatchDetailsData, MatchLineupData, MatchesArchiveData, MatchesData, Player, PlayerDetailsData,
+    PlayerEvents, PlayerTransfers, PlayersData, RegionDetails, SearchResults, SearchType,
+    StaffListData, Supporters, TeamTransfers, TournamentDetails, TournamentList, TrainingEvents,
+    TransferSearchCriteria, TransferSearchResults, Translations, WorldCup, WorldDetails,
+    WorldLanguages,
 };
-use crate::chpp::{CHPP_URL, NUTMEG_USER_AGENT};
+use crate::chpp::{user_agent, CHPP_URL};
+use crate::error::NutmegError;
 
 use serde::de::DeserializeOwned;
 
 pub async fn chpp_request<T: DeserializeOwned>(
-    file: &str,
-    version: &str,
+    client: &reqwest::Client,
+    endpoint: &EndpointInfo,
     extra_params: Option<&Vec<(&str, &str)>>,
-    mut data: OAuthData,
+    data: OAuthData,
     key: SigningKey,
 ) -> Result<T, NutmegError> {
-    use crate::chpp::retry::{should_retry, RetryConfig};
+    use crate::chpp::retry::{retry_operation, RetryConfig, TokioSleeper};
 
-    let config = RetryConfig::default();
-    let mut backoff_ms = config.initial_backoff_ms;
+    let config = RetryConfig::jittered();
+    let operation_name = format!("CHPP request to {} v{}", endpoint.name, endpoint.version);
+    // Each attempt signs with a fresh nonce, so the attempts take turns with
+    // the one `OAuthData`.
+    let data = tokio::sync::Mutex::new(data);
+    let (data, key) = (&data, &key);
 
-    for attempt in 0..=config.max_retries {
-        let result =
-            perform_single_request::<T>(file, version, extra_params, &mut data, &key).await;
-
-        match result {
-            Ok(data) => return Ok(data),
-            Err(e) => {
-                if attempt == config.max_retries {
-                    log::error!(
-                        "CHPP request to {} v{} failed after {} retries: {}",
-                        file,
-                        version,
-                        config.max_retries,
-                        e
-                    );
-                    return Err(e);
-                }
+    retry_operation(
+        &operation_name,
+        move || async move {
+            let mut data = data.lock().await;
+            perform_single_request::<T>(client, endpoint, extra_params, &mut data, key).await
+        },
+        &config,
+        &TokioSleeper,
+        &mut fastrand::Rng::new(),
+    )
+    .await
+}
 
-                if should_retry(&e) {
-                    log::warn!(
-                        "CHPP request to {} v{} attempt {}/{} failed: {}. Retrying in {}ms...",
-                        file,
-                        version,
-                        attempt + 1,
-                        config.max_retries + 1,
-                        e,
-                        backoff_ms
-                    );
+/// Build the request URL for an endpoint, taking `file` and `version`
+/// from its metadata so the declared version is always the one requested.
+fn build_request_url(
+    endpoint: &EndpointInfo,
+    extra_params: Option<&Vec<(&str, &str)>>,
+) -> Result<Url, NutmegError> {
+    let mut send_url_builder =
+        Url::parse(CHPP_URL).map_err(|e| NutmegError::Network(format!("Invalid URL: {}", e)))?;
+    {
+        let mut pairs = send_url_builder.query_pairs_mut();
+        pairs.append_pair("file", endpoint.name);
+        pairs.append_pair("version", endpoint.version);
 
-                    tokio::time::sleep(tokio::time::Duration::from_millis(backoff_ms)).await;
-                    backoff_ms = std::cmp::min(backoff_ms * 2, config.max_backoff_ms);
-                } else {
-                    log::error!(
-                        "CHPP request to {} v{} encountered non-retryable error: {}",
-                        file,
-                        version,
-                        e
-                    );
-                    return Err(e);
-                }
+        if let Some(extras) = extra_params {
+            for (k, v) in extras {
+                pairs.append_pair(k, v);
             }
         }
     }
+    Url::parse(send_url_builder.as_ref())
+        .map_err(|e| NutmegError::Network(format!("Invalid send URL: {}", e)))
+}
+
+/// CHPP error codes reporting that the access token was rejected, e.g. after
+/// the user revoked Nutmeg's access on Hattrick.
+pub const AUTH_CHPP_CODES: &[u32] = &[401];
+
+/// Convert a CHPP error document into an error. Rejected tokens become
+/// `NutmegError::Auth` so the caller can offer to re-authenticate.
+fn chpp_error(response: ChppErrorResponse) -> NutmegError {
+    if AUTH_CHPP_CODES.contains(&response.ErrorCode) {
+        return NutmegError::Auth(response.Error);
+    }
+    NutmegError::ChppApi {
+        code: response.ErrorCode,
+        message: response.Error,
+        error_guid: response.ErrorGUID,
+        request: response.Request,
+    }
+}
+
+/// How long a CHPP request may take, body included, unless the
+/// `HattrickClient` making it says otherwise.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) fn http_client(timeout: Duration) -> Result<reqwest::Client, NutmegError> {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| network_error("Failed to build HTTP client", e))
+}
 
-    unreachable!()
+/// Timeouts and connection failures are both `Network` errors, which the
+/// retry logic treats as transient.
+fn network_error(context: &str, e: reqwest::Error) -> NutmegError {
+    if e.is_timeout() {
+        NutmegError::Network(format!("{}: request timed out", context))
+    } else {
+        NutmegError::Network(format!("{}: {}", context, e))
+    }
+}
+
+/// Drops a leading UTF-8 BOM and any whitespace ahead of the XML
+/// declaration; the XML parser rejects a declaration that is not the very
+/// first thing in the document. The declaration itself is left in place.
+pub fn strip_xml_preamble(body: &str) -> &str {
+    body.trim_start_matches('\u{feff}').trim_start()
+}
+
+async fn send_chpp_get(
+    client: &reqwest::Client,
+    url: &str,
+    authorization: &str,
+) -> reqwest::Result<reqwest::Response> {
+    client
+        .get(url)
+        .header("Authorization", authorization)
+        .header("Content-Length", "0")
+        .header("User-Agent", user_agent())
+        .header("Accept-Language", "en")
+        .header(
+            "Accept",
+            "image/gif, image/x-xbitmap, image/jpeg, image/pjpeg, */*",
+        )
+        .send()
+        .await
 }
 
 async fn perform_single_request<T: DeserializeOwned>(
-    file: &str,
-    version: &str,
+    client: &reqwest::Client,
+    endpoint: &EndpointInfo,
     extra_params: Option<&Vec<(&str, &str)>>,
     data: &mut OAuthData,
     key: &SigningKey,
 ) -> Result<T, NutmegError> {
-    let chpp_str_url = CHPP_URL.replace(":file", file).replace(":version", version);
-    let chpp_url = Url::parse(chpp_str_url.as_str())
-        .map_err(|e| NutmegError::Network(format!("Invalid URL: {}", e)))?;
+    let (file, version) = (endpoint.name, endpoint.version);
+    let chpp_url =
+        Url::parse(CHPP_URL).map_err(|e| NutmegError::Network(format!("Invalid URL: {}", e)))?;
 
     let mut params = BTreeMap::new();
     params.insert(String::from("file"), String::from(file));
     params.insert(String::from("version"), String::from(version));
-
-    // Build URL for request with query parameters
-    let mut send_url_builder = chpp_url.clone();
-    {
-        let mut pairs = send_url_builder.query_pairs_mut();
-        pairs.append_pair("file", file);
-        pairs.append_pair("version", version);
-
-        if let Some(extras) = extra_params {
-            for (k, v) in extras {
-                pairs.append_pair(k, v);
-                params.insert(k.to_string(), v.to_string());
-            }
+    if let Some(extras) = extra_params {
+        for (k, v) in extras {
+            params.insert(k.to_string(), v.to_string());
         }
     }
-    let send_url = Url::parse(send_url_builder.as_ref())
-        .map_err(|e| NutmegError::Network(format!("Invalid send URL: {}", e)))?;
+
+    let send_url = build_request_url(endpoint, extra_params)?;
 
     data.regen_nonce();
     for (k, v) in data.parameters() {
@@ -141,32 +196,25 @@ async fn perform_single_request<T: DeserializeOwned>(
     let authorization = data.authorization(req, AuthorizationType::Request, key);
     debug!("---\nAuthorization: {}", authorization);
 
-    let client = reqwest::Client::new();
-    let response = client
-        .get(send_url)
-        .header("Authorization", authorization)
-        .header("Content-Length", "0")
-        .header("User-Agent", NUTMEG_USER_AGENT)
-        .header("Accept-Language", "en")
-        .header(
-            "Accept",
-            "image/gif, image/x-xbitmap, image/jpeg, image/pjpeg, */*",
-        )
-        .send()
-        .await;
+    let response = send_chpp_get(client, send_url.as_str(), &authorization).await;
 
     match response {
+        Ok(resp) if resp.status() == reqwest::StatusCode::UNAUTHORIZED => {
+            Err(NutmegError::Auth("unauthorized".to_string()))
+        }
         Ok(resp) => {
             let data_str = resp
                 .text()
                 .await
-                .map_err(|e| NutmegError::Network(format!("Failed to read response: {}", e)))?;
+                .map_err(|e| network_error("Failed to read response", e))?;
             info!("Output: {}", data_str);
 
             // Check if this is an error response before attempting deserialization
             if data_str.contains("<ErrorCode>") {
-                let error_response: ChppErrorResponse = from_str(data_str.as_str())
-                    .map_err(|e| NutmegError::Xml(format!("Failed to parse error response: {}", e)))?;
+                let error_response: ChppErrorResponse = from_str(strip_xml_preamble(&data_str))
+                    .map_err(|e| {
+                        NutmegError::Xml(format!("Failed to parse error response: {}", e))
+                    })?;
 
                 log::error!(
                     "CHPP API error {}: {} (Request: {}, GUID: {})",
@@ -176,12 +224,7 @@ async fn perform_single_request<T: DeserializeOwned>(
                     error_response.ErrorGUID.as_deref().unwrap_or("none")
                 );
 
-                return Err(NutmegError::ChppApi {
-                    code: error_response.ErrorCode,
-                    message: error_response.Error,
-                    error_guid: error_response.ErrorGUID,
-                    request: error_response.Request,
-                });
+                return Err(chpp_error(error_response));
             }
 
             // Special debug: save league details XML to file for inspection
@@ -193,7 +236,7 @@ async fn perform_single_request<T: DeserializeOwned>(
                 }
             }
 
-            let hattrick_data: T = match from_str(data_str.as_str()) {
+            let hattrick_data: T = match from_str(strip_xml_preamble(&data_str)) {
                 Ok(data) => data,
                 Err(e) => {
                     let preview = if data_str.len() > 100 {
@@ -221,23 +264,39 @@ async fn perform_single_request<T: DeserializeOwned>(
                         });
                     }
 
-                    return Err(NutmegError::Xml(format!("Failed to deserialize XML: {}", e)));
+                    return Err(NutmegError::Xml(format!(
+                        "Failed to deserialize XML: {}",
+                        e
+                    )));
                 }
             };
             Ok(hattrick_data)
         }
-        Err(e) => Err(NutmegError::Network(e.to_string())),
+        Err(e) => Err(network_error("CHPP request failed", e)),
     }
 }
 
+/// `leagueID` narrows the response to one league instead of all of them.
+fn world_details_params(league_id: Option<u32>) -> Vec<(&'static str, String)> {
+    league_id
+        .map(|lid| ("leagueID", lid.to_string()))
+        .into_iter()
+        .collect()
+}
+
 pub async fn world_details_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
+    league_id: Option<u32>,
 ) -> Result<WorldDetails, NutmegError> {
+    let owned = world_details_params(league_id);
+    let params: Vec<(&str, &str)> = owned.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
     chpp_request::<WorldDetails>(
-        ChppEndpoints::WORLD_DETAILS.name,
-        ChppEndpoints::WORLD_DETAILS.version,
-        None,
+        client,
+        &ChppEndpoints::WORLD_DETAILS,
+        Some(&params),
         data,
         key,
     )
@@ -245,6 +304,7 @@ pub async fn world_details_request(
 }
 
 pub async fn team_details_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     team_id: Option<u32>,
@@ -252,27 +312,15 @@ pub async fn team_details_request(
     if let Some(tid) = team_id {
         let tid_str = tid.to_string();
         let p = vec![("teamID", tid_str.as_str())];
-        chpp_request::<HattrickData>(
-            ChppEndpoints::TEAM_DETAILS.name,
-            ChppEndpoints::TEAM_DETAILS.version,
-            Some(&p),
-            data,
-            key,
-        )
-        .await
+        chpp_request::<HattrickData>(client, &ChppEndpoints::TEAM_DETAILS, Some(&p), data, key)
+            .await
     } else {
-        chpp_request::<HattrickData>(
-            ChppEndpoints::TEAM_DETAILS.name,
-            ChppEndpoints::TEAM_DETAILS.version,
-            None,
-            data,
-            key,
-        )
-        .await
+        chpp_request::<HattrickData>(client, &ChppEndpoints::TEAM_DETAILS, None, data, key).await
     }
 }
 
 pub async fn players_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     team_id: Option<u32>,
@@ -285,17 +333,11 @@ pub async fn players_request(
     }
     params.push(("actionType", "view"));
     params.push(("includeMatchInfo", "true"));
-    chpp_request::<PlayersData>(
-        ChppEndpoints::PLAYERS.name,
-        ChppEndpoints::PLAYERS.version,
-        Some(&params),
-        data,
-        key,
-    )
-    .await
+    chpp_request::<PlayersData>(client, &ChppEndpoints::PLAYERS, Some(&params), data, key).await
 }
 
 pub async fn player_details_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     player_id: u32,
@@ -304,8 +346,8 @@ pub async fn player_details_request(
     let params = vec![("playerID", pid_str.as_str())];
 
     let response = chpp_request::<PlayerDetailsData>(
-        ChppEndpoints::PLAYER_DETAILS.name,
-        ChppEndpoints::PLAYER_DETAILS.version,
+        client,
+        &ChppEndpoints::PLAYER_DETAILS,
         Some(&params),
         data,
         key,
@@ -315,6 +357,7 @@ pub async fn player_details_request(
 }
 
 pub async fn avatars_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     team_id: Option<u32>,
@@ -327,17 +370,11 @@ pub async fn avatars_request(
         params.push(("teamId", tid_str.as_str()));
     }
 
-    chpp_request::<AvatarsData>(
-        ChppEndpoints::AVATARS.name,
-        ChppEndpoints::AVATARS.version,
-        Some(&params),
-        data,
-        key,
-    )
-    .await
+    chpp_request::<AvatarsData>(client, &ChppEndpoints::AVATARS, Some(&params), data, key).await
 }
 
 pub async fn league_details_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     league_level_unit_id: u32,
@@ -346,8 +383,8 @@ pub async fn league_details_request(
     let params = vec![("leagueLevelUnitID", id_str.as_str())];
 
     chpp_request::<LeagueDetailsData>(
-        ChppEndpoints::LEAGUE_DETAILS.name,
-        ChppEndpoints::LEAGUE_DETAILS.version,
+        client,
+        &ChppEndpoints::LEAGUE_DETAILS,
         Some(&params),
         data,
         key,
@@ -356,6 +393,7 @@ pub async fn league_details_request(
 }
 
 pub async fn matches_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     team_id: Option<u32>,
@@ -367,42 +405,42 @@ pub async fn matches_request(
         params.push(("teamID", tid_str.as_str()));
     }
 
-    chpp_request::<MatchesData>(
-        ChppEndpoints::MATCHES.name,
-        ChppEndpoints::MATCHES.version,
-        Some(&params),
-        data,
-        key,
-    )
-    .await
+    chpp_request::<MatchesData>(client, &ChppEndpoints::MATCHES, Some(&params), data, key).await
 }
 
-pub async fn matches_archive_request(
-    data: OAuthData,
-    key: SigningKey,
+/// Query parameters for matchesarchive. Dates are sent as YYYY-MM-DD.
+fn matches_archive_params(
     team_id: Option<u32>,
-    first_match_date: Option<String>,
-    last_match_date: Option<String>,
-) -> Result<MatchesArchiveData, NutmegError> {
+    first_match_date: Option<NaiveDate>,
+    last_match_date: Option<NaiveDate>,
+) -> Vec<(&'static str, String)> {
     let mut params = Vec::new();
-    let tid_str;
     if let Some(tid) = team_id {
-        tid_str = tid.to_string();
-        params.push(("teamID", tid_str.as_str()));
+        params.push(("teamID", tid.to_string()));
     }
-
-    // We need to keep strings alive if we borrow them
-    // But since function args are owned Option<String>, we can borrow from them
-    if let Some(ref fmd) = first_match_date {
-        params.push(("FirstMatchDate", fmd.as_str()));
+    if let Some(date) = first_match_date {
+        params.push(("FirstMatchDate", date.format("%Y-%m-%d").to_string()));
     }
-    if let Some(ref lmd) = last_match_date {
-        params.push(("LastMatchDate", lmd.as_str()));
+    if let Some(date) = last_match_date {
+        params.push(("LastMatchDate", date.format("%Y-%m-%d").to_string()));
     }
+    params
+}
+
+pub async fn matches_archive_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: Option<u32>,
+    first_match_date: Option<NaiveDate>,
+    last_match_date: Option<NaiveDate>,
+) -> Result<MatchesArchiveData, NutmegError> {
+    let owned = matches_archive_params(team_id, first_match_date, last_match_date);
+    let params: Vec<(&str, &str)> = owned.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
     chpp_request::<MatchesArchiveData>(
-        ChppEndpoints::MATCHES_ARCHIVE.name,
-        ChppEndpoints::MATCHES_ARCHIVE.version,
+        client,
+        &ChppEndpoints::MATCHES_ARCHIVE,
         Some(&params),
         data,
         key,
@@ -410,6 +448,7 @@ pub async fn matches_archive_request(
     .await
 }
 pub async fn staff_list_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     team_id: Option<u32>,
@@ -421,17 +460,12 @@ pub async fn staff_list_request(
         params.push(("teamId", tid_str.as_str()));
     }
 
-    chpp_request::<StaffListData>(
-        ChppEndpoints::STAFF_LIST.name,
-        ChppEndpoints::STAFF_LIST.version,
-        Some(&params),
-        data,
-        key,
-    )
-    .await
+    chpp_request::<StaffListData>(client, &ChppEndpoints::STAFF_LIST, Some(&params), data, key)
+        .await
 }
 
 pub async fn match_details_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     match_id: u32,
@@ -445,8 +479,8 @@ pub async fn match_details_request(
     ];
 
     chpp_request::<MatchDetailsData>(
-        ChppEndpoints::MATCH_DETAILS.name,
-        ChppEndpoints::MATCH_DETAILS.version,
+        client,
+        &ChppEndpoints::MATCH_DETAILS,
         Some(&params),
         data,
         key,
@@ -455,6 +489,7 @@ pub async fn match_details_request(
 }
 
 pub async fn match_lineup_request(
+    client: &reqwest::Client,
     data: OAuthData,
     key: SigningKey,
     match_id: u32,
@@ -470,11 +505,620 @@ pub async fn match_lineup_request(
     ];
 
     chpp_request::<MatchLineupData>(
-        ChppEndpoints::MATCH_LINEUP.name,
-        ChppEndpoints::MATCH_LINEUP.version,
+        client,
+        &ChppEndpoints::MATCH_LINEUP,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn alliances_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    search_name: &str,
+) -> Result<AllianceSearchResults, NutmegError> {
+    let params = vec![("searchFor", search_name)];
+    chpp_request::<AllianceSearchResults>(
+        client,
+        &ChppEndpoints::ALLIANCES,
         Some(&params),
         data,
         key,
     )
     .await
 }
+
+pub async fn alliance_details_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    alliance_id: u32,
+) -> Result<AllianceDetails, NutmegError> {
+    let aid_str = alliance_id.to_string();
+    let params = vec![("actionType", "view"), ("allianceID", aid_str.as_str())];
+    chpp_request::<AllianceDetails>(
+        client,
+        &ChppEndpoints::ALLIANCE_DETAILS,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn transfers_player_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    player_id: u32,
+) -> Result<PlayerTransfers, NutmegError> {
+    let pid_str = player_id.to_string();
+    let params = vec![("playerID", pid_str.as_str())];
+    chpp_request::<PlayerTransfers>(
+        client,
+        &ChppEndpoints::TRANSFERS_PLAYER,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn player_events_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    player_id: u32,
+) -> Result<PlayerEvents, NutmegError> {
+    let pid_str = player_id.to_string();
+    let params = vec![("playerID", pid_str.as_str())];
+    chpp_request::<PlayerEvents>(
+        client,
+        &ChppEndpoints::PLAYER_EVENTS,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn current_bids_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+) -> Result<CurrentBids, NutmegError> {
+    let params = vec![("actionType", "view")];
+    chpp_request::<CurrentBids>(
+        client,
+        &ChppEndpoints::CURRENT_BIDS,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn search_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    search_type: SearchType,
+    query: &str,
+) -> Result<SearchResults, NutmegError> {
+    let type_str = search_type.code().to_string();
+    let params = vec![("searchType", type_str.as_str()), ("searchString", query)];
+    chpp_request::<SearchResults>(client, &ChppEndpoints::SEARCH, Some(&params), data, key).await
+}
+
+pub async fn region_details_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    region_id: u32,
+) -> Result<RegionDetails, NutmegError> {
+    let rid_str = region_id.to_string();
+    let params = vec![("regionID", rid_str.as_str())];
+    chpp_request::<RegionDetails>(
+        client,
+        &ChppEndpoints::REGION_DETAILS,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn world_languages_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+) -> Result<WorldLanguages, NutmegError> {
+    chpp_request::<WorldLanguages>(client, &ChppEndpoints::WORLD_LANGUAGES, None, data, key).await
+}
+
+pub async fn manager_compendium_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    user_id: Option<u32>,
+) -> Result<ManagerCompendium, NutmegError> {
+    if let Some(uid) = user_id {
+        let uid_str = uid.to_string();
+        let p = vec![("userId", uid_str.as_str())];
+        chpp_request::<ManagerCompendium>(
+            client,
+            &ChppEndpoints::MANAGER_COMPENDIUM,
+            Some(&p),
+            data,
+            key,
+        )
+        .await
+    } else {
+        chpp_request::<ManagerCompendium>(
+            client,
+            &ChppEndpoints::MANAGER_COMPENDIUM,
+            None,
+            data,
+            key,
+        )
+        .await
+    }
+}
+
+pub async fn cup_matches_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: Option<u32>,
+) -> Result<CupMatches, NutmegError> {
+    if let Some(tid) = team_id {
+        let tid_str = tid.to_string();
+        let p = vec![("teamID", tid_str.as_str())];
+        chpp_request::<CupMatches>(client, &ChppEndpoints::CUP_MATCHES, Some(&p), data, key).await
+    } else {
+        chpp_request::<CupMatches>(client, &ChppEndpoints::CUP_MATCHES, None, data, key).await
+    }
+}
+
+pub async fn training_events_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    player_id: u32,
+) -> Result<TrainingEvents, NutmegError> {
+    let pid_str = player_id.to_string();
+    let params = vec![("playerID", pid_str.as_str())];
+    chpp_request::<TrainingEvents>(
+        client,
+        &ChppEndpoints::TRAINING_EVENTS,
+        Some(&params),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn economy_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: Option<u32>,
+) -> Result<Economy, NutmegError> {
+    if let Some(tid) = team_id {
+        let tid_str = tid.to_string();
+        let p = vec![("teamId", tid_str.as_str())];
+        chpp_request::<Economy>(client, &ChppEndpoints::ECONOMY, Some(&p), data, key).await
+    } else {
+        chpp_request::<Economy>(client, &ChppEndpoints::ECONOMY, None, data, key).await
+    }
+}
+
+pub async fn arena_details_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    arena_id: Option<u32>,
+) -> Result<ArenaDetails, NutmegError> {
+    if let Some(aid) = arena_id {
+        let aid_str = aid.to_string();
+        let p = vec![("arenaID", aid_str.as_str())];
+        chpp_request::<ArenaDetails>(client, &ChppEndpoints::ARENA_DETAILS, Some(&p), data, key)
+            .await
+    } else {
+        chpp_request::<ArenaDetails>(client, &ChppEndpoints::ARENA_DETAILS, None, data, key).await
+    }
+}
+
+pub async fn league_fixtures_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    league_level_unit_id: u32,
+    season: Option<u32>,
+) -> Result<LeagueFixtures, NutmegError> {
+    let lku_str = league_level_unit_id.to_string();
+    let mut p = vec![("leagueLevelUnitID", lku_str.as_str())];
+    let season_str;
+    if let Some(s) = season {
+        season_str = s.to_string();
+        p.push(("season", season_str.as_str()));
+    }
+    chpp_request::<LeagueFixtures>(client, &ChppEndpoints::LEAGUE_FIXTURES, Some(&p), data, key)
+        .await
+}
+
+pub async fn transfers_team_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: u32,
+    page: u32,
+) -> Result<TeamTransfers, NutmegError> {
+    let tid_str = team_id.to_string();
+    let page_str = page.to_string();
+    let p = vec![
+        ("teamID", tid_str.as_str()),
+        ("pageIndex", page_str.as_str()),
+    ];
+    chpp_request::<TeamTransfers>(client, &ChppEndpoints::TRANSFERS_TEAM, Some(&p), data, key).await
+}
+
+pub async fn translations_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    language_id: u32,
+) -> Result<Translations, NutmegError> {
+    let lid_str = language_id.to_string();
+    let p = vec![("languageId", lid_str.as_str())];
+    chpp_request::<Translations>(client, &ChppEndpoints::TRANSLATIONS, Some(&p), data, key).await
+}
+
+pub async fn world_cup_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    cup_id: u32,
+    season: Option<u32>,
+) -> Result<WorldCup, NutmegError> {
+    let cid_str = cup_id.to_string();
+    let mut p = vec![("actionType", "viewMatches"), ("cupID", cid_str.as_str())];
+    let season_str;
+    if let Some(s) = season {
+        season_str = s.to_string();
+        p.push(("season", season_str.as_str()));
+    }
+    chpp_request::<WorldCup>(client, &ChppEndpoints::WORLD_CUP, Some(&p), data, key).await
+}
+
+pub async fn hof_players_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: Option<u32>,
+) -> Result<HofPlayers, NutmegError> {
+    if let Some(tid) = team_id {
+        let tid_str = tid.to_string();
+        let p = vec![("teamID", tid_str.as_str())];
+        chpp_request::<HofPlayers>(client, &ChppEndpoints::HOF_PLAYERS, Some(&p), data, key).await
+    } else {
+        chpp_request::<HofPlayers>(client, &ChppEndpoints::HOF_PLAYERS, None, data, key).await
+    }
+}
+
+pub async fn bookmarks_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+) -> Result<Bookmarks, NutmegError> {
+    chpp_request::<Bookmarks>(client, &ChppEndpoints::BOOKMARKS, None, data, key).await
+}
+
+pub async fn challenges_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: u32,
+    is_weekend: bool,
+) -> Result<Challenges, NutmegError> {
+    let tid_str = team_id.to_string();
+    let weekend = if is_weekend { "1" } else { "0" };
+    let p = vec![
+        ("actionType", "view"),
+        ("teamId", tid_str.as_str()),
+        ("isWeekendFriendly", weekend),
+    ];
+    chpp_request::<Challenges>(client, &ChppEndpoints::CHALLENGES, Some(&p), data, key).await
+}
+
+pub async fn tournament_list_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    user_id: Option<u32>,
+) -> Result<TournamentList, NutmegError> {
+    let uid_str = user_id.map(|id| id.to_string());
+    let mut p = vec![];
+    if let Some(uid) = uid_str.as_deref() {
+        p.push(("userId", uid));
+    }
+    chpp_request::<TournamentList>(client, &ChppEndpoints::TOURNAMENT_LIST, Some(&p), data, key)
+        .await
+}
+
+pub async fn tournament_details_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    tournament_id: u32,
+) -> Result<TournamentDetails, NutmegError> {
+    let tid_str = tournament_id.to_string();
+    let p = vec![("tournamentId", tid_str.as_str())];
+    chpp_request::<TournamentDetails>(
+        client,
+        &ChppEndpoints::TOURNAMENT_DETAILS,
+        Some(&p),
+        data,
+        key,
+    )
+    .await
+}
+
+pub async fn achievements_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    user_id: Option<u32>,
+) -> Result<Achievements, NutmegError> {
+    let uid_str = user_id.map(|id| id.to_string());
+    let mut p = vec![];
+    if let Some(uid) = uid_str.as_deref() {
+        p.push(("userID", uid));
+    }
+    chpp_request::<Achievements>(client, &ChppEndpoints::ACHIEVEMENTS, Some(&p), data, key).await
+}
+
+/// With a team id, lists the teams supporting that team; without one, the
+/// teams the authenticated user supports.
+pub async fn supporters_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    team_id: Option<u32>,
+) -> Result<Supporters, NutmegError> {
+    let tid_str = team_id.map(|id| id.to_string());
+    let p = match tid_str.as_deref() {
+        Some(tid) => vec![("actionType", "mysupporters"), ("teamId", tid)],
+        None => vec![("actionType", "supportedteams")],
+    };
+    chpp_request::<Supporters>(client, &ChppEndpoints::SUPPORTERS, Some(&p), data, key).await
+}
+
+/// `match_ids` are only sent, comma-separated, with `AddMatch` and
+/// `DeleteMatch`; the view actions return every subscribed match.
+pub async fn live_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    match_ids: &[u32],
+    action_type: LiveActionType,
+) -> Result<LiveData, NutmegError> {
+    let ids = match_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut p = vec![("actionType", action_type.as_param())];
+    if matches!(
+        action_type,
+        LiveActionType::AddMatch | LiveActionType::DeleteMatch
+    ) {
+        p.push(("matchID", ids.as_str()));
+        p.push(("sourceSystem", "hattrick"));
+    }
+    chpp_request::<LiveData>(client, &ChppEndpoints::LIVE, Some(&p), data, key).await
+}
+
+pub async fn transfer_search_request(
+    client: &reqwest::Client,
+    data: OAuthData,
+    key: SigningKey,
+    criteria: TransferSearchCriteria,
+) -> Result<TransferSearchResults, NutmegError> {
+    let owned = criteria.query_params();
+    let p: Vec<(&str, &str)> = owned
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    chpp_request::<TransferSearchResults>(
+        client,
+        &ChppEndpoints::TRANSFER_SEARCH,
+        Some(&p),
+        data,
+        key,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_response(code: u32) -> ChppErrorResponse {
+        ChppErrorResponse {
+            Error: "error".to_string(),
+            ErrorCode: code,
+            ErrorGUID: None,
+            Request: Some("/chppxml.ashx".to_string()),
+            LineNumber: None,
+        }
+    }
+
+    #[test]
+    fn test_chpp_error_maps_rejected_token_to_auth() {
+        for code in AUTH_CHPP_CODES {
+            assert!(matches!(
+                chpp_error(error_response(*code)),
+                NutmegError::Auth(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_chpp_error_keeps_other_codes() {
+        match chpp_error(error_response(50)) {
+            NutmegError::ChppApi { code, request, .. } => {
+                assert_eq!(code, 50);
+                assert_eq!(request.as_deref(), Some("/chppxml.ashx"));
+            }
+            other => panic!("Expected ChppApi, got {:?}", other),
+        }
+    }
+
+    const TEAM_DETAILS_XML: &str =
+        include_str!("../../tests/resources/teamdetails_announcements.xml");
+
+    fn parse_team_details(body: &str) -> HattrickData {
+        from_str(strip_xml_preamble(body)).expect("Failed to deserialize HattrickData data")
+    }
+
+    #[test]
+    fn test_strip_xml_preamble_removes_bom() {
+        assert_eq!(strip_xml_preamble("\u{feff}<A/>"), "<A/>");
+        assert_eq!(strip_xml_preamble("<A/>"), "<A/>");
+        assert_eq!(
+            strip_xml_preamble("\u{feff}\r\n<?xml version=\"1.0\"?><A/>"),
+            "<?xml version=\"1.0\"?><A/>"
+        );
+    }
+
+    #[test]
+    fn test_parse_team_details_with_bom() {
+        let body = format!("\u{feff}{}", TEAM_DETAILS_XML);
+        let data = parse_team_details(&body);
+        assert_eq!(data.Teams.Teams[0].TeamID, "1000");
+    }
+
+    #[test]
+    fn test_parse_team_details_with_xml_declaration() {
+        let body = format!(
+            "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n{}",
+            TEAM_DETAILS_XML
+        );
+        let data = parse_team_details(&body);
+        assert_eq!(data.User.Loginname, "tychobrailleur");
+
+        let body = format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n{}",
+            TEAM_DETAILS_XML
+        );
+        let data = parse_team_details(&body);
+        assert_eq!(data.Teams.Teams[0].TeamName, "Test Team A");
+    }
+
+    #[tokio::test]
+    async fn test_stalled_request_times_out_as_network_error() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        // Accept the connection, then never answer.
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        });
+
+        let client = http_client(Duration::from_millis(200)).unwrap();
+        let started = std::time::Instant::now();
+        let result =
+            send_chpp_get(&client, &format!("http://{}/chppxml.ashx", addr), "OAuth").await;
+        let elapsed = started.elapsed();
+
+        let err = result.expect_err("a stalled request should not succeed");
+        assert!(err.is_timeout());
+        match network_error("CHPP request failed", err) {
+            NutmegError::Network(msg) => assert!(msg.contains("timed out"), "{}", msg),
+            other => panic!("Expected a Network error, got {:?}", other),
+        }
+        assert!(elapsed >= Duration::from_millis(200), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn test_request_url_uses_endpoint_metadata() {
+        let url = build_request_url(&ChppEndpoints::TEAM_DETAILS, None).unwrap();
+        assert!(url.as_str().contains("file=teamdetails&version=3.8"));
+    }
+
+    #[test]
+    fn test_matches_archive_params_format_dates() {
+        let first = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let last = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        let owned = matches_archive_params(Some(12345), Some(first), Some(last));
+        let params: Vec<(&str, &str)> = owned.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let url = build_request_url(&ChppEndpoints::MATCHES_ARCHIVE, Some(&params)).unwrap();
+
+        assert!(
+            url.as_str()
+                .ends_with("&teamID=12345&FirstMatchDate=2026-01-05&LastMatchDate=2026-03-31"),
+            "{}",
+            url
+        );
+    }
+
+    #[test]
+    fn test_matches_archive_params_omit_unset_dates() {
+        assert_eq!(
+            matches_archive_params(None, None, None),
+            Vec::<(&str, String)>::new()
+        );
+        let last = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        assert_eq!(
+            matches_archive_params(None, None, Some(last)),
+            vec![("LastMatchDate", "2026-03-31".to_string())]
+        );
+    }
+
+    fn world_details_url(league_id: Option<u32>) -> String {
+        let owned = world_details_params(league_id);
+        let params: Vec<(&str, &str)> = owned.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        build_request_url(&ChppEndpoints::WORLD_DETAILS, Some(&params))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_world_details_url_filters_by_league() {
+        let url = world_details_url(Some(21));
+        assert!(url.ends_with("&leagueID=21"), "{}", url);
+    }
+
+    #[test]
+    fn test_world_details_url_omits_unset_league() {
+        let url = world_details_url(None);
+        assert!(!url.contains("leagueID"), "{}", url);
+        assert_eq!(
+            url,
+            format!(
+                "{}?file=worlddetails&version={}",
+                CHPP_URL,
+                ChppEndpoints::WORLD_DETAILS.version
+            )
+        );
+    }
+
+    #[test]
+    fn test_request_url_appends_extra_params() {
+        let params = vec![("teamID", "12345")];
+        let url = build_request_url(&ChppEndpoints::PLAYERS, Some(&params)).unwrap();
+        assert_eq!(
+            url.as_str(),
+            format!(
+                "{}?file=players&version={}&teamID=12345",
+                CHPP_URL,
+                ChppEndpoints::PLAYERS.version
+            )
+        );
+    }
+}
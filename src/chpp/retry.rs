@@ -24,6 +24,12 @@
 //! exponential backoff, handling transient failures transparently.
 
 use crate::error::NutmegError;
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// CHPP application error codes that indicate a temporary condition
+/// (server overloaded, maintenance in progress) worth retrying.
+pub const RETRYABLE_CHPP_CODES: &[u32] = &[50, 51];
 
 /// Configuration for retry behavior
 #[derive(Debug, Clone)]
@@ -34,6 +40,22 @@ pub struct RetryConfig {
     pub initial_backoff_ms: u64,
     /// Maximum backoff duration in milliseconds
     pub max_backoff_ms: u64,
+    /// CHPP `ErrorCode` values considered transient
+    pub retryable_chpp_codes: Vec<u32>,
+    /// Wait a random duration between zero and the backoff ("full jitter")
+    /// so concurrent retries do not all wake at the same time
+    pub jitter: bool,
+}
+
+impl RetryConfig {
+    /// The default configuration with jitter turned on, for requests that
+    /// may run concurrently and hit a rate limit together.
+    pub fn jittered() -> Self {
+        Self {
+            jitter: true,
+            ..Default::default()
+        }
+    }
 }
 
 impl Default for RetryConfig {
@@ -42,18 +64,48 @@ impl Default for RetryConfig {
             max_retries: 3,
             initial_backoff_ms: 1000, // 1 second
             max_backoff_ms: 32000,    // 32 seconds
+            retryable_chpp_codes: RETRYABLE_CHPP_CODES.to_vec(),
+            jitter: false,
         }
     }
 }
 
+/// Waits between retry attempts. Tests substitute an implementation that
+/// records the requested durations instead of sleeping.
+#[async_trait]
+pub trait Sleeper: Send + Sync {
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Sleeps on the tokio timer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+#[async_trait]
+impl Sleeper for TokioSleeper {
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// Delay before the next attempt for the current `backoff_ms`: the backoff
+/// itself, or a random value in `[0, backoff_ms]` when jitter is enabled.
+pub fn backoff_delay_ms(backoff_ms: u64, config: &RetryConfig, rng: &mut fastrand::Rng) -> u64 {
+    if config.jitter {
+        rng.u64(0..=backoff_ms)
+    } else {
+        backoff_ms
+    }
+}
+
 /// Determine if an error should trigger a retry
-pub fn should_retry(error: &NutmegError) -> bool {
+pub fn should_retry(error: &NutmegError, config: &RetryConfig) -> bool {
     match error {
         NutmegError::Network(_) => true,
         NutmegError::ChppApi { code, .. } => {
             // Retry on common transient error codes
             // 503 = Service unavailable, 429 = Rate limit
-            matches!(code, 503 | 429)
+            matches!(code, 503 | 429) || config.retryable_chpp_codes.contains(code)
         }
         _ => false,
     }
@@ -80,13 +132,66 @@ where
     F: Fn(oauth_1a::OAuthData, oauth_1a::SigningKey) -> Fut,
     G: Fn() -> (oauth_1a::OAuthData, oauth_1a::SigningKey),
     Fut: std::future::Future<Output = Result<T, NutmegError>>,
+{
+    retry_with_sleeper(
+        operation_name,
+        get_credentials,
+        operation,
+        config,
+        &TokioSleeper,
+        &mut fastrand::Rng::new(),
+    )
+    .await
+}
+
+/// Same as [`retry_with_backoff`], waiting between attempts with `sleeper`
+/// and drawing jitter from `rng`.
+pub async fn retry_with_sleeper<T, F, G, Fut, S>(
+    operation_name: &str,
+    get_credentials: G,
+    operation: F,
+    config: &RetryConfig,
+    sleeper: &S,
+    rng: &mut fastrand::Rng,
+) -> Result<T, NutmegError>
+where
+    F: Fn(oauth_1a::OAuthData, oauth_1a::SigningKey) -> Fut,
+    G: Fn() -> (oauth_1a::OAuthData, oauth_1a::SigningKey),
+    Fut: std::future::Future<Output = Result<T, NutmegError>>,
+    S: Sleeper + ?Sized,
+{
+    retry_operation(
+        operation_name,
+        || {
+            let (data, key) = get_credentials();
+            operation(data, key)
+        },
+        config,
+        sleeper,
+        rng,
+    )
+    .await
+}
+
+/// The retry loop behind [`retry_with_sleeper`], for operations that manage
+/// their own credentials (e.g. `chpp_request`, which reuses one `OAuthData`
+/// with a fresh nonce on each attempt).
+pub async fn retry_operation<T, F, Fut, S>(
+    operation_name: &str,
+    operation: F,
+    config: &RetryConfig,
+    sleeper: &S,
+    rng: &mut fastrand::Rng,
+) -> Result<T, NutmegError>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<T, NutmegError>>,
+    S: Sleeper + ?Sized,
 {
     let mut backoff_ms = config.initial_backoff_ms;
 
     for attempt in 0..=config.max_retries {
-        let (data, key) = get_credentials();
-
-        match operation(data, key).await {
+        match operation().await {
             Ok(result) => return Ok(result),
             Err(e) => {
                 if attempt == config.max_retries {
@@ -99,17 +204,18 @@ where
                     return Err(e);
                 }
 
-                if should_retry(&e) {
+                if should_retry(&e, config) {
+                    let delay_ms = backoff_delay_ms(backoff_ms, config, rng);
                     log::warn!(
                         "{} attempt {}/{} failed: {}. Retrying in {}ms...",
                         operation_name,
                         attempt + 1,
                         config.max_retries + 1,
                         e,
-                        backoff_ms
+                        delay_ms
                     );
 
-                    tokio::time::sleep(tokio::time::Duration::from_millis(backoff_ms)).await;
+                    sleeper.sleep(Duration::from_millis(delay_ms)).await;
                     backoff_ms = std::cmp::min(backoff_ms * 2, config.max_backoff_ms);
                 } else {
                     log::error!("{} encountered non-retryable error: {}", operation_name, e);
@@ -122,7 +228,8 @@ where
     unreachable!()
 }
 
-/// Convenience wrapper for retry with default configuration
+/// Convenience wrapper for retry with default configuration and jitter, so
+/// concurrent callers (e.g. player details) do not retry in lockstep.
 pub async fn retry_with_default_config<T, F, G, Fut>(
     operation_name: &str,
     get_credentials: G,
@@ -137,7 +244,7 @@ where
         operation_name,
         get_credentials,
         operation,
-        &RetryConfig::default(),
+        &RetryConfig::jittered(),
     )
     .await
 }
@@ -180,6 +287,7 @@ mod tests {
             max_retries: 3,
             initial_backoff_ms: 10,
             max_backoff_ms: 100,
+            ..Default::default()
         };
 
         let result = retry_with_backoff("test_op", get_creds, operation, &config).await;
@@ -217,6 +325,7 @@ mod tests {
             max_retries: 3,
             initial_backoff_ms: 10,
             max_backoff_ms: 100,
+            ..Default::default()
         };
 
         let result = retry_with_backoff("test_op", get_creds, operation, &config).await;
@@ -255,6 +364,7 @@ mod tests {
             max_retries: 2,
             initial_backoff_ms: 10,
             max_backoff_ms: 100,
+            ..Default::default()
         };
 
         let result = retry_with_backoff("test_op", get_creds, operation, &config).await;
@@ -262,4 +372,154 @@ mod tests {
         // Should attempt 3 times total (initial + 2 retries)
         assert_eq!(*attempts.lock().unwrap(), 3);
     }
+
+    fn chpp_error(code: u32) -> NutmegError {
+        NutmegError::ChppApi {
+            code,
+            message: "test".to_string(),
+            error_guid: None,
+            request: None,
+        }
+    }
+
+    #[test]
+    fn test_should_retry_transient_chpp_codes() {
+        let config = RetryConfig::default();
+        for code in RETRYABLE_CHPP_CODES {
+            assert!(should_retry(&chpp_error(*code), &config));
+        }
+        assert!(should_retry(&chpp_error(503), &config));
+    }
+
+    #[test]
+    fn test_should_not_retry_auth_chpp_code() {
+        let config = RetryConfig::default();
+        assert!(!should_retry(&chpp_error(401), &config));
+
+        // Codes are configurable: an empty set disables application-level retries.
+        let config = RetryConfig {
+            retryable_chpp_codes: Vec::new(),
+            ..Default::default()
+        };
+        assert!(!should_retry(&chpp_error(50), &config));
+    }
+
+    /// Records the requested sleeps and returns immediately.
+    #[derive(Default)]
+    struct RecordingSleeper {
+        durations: std::sync::Mutex<Vec<Duration>>,
+    }
+
+    #[async_trait]
+    impl Sleeper for RecordingSleeper {
+        async fn sleep(&self, duration: Duration) {
+            self.durations.lock().unwrap().push(duration);
+        }
+    }
+
+    #[test]
+    fn test_jittered_config() {
+        let config = RetryConfig::jittered();
+        assert!(config.jitter);
+        assert_eq!(config.max_retries, RetryConfig::default().max_retries);
+        assert!(!RetryConfig::default().jitter);
+    }
+
+    #[tokio::test]
+    async fn test_backoff_doubles_and_caps() {
+        let get_creds = || {
+            (
+                oauth_1a::OAuthData {
+                    client_id: oauth_1a::ClientId("test".to_string()),
+                    token: None,
+                    signature_method: oauth_1a::SignatureMethod::HmacSha1,
+                    nonce: oauth_1a::Nonce::generate(),
+                },
+                oauth_1a::SigningKey::without_token(oauth_1a::ClientSecret("test".to_string())),
+            )
+        };
+        let operation = |_data: oauth_1a::OAuthData, _key: oauth_1a::SigningKey| async {
+            Err::<&str, _>(NutmegError::Network("Persistent failure".to_string()))
+        };
+
+        let config = RetryConfig {
+            max_retries: 5,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 4000,
+            ..Default::default()
+        };
+        let sleeper = RecordingSleeper::default();
+
+        let mut rng = fastrand::Rng::new();
+
+        let result =
+            retry_with_sleeper("test_op", get_creds, operation, &config, &sleeper, &mut rng).await;
+        assert!(result.is_err());
+
+        let recorded: Vec<u64> = sleeper
+            .durations
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|d| d.as_millis() as u64)
+            .collect();
+        // One sleep between each of the 6 attempts.
+        assert_eq!(recorded, vec![1000, 2000, 4000, 4000, 4000]);
+    }
+
+    #[tokio::test]
+    async fn test_jittered_backoff_stays_within_backoff() {
+        let get_creds = || {
+            (
+                oauth_1a::OAuthData {
+                    client_id: oauth_1a::ClientId("test".to_string()),
+                    token: None,
+                    signature_method: oauth_1a::SignatureMethod::HmacSha1,
+                    nonce: oauth_1a::Nonce::generate(),
+                },
+                oauth_1a::SigningKey::without_token(oauth_1a::ClientSecret("test".to_string())),
+            )
+        };
+        let operation = |_data: oauth_1a::OAuthData, _key: oauth_1a::SigningKey| async {
+            Err::<&str, _>(NutmegError::ChppApi {
+                code: 429,
+                message: "Too many requests".to_string(),
+                error_guid: None,
+                request: None,
+            })
+        };
+
+        let config = RetryConfig {
+            max_retries: 5,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 4000,
+            jitter: true,
+            ..Default::default()
+        };
+        let sleeper = RecordingSleeper::default();
+        let mut rng = fastrand::Rng::with_seed(42);
+
+        let result =
+            retry_with_sleeper("test_op", get_creds, operation, &config, &sleeper, &mut rng).await;
+        assert!(result.is_err());
+
+        let recorded = sleeper.durations.lock().unwrap();
+        let backoffs = [1000, 2000, 4000, 4000, 4000];
+        assert_eq!(recorded.len(), backoffs.len());
+        for (delay, backoff) in recorded.iter().zip(backoffs) {
+            assert!(
+                delay.as_millis() as u64 <= backoff,
+                "{:?} > {}",
+                delay,
+                backoff
+            );
+        }
+    }
+
+    #[test]
+    fn test_backoff_delay_without_jitter_is_backoff() {
+        let config = RetryConfig::default();
+        let mut rng = fastrand::Rng::with_seed(7);
+        assert_eq!(backoff_delay_ms(2000, &config, &mut rng), 2000);
+    }
 }
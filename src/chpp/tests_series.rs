@@ -53,6 +53,71 @@ mod tests {
         assert_eq!(data.Teams[0].Points, 15);
     }
 
+    #[test]
+    fn test_deserialize_league_details_full_series() {
+        let teams: String = (1..=8)
+            .map(|pos| {
+                format!(
+                    "<Team>
+                        <UserId>{uid}</UserId>
+                        <TeamID>{tid}</TeamID>
+                        <TeamName>Team {pos}</TeamName>
+                        <Position>{pos}</Position>
+                        <PositionChange>0</PositionChange>
+                        <Matches>14</Matches>
+                        <GoalsFor>{gf}</GoalsFor>
+                        <GoalsAgainst>{ga}</GoalsAgainst>
+                        <Points>{pts}</Points>
+                        <Won>{won}</Won>
+                        <Draws>0</Draws>
+                        <Lost>{lost}</Lost>
+                    </Team>",
+                    uid = 500 + pos,
+                    tid = 2000 + pos,
+                    pos = pos,
+                    gf = 40 - 3 * pos,
+                    ga = 10 + 2 * pos,
+                    pts = 3 * (15 - pos),
+                    won = 15 - pos,
+                    lost = pos - 1,
+                )
+            })
+            .collect();
+        let xml = format!(
+            "<HattrickData>
+                <LeagueID>5</LeagueID>
+                <LeagueName>Italia</LeagueName>
+                <LeagueLevel>3</LeagueLevel>
+                <MaxLevel>10</MaxLevel>
+                <LeagueLevelUnitID>3620</LeagueLevelUnitID>
+                <LeagueLevelUnitName>III.1</LeagueLevelUnitName>
+                <CurrentMatchRound>14</CurrentMatchRound>
+                {}
+            </HattrickData>",
+            teams
+        );
+
+        let data: LeagueDetailsData =
+            from_str(&xml).expect("Failed to deserialize LeagueDetailsData");
+        assert_eq!(data.LeagueLevelUnitID, 3620);
+        assert_eq!(data.CurrentMatchRound, Some(14));
+        assert_eq!(data.Teams.len(), 8);
+
+        let last = &data.Teams[7];
+        assert_eq!(last.TeamID, "2008");
+        assert_eq!(last.TeamName, "Team 8");
+        assert_eq!(last.Position, 8);
+        assert_eq!(last.Matches, 14);
+        assert_eq!(last.GoalsFor, 16);
+        assert_eq!(last.GoalsAgainst, 26);
+        assert_eq!(last.Points, 21);
+        assert!(data
+            .Teams
+            .iter()
+            .enumerate()
+            .all(|(i, t)| t.Position == i as u32 + 1));
+    }
+
     #[test]
     fn test_deserialize_matches() {
         let xml = r#"<HattrickData><Team><TeamID>1001</TeamID><TeamName>My Team</TeamName><LeagueLevelUnitID>100</LeagueLevelUnitID><MatchList><Match><MatchID>5001</MatchID><HomeTeam><HomeTeamID>1001</HomeTeamID><HomeTeamName>My Team</HomeTeamName></HomeTeam><AwayTeam><AwayTeamID>1002</AwayTeamID><AwayTeamName>Opponent</AwayTeamName></AwayTeam><HomeGoals>2</HomeGoals><AwayGoals>1</AwayGoals><MatchDate>2023-11-01 15:00:00</MatchDate><MatchType>1</MatchType><Status>FINISHED</Status></Match><Match><MatchID>5002</MatchID><HomeTeam><HomeTeamID>1003</HomeTeamID><HomeTeamName>External</HomeTeamName></HomeTeam><AwayTeam><AwayTeamID>1001</AwayTeamID><AwayTeamName>My Team</AwayTeamName></AwayTeam><MatchDate>2023-11-08 15:00:00</MatchDate><MatchType>1</MatchType><Status>UPCOMING</Status></Match></MatchList></Team></HattrickData>"#;
@@ -0,0 +1,122 @@
+/* cli.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Command-line entry points that run without the GTK front end, e.g. for
+//! cron-driven syncs: `nutmeg --headless sync`.
+
+use crate::db::manager::DbManager;
+use crate::error::NutmegError;
+use crate::service::sync::{DataSyncService, SyncService};
+use std::sync::Arc;
+
+pub const HEADLESS_FLAG: &str = "--headless";
+
+pub const USAGE: &str = "Usage: nutmeg [--headless sync]";
+
+/// What the process should do, decided from its arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Launch the GTK application; remaining arguments are left to GTK.
+    Gui,
+    /// Download the latest data with the stored credentials, then exit.
+    HeadlessSync,
+}
+
+/// Maps the process arguments (including the program name) to a `Command`.
+/// Only `--headless` is recognised here; without it everything is handed
+/// to the GUI untouched.
+pub fn parse_args<I, S>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let args: Vec<S> = args.into_iter().skip(1).collect();
+    let mut args = args.iter().map(AsRef::as_ref);
+
+    match args.next() {
+        Some(HEADLESS_FLAG) => match (args.next(), args.next()) {
+            (Some("sync"), None) => Ok(Command::HeadlessSync),
+            _ => Err(USAGE.to_string()),
+        },
+        _ => Ok(Command::Gui),
+    }
+}
+
+/// Runs a full sync with the access token saved by a previous interactive
+/// login and returns a one-line summary.
+pub async fn run_headless_sync() -> Result<String, NutmegError> {
+    crate::config::validate_consumer_credentials().map_err(|e| NutmegError::Auth(e.to_string()))?;
+
+    let db = Arc::new(DbManager::new());
+    let sync = SyncService::new(db);
+
+    let result = sync
+        .perform_sync_with_stored_secrets(
+            crate::config::consumer_key(),
+            crate::config::consumer_secret(),
+            Box::new(|p, m| log::info!("Sync: {:.0}% - {}", p * 100.0, m)),
+        )
+        .await?;
+
+    match result {
+        Some((team_id, download_id)) => Ok(format!(
+            "Synced team {} (download {})",
+            team_id, download_id
+        )),
+        None => Err(NutmegError::Auth(
+            "No stored credentials; authorise Nutmeg from the GUI first".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args_headless_sync() {
+        assert_eq!(
+            parse_args(["nutmeg", "--headless", "sync"]),
+            Ok(Command::HeadlessSync)
+        );
+    }
+
+    #[test]
+    fn test_parse_args_defaults_to_gui() {
+        assert_eq!(parse_args(["nutmeg"]), Ok(Command::Gui));
+        assert_eq!(
+            parse_args(["nutmeg", "--gapplication-service"]),
+            Ok(Command::Gui)
+        );
+    }
+
+    #[test]
+    fn test_parse_args_rejects_unknown_headless_command() {
+        assert_eq!(parse_args(["nutmeg", "--headless"]), Err(USAGE.to_string()));
+        assert_eq!(
+            parse_args(["nutmeg", "--headless", "export"]),
+            Err(USAGE.to_string())
+        );
+        assert_eq!(
+            parse_args(["nutmeg", "--headless", "sync", "extra"]),
+            Err(USAGE.to_string())
+        );
+    }
+}
@@ -32,3 +32,61 @@ pub fn consumer_key() -> String {
 pub fn consumer_secret() -> String {
     env::var("HT_CONSUMER_SECRET").unwrap_or_default()
 }
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("HT_CONSUMER_KEY is not set")]
+    MissingConsumerKey,
+    #[error("HT_CONSUMER_SECRET is not set")]
+    MissingConsumerSecret,
+}
+
+/// Check that the CHPP consumer key and secret are available, so a missing
+/// configuration is reported up front rather than deep in the OAuth flow.
+pub fn validate_consumer_credentials() -> Result<(), ConfigError> {
+    check_consumer_credentials(&consumer_key(), &consumer_secret())
+}
+
+fn check_consumer_credentials(key: &str, secret: &str) -> Result<(), ConfigError> {
+    if key.trim().is_empty() {
+        return Err(ConfigError::MissingConsumerKey);
+    }
+    if secret.trim().is_empty() {
+        return Err(ConfigError::MissingConsumerSecret);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_consumer_credentials_present() {
+        assert_eq!(check_consumer_credentials("key", "secret"), Ok(()));
+    }
+
+    #[test]
+    fn test_check_consumer_credentials_empty() {
+        assert_eq!(
+            check_consumer_credentials("", "secret"),
+            Err(ConfigError::MissingConsumerKey)
+        );
+        assert_eq!(
+            check_consumer_credentials("key", ""),
+            Err(ConfigError::MissingConsumerSecret)
+        );
+    }
+
+    #[test]
+    fn test_check_consumer_credentials_whitespace_only() {
+        assert_eq!(
+            check_consumer_credentials("  \t", "secret"),
+            Err(ConfigError::MissingConsumerKey)
+        );
+        assert_eq!(
+            check_consumer_credentials("key", "\n "),
+            Err(ConfigError::MissingConsumerSecret)
+        );
+    }
+}
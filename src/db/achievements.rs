@@ -0,0 +1,173 @@
+/* achievements.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Achievement, Achievements};
+use crate::db::schema::achievements;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = achievements)]
+struct AchievementEntity {
+    user_id: i32,
+    achievement_id: i32,
+    download_id: i32,
+    title: String,
+    category_id: i32,
+    points: i32,
+    event_date: String,
+    number_of_events: Option<i32>,
+}
+
+impl From<AchievementEntity> for Achievement {
+    fn from(entity: AchievementEntity) -> Self {
+        Achievement {
+            AchievementTypeID: entity.achievement_id as u32,
+            AchievementTitle: entity.title,
+            CategoryID: entity.category_id as u32,
+            EventDate: entity.event_date,
+            Points: entity.points as u32,
+            NumberOfEvents: entity.number_of_events.map(|v| v as u32),
+            ..Default::default()
+        }
+    }
+}
+
+/// Store the achievements of `user_id`. A later fetch of the same
+/// achievement replaces the stored one.
+pub fn save_achievements(
+    conn: &mut SqliteConnection,
+    data: &Achievements,
+    user_id: u32,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<AchievementEntity> = data
+        .AchievementList
+        .Achievements
+        .iter()
+        .map(|a| AchievementEntity {
+            user_id: user_id as i32,
+            achievement_id: a.AchievementTypeID as i32,
+            download_id,
+            title: a.AchievementTitle.clone(),
+            category_id: a.CategoryID as i32,
+            points: a.Points as i32,
+            event_date: a.EventDate.clone(),
+            number_of_events: a.NumberOfEvents.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::replace_into(achievements::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save achievements: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Achievements of a user, most recently earned first.
+pub fn get_achievements(
+    conn: &mut SqliteConnection,
+    user_id: u32,
+) -> Result<Vec<Achievement>, NutmegError> {
+    let rows = achievements::table
+        .filter(achievements::user_id.eq(user_id as i32))
+        .order((
+            achievements::event_date.desc(),
+            achievements::achievement_id.asc(),
+        ))
+        .load::<AchievementEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load achievements: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(rows.into_iter().map(Achievement::from).collect())
+}
+
+/// Sum of the points of every stored achievement of a user; 0 when none.
+pub fn total_achievement_points(
+    conn: &mut SqliteConnection,
+    user_id: u32,
+) -> Result<u32, NutmegError> {
+    let total: Option<i64> = achievements::table
+        .filter(achievements::user_id.eq(user_id as i32))
+        .select(diesel::dsl::sum(achievements::points))
+        .first(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to sum achievement points: {}", e)).or_locked(&e)
+        })?;
+    Ok(total.unwrap_or(0) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::AchievementList;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn achievements(items: &[(u32, u32)]) -> Achievements {
+        Achievements {
+            AchievementList: AchievementList {
+                Achievements: items
+                    .iter()
+                    .map(|&(id, points)| Achievement {
+                        AchievementTypeID: id,
+                        AchievementTitle: format!("Achievement {}", id),
+                        CategoryID: 1,
+                        EventDate: format!("2026-01-{:02} 12:00:00", id),
+                        Points: points,
+                        ..Default::default()
+                    })
+                    .collect(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_total_achievement_points_sums_per_user() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-09T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+
+        assert_eq!(total_achievement_points(&mut conn, 1).unwrap(), 0);
+
+        save_achievements(&mut conn, &achievements(&[(1, 50), (2, 25)]), 1, dl1).unwrap();
+        save_achievements(&mut conn, &achievements(&[(1, 40)]), 2, dl1).unwrap();
+        // Refetching an achievement does not count it twice.
+        save_achievements(&mut conn, &achievements(&[(2, 25), (3, 10)]), 1, dl2).unwrap();
+
+        assert_eq!(total_achievement_points(&mut conn, 1).unwrap(), 85);
+        assert_eq!(total_achievement_points(&mut conn, 2).unwrap(), 40);
+
+        let ids: Vec<u32> = get_achievements(&mut conn, 1)
+            .unwrap()
+            .iter()
+            .map(|a| a.AchievementTypeID)
+            .collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+}
@@ -0,0 +1,193 @@
+/* arena.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{ArenaCapacity, ArenaDetails, ArenaInfo, ArenaTeam};
+use crate::db::schema::arena_details;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = arena_details)]
+struct ArenaDetailsEntity {
+    arena_id: i32,
+    download_id: i32,
+    arena_name: String,
+    team_id: Option<i32>,
+    team_name: Option<String>,
+    terraces: i32,
+    basic: i32,
+    roof: i32,
+    vip: i32,
+    expansion_date: Option<String>,
+    expanded_terraces: Option<i32>,
+    expanded_basic: Option<i32>,
+    expanded_roof: Option<i32>,
+    expanded_vip: Option<i32>,
+}
+
+pub fn save_arena_details(
+    conn: &mut SqliteConnection,
+    details: &ArenaDetails,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let arena = &details.Arena;
+    let current = &arena.CurrentCapacity;
+    let expanded = arena
+        .ExpandedCapacity
+        .as_ref()
+        .filter(|_| arena.is_expanding());
+    let entity = ArenaDetailsEntity {
+        arena_id: arena.ArenaID as i32,
+        download_id,
+        arena_name: arena.ArenaName.clone(),
+        team_id: arena.Team.as_ref().map(|t| t.TeamID as i32),
+        team_name: arena.Team.as_ref().map(|t| t.TeamName.clone()),
+        terraces: current.Terraces as i32,
+        basic: current.Basic as i32,
+        roof: current.Roof as i32,
+        vip: current.VIP as i32,
+        expansion_date: expanded.and_then(|c| c.ExpansionDate.clone()),
+        expanded_terraces: expanded.map(|c| c.Terraces as i32),
+        expanded_basic: expanded.map(|c| c.Basic as i32),
+        expanded_roof: expanded.map(|c| c.Roof as i32),
+        expanded_vip: expanded.map(|c| c.VIP as i32),
+    };
+
+    diesel::insert_or_ignore_into(arena_details::table)
+        .values(&entity)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save arena details: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Latest stored details for an arena.
+pub fn get_arena_details(
+    conn: &mut SqliteConnection,
+    aid: u32,
+) -> Result<Option<ArenaDetails>, NutmegError> {
+    use crate::db::schema::arena_details::dsl::*;
+
+    let entity = arena_details
+        .filter(arena_id.eq(aid as i32))
+        .order(download_id.desc())
+        .first::<ArenaDetailsEntity>(conn)
+        .optional()
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get arena details: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(entity.map(|e| {
+        let expanded = e.expanded_terraces.map(|t| ArenaCapacity {
+            Terraces: t as u32,
+            Basic: e.expanded_basic.unwrap_or_default() as u32,
+            Roof: e.expanded_roof.unwrap_or_default() as u32,
+            VIP: e.expanded_vip.unwrap_or_default() as u32,
+            ExpansionDate: e.expansion_date.clone(),
+        });
+        ArenaDetails {
+            Arena: ArenaInfo {
+                ArenaID: e.arena_id as u32,
+                ArenaName: e.arena_name,
+                Team: e.team_id.map(|tid| ArenaTeam {
+                    TeamID: tid as u32,
+                    TeamName: e.team_name.unwrap_or_default(),
+                }),
+                CurrentCapacity: ArenaCapacity {
+                    Terraces: e.terraces as u32,
+                    Basic: e.basic as u32,
+                    Roof: e.roof as u32,
+                    VIP: e.vip as u32,
+                    ExpansionDate: None,
+                },
+                ExpandedCapacity: expanded,
+            },
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::total_capacity;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn details(terraces: u32, expanded: Option<ArenaCapacity>) -> ArenaDetails {
+        ArenaDetails {
+            Arena: ArenaInfo {
+                ArenaID: 54321,
+                ArenaName: "Nutmeg Park".to_string(),
+                Team: Some(ArenaTeam {
+                    TeamID: 54321,
+                    TeamName: "Nutmeg FC".to_string(),
+                }),
+                CurrentCapacity: ArenaCapacity {
+                    Terraces: terraces,
+                    Basic: 3500,
+                    Roof: 1200,
+                    VIP: 300,
+                    ExpansionDate: None,
+                },
+                ExpandedCapacity: expanded,
+            },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_arena_details_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-08T00:00:00Z", "completed").unwrap();
+
+        assert!(get_arena_details(&mut conn, 54321).unwrap().is_none());
+
+        save_arena_details(&mut conn, &details(8000, None), dl1).unwrap();
+        let expansion = ArenaCapacity {
+            Terraces: 1000,
+            Basic: 0,
+            Roof: 500,
+            VIP: 0,
+            ExpansionDate: Some("2026-03-30 10:00:00".to_string()),
+        };
+        save_arena_details(&mut conn, &details(9000, Some(expansion)), dl2).unwrap();
+
+        let loaded = get_arena_details(&mut conn, 54321)
+            .unwrap()
+            .expect("arena details");
+        assert_eq!(loaded.Arena.ArenaName, "Nutmeg Park");
+        let team = loaded.Arena.Team.as_ref().expect("arena team");
+        assert_eq!(team.TeamID, 54321);
+        assert_eq!(team.TeamName, "Nutmeg FC");
+        assert_eq!(total_capacity(&loaded), 14000);
+        assert!(loaded.Arena.is_expanding());
+        let expanded = loaded.Arena.ExpandedCapacity.unwrap();
+        assert_eq!(expanded.total(), 1500);
+        assert_eq!(
+            expanded.ExpansionDate.as_deref(),
+            Some("2026-03-30 10:00:00")
+        );
+    }
+}
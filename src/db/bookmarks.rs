@@ -0,0 +1,159 @@
+/* bookmarks.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Bookmark, Bookmarks};
+use crate::db::schema::bookmarks;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = bookmarks)]
+struct BookmarkEntity {
+    bookmark_type: i32,
+    target_id: i32,
+    bookmark_id: i32,
+    download_id: i32,
+    text: String,
+    text2: Option<String>,
+    target_id2: Option<i32>,
+    comment: Option<String>,
+}
+
+impl From<BookmarkEntity> for Bookmark {
+    fn from(entity: BookmarkEntity) -> Self {
+        Bookmark {
+            BookmarkID: entity.bookmark_id as u32,
+            BookmarkTypeID: entity.bookmark_type as u32,
+            Text: entity.text,
+            Text2: entity.text2,
+            ObjectID: entity.target_id as u32,
+            ObjectID2: entity.target_id2.map(|v| v as u32),
+            Comment: entity.comment,
+        }
+    }
+}
+
+/// Store the user's bookmarks, replacing the previously synced set so that
+/// bookmarks removed on Hattrick disappear here too.
+pub fn save_bookmarks(
+    conn: &mut SqliteConnection,
+    data: &Bookmarks,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<BookmarkEntity> = data
+        .BookmarkList
+        .Bookmarks
+        .iter()
+        .map(|b| BookmarkEntity {
+            bookmark_type: b.BookmarkTypeID as i32,
+            target_id: b.ObjectID as i32,
+            bookmark_id: b.BookmarkID as i32,
+            download_id,
+            text: b.Text.clone(),
+            text2: b.Text2.clone(),
+            target_id2: b.ObjectID2.map(|v| v as i32),
+            comment: b.Comment.clone(),
+        })
+        .collect();
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::delete(bookmarks::table).execute(conn)?;
+        diesel::replace_into(bookmarks::table)
+            .values(&entities)
+            .execute(conn)?;
+        Ok(())
+    })
+    .map_err(|e| NutmegError::Db(format!("Failed to save bookmarks: {}", e)).or_locked(&e))
+}
+
+/// The user's bookmarks, grouped by type and in the order they were added.
+pub fn get_bookmarks(conn: &mut SqliteConnection) -> Result<Vec<Bookmark>, NutmegError> {
+    let rows = bookmarks::table
+        .order((bookmarks::bookmark_type.asc(), bookmarks::bookmark_id.asc()))
+        .load::<BookmarkEntity>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load bookmarks: {}", e)).or_locked(&e))?;
+
+    Ok(rows.into_iter().map(Bookmark::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{BookmarkList, BookmarkType};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn bookmark(id: u32, type_id: u32, object_id: u32) -> Bookmark {
+        Bookmark {
+            BookmarkID: id,
+            BookmarkTypeID: type_id,
+            Text: format!("Bookmark {}", id),
+            ObjectID: object_id,
+            ..Default::default()
+        }
+    }
+
+    fn bookmarks(list: Vec<Bookmark>) -> Bookmarks {
+        Bookmarks {
+            BookmarkList: BookmarkList { Bookmarks: list },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_bookmarks_replaces_previous_set() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+
+        save_bookmarks(
+            &mut conn,
+            &bookmarks(vec![bookmark(1, 1, 99999), bookmark(2, 2, 470002)]),
+            dl1,
+        )
+        .unwrap();
+        let mut player = bookmark(3, 2, 480003);
+        player.Comment = Some("Watch list".to_string());
+        save_bookmarks(
+            &mut conn,
+            &bookmarks(vec![player, bookmark(4, 3, 700123), bookmark(1, 1, 99999)]),
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_bookmarks(&mut conn).unwrap();
+        let keys: Vec<(BookmarkType, u32)> = loaded
+            .iter()
+            .map(|b| (b.bookmark_type(), b.ObjectID))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                (BookmarkType::Team, 99999),
+                (BookmarkType::Player, 480003),
+                (BookmarkType::Match, 700123)
+            ]
+        );
+        assert_eq!(loaded[1].Comment.as_deref(), Some("Watch list"));
+    }
+}
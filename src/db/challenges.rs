@@ -0,0 +1,244 @@
+/* challenges.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Challenge, Challenges};
+use crate::db::schema::challenges;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+/// Who sent a challenge, seen from the synced team.
+pub const CHALLENGE_SIDE_SENT: &str = "sent";
+pub const CHALLENGE_SIDE_RECEIVED: &str = "received";
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = challenges)]
+pub struct ChallengeEntry {
+    pub training_match_id: i32,
+    pub download_id: i32,
+    pub team_id: i32,
+    pub is_weekend: bool,
+    /// `CHALLENGE_SIDE_SENT` or `CHALLENGE_SIDE_RECEIVED`.
+    pub side: String,
+    pub match_time: String,
+    pub friendly_type: i32,
+    pub opponent_team_id: i32,
+    pub opponent_name: String,
+    pub arena_id: Option<i32>,
+    pub arena_name: Option<String>,
+    pub is_agreed: bool,
+}
+
+impl ChallengeEntry {
+    fn from_challenge(
+        challenge: &Challenge,
+        team_id: u32,
+        is_weekend: bool,
+        side: &str,
+        download_id: i32,
+    ) -> Self {
+        ChallengeEntry {
+            training_match_id: challenge.TrainingMatchID as i32,
+            download_id,
+            team_id: team_id as i32,
+            is_weekend,
+            side: side.to_string(),
+            match_time: challenge.MatchTime.clone(),
+            friendly_type: challenge.FriendlyType as i32,
+            opponent_team_id: challenge.Opponent.TeamID as i32,
+            opponent_name: challenge.Opponent.TeamName.clone(),
+            arena_id: challenge.Arena.as_ref().map(|a| a.ArenaID as i32),
+            arena_name: challenge.Arena.as_ref().map(|a| a.ArenaName.clone()),
+            is_agreed: challenge.IsAgreed,
+        }
+    }
+}
+
+pub fn save_challenges(
+    conn: &mut SqliteConnection,
+    data: &Challenges,
+    is_weekend: bool,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let team_id = data.Team.TeamID;
+    let sent = data.Team.ChallengesByMe.Challenges.iter().map(|c| {
+        ChallengeEntry::from_challenge(c, team_id, is_weekend, CHALLENGE_SIDE_SENT, download_id)
+    });
+    let received = data.Team.OffersByOthers.Challenges.iter().map(|c| {
+        ChallengeEntry::from_challenge(c, team_id, is_weekend, CHALLENGE_SIDE_RECEIVED, download_id)
+    });
+    let entries: Vec<ChallengeEntry> = sent.chain(received).collect();
+
+    // Replace the earlier fetch, so a challenge withdrawn since, or an
+    // empty list, does not leave stale rows behind.
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::delete(
+            challenges::table
+                .filter(challenges::team_id.eq(team_id as i32))
+                .filter(challenges::is_weekend.eq(is_weekend)),
+        )
+        .execute(conn)?;
+        diesel::insert_or_ignore_into(challenges::table)
+            .values(&entries)
+            .execute(conn)?;
+        Ok(())
+    })
+    .map_err(|e| NutmegError::Db(format!("Failed to save challenges: {}", e)).or_locked(&e))
+}
+
+/// Load a team's weekend or midweek challenges from their latest fetch,
+/// agreed friendly first.
+pub fn get_challenges(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+    is_weekend: bool,
+) -> Result<Vec<ChallengeEntry>, NutmegError> {
+    let latest: Option<i32> = challenges::table
+        .filter(challenges::team_id.eq(team_id as i32))
+        .filter(challenges::is_weekend.eq(is_weekend))
+        .select(diesel::dsl::max(challenges::download_id))
+        .first::<Option<i32>>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get challenges download: {}", e)).or_locked(&e)
+        })?;
+
+    let Some(latest) = latest else {
+        return Ok(Vec::new());
+    };
+
+    challenges::table
+        .filter(challenges::team_id.eq(team_id as i32))
+        .filter(challenges::is_weekend.eq(is_weekend))
+        .filter(challenges::download_id.eq(latest))
+        .order((
+            challenges::is_agreed.desc(),
+            challenges::training_match_id.asc(),
+        ))
+        .load::<ChallengeEntry>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load challenges: {}", e)).or_locked(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{ChallengeList, ChallengeOpponent, ChallengesTeam};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn challenge(id: u32, agreed: bool) -> Challenge {
+        Challenge {
+            TrainingMatchID: id,
+            MatchTime: "2026-03-12 19:30:00".to_string(),
+            FriendlyType: 0,
+            Opponent: ChallengeOpponent {
+                TeamID: id * 10,
+                TeamName: format!("Opponent {}", id),
+            },
+            Arena: None,
+            IsAgreed: agreed,
+        }
+    }
+
+    fn challenges(sent: Vec<Challenge>, received: Vec<Challenge>) -> Challenges {
+        Challenges {
+            Team: ChallengesTeam {
+                TeamID: 7,
+                TeamName: "Nutmeg FC".to_string(),
+                ChallengesByMe: ChallengeList { Challenges: sent },
+                OffersByOthers: ChallengeList {
+                    Challenges: received,
+                },
+            },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_challenges_returns_latest_download() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-09T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+
+        save_challenges(
+            &mut conn,
+            &challenges(vec![challenge(1, false)], vec![]),
+            true,
+            dl1,
+        )
+        .unwrap();
+        save_challenges(
+            &mut conn,
+            &challenges(vec![challenge(1, false)], vec![challenge(2, true)]),
+            true,
+            dl2,
+        )
+        .unwrap();
+        save_challenges(
+            &mut conn,
+            &challenges(vec![challenge(3, false)], vec![]),
+            false,
+            dl2,
+        )
+        .unwrap();
+
+        let weekend = get_challenges(&mut conn, 7, true).unwrap();
+        let ids: Vec<i32> = weekend.iter().map(|c| c.training_match_id).collect();
+        assert_eq!(ids, vec![2, 1]);
+        assert!(weekend[0].is_agreed);
+        assert_eq!(weekend[0].side, CHALLENGE_SIDE_RECEIVED);
+        assert_eq!(weekend[1].side, CHALLENGE_SIDE_SENT);
+        assert_eq!(weekend[1].opponent_name, "Opponent 1");
+
+        assert_eq!(get_challenges(&mut conn, 7, false).unwrap().len(), 1);
+        assert!(get_challenges(&mut conn, 8, true).unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_empty_fetch_clears_challenges() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-09T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+
+        save_challenges(
+            &mut conn,
+            &challenges(vec![challenge(1, true)], vec![]),
+            true,
+            dl1,
+        )
+        .unwrap();
+        save_challenges(
+            &mut conn,
+            &challenges(vec![challenge(3, false)], vec![]),
+            false,
+            dl1,
+        )
+        .unwrap();
+        save_challenges(&mut conn, &challenges(vec![], vec![]), true, dl2).unwrap();
+
+        assert!(get_challenges(&mut conn, 7, true).unwrap().is_empty());
+        // Midweek challenges are fetched separately.
+        assert_eq!(get_challenges(&mut conn, 7, false).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,223 @@
+/* cup_matches.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{CupAwayTeam, CupHomeTeam, CupMatch, CupMatchResult, CupMatches};
+use crate::db::schema::cup_matches;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = cup_matches)]
+struct CupMatchEntity {
+    match_id: i32,
+    download_id: i32,
+    cup_id: i32,
+    season: Option<i32>,
+    cup_round: Option<i32>,
+    match_date: String,
+    home_team_id: i32,
+    home_team_name: String,
+    away_team_id: i32,
+    away_team_name: String,
+    home_goals: Option<i32>,
+    away_goals: Option<i32>,
+}
+
+impl From<CupMatchEntity> for CupMatch {
+    fn from(entity: CupMatchEntity) -> Self {
+        let result = match (entity.home_goals, entity.away_goals) {
+            (None, None) => None,
+            (home, away) => Some(CupMatchResult {
+                HomeGoals: home.map(|v| v as u32),
+                AwayGoals: away.map(|v| v as u32),
+            }),
+        };
+        CupMatch {
+            MatchID: entity.match_id as u32,
+            MatchDate: entity.match_date,
+            HomeTeam: CupHomeTeam {
+                HomeTeamId: entity.home_team_id as u32,
+                HomeTeamName: entity.home_team_name,
+            },
+            AwayTeam: CupAwayTeam {
+                AwayTeamId: entity.away_team_id as u32,
+                AwayTeamName: entity.away_team_name,
+            },
+            MatchResult: result,
+        }
+    }
+}
+
+pub fn save_cup_matches(
+    conn: &mut SqliteConnection,
+    matches: &CupMatches,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let cup = &matches.Cup;
+    let entities: Vec<CupMatchEntity> = cup
+        .MatchList
+        .Matches
+        .iter()
+        .map(|m| CupMatchEntity {
+            match_id: m.MatchID as i32,
+            download_id,
+            cup_id: cup.CupID as i32,
+            season: cup.Season.map(|v| v as i32),
+            cup_round: cup.CupRound.map(|v| v as i32),
+            match_date: m.MatchDate.clone(),
+            home_team_id: m.HomeTeam.HomeTeamId as i32,
+            home_team_name: m.HomeTeam.HomeTeamName.clone(),
+            away_team_id: m.AwayTeam.AwayTeamId as i32,
+            away_team_name: m.AwayTeam.AwayTeamName.clone(),
+            home_goals: m
+                .MatchResult
+                .as_ref()
+                .and_then(|r| r.HomeGoals)
+                .map(|v| v as i32),
+            away_goals: m
+                .MatchResult
+                .as_ref()
+                .and_then(|r| r.AwayGoals)
+                .map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(cup_matches::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to save cup matches: {}", e)).or_locked(&e))?;
+    Ok(())
+}
+
+/// Cup fixtures involving the team, oldest first, using the latest stored
+/// version of each match.
+pub fn get_cup_matches(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<Vec<CupMatch>, NutmegError> {
+    let tid = team_id as i32;
+    let rows: Vec<CupMatchEntity> = cup_matches::table
+        .filter(
+            cup_matches::home_team_id
+                .eq(tid)
+                .or(cup_matches::away_team_id.eq(tid)),
+        )
+        .order((
+            cup_matches::match_date.asc(),
+            cup_matches::match_id.asc(),
+            cup_matches::download_id.desc(),
+        ))
+        .load::<CupMatchEntity>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load cup matches: {}", e)).or_locked(&e))?;
+
+    let mut seen = std::collections::HashSet::new();
+    Ok(rows
+        .into_iter()
+        .filter(|r| seen.insert(r.match_id))
+        .map(CupMatch::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{CupMatchList, CupMatchesCup};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn cup_match(id: u32, date: &str, home: u32, away: u32, goals: Option<(u32, u32)>) -> CupMatch {
+        CupMatch {
+            MatchID: id,
+            MatchDate: date.to_string(),
+            HomeTeam: CupHomeTeam {
+                HomeTeamId: home,
+                HomeTeamName: format!("Team {}", home),
+            },
+            AwayTeam: CupAwayTeam {
+                AwayTeamId: away,
+                AwayTeamName: format!("Team {}", away),
+            },
+            MatchResult: goals.map(|(h, a)| CupMatchResult {
+                HomeGoals: Some(h),
+                AwayGoals: Some(a),
+            }),
+        }
+    }
+
+    fn cup(round: u32, matches: Vec<CupMatch>) -> CupMatches {
+        CupMatches {
+            Cup: CupMatchesCup {
+                CupID: 137,
+                CupName: Some("Irish Cup".to_string()),
+                Season: Some(92),
+                CupRound: Some(round),
+                MatchList: CupMatchList { Matches: matches },
+            },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_cup_matches_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-17T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-24T00:00:00Z", "completed").unwrap();
+
+        assert!(get_cup_matches(&mut conn, 54321).unwrap().is_empty());
+
+        save_cup_matches(
+            &mut conn,
+            &cup(
+                4,
+                vec![
+                    cup_match(2, "2026-03-24 20:00:00", 11111, 54321, None),
+                    cup_match(3, "2026-03-24 20:00:00", 22222, 33333, None),
+                ],
+            ),
+            dl1,
+        )
+        .unwrap();
+        save_cup_matches(
+            &mut conn,
+            &cup(
+                4,
+                vec![
+                    cup_match(1, "2026-03-17 20:00:00", 54321, 98765, Some((3, 1))),
+                    cup_match(2, "2026-03-24 20:00:00", 11111, 54321, Some((0, 2))),
+                ],
+            ),
+            dl2,
+        )
+        .unwrap();
+
+        let matches = get_cup_matches(&mut conn, 54321).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].MatchID, 1);
+        assert_eq!(matches[0].AwayTeam.AwayTeamName, "Team 98765");
+        assert_eq!(matches[0].MatchResult.as_ref().unwrap().HomeGoals, Some(3));
+
+        // The later download carries the result of the second match.
+        assert_eq!(matches[1].MatchID, 2);
+        assert_eq!(matches[1].MatchResult.as_ref().unwrap().AwayGoals, Some(2));
+    }
+}
@@ -0,0 +1,324 @@
+/* diff.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::db::schema::{downloads, players};
+use crate::error::NutmegError;
+use crate::rating::types::PlayerSkill;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use std::collections::HashMap;
+
+/// Direction a value moved between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Up,
+    Down,
+    Flat,
+}
+
+impl Trend {
+    fn between(previous: i32, current: i32) -> Self {
+        match current.cmp(&previous) {
+            std::cmp::Ordering::Greater => Trend::Up,
+            std::cmp::Ordering::Less => Trend::Down,
+            std::cmp::Ordering::Equal => Trend::Flat,
+        }
+    }
+
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Trend::Up => "▲",
+            Trend::Down => "▼",
+            Trend::Flat => "–",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerTrend {
+    pub tsi: Trend,
+    pub form: Trend,
+}
+
+fn load_snapshot(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+    download_id: i32,
+) -> Result<Vec<(i32, i32, i32)>, NutmegError> {
+    players::table
+        .filter(players::team_id.eq(team_id as i32))
+        .filter(players::download_id.eq(download_id))
+        .select((players::id, players::tsi, players::player_form))
+        .load::<(i32, i32, i32)>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load player snapshot: {}", e)).or_locked(&e)
+        })
+}
+
+/// TSI and form trends of a team's players between two downloads, keyed on
+/// player ID. Players missing from either snapshot have no entry.
+pub fn player_trends(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+    current_download: i32,
+    previous_download: i32,
+) -> Result<HashMap<u32, PlayerTrend>, NutmegError> {
+    let previous: HashMap<i32, (i32, i32)> = load_snapshot(conn, team_id, previous_download)?
+        .into_iter()
+        .map(|(id, tsi, form)| (id, (tsi, form)))
+        .collect();
+
+    Ok(load_snapshot(conn, team_id, current_download)?
+        .into_iter()
+        .filter_map(|(id, tsi, form)| {
+            let (prev_tsi, prev_form) = previous.get(&id)?;
+            Some((
+                id as u32,
+                PlayerTrend {
+                    tsi: Trend::between(*prev_tsi, tsi),
+                    form: Trend::between(*prev_form, form),
+                },
+            ))
+        })
+        .collect())
+}
+
+/// The two most recent downloads holding players for the team, as
+/// `(current, previous)`.
+pub fn latest_player_downloads(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<Option<(i32, i32)>, NutmegError> {
+    let ids: Vec<i32> = players::table
+        .filter(players::team_id.eq(team_id as i32))
+        .select(players::download_id)
+        .distinct()
+        .order(players::download_id.desc())
+        .limit(2)
+        .load::<i32>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get player downloads: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(match ids.as_slice() {
+        [current, previous] => Some((*current, *previous)),
+        _ => None,
+    })
+}
+
+/// Trends between the team's two most recent player downloads, empty when
+/// there is no earlier snapshot to compare against.
+pub fn latest_player_trends(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<HashMap<u32, PlayerTrend>, NutmegError> {
+    match latest_player_downloads(conn, team_id)? {
+        Some((current, previous)) => player_trends(conn, team_id, current, previous),
+        None => Ok(HashMap::new()),
+    }
+}
+
+type SkillRow = (
+    String,
+    Option<i32>,
+    Option<i32>,
+    Option<i32>,
+    Option<i32>,
+    Option<i32>,
+    Option<i32>,
+    Option<i32>,
+    i32,
+    Option<i32>,
+    i32,
+    i32,
+);
+
+fn skill_value(row: &SkillRow, skill: PlayerSkill) -> Option<i32> {
+    match skill {
+        PlayerSkill::Keeper => row.1,
+        PlayerSkill::Defending => row.2,
+        PlayerSkill::Playmaking => row.3,
+        PlayerSkill::Passing => row.4,
+        PlayerSkill::Winger => row.5,
+        PlayerSkill::Scoring => row.6,
+        PlayerSkill::SetPieces => row.7,
+        PlayerSkill::Form => Some(row.8),
+        PlayerSkill::Stamina => row.9,
+        PlayerSkill::Experience => Some(row.10),
+        PlayerSkill::Loyalty => Some(row.11),
+    }
+}
+
+/// Level of one skill of a player at each completed download, oldest
+/// first. Snapshots where the skill was hidden are left out.
+pub fn skill_history(
+    conn: &mut SqliteConnection,
+    player_id: u32,
+    skill: PlayerSkill,
+) -> Result<Vec<(DateTime<Utc>, u32)>, NutmegError> {
+    let rows: Vec<SkillRow> = players::table
+        .inner_join(downloads::table)
+        .filter(players::id.eq(player_id as i32))
+        .filter(downloads::status.eq("completed"))
+        .order(downloads::id.asc())
+        .select((
+            downloads::timestamp,
+            players::keeper_skill,
+            players::defender_skill,
+            players::playmaker_skill,
+            players::passing_skill,
+            players::winger_skill,
+            players::scorer_skill,
+            players::set_pieces_skill,
+            players::player_form,
+            players::stamina_skill,
+            players::experience,
+            players::loyalty,
+        ))
+        .load::<SkillRow>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load skill history: {}", e)).or_locked(&e)
+        })?;
+
+    rows.iter()
+        .filter_map(|row| Some((&row.0, skill_value(row, skill)?)))
+        .map(|(ts, value)| {
+            let at = DateTime::parse_from_rfc3339(ts)
+                .map_err(|e| {
+                    NutmegError::Parse(format!("Invalid download timestamp {}: {}", ts, e))
+                })?
+                .with_timezone(&Utc);
+            Ok((at, value as u32))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{Player, PlayerSkills};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use crate::db::teams::save_players;
+    use serial_test::serial;
+
+    fn player(id: u32, tsi: u32, form: u32) -> Player {
+        Player {
+            PlayerID: id,
+            FirstName: "Test".to_string(),
+            LastName: format!("Player{}", id),
+            TSI: tsi,
+            PlayerForm: form,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_player_trends() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-08T00:00:00Z", "completed").unwrap();
+
+        assert_eq!(latest_player_downloads(&mut conn, 300).unwrap(), None);
+        assert!(latest_player_trends(&mut conn, 300).unwrap().is_empty());
+
+        save_players(
+            &mut conn,
+            &[player(1, 1000, 5), player(2, 2000, 7)],
+            300,
+            dl1,
+        )
+        .unwrap();
+        save_players(
+            &mut conn,
+            &[player(1, 1500, 5), player(2, 2000, 6), player(3, 800, 4)],
+            300,
+            dl2,
+        )
+        .unwrap();
+
+        assert_eq!(
+            latest_player_downloads(&mut conn, 300).unwrap(),
+            Some((dl2, dl1))
+        );
+
+        let trends = player_trends(&mut conn, 300, dl2, dl1).unwrap();
+        assert_eq!(trends[&1].tsi, Trend::Up);
+        assert_eq!(trends[&1].form, Trend::Flat);
+        assert_eq!(trends[&2].tsi, Trend::Flat);
+        assert_eq!(trends[&2].form, Trend::Down);
+        // New arrival, nothing to compare against.
+        assert_eq!(trends.get(&3), None);
+        assert_eq!(latest_player_trends(&mut conn, 300).unwrap(), trends);
+    }
+
+    #[test]
+    #[serial]
+    fn test_skill_history() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+
+        let snapshots = [
+            ("2026-03-01T00:00:00Z", 5),
+            ("2026-03-08T00:00:00Z", 6),
+            ("2026-03-15T00:00:00Z", 7),
+        ];
+        let scorer = |level: u32| {
+            let mut p = player(1, 1000, 5);
+            p.PlayerSkills = Some(PlayerSkills {
+                ScorerSkill: level,
+                ..Default::default()
+            });
+            p
+        };
+        for (ts, level) in snapshots {
+            let dl = create_download(&mut conn, ts, "completed").unwrap();
+            save_players(&mut conn, &[scorer(level)], 300, dl).unwrap();
+        }
+        // Failed downloads are not part of the history.
+        let failed = create_download(&mut conn, "2026-03-22T00:00:00Z", "failed").unwrap();
+        save_players(&mut conn, &[scorer(8)], 300, failed).unwrap();
+
+        let history = skill_history(&mut conn, 1, PlayerSkill::Scoring).unwrap();
+        let expected: Vec<(DateTime<Utc>, u32)> = snapshots
+            .iter()
+            .map(|(ts, level)| {
+                let at = DateTime::parse_from_rfc3339(ts).unwrap();
+                (at.with_timezone(&Utc), *level)
+            })
+            .collect();
+        assert_eq!(history, expected);
+
+        assert!(skill_history(&mut conn, 2, PlayerSkill::Scoring)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_trend_arrow() {
+        assert_eq!(Trend::Up.arrow(), "▲");
+        assert_eq!(Trend::Down.arrow(), "▼");
+        assert_eq!(Trend::Flat.arrow(), "–");
+    }
+}
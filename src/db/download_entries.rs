@@ -22,6 +22,8 @@
 // Record download metadata.
 
 use crate::db::schema::download_entries;
+use crate::error::{Context, NutmegError};
+use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 
 #[derive(Queryable, Selectable, Debug, Clone)]
@@ -38,6 +40,18 @@ pub struct DownloadEntry {
     pub retry_count: i32,
 }
 
+/// One endpoint call of a download, as shown in the sync report.
+#[derive(Queryable, Selectable, Debug, Clone, PartialEq)]
+#[diesel(table_name = download_entries)]
+pub struct DownloadEntryRow {
+    pub endpoint: String,
+    pub version: String,
+    pub status: String,
+    pub fetched_date: String,
+    pub error_message: Option<String>,
+    pub retry_count: i32,
+}
+
 #[derive(Insertable, Debug)]
 #[diesel(table_name = download_entries)]
 pub struct NewDownloadEntry {
@@ -67,6 +81,35 @@ pub fn create_download_entry(
     .get_result(conn)
 }
 
+/// Record the outcome of a single CHPP endpoint call for a download.
+///
+/// `fetched_date` is set to the current time.
+#[allow(clippy::too_many_arguments)]
+pub fn record_download_entry(
+    conn: &mut SqliteConnection,
+    download_id: i32,
+    endpoint: &str,
+    version: &str,
+    user_id: Option<i32>,
+    status: &str,
+    error_message: Option<String>,
+    retry_count: i32,
+) -> QueryResult<i32> {
+    create_download_entry(
+        conn,
+        NewDownloadEntry {
+            download_id,
+            endpoint: endpoint.to_string(),
+            version: version.to_string(),
+            user_id,
+            status: status.to_string(),
+            fetched_date: Utc::now().to_rfc3339(),
+            error_message,
+            retry_count,
+        },
+    )
+}
+
 #[derive(Insertable)]
 #[diesel(table_name = crate::db::schema::downloads)]
 pub struct NewDownload {
@@ -141,12 +184,277 @@ pub fn get_entries_for_download(
         .load::<DownloadEntry>(conn)
 }
 
+/// Endpoints fetched for a download, in the order they were fetched.
+pub fn get_download_entries(
+    conn: &mut SqliteConnection,
+    target_download_id: i32,
+) -> Result<Vec<DownloadEntryRow>, NutmegError> {
+    use crate::db::schema::download_entries::dsl::*;
+
+    download_entries
+        .filter(download_id.eq(target_download_id))
+        .order(id.asc())
+        .select(DownloadEntryRow::as_select())
+        .load(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get download entries: {}", e)).or_locked(&e)
+        })
+}
+
 /// Get the latest download ID from the downloads table
 pub fn get_latest_download_id(conn: &mut SqliteConnection) -> QueryResult<i32> {
     use crate::db::schema::downloads::dsl::*;
     downloads.select(id).order(id.desc()).first(conn)
 }
 
+/// Get the time of the most recent completed download, if any.
+///
+/// In-progress and failed downloads are ignored, so this is the time of the
+/// last sync that actually produced a usable snapshot.
+pub fn get_last_completed_sync_time(
+    conn: &mut SqliteConnection,
+) -> Result<Option<DateTime<Utc>>, NutmegError> {
+    use crate::db::schema::downloads::dsl::*;
+
+    let latest = downloads
+        .filter(status.eq("completed"))
+        .order(id.desc())
+        .select(timestamp)
+        .first::<String>(conn)
+        .optional()
+        .map_err(|e| NutmegError::Db(format!("Failed to get last sync time: {}", e)))?;
+
+    match latest {
+        Some(ts) => DateTime::parse_from_rfc3339(&ts)
+            .map(|dt| Some(dt.with_timezone(&Utc)))
+            .map_err(|e| NutmegError::Parse(format!("Invalid download timestamp {}: {}", ts, e))),
+        None => Ok(None),
+    }
+}
+
+/// Time elapsed between the last completed download and `now`, or `None`
+/// if nothing has been synced yet.
+pub fn data_age(
+    conn: &mut SqliteConnection,
+    now: DateTime<Utc>,
+) -> Result<Option<chrono::Duration>, NutmegError> {
+    Ok(get_last_completed_sync_time(conn)?.map(|synced| now - synced))
+}
+
+/// Data older than this gets a warning banner in the main window.
+pub const STALE_DATA_AGE: chrono::Duration = chrono::Duration::days(7);
+
+/// Whether the stored data is recent enough to show without a warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFreshness {
+    Fresh,
+    Stale(chrono::Duration),
+    NeverSynced,
+}
+
+impl DataFreshness {
+    /// Classify a `data_age` result; data exactly `threshold` old is stale.
+    pub fn from_age(age: Option<chrono::Duration>, threshold: chrono::Duration) -> Self {
+        match age {
+            None => DataFreshness::NeverSynced,
+            Some(age) if age >= threshold => DataFreshness::Stale(age),
+            Some(_) => DataFreshness::Fresh,
+        }
+    }
+}
+
+/// Get the time of the most recent successful fetch of `endpoint`, if any.
+pub fn get_last_successful_fetch(
+    conn: &mut SqliteConnection,
+    target_endpoint: &str,
+) -> Result<Option<DateTime<Utc>>, NutmegError> {
+    use crate::db::schema::download_entries::dsl::*;
+
+    let latest = download_entries
+        .filter(endpoint.eq(target_endpoint))
+        .filter(status.eq("success"))
+        .order(id.desc())
+        .select(fetched_date)
+        .first::<String>(conn)
+        .optional()
+        .map_err(|e| NutmegError::Db(format!("Failed to get last fetch time: {}", e)))?;
+
+    Ok(latest
+        .and_then(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+        .map(|dt| dt.with_timezone(&Utc)))
+}
+
+/// Whether world details (leagues, countries, currencies) are older than
+/// `max_age` and should be downloaded again.
+pub fn should_fetch_world_details(
+    conn: &mut SqliteConnection,
+    max_age: std::time::Duration,
+) -> Result<bool, NutmegError> {
+    let last_fetch = get_last_successful_fetch(
+        conn,
+        crate::chpp::metadata::ChppEndpoints::WORLD_DETAILS.name,
+    )?;
+
+    Ok(match last_fetch {
+        Some(fetched) => {
+            let max_age = chrono::Duration::from_std(max_age).unwrap_or(chrono::Duration::MAX);
+            Utc::now() - fetched >= max_age
+        }
+        None => true,
+    })
+}
+
+/// Number of completed downloads kept by the "Compact Database" action.
+pub const DEFAULT_DOWNLOADS_TO_KEEP: usize = 5;
+
+/// Tables holding per-download rows, children before their parents.
+///
+/// Foreign key enforcement is a per-connection setting in SQLite, and
+/// `download_entries` and `avatars` have no `ON DELETE CASCADE` anyway, so
+/// deleting a download removes these rows explicitly.
+const DOWNLOAD_CHILD_TABLES: &[&str] = &[
+    "players",
+    "teams",
+    "league_unit_teams",
+    "league_units",
+    "match_ratings",
+    "matches",
+    "staff",
+    "users",
+    "countries",
+    "currencies",
+    "leagues",
+    "regions",
+    "cups",
+    "languages",
+    "avatars",
+    "player_transfers",
+    "player_events",
+    "current_bids",
+    "region_details",
+    "cup_matches",
+    "training_events",
+    "arena_details",
+    "economy",
+    "matches_archive",
+    "league_fixtures",
+    "team_transfers",
+    "translations",
+    "world_cup_matches",
+    "hof_players",
+    "bookmarks",
+    "challenges",
+    "tournaments",
+    "achievements",
+    "supporters",
+    "national_teams",
+    "download_entries",
+];
+
+/// Versioned world tables, keyed on `id`. They are only fetched once per
+/// TTL, and some fetches cover a single league, so the app reads the newest
+/// row of each id wherever it was saved.
+const WORLD_DETAILS_TABLES: &[&str] =
+    &["countries", "currencies", "leagues", "regions", "languages"];
+
+/// Downloads that pruning must keep so no world details are lost: every
+/// download still holding the newest row of some country, currency, league,
+/// region or language, and the download with the last full country list.
+fn downloads_holding_world_details(
+    conn: &mut SqliteConnection,
+) -> Result<std::collections::HashSet<i32>, NutmegError> {
+    #[derive(QueryableByName)]
+    struct DownloadRow {
+        #[diesel(sql_type = diesel::sql_types::Integer)]
+        download_id: i32,
+    }
+
+    let mut held = std::collections::HashSet::new();
+    for table in WORLD_DETAILS_TABLES {
+        let rows = diesel::sql_query(format!(
+            "SELECT DISTINCT MAX(download_id) AS download_id FROM {} GROUP BY id",
+            table
+        ))
+        .load::<DownloadRow>(conn)
+        .context(&format!("Failed to list downloads holding {}", table))?;
+        held.extend(rows.into_iter().map(|row| row.download_id));
+    }
+    held.extend(crate::db::teams::last_full_country_list(conn)?);
+    Ok(held)
+}
+
+/// Tables of permanent history rather than per-download snapshots, whose
+/// rows are never fetched again. Pruning moves them onto the newest kept
+/// download instead of deleting them.
+const DOWNLOAD_CARRIED_TABLES: &[&str] = &["training_events"];
+
+/// Delete a download and every row recorded against it, in one transaction.
+pub fn delete_download(conn: &mut SqliteConnection, target_download_id: i32) -> QueryResult<()> {
+    use crate::db::schema::downloads;
+
+    conn.transaction(|conn| {
+        for table in DOWNLOAD_CHILD_TABLES {
+            diesel::sql_query(format!("DELETE FROM {} WHERE download_id = ?", table))
+                .bind::<diesel::sql_types::Integer, _>(target_download_id)
+                .execute(conn)?;
+        }
+        diesel::delete(downloads::table.find(target_download_id)).execute(conn)?;
+        Ok(())
+    })
+}
+
+/// Delete all but the `keep` most recent completed downloads, then `VACUUM`
+/// to give the freed pages back to the filesystem. Older downloads still
+/// holding the latest world details are kept too, and skill history is moved
+/// onto the newest kept download. Returns the number of downloads removed.
+pub fn prune_old_downloads(conn: &mut SqliteConnection, keep: usize) -> Result<usize, NutmegError> {
+    use crate::db::schema::downloads::dsl::*;
+
+    let held = downloads_holding_world_details(conn)?;
+    let stale: Vec<i32> = downloads
+        .filter(status.eq("completed"))
+        .order(id.desc())
+        .offset(keep as i64)
+        .select(id)
+        .load::<i32>(conn)
+        .context("Failed to list old downloads")?
+        .into_iter()
+        .filter(|download| !held.contains(download))
+        .collect();
+
+    let newest_kept: Option<i32> = downloads
+        .filter(id.ne_all(&stale))
+        .select(diesel::dsl::max(id))
+        .first(conn)
+        .context("Failed to find the newest kept download")?;
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        for download in &stale {
+            if let Some(newest_kept) = newest_kept {
+                for table in DOWNLOAD_CARRIED_TABLES {
+                    diesel::sql_query(format!(
+                        "UPDATE {} SET download_id = ? WHERE download_id = ?",
+                        table
+                    ))
+                    .bind::<diesel::sql_types::Integer, _>(newest_kept)
+                    .bind::<diesel::sql_types::Integer, _>(*download)
+                    .execute(conn)?;
+                }
+            }
+            delete_download(conn, *download)?;
+        }
+        Ok(())
+    })
+    .context("Failed to prune old downloads")?;
+
+    // VACUUM cannot run inside a transaction.
+    diesel::sql_query("VACUUM")
+        .execute(conn)
+        .context("Failed to vacuum database")?;
+
+    Ok(stale.len())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +508,44 @@ mod tests {
         assert_eq!(entries[0].retry_count, 0);
     }
 
+    #[test]
+    #[serial]
+    fn test_get_download_entries_in_fetch_order() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        let download = create_download(&mut conn, "2026-02-04T18:45:00Z", "completed")
+            .expect("Failed to create download");
+        let other = create_download(&mut conn, "2026-02-05T18:45:00Z", "completed")
+            .expect("Failed to create download");
+
+        for (dl, endpoint, status, error) in [
+            (download, "teamdetails", "success", None),
+            (other, "players", "success", None),
+            (download, "players", "success", None),
+            (download, "staff", "error", Some("timed out".to_string())),
+            (download, "economy", "success", None),
+        ] {
+            record_download_entry(&mut conn, dl, endpoint, "1.0", None, status, error, 0)
+                .expect("Failed to record entry");
+        }
+
+        let entries = get_download_entries(&mut conn, download).expect("Failed to get entries");
+        let endpoints: Vec<&str> = entries.iter().map(|e| e.endpoint.as_str()).collect();
+        assert_eq!(
+            endpoints,
+            vec!["teamdetails", "players", "staff", "economy"]
+        );
+        assert_eq!(entries[2].status, "error");
+        assert_eq!(entries[2].error_message.as_deref(), Some("timed out"));
+        assert_eq!(entries[0].version, "1.0");
+
+        assert!(get_download_entries(&mut conn, other + 1)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     #[serial]
     fn test_update_entry_status() {
@@ -247,4 +593,398 @@ mod tests {
         assert_eq!(entries[0].status, "success");
         assert_eq!(entries[0].retry_count, 1); // Should not increment
     }
+
+    #[test]
+    #[serial]
+    fn test_last_completed_sync_time_no_downloads() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        let last = get_last_completed_sync_time(&mut conn).expect("Query failed");
+        assert!(last.is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn test_last_completed_sync_time_in_progress_only() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        create_download(&mut conn, "2026-02-04T18:45:00Z", "in_progress")
+            .expect("Failed to create download");
+
+        let last = get_last_completed_sync_time(&mut conn).expect("Query failed");
+        assert!(last.is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn test_last_completed_sync_time_completed() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        create_download(&mut conn, "2026-02-03T10:00:00Z", "completed")
+            .expect("Failed to create download");
+        create_download(&mut conn, "2026-02-04T18:45:00+00:00", "completed")
+            .expect("Failed to create download");
+        create_download(&mut conn, "2026-02-05T08:00:00Z", "in_progress")
+            .expect("Failed to create download");
+
+        let last = get_last_completed_sync_time(&mut conn)
+            .expect("Query failed")
+            .expect("Expected a completed sync");
+        assert_eq!(last.to_rfc3339(), "2026-02-04T18:45:00+00:00");
+    }
+
+    fn parse_time(ts: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(ts)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    #[serial]
+    fn test_data_age_fresh() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        create_download(&mut conn, "2026-02-04T18:00:00Z", "completed")
+            .expect("Failed to create download");
+
+        let age = data_age(&mut conn, parse_time("2026-02-06T18:00:00Z")).expect("Query failed");
+        assert_eq!(age, Some(chrono::Duration::days(2)));
+        assert_eq!(
+            DataFreshness::from_age(age, STALE_DATA_AGE),
+            DataFreshness::Fresh
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_data_age_stale() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        create_download(&mut conn, "2026-01-20T09:00:00Z", "completed")
+            .expect("Failed to create download");
+        // A recent failed sync does not refresh the data.
+        create_download(&mut conn, "2026-02-10T09:00:00Z", "failed")
+            .expect("Failed to create download");
+
+        let age = data_age(&mut conn, parse_time("2026-02-10T09:00:00Z")).expect("Query failed");
+        assert_eq!(age, Some(chrono::Duration::days(21)));
+        assert_eq!(
+            DataFreshness::from_age(age, STALE_DATA_AGE),
+            DataFreshness::Stale(chrono::Duration::days(21))
+        );
+        assert_eq!(
+            DataFreshness::from_age(Some(STALE_DATA_AGE), STALE_DATA_AGE),
+            DataFreshness::Stale(STALE_DATA_AGE)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_data_age_never_synced() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        let age = data_age(&mut conn, Utc::now()).expect("Query failed");
+        assert_eq!(age, None);
+        assert_eq!(
+            DataFreshness::from_age(age, STALE_DATA_AGE),
+            DataFreshness::NeverSynced
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_should_fetch_world_details() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+        let ttl = std::time::Duration::from_secs(3600);
+
+        assert!(should_fetch_world_details(&mut conn, ttl).unwrap());
+
+        let download = create_download(&mut conn, "2026-02-04T18:45:00Z", "completed")
+            .expect("Failed to create download");
+        record_download_entry(
+            &mut conn,
+            download,
+            "worlddetails",
+            "1.9",
+            None,
+            "success",
+            None,
+            0,
+        )
+        .expect("Failed to record entry");
+        assert!(!should_fetch_world_details(&mut conn, ttl).unwrap());
+
+        // Once the TTL elapses the data is considered stale again.
+        let two_hours_ago = (Utc::now() - chrono::Duration::hours(2)).to_rfc3339();
+        diesel::update(download_entries::table)
+            .set(download_entries::fetched_date.eq(two_hours_ago))
+            .execute(&mut conn)
+            .expect("Failed to age entry");
+        assert!(should_fetch_world_details(&mut conn, ttl).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_prune_old_downloads_keeps_most_recent() {
+        use crate::chpp::model::Player;
+        use crate::db::schema::{players, teams};
+
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        let mut download_ids = Vec::new();
+        for day in 1..=4 {
+            let download = create_download(
+                &mut conn,
+                &format!("2026-02-0{}T10:00:00Z", day),
+                "completed",
+            )
+            .expect("Failed to create download");
+            diesel::sql_query(
+                "INSERT INTO teams (id, download_id, name, raw_data) VALUES (200, ?, 'Test FC', '')",
+            )
+            .bind::<diesel::sql_types::Integer, _>(download)
+            .execute(&mut conn)
+            .expect("Failed to insert team");
+            let squad = [Player {
+                PlayerID: 1,
+                ..Default::default()
+            }];
+            crate::db::teams::save_players(&mut conn, &squad, 200, download)
+                .expect("Failed to save players");
+            record_download_entry(
+                &mut conn, download, "players", "2.7", None, "success", None, 0,
+            )
+            .expect("Failed to record entry");
+            download_ids.push(download);
+        }
+
+        let removed = prune_old_downloads(&mut conn, 2).expect("Prune failed");
+        assert_eq!(removed, 2);
+
+        let remaining: Vec<i32> = downloads::table
+            .select(downloads::id)
+            .order(downloads::id.asc())
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(remaining, download_ids[2..]);
+
+        for table_download_ids in [
+            teams::table
+                .select(teams::download_id)
+                .load::<i32>(&mut conn)
+                .unwrap(),
+            players::table
+                .select(players::download_id)
+                .load::<i32>(&mut conn)
+                .unwrap(),
+            download_entries::table
+                .select(download_entries::download_id)
+                .load::<i32>(&mut conn)
+                .unwrap(),
+        ] {
+            let mut ids = table_download_ids;
+            ids.sort();
+            assert_eq!(ids, download_ids[2..]);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_prune_old_downloads_keeps_world_details() {
+        use crate::db::schema::{countries, leagues};
+
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        let download_ids: Vec<i32> = (1..=5)
+            .map(|day| {
+                create_download(
+                    &mut conn,
+                    &format!("2026-02-0{}T10:00:00Z", day),
+                    "completed",
+                )
+                .expect("Failed to create download")
+            })
+            .collect();
+        let insert = |conn: &mut SqliteConnection, query: &str, download: i32| {
+            diesel::sql_query(query)
+                .bind::<diesel::sql_types::Integer, _>(download)
+                .execute(conn)
+                .expect("Failed to insert world details");
+        };
+        // A full country list and every league in the first download...
+        insert(
+            &mut conn,
+            "INSERT INTO countries (id, download_id, name) VALUES (1, ?, 'Ireland'), (2, ?1, 'France')",
+            download_ids[0],
+        );
+        insert(
+            &mut conn,
+            "INSERT INTO leagues (id, download_id, name, league_system_id) VALUES (21, ?, 'Ireland', 1), (5, ?1, 'France', 1)",
+            download_ids[0],
+        );
+        // ...then a league-filtered refresh of Ireland in the second one.
+        insert(
+            &mut conn,
+            "INSERT INTO countries (id, download_id, name) VALUES (1, ?, 'Ireland')",
+            download_ids[1],
+        );
+        insert(
+            &mut conn,
+            "INSERT INTO leagues (id, download_id, name, league_system_id) VALUES (21, ?, 'Ireland', 1)",
+            download_ids[1],
+        );
+
+        let removed = prune_old_downloads(&mut conn, 2).expect("Prune failed");
+        assert_eq!(removed, 1);
+
+        let remaining: Vec<i32> = downloads::table
+            .select(downloads::id)
+            .order(downloads::id.asc())
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(
+            remaining,
+            vec![
+                download_ids[0],
+                download_ids[1],
+                download_ids[3],
+                download_ids[4]
+            ]
+        );
+
+        let mut country_ids: Vec<i32> = countries::table
+            .select(countries::id)
+            .distinct()
+            .load(&mut conn)
+            .unwrap();
+        country_ids.sort();
+        assert_eq!(country_ids, vec![1, 2]);
+        let mut league_ids: Vec<i32> = leagues::table
+            .select(leagues::id)
+            .distinct()
+            .load(&mut conn)
+            .unwrap();
+        league_ids.sort();
+        assert_eq!(league_ids, vec![5, 21]);
+        assert_eq!(
+            crate::db::teams::last_full_country_list(&mut conn).unwrap(),
+            Some(download_ids[0])
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_prune_old_downloads_keeps_training_events() {
+        use crate::chpp::model::TrainingEvent;
+        use crate::db::training_events::{get_training_events, save_training_events};
+
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+
+        let download_ids: Vec<i32> = (1..=3)
+            .map(|day| {
+                create_download(
+                    &mut conn,
+                    &format!("2026-02-0{}T10:00:00Z", day),
+                    "completed",
+                )
+                .expect("Failed to create download")
+            })
+            .collect();
+        let skill_up = TrainingEvent {
+            EventDate: "2026-01-15 12:00:00".to_string(),
+            SkillID: 3,
+            OldLevel: 6,
+            NewLevel: 7,
+            Season: Some(92),
+            MatchRound: Some(5),
+        };
+        save_training_events(&mut conn, 42, &[skill_up], download_ids[0])
+            .expect("Failed to save training events");
+
+        let removed = prune_old_downloads(&mut conn, 2).expect("Prune failed");
+        assert_eq!(removed, 1);
+
+        let events = get_training_events(&mut conn, 42).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].NewLevel, 7);
+        let carried: Vec<i32> = crate::db::schema::training_events::table
+            .select(crate::db::schema::training_events::download_id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(carried, vec![download_ids[2]]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_delete_download_leaves_no_orphans() {
+        use crate::chpp::model::Player;
+
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("Failed to run migrations");
+        let mut conn = db.get_connection().expect("Failed to get connection");
+        // Exercise the explicit deletes rather than the cascades.
+        diesel::sql_query("PRAGMA foreign_keys = OFF")
+            .execute(&mut conn)
+            .unwrap();
+
+        let old = create_download(&mut conn, "2026-02-01T10:00:00Z", "completed").unwrap();
+        let new = create_download(&mut conn, "2026-02-02T10:00:00Z", "completed").unwrap();
+        for download in [old, new] {
+            crate::db::teams::save_players(&mut conn, &[Player::default()], 200, download).unwrap();
+            record_download_entry(
+                &mut conn, download, "players", "2.7", None, "success", None, 0,
+            )
+            .unwrap();
+            diesel::sql_query(
+                "INSERT INTO avatars (player_id, download_id, image) VALUES (1, ?, x'00')",
+            )
+            .bind::<diesel::sql_types::Integer, _>(download)
+            .execute(&mut conn)
+            .unwrap();
+        }
+
+        delete_download(&mut conn, old).expect("Failed to delete download");
+
+        #[derive(QueryableByName)]
+        struct Count {
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            n: i64,
+        }
+        for table in DOWNLOAD_CHILD_TABLES {
+            let orphans: Count = diesel::sql_query(format!(
+                "SELECT COUNT(*) AS n FROM {} WHERE download_id = ?",
+                table
+            ))
+            .bind::<diesel::sql_types::Integer, _>(old)
+            .get_result(&mut conn)
+            .unwrap();
+            assert_eq!(orphans.n, 0, "orphan rows left in {}", table);
+        }
+        let remaining: Vec<i32> = downloads::table
+            .select(downloads::id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(remaining, vec![new]);
+        assert_eq!(get_entries_for_download(&mut conn, new).unwrap().len(), 1);
+    }
 }
@@ -0,0 +1,196 @@
+/* economy.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::chpp::model::{Economy, EconomyTeam};
+use crate::db::schema::{downloads, economy};
+use crate::error::NutmegError;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = economy)]
+struct EconomyEntity {
+    team_id: i32,
+    download_id: i32,
+    cash: i64,
+    expected_cash: Option<i64>,
+    fan_club_size: Option<i32>,
+    income_spectators: Option<i64>,
+    income_sponsors: Option<i64>,
+    income_sum: Option<i64>,
+    costs_sum: Option<i64>,
+    expected_weeks_total: Option<i64>,
+}
+
+pub fn save_economy(
+    conn: &mut SqliteConnection,
+    data: &Economy,
+    team_id: u32,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let team = &data.Team;
+    let entity = EconomyEntity {
+        team_id: team_id as i32,
+        download_id,
+        cash: team.Cash,
+        expected_cash: team.ExpectedCash,
+        fan_club_size: team.FanClubSize.map(|v| v as i32),
+        income_spectators: team.IncomeSpectators,
+        income_sponsors: team.IncomeSponsors,
+        income_sum: team.IncomeSum,
+        costs_sum: team.CostsSum,
+        expected_weeks_total: team.ExpectedWeeksTotal,
+    };
+
+    diesel::insert_or_ignore_into(economy::table)
+        .values(&entity)
+        .execute(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to save economy: {}", e)).or_locked(&e))?;
+    Ok(())
+}
+
+/// Latest stored economy of a team.
+pub fn get_economy(conn: &mut SqliteConnection, tid: u32) -> Result<Option<Economy>, NutmegError> {
+    let entity = economy::table
+        .filter(economy::team_id.eq(tid as i32))
+        .order(economy::download_id.desc())
+        .first::<EconomyEntity>(conn)
+        .optional()
+        .map_err(|e| NutmegError::Db(format!("Failed to get economy: {}", e)).or_locked(&e))?;
+
+    Ok(entity.map(|e| Economy {
+        Team: EconomyTeam {
+            TeamID: e.team_id as u32,
+            TeamName: String::new(),
+            Cash: e.cash,
+            ExpectedCash: e.expected_cash,
+            FanClubSize: e.fan_club_size.map(|v| v as u32),
+            IncomeSpectators: e.income_spectators,
+            IncomeSponsors: e.income_sponsors,
+            IncomeSum: e.income_sum,
+            CostsSum: e.costs_sum,
+            ExpectedWeeksTotal: e.expected_weeks_total,
+        },
+    }))
+}
+
+/// A team's cash at each completed download, oldest first.
+pub fn cash_history(
+    conn: &mut SqliteConnection,
+    tid: u32,
+) -> Result<Vec<(DateTime<Utc>, i64)>, NutmegError> {
+    let rows: Vec<(String, i64)> = economy::table
+        .inner_join(downloads::table)
+        .filter(economy::team_id.eq(tid as i32))
+        .filter(downloads::status.eq("completed"))
+        .order(downloads::id.asc())
+        .select((downloads::timestamp, economy::cash))
+        .load(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load cash history: {}", e)).or_locked(&e)
+        })?;
+
+    rows.into_iter()
+        .map(|(ts, cash)| {
+            let at = DateTime::parse_from_rfc3339(&ts)
+                .map_err(|e| {
+                    NutmegError::Parse(format!("Invalid download timestamp {}: {}", ts, e))
+                })?
+                .with_timezone(&Utc);
+            Ok((at, cash))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn economy_with_cash(cash: i64) -> Economy {
+        Economy {
+            Team: EconomyTeam {
+                TeamID: 54321,
+                TeamName: "Nutmeg FC".to_string(),
+                Cash: cash,
+                ExpectedCash: Some(cash + 50_000),
+                FanClubSize: Some(1842),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn at(ts: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(ts)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    #[serial]
+    fn test_cash_history_is_chronological() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-08T00:00:00Z", "completed").unwrap();
+        let dl3 = create_download(&mut conn, "2026-03-15T00:00:00Z", "completed").unwrap();
+        let failed = create_download(&mut conn, "2026-03-16T00:00:00Z", "failed").unwrap();
+
+        assert!(cash_history(&mut conn, 54321).unwrap().is_empty());
+
+        // Saved out of order: the series follows the downloads, not insertion.
+        save_economy(&mut conn, &economy_with_cash(1_250_000), 54321, dl3).unwrap();
+        save_economy(&mut conn, &economy_with_cash(1_000_000), 54321, dl1).unwrap();
+        save_economy(&mut conn, &economy_with_cash(-20_000), 54321, dl2).unwrap();
+        save_economy(&mut conn, &economy_with_cash(9_999_999), 54321, failed).unwrap();
+        save_economy(&mut conn, &economy_with_cash(500), 11111, dl2).unwrap();
+
+        assert_eq!(
+            cash_history(&mut conn, 54321).unwrap(),
+            vec![
+                (at("2026-03-01T00:00:00Z"), 1_000_000),
+                (at("2026-03-08T00:00:00Z"), -20_000),
+                (at("2026-03-15T00:00:00Z"), 1_250_000),
+            ]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_economy_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl = create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+
+        assert!(get_economy(&mut conn, 54321).unwrap().is_none());
+        save_economy(&mut conn, &economy_with_cash(3_000_000_000), 54321, dl).unwrap();
+
+        let loaded = get_economy(&mut conn, 54321).unwrap().expect("economy");
+        assert_eq!(loaded.Team.TeamID, 54321);
+        assert_eq!(loaded.Team.Cash, 3_000_000_000);
+        assert_eq!(loaded.Team.ExpectedCash, Some(3_000_050_000));
+        assert_eq!(loaded.Team.FanClubSize, Some(1842));
+    }
+}
@@ -0,0 +1,211 @@
+/* hof_players.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{HofPlayer, HofPlayers};
+use crate::db::schema::hof_players;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = hof_players)]
+struct HofPlayerEntity {
+    player_id: i32,
+    download_id: i32,
+    team_id: i32,
+    first_name: String,
+    nick_name: Option<String>,
+    last_name: String,
+    age: i32,
+    arrival_date: Option<String>,
+    hof_date: String,
+    hof_age: Option<i32>,
+    expert_type: i32,
+    keeper_skill: Option<i32>,
+    playmaker_skill: Option<i32>,
+    scorer_skill: Option<i32>,
+    passing_skill: Option<i32>,
+    winger_skill: Option<i32>,
+    defender_skill: Option<i32>,
+    set_pieces_skill: Option<i32>,
+}
+
+impl From<HofPlayerEntity> for HofPlayer {
+    fn from(entity: HofPlayerEntity) -> Self {
+        let skill = |v: Option<i32>| v.map(|v| v as u32);
+        HofPlayer {
+            PlayerId: entity.player_id as u32,
+            FirstName: entity.first_name,
+            NickName: entity.nick_name,
+            LastName: entity.last_name,
+            Age: entity.age as u32,
+            ArrivalDate: entity.arrival_date,
+            HofDate: entity.hof_date,
+            HofAge: entity.hof_age.map(|v| v as u32),
+            ExpertType: entity.expert_type as u32,
+            KeeperSkill: skill(entity.keeper_skill),
+            PlaymakerSkill: skill(entity.playmaker_skill),
+            ScorerSkill: skill(entity.scorer_skill),
+            PassingSkill: skill(entity.passing_skill),
+            WingerSkill: skill(entity.winger_skill),
+            DefenderSkill: skill(entity.defender_skill),
+            SetPiecesSkill: skill(entity.set_pieces_skill),
+        }
+    }
+}
+
+pub fn save_hof_players(
+    conn: &mut SqliteConnection,
+    hof: &HofPlayers,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let skill = |v: Option<u32>| v.map(|v| v as i32);
+    let entities: Vec<HofPlayerEntity> = hof
+        .PlayerList
+        .Players
+        .iter()
+        .map(|p| HofPlayerEntity {
+            player_id: p.PlayerId as i32,
+            download_id,
+            team_id: hof.TeamID as i32,
+            first_name: p.FirstName.clone(),
+            nick_name: p.NickName.clone(),
+            last_name: p.LastName.clone(),
+            age: p.Age as i32,
+            arrival_date: p.ArrivalDate.clone(),
+            hof_date: p.HofDate.clone(),
+            hof_age: p.HofAge.map(|v| v as i32),
+            expert_type: p.ExpertType as i32,
+            keeper_skill: skill(p.KeeperSkill),
+            playmaker_skill: skill(p.PlaymakerSkill),
+            scorer_skill: skill(p.ScorerSkill),
+            passing_skill: skill(p.PassingSkill),
+            winger_skill: skill(p.WingerSkill),
+            defender_skill: skill(p.DefenderSkill),
+            set_pieces_skill: skill(p.SetPiecesSkill),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(hof_players::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to save hof players: {}", e)).or_locked(&e))?;
+    Ok(())
+}
+
+/// Load a team's Hall of Fame from its latest download, in induction order.
+pub fn get_hof_players(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<Vec<HofPlayer>, NutmegError> {
+    let latest: Option<i32> = hof_players::table
+        .filter(hof_players::team_id.eq(team_id as i32))
+        .select(diesel::dsl::max(hof_players::download_id))
+        .first::<Option<i32>>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get hof players download: {}", e)).or_locked(&e)
+        })?;
+
+    let Some(latest) = latest else {
+        return Ok(Vec::new());
+    };
+
+    let rows = hof_players::table
+        .filter(hof_players::team_id.eq(team_id as i32))
+        .filter(hof_players::download_id.eq(latest))
+        .order((hof_players::hof_date.asc(), hof_players::player_id.asc()))
+        .load::<HofPlayerEntity>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load hof players: {}", e)).or_locked(&e))?;
+
+    Ok(rows.into_iter().map(HofPlayer::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::HofPlayerList;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn hof_player(id: u32, hof_date: &str) -> HofPlayer {
+        HofPlayer {
+            PlayerId: id,
+            FirstName: "Declan".to_string(),
+            NickName: Some("The Wall".to_string()),
+            LastName: format!("Player {}", id),
+            Age: 38,
+            ArrivalDate: None,
+            HofDate: hof_date.to_string(),
+            HofAge: Some(37),
+            ExpertType: 1,
+            KeeperSkill: Some(17),
+            SetPiecesSkill: Some(14),
+            ..Default::default()
+        }
+    }
+
+    fn hof(team_id: u32, players: Vec<HofPlayer>) -> HofPlayers {
+        HofPlayers {
+            TeamID: team_id,
+            PlayerList: HofPlayerList { Players: players },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_hof_players_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+
+        assert!(get_hof_players(&mut conn, 7).unwrap().is_empty());
+
+        save_hof_players(
+            &mut conn,
+            &hof(7, vec![hof_player(1, "2025-06-14 08:00:00")]),
+            dl1,
+        )
+        .unwrap();
+        let latest = vec![
+            hof_player(2, "2025-12-06 08:00:00"),
+            hof_player(1, "2025-06-14 08:00:00"),
+        ];
+        save_hof_players(&mut conn, &hof(7, latest), dl2).unwrap();
+        save_hof_players(
+            &mut conn,
+            &hof(8, vec![hof_player(3, "2024-01-01 08:00:00")]),
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_hof_players(&mut conn, 7).unwrap();
+        let ids: Vec<u32> = loaded.iter().map(|p| p.PlayerId).collect();
+        assert_eq!(ids, vec![1, 2]);
+        let keeper = &loaded[0];
+        assert_eq!(keeper.NickName.as_deref(), Some("The Wall"));
+        assert_eq!(keeper.HofAge, Some(37));
+        assert_eq!(keeper.ExpertType, 1);
+        assert_eq!(keeper.KeeperSkill, Some(17));
+        assert_eq!(keeper.SetPiecesSkill, Some(14));
+        assert_eq!(keeper.ScorerSkill, None);
+    }
+}
@@ -0,0 +1,196 @@
+/* league_fixtures.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{FixtureAwayTeam, FixtureHomeTeam, LeagueFixture, LeagueFixtures};
+use crate::db::schema::league_fixtures;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = league_fixtures)]
+struct LeagueFixtureEntity {
+    lku_id: i32,
+    season: i32,
+    round: i32,
+    home_team_id: i32,
+    match_id: i32,
+    download_id: i32,
+    home_team_name: String,
+    away_team_id: i32,
+    away_team_name: String,
+    match_date: String,
+    home_goals: Option<i32>,
+    away_goals: Option<i32>,
+}
+
+impl From<LeagueFixtureEntity> for LeagueFixture {
+    fn from(entity: LeagueFixtureEntity) -> Self {
+        LeagueFixture {
+            MatchID: entity.match_id as u32,
+            MatchRound: entity.round as u32,
+            HomeTeam: FixtureHomeTeam {
+                HomeTeamID: entity.home_team_id as u32,
+                HomeTeamName: entity.home_team_name,
+            },
+            AwayTeam: FixtureAwayTeam {
+                AwayTeamID: entity.away_team_id as u32,
+                AwayTeamName: entity.away_team_name,
+            },
+            MatchDate: entity.match_date,
+            HomeGoals: entity.home_goals.map(|v| v as u32),
+            AwayGoals: entity.away_goals.map(|v| v as u32),
+        }
+    }
+}
+
+/// Store a season schedule. Fixtures are keyed on their slot in the season,
+/// so a later fetch replaces the earlier one and fills in results.
+pub fn save_league_fixtures(
+    conn: &mut SqliteConnection,
+    fixtures: &LeagueFixtures,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<LeagueFixtureEntity> = fixtures
+        .Matches
+        .iter()
+        .map(|m| LeagueFixtureEntity {
+            lku_id: fixtures.LeagueLevelUnitID as i32,
+            season: fixtures.Season as i32,
+            round: m.MatchRound as i32,
+            home_team_id: m.HomeTeam.HomeTeamID as i32,
+            match_id: m.MatchID as i32,
+            download_id,
+            home_team_name: m.HomeTeam.HomeTeamName.clone(),
+            away_team_id: m.AwayTeam.AwayTeamID as i32,
+            away_team_name: m.AwayTeam.AwayTeamName.clone(),
+            match_date: m.MatchDate.clone(),
+            home_goals: m.HomeGoals.map(|v| v as i32),
+            away_goals: m.AwayGoals.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::replace_into(league_fixtures::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save league fixtures: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Fixtures of a series for a season, by round then home team.
+pub fn get_fixtures(
+    conn: &mut SqliteConnection,
+    lku_id: u32,
+    season: u32,
+) -> Result<Vec<LeagueFixture>, NutmegError> {
+    let rows = league_fixtures::table
+        .filter(league_fixtures::lku_id.eq(lku_id as i32))
+        .filter(league_fixtures::season.eq(season as i32))
+        .order((
+            league_fixtures::round.asc(),
+            league_fixtures::home_team_id.asc(),
+        ))
+        .load::<LeagueFixtureEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load league fixtures: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(rows.into_iter().map(LeagueFixture::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn fixture(round: u32, home: u32, away: u32, goals: Option<(u32, u32)>) -> LeagueFixture {
+        LeagueFixture {
+            MatchID: round * 100 + home,
+            MatchRound: round,
+            HomeTeam: FixtureHomeTeam {
+                HomeTeamID: home,
+                HomeTeamName: format!("Team {}", home),
+            },
+            AwayTeam: FixtureAwayTeam {
+                AwayTeamID: away,
+                AwayTeamName: format!("Team {}", away),
+            },
+            MatchDate: format!("2026-01-{:02} 15:00:00", round),
+            HomeGoals: goals.map(|g| g.0),
+            AwayGoals: goals.map(|g| g.1),
+        }
+    }
+
+    fn fixtures(season: u32, matches: Vec<LeagueFixture>) -> LeagueFixtures {
+        LeagueFixtures {
+            LeagueLevelUnitID: 3001,
+            LeagueLevelUnitName: Some("IV.12".to_string()),
+            Season: season,
+            Matches: matches,
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_fixtures_ordered_by_round_and_updated() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-01-08T00:00:00Z", "completed").unwrap();
+
+        save_league_fixtures(
+            &mut conn,
+            &fixtures(
+                92,
+                vec![
+                    fixture(2, 3, 1, None),
+                    fixture(1, 2, 3, None),
+                    fixture(1, 1, 4, None),
+                ],
+            ),
+            dl1,
+        )
+        .unwrap();
+        save_league_fixtures(&mut conn, &fixtures(91, vec![fixture(1, 1, 2, None)]), dl1).unwrap();
+        save_league_fixtures(
+            &mut conn,
+            &fixtures(92, vec![fixture(1, 1, 4, Some((3, 1)))]),
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_fixtures(&mut conn, 3001, 92).unwrap();
+        let slots: Vec<(u32, u32)> = loaded
+            .iter()
+            .map(|f| (f.MatchRound, f.HomeTeam.HomeTeamID))
+            .collect();
+        assert_eq!(slots, vec![(1, 1), (1, 2), (2, 3)]);
+        assert_eq!(loaded[0].HomeGoals, Some(3));
+        assert_eq!(loaded[0].AwayTeam.AwayTeamName, "Team 4");
+        assert_eq!(loaded[1].HomeGoals, None);
+
+        assert_eq!(get_fixtures(&mut conn, 3001, 91).unwrap().len(), 1);
+        assert!(get_fixtures(&mut conn, 3002, 92).unwrap().is_empty());
+    }
+}
@@ -25,7 +25,8 @@ use diesel::sqlite::SqliteConnection;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use std::env;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::OnceLock;
 
 // Inspired by Shortwave
@@ -33,11 +34,19 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
 pub type SqlitePool = Pool<ConnectionManager<SqliteConnection>>;
 
+/// Default maximum number of pooled connections.
+pub const DEFAULT_POOL_SIZE: u32 = 8;
+
 static DB_POOL: OnceLock<SqlitePool> = OnceLock::new();
 
+/// Counter used to give each in-memory test database its own name.
+static MEMORY_DB_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
 /// r2d2 connection customizer that sets per-connection PRAGMAs on every
 /// acquired connection. SQLite disables FKs by default; this ensures the
 /// PRAGMA is applied before any application code runs on the connection.
+/// WAL lets the concurrent sync writers proceed without "database is locked"
+/// errors; it is a no-op for in-memory databases.
 #[derive(Debug)]
 struct ConnectionOptions;
 
@@ -45,13 +54,25 @@ impl r2d2::CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for Connec
     fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
         use diesel::connection::SimpleConnection;
         conn.batch_execute(
-            "PRAGMA busy_timeout = 5000;
+            "PRAGMA journal_mode = WAL;
+             PRAGMA busy_timeout = 5000;
              PRAGMA foreign_keys = ON;",
         )
         .map_err(diesel::r2d2::Error::QueryError)
     }
 }
 
+/// Environment variable overriding the database file location.
+pub const DB_PATH_ENV: &str = "NUTMEG_DB_PATH";
+
+fn non_empty_var(key: &str) -> Option<std::ffi::OsString> {
+    env::var_os(key).filter(|value| !value.is_empty())
+}
+
+fn home_dir() -> PathBuf {
+    PathBuf::from(env::var("HOME").expect("HOME environment variable not set"))
+}
+
 #[derive(Clone, Debug)]
 pub struct DbManager {
     pool: SqlitePool,
@@ -62,24 +83,18 @@ impl DbManager {
         let pool = DB_POOL.get_or_init(|| {
             let db_path = Self::get_db_path();
             let database_url = db_path.to_string_lossy().to_string();
-            let manager = ConnectionManager::<SqliteConnection>::new(database_url);
-            let pool = r2d2::Pool::builder()
-                .connection_customizer(Box::new(ConnectionOptions))
-                .build(manager)
-                .expect("Failed to create pool.");
-
-            // Set WAL mode and performance PRAGMAs once for the database file.
-            // journal_mode=WAL persists in the db file; the others are set here
-            // for the initial connection to seed the pool.
+            let pool = Self::build_pool(&database_url, DEFAULT_POOL_SIZE);
+
+            // Set performance PRAGMAs for the initial connection to seed the pool.
+            // WAL mode is applied to every connection by `ConnectionOptions`.
             if let Ok(mut conn) = pool.get() {
                 use diesel::connection::SimpleConnection;
                 if let Err(e) = conn.batch_execute(
-                    "PRAGMA journal_mode = WAL;
-                     PRAGMA synchronous = NORMAL;
+                    "PRAGMA synchronous = NORMAL;
                      PRAGMA temp_store = MEMORY;
                      PRAGMA mmap_size = 134217728;",
                 ) {
-                    eprintln!("Failed to set WAL/performance PRAGMAs: {e}");
+                    eprintln!("Failed to set performance PRAGMAs: {e}");
                 }
             }
 
@@ -98,23 +113,79 @@ impl DbManager {
     // Constructor for testing with in-memory DB or custom path
     #[allow(dead_code)]
     pub fn from_url(database_url: &str) -> Self {
+        Self::from_url_with_pool_size(database_url, DEFAULT_POOL_SIZE)
+    }
+
+    /// Same as `from_url`, with an explicit maximum pool size.
+    ///
+    /// A plain `:memory:` URL would give every pooled connection its own
+    /// private database, so it is rewritten to a uniquely named shared-cache
+    /// in-memory database that all connections of this pool see.
+    #[allow(dead_code)]
+    pub fn from_url_with_pool_size(database_url: &str, max_size: u32) -> Self {
+        let url = if database_url == ":memory:" {
+            let n = MEMORY_DB_COUNTER.fetch_add(1, Ordering::Relaxed);
+            format!(
+                "file:nutmeg_mem_{}_{}?mode=memory&cache=shared",
+                std::process::id(),
+                n
+            )
+        } else {
+            database_url.to_string()
+        };
+        Self {
+            pool: Self::build_pool(&url, max_size),
+        }
+    }
+
+    fn build_pool(database_url: &str, max_size: u32) -> SqlitePool {
         let manager = ConnectionManager::<SqliteConnection>::new(database_url);
-        let pool = r2d2::Pool::builder()
+        r2d2::Pool::builder()
+            .max_size(max_size)
             .connection_customizer(Box::new(ConnectionOptions))
             .build(manager)
-            .expect("Failed to create pool.");
-        Self { pool }
+            .expect("Failed to create pool.")
+    }
+
+    /// Resolve the SQLite file location: `NUTMEG_DB_PATH` if set, otherwise
+    /// `$XDG_DATA_HOME/nutmeg/nutmeg.db`, falling back to
+    /// `~/.local/share/nutmeg/nutmeg.db`. The parent directory is created if
+    /// it does not exist yet.
+    pub fn default_db_path() -> PathBuf {
+        let db_path = match non_empty_var(DB_PATH_ENV) {
+            Some(path) => PathBuf::from(path),
+            None => {
+                // The XDG spec says relative paths must be ignored.
+                let data_dir = non_empty_var("XDG_DATA_HOME")
+                    .map(PathBuf::from)
+                    .filter(|dir| dir.is_absolute())
+                    .unwrap_or_else(|| home_dir().join(".local").join("share"));
+                data_dir.join("nutmeg").join("nutmeg.db")
+            }
+        };
+
+        if let Some(parent) = db_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            if let Err(e) = fs::create_dir_all(parent) {
+                eprintln!("Failed to create data directory: {}", e);
+            }
+        }
+
+        db_path
     }
 
     fn get_db_path() -> PathBuf {
-        let home_dir = env::var("HOME").expect("HOME environment variable not set");
-        let config_dir = Path::new(&home_dir).join(".nutmeg");
+        let db_path = Self::default_db_path();
 
-        if let Err(e) = fs::create_dir_all(&config_dir) {
-            eprintln!("Failed to create config directory: {}", e);
+        // Keep using a database created by earlier versions in ~/.nutmeg
+        // rather than silently starting from an empty one.
+        if non_empty_var(DB_PATH_ENV).is_none() && !db_path.exists() {
+            let legacy_path = home_dir().join(".nutmeg").join("nutmeg.db");
+            if legacy_path.exists() {
+                return legacy_path;
+            }
         }
 
-        config_dir.join("nutmeg.db")
+        db_path
     }
 
     pub fn get_connection(
@@ -187,29 +258,19 @@ impl DbManager {
         .map_err(|e| NutmegError::Io(format!("Failed to clear database: {}", e)))
     }
 
-    /// Deletes all completed downloads except the `keep_count` most recent.
-    /// Child rows in all entity tables are removed via ON DELETE CASCADE.
-    pub fn prune_old_downloads(&self, keep_count: u32) -> Result<usize, NutmegError> {
+    /// Deletes all completed downloads except the `keep` most recent and
+    /// compacts the database file. See `download_entries::prune_old_downloads`.
+    pub fn prune_old_downloads(&self, keep: usize) -> Result<usize, NutmegError> {
         let mut conn = self.get_connection()?;
-        diesel::sql_query(
-            "DELETE FROM downloads
-             WHERE status = 'completed'
-               AND id NOT IN (
-                   SELECT id FROM downloads
-                   WHERE status = 'completed'
-                   ORDER BY id DESC
-                   LIMIT ?
-               )",
-        )
-        .bind::<diesel::sql_types::Integer, _>(keep_count as i32)
-        .execute(&mut conn)
-        .map_err(|e| NutmegError::Db(format!("Prune failed: {}", e)))
+        crate::db::download_entries::prune_old_downloads(&mut conn, keep)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
+    use std::path::Path;
 
     #[test]
     fn test_db_manager_pool() {
@@ -227,4 +288,166 @@ mod tests {
             .get_connection()
             .expect("Failed to get second connection");
     }
+
+    #[test]
+    fn test_memory_pool_connections_share_database() {
+        let manager = DbManager::from_url_with_pool_size(":memory:", 4);
+        manager.run_migrations().expect("Migrations failed");
+
+        // Hold one connection so the next checkout is a different one.
+        let _held = manager.get_connection().expect("Failed to get connection");
+        assert!(!manager.has_users().expect("Schema should be visible"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_checkouts() {
+        use crate::db::schema::downloads::dsl::*;
+
+        let manager = std::sync::Arc::new(DbManager::from_url(":memory:"));
+        manager.run_migrations().expect("Migrations failed");
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let db = manager.clone();
+                tokio::task::spawn_blocking(move || {
+                    let mut conn = db.get_connection()?;
+                    downloads
+                        .count()
+                        .get_result::<i64>(&mut conn)
+                        .map_err(NutmegError::from)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let count = handle
+                .await
+                .expect("Task panicked")
+                .expect("Query should not fail");
+            assert_eq!(count, 0);
+        }
+    }
+
+    #[test]
+    fn test_parallel_writers_on_file_db() {
+        use crate::db::download_entries::create_download;
+
+        let path = std::env::temp_dir().join(format!(
+            "nutmeg_writers_{}_{}.db",
+            std::process::id(),
+            MEMORY_DB_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let manager = DbManager::from_url(&path.to_string_lossy());
+        manager.run_migrations().expect("Migrations failed");
+
+        let writers: Vec<_> = (0..2)
+            .map(|writer| {
+                let db = manager.clone();
+                std::thread::spawn(move || -> Result<(), NutmegError> {
+                    for i in 0..25 {
+                        let mut conn = db.get_connection()?;
+                        let ts = format!("2026-01-01T00:{:02}:{:02}Z", writer, i);
+                        create_download(&mut conn, &ts, "completed")?;
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+
+        for writer in writers {
+            writer
+                .join()
+                .expect("Writer panicked")
+                .expect("Writer should not hit a locked database");
+        }
+
+        let mut conn = manager.get_connection().expect("Failed to get connection");
+        let count: i64 = crate::db::schema::downloads::table
+            .count()
+            .get_result(&mut conn)
+            .expect("Failed to count downloads");
+        assert_eq!(count, 50);
+
+        drop(conn);
+        drop(manager);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = fs::remove_file(format!("{}{}", path.to_string_lossy(), suffix));
+        }
+    }
+
+    /// Runs `f` with the given environment variables set (or removed when
+    /// `None`), restoring the previous values afterwards.
+    fn with_env<F: FnOnce()>(vars: &[(&str, Option<&Path>)], f: F) {
+        let saved: Vec<_> = vars.iter().map(|(k, _)| (*k, env::var_os(k))).collect();
+        for (key, value) in vars {
+            match value {
+                Some(v) => env::set_var(key, v),
+                None => env::remove_var(key),
+            }
+        }
+        f();
+        for (key, value) in saved {
+            match value {
+                Some(v) => env::set_var(key, v),
+                None => env::remove_var(key),
+            }
+        }
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("nutmeg_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    #[serial]
+    fn test_default_db_path_uses_xdg_data_home() {
+        let data_home = scratch_dir("xdg");
+        with_env(
+            &[(DB_PATH_ENV, None), ("XDG_DATA_HOME", Some(&data_home))],
+            || {
+                let path = DbManager::default_db_path();
+                assert_eq!(path, data_home.join("nutmeg").join("nutmeg.db"));
+                assert!(data_home.join("nutmeg").is_dir());
+            },
+        );
+        let _ = fs::remove_dir_all(&data_home);
+    }
+
+    #[test]
+    #[serial]
+    fn test_default_db_path_falls_back_to_home() {
+        let home = scratch_dir("home");
+        with_env(
+            &[
+                (DB_PATH_ENV, None),
+                ("XDG_DATA_HOME", None),
+                ("HOME", Some(&home)),
+            ],
+            || {
+                let path = DbManager::default_db_path();
+                let expected_dir = home.join(".local").join("share").join("nutmeg");
+                assert_eq!(path, expected_dir.join("nutmeg.db"));
+                assert!(expected_dir.is_dir());
+            },
+        );
+        let _ = fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    #[serial]
+    fn test_default_db_path_env_override_wins() {
+        let data_home = scratch_dir("xdg_ignored");
+        let custom = scratch_dir("override").join("custom.db");
+        with_env(
+            &[
+                (DB_PATH_ENV, Some(&custom)),
+                ("XDG_DATA_HOME", Some(&data_home)),
+            ],
+            || {
+                assert_eq!(DbManager::default_db_path(), custom);
+                assert!(!data_home.exists());
+            },
+        );
+        let _ = fs::remove_dir_all(custom.parent().unwrap());
+    }
 }
@@ -0,0 +1,231 @@
+/* matches_archive.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{MatchAwayTeam, MatchDetails, MatchHomeTeam, MatchesArchiveData};
+use crate::db::schema::matches_archive;
+use crate::error::NutmegError;
+use chrono::NaiveDate;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = matches_archive)]
+struct ArchivedMatchEntity {
+    team_id: i32,
+    match_id: i32,
+    download_id: i32,
+    home_team_id: i32,
+    home_team_name: String,
+    away_team_id: i32,
+    away_team_name: String,
+    match_date: String,
+    match_type: i32,
+    match_context_id: Option<i32>,
+    home_goals: Option<i32>,
+    away_goals: Option<i32>,
+}
+
+impl From<ArchivedMatchEntity> for MatchDetails {
+    fn from(entity: ArchivedMatchEntity) -> Self {
+        MatchDetails {
+            MatchID: entity.match_id as u32,
+            HomeTeam: MatchHomeTeam {
+                HomeTeamID: entity.home_team_id.to_string(),
+                HomeTeamName: entity.home_team_name,
+                ..Default::default()
+            },
+            AwayTeam: MatchAwayTeam {
+                AwayTeamID: entity.away_team_id.to_string(),
+                AwayTeamName: entity.away_team_name,
+                ..Default::default()
+            },
+            MatchDate: entity.match_date,
+            MatchType: entity.match_type as u32,
+            MatchContextId: entity.match_context_id.map(|v| v as u32),
+            HomeGoals: entity.home_goals.map(|v| v as u32),
+            AwayGoals: entity.away_goals.map(|v| v as u32),
+            Status: "FINISHED".to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+pub fn save_matches_archive(
+    conn: &mut SqliteConnection,
+    archive: &MatchesArchiveData,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let team_id = archive.Team.TeamID.parse::<i32>().unwrap_or(0);
+    let entities: Vec<ArchivedMatchEntity> = archive
+        .Team
+        .MatchList
+        .Matches
+        .iter()
+        .map(|m| ArchivedMatchEntity {
+            team_id,
+            match_id: m.MatchID as i32,
+            download_id,
+            home_team_id: m.HomeTeam.HomeTeamID.parse::<i32>().unwrap_or(0),
+            home_team_name: m.HomeTeam.HomeTeamName.clone(),
+            away_team_id: m.AwayTeam.AwayTeamID.parse::<i32>().unwrap_or(0),
+            away_team_name: m.AwayTeam.AwayTeamName.clone(),
+            match_date: m.MatchDate.clone(),
+            match_type: m.MatchType as i32,
+            match_context_id: m.MatchContextId.map(|v| v as i32),
+            home_goals: m.HomeGoals.map(|v| v as i32),
+            away_goals: m.AwayGoals.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(matches_archive::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save matches archive: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Archived matches of a team between the two dates (both inclusive),
+/// oldest first, using the latest stored version of each match.
+pub fn get_matches_archive(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+    first_date: Option<NaiveDate>,
+    last_date: Option<NaiveDate>,
+) -> Result<Vec<MatchDetails>, NutmegError> {
+    let mut query = matches_archive::table
+        .filter(matches_archive::team_id.eq(team_id as i32))
+        .into_boxed();
+    // Match dates are stored as "YYYY-MM-DD HH:MM:SS", so text comparison
+    // against a bare date orders correctly.
+    if let Some(first) = first_date {
+        let from = first.format("%Y-%m-%d").to_string();
+        query = query.filter(matches_archive::match_date.ge(from));
+    }
+    if let Some(next_day) = last_date.and_then(|d| d.succ_opt()) {
+        let until = next_day.format("%Y-%m-%d").to_string();
+        query = query.filter(matches_archive::match_date.lt(until));
+    }
+
+    let rows: Vec<ArchivedMatchEntity> = query
+        .order((
+            matches_archive::match_date.asc(),
+            matches_archive::match_id.asc(),
+            matches_archive::download_id.desc(),
+        ))
+        .load::<ArchivedMatchEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load matches archive: {}", e)).or_locked(&e)
+        })?;
+
+    let mut seen = std::collections::HashSet::new();
+    Ok(rows
+        .into_iter()
+        .filter(|r| seen.insert(r.match_id))
+        .map(MatchDetails::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{MatchesArchiveTeamWrapper, MatchesListWrapper};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn archived(match_id: u32, date: &str, home_goals: u32) -> MatchDetails {
+        MatchDetails {
+            MatchID: match_id,
+            HomeTeam: MatchHomeTeam {
+                HomeTeamID: "1000".to_string(),
+                HomeTeamName: "Test Team A".to_string(),
+                ..Default::default()
+            },
+            AwayTeam: MatchAwayTeam {
+                AwayTeamID: "2000".to_string(),
+                AwayTeamName: "Test Team B".to_string(),
+                ..Default::default()
+            },
+            MatchDate: date.to_string(),
+            MatchType: 1,
+            HomeGoals: Some(home_goals),
+            AwayGoals: Some(0),
+            ..Default::default()
+        }
+    }
+
+    fn archive(matches: Vec<MatchDetails>) -> MatchesArchiveData {
+        MatchesArchiveData {
+            Team: MatchesArchiveTeamWrapper {
+                TeamID: "1000".to_string(),
+                TeamName: "Test Team A".to_string(),
+                FirstMatchDate: None,
+                LastMatchDate: None,
+                MatchList: MatchesListWrapper { Matches: matches },
+            },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_matches_archive_round_trip_with_date_range() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-08T00:00:00Z", "completed").unwrap();
+
+        save_matches_archive(
+            &mut conn,
+            &archive(vec![
+                archived(1, "2026-01-10 15:00:00", 1),
+                archived(2, "2026-02-14 15:00:00", 3),
+            ]),
+            dl1,
+        )
+        .unwrap();
+        save_matches_archive(
+            &mut conn,
+            &archive(vec![
+                archived(2, "2026-02-14 15:00:00", 4),
+                archived(3, "2026-03-31 21:00:00", 2),
+            ]),
+            dl2,
+        )
+        .unwrap();
+
+        let all = get_matches_archive(&mut conn, 1000, None, None).unwrap();
+        let ids: Vec<u32> = all.iter().map(|m| m.MatchID).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(all[1].HomeGoals, Some(4));
+        assert_eq!(all[1].Status, "FINISHED");
+
+        let first = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let last = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        let ranged = get_matches_archive(&mut conn, 1000, Some(first), Some(last)).unwrap();
+        let ids: Vec<u32> = ranged.iter().map(|m| m.MatchID).collect();
+        assert_eq!(ids, vec![2, 3]);
+
+        assert!(get_matches_archive(&mut conn, 2000, None, None)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -18,11 +18,33 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
+pub mod achievements;
+pub mod arena;
+pub mod bookmarks;
+pub mod challenges;
+pub mod cup_matches;
+pub mod diff;
 pub mod download_entries;
+pub mod economy;
+pub mod hof_players;
+pub mod league_fixtures;
 pub mod manager;
 pub mod match_ratings;
+pub mod matches_archive;
+pub mod national_teams;
+pub mod player_events;
+pub mod preferences;
+pub mod region_details;
 pub mod repository;
+pub mod retry;
 pub mod schema;
 pub mod series;
 pub mod staff;
+pub mod supporters;
+pub mod team_cache;
 pub mod teams;
+pub mod tournaments;
+pub mod training_events;
+pub mod transfers;
+pub mod translations;
+pub mod world_cup;
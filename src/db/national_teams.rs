@@ -0,0 +1,138 @@
+/* national_teams.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{NationalTeam, NationalTeams};
+use crate::db::schema::national_teams;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = national_teams)]
+struct NationalTeamEntity {
+    user_id: i32,
+    nt_team_id: i32,
+    download_id: i32,
+    nt_team_name: Option<String>,
+    league_id: Option<i32>,
+    role: Option<i32>,
+}
+
+impl From<NationalTeamEntity> for NationalTeam {
+    fn from(entity: NationalTeamEntity) -> Self {
+        NationalTeam {
+            NationalTeamID: entity.nt_team_id as u32,
+            NationalTeamName: entity.nt_team_name,
+            LeagueID: entity.league_id.map(|v| v as u32),
+            NationalTeamStaffType: entity.role.map(|v| v as u32),
+        }
+    }
+}
+
+/// Replace the stored NT roles of `user_id` with `data`, so roles the
+/// user no longer holds disappear.
+pub fn save_national_teams(
+    conn: &mut SqliteConnection,
+    data: &NationalTeams,
+    user_id: u32,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let rows: Vec<NationalTeamEntity> = data
+        .NationalTeams
+        .iter()
+        .map(|nt| NationalTeamEntity {
+            user_id: user_id as i32,
+            nt_team_id: nt.NationalTeamID as i32,
+            download_id,
+            nt_team_name: nt.NationalTeamName.clone(),
+            league_id: nt.LeagueID.map(|v| v as i32),
+            role: nt.NationalTeamStaffType.map(|v| v as i32),
+        })
+        .collect();
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::delete(national_teams::table.filter(national_teams::user_id.eq(user_id as i32)))
+            .execute(conn)?;
+        diesel::insert_into(national_teams::table)
+            .values(&rows)
+            .execute(conn)?;
+        Ok(())
+    })
+    .map_err(|e| NutmegError::Db(format!("Failed to save national teams: {}", e)).or_locked(&e))
+}
+
+/// Stored NT roles of `user_id`, by national team id.
+pub fn get_national_teams(
+    conn: &mut SqliteConnection,
+    user_id: u32,
+) -> Result<Vec<NationalTeam>, NutmegError> {
+    let rows = national_teams::table
+        .filter(national_teams::user_id.eq(user_id as i32))
+        .order(national_teams::nt_team_id.asc())
+        .load::<NationalTeamEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load national teams: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(rows.into_iter().map(NationalTeam::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn coach_of(id: u32, role: u32) -> NationalTeam {
+        NationalTeam {
+            NationalTeamID: id,
+            NationalTeamName: Some(format!("NT {}", id)),
+            LeagueID: Some(21),
+            NationalTeamStaffType: Some(role),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_national_teams_replaced_per_user() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-04-08T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-04-09T00:00:00Z", "completed").unwrap();
+
+        let roles = NationalTeams {
+            NationalTeams: vec![coach_of(3115, 2), coach_of(3015, 1)],
+        };
+        save_national_teams(&mut conn, &roles, 100, dl1).unwrap();
+        save_national_teams(&mut conn, &roles, 200, dl1).unwrap();
+
+        let stored = get_national_teams(&mut conn, 100).unwrap();
+        let ids: Vec<u32> = stored.iter().map(|nt| nt.NationalTeamID).collect();
+        assert_eq!(ids, vec![3015, 3115]);
+        assert_eq!(stored[0].NationalTeamStaffType, Some(1));
+        assert_eq!(stored[0].NationalTeamName.as_deref(), Some("NT 3015"));
+
+        // Losing the job clears the roles of that user only.
+        save_national_teams(&mut conn, &NationalTeams::default(), 100, dl2).unwrap();
+        assert!(get_national_teams(&mut conn, 100).unwrap().is_empty());
+        assert_eq!(get_national_teams(&mut conn, 200).unwrap().len(), 2);
+    }
+}
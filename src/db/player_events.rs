@@ -0,0 +1,144 @@
+/* player_events.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::PlayerEvent;
+use crate::db::schema::player_events;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = player_events)]
+struct PlayerEventEntity {
+    player_id: i32,
+    download_id: i32,
+    event_date: String,
+    event_type_id: i32,
+    event_text: String,
+}
+
+impl From<PlayerEventEntity> for PlayerEvent {
+    fn from(entity: PlayerEventEntity) -> Self {
+        PlayerEvent {
+            EventDate: entity.event_date,
+            PlayerEventTypeID: entity.event_type_id as u32,
+            EventText: entity.event_text,
+        }
+    }
+}
+
+/// Store a player's events. The event text is kept as sent by Hattrick,
+/// markup included, so it can be rendered later.
+pub fn save_player_events(
+    conn: &mut SqliteConnection,
+    player_id: u32,
+    events: &[PlayerEvent],
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<PlayerEventEntity> = events
+        .iter()
+        .map(|e| PlayerEventEntity {
+            player_id: player_id as i32,
+            download_id,
+            event_date: e.EventDate.clone(),
+            event_type_id: e.PlayerEventTypeID as i32,
+            event_text: e.EventText.clone(),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(player_events::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save player events: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Load the events of a player from the latest download that has any,
+/// most recent event first.
+pub fn get_player_events(
+    conn: &mut SqliteConnection,
+    pid: u32,
+) -> Result<Vec<PlayerEvent>, NutmegError> {
+    use crate::db::schema::player_events::dsl::*;
+
+    let latest: Option<i32> = player_events
+        .filter(player_id.eq(pid as i32))
+        .select(diesel::dsl::max(download_id))
+        .first::<Option<i32>>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get player events download: {}", e)).or_locked(&e)
+        })?;
+
+    let Some(latest) = latest else {
+        return Ok(Vec::new());
+    };
+
+    let rows = player_events
+        .filter(player_id.eq(pid as i32))
+        .filter(download_id.eq(latest))
+        .order(event_date.desc())
+        .load::<PlayerEventEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load player events: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(rows.into_iter().map(PlayerEvent::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_player_events_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+
+        let events = vec![
+            PlayerEvent {
+                EventDate: "2023-08-01 09:30:00".to_string(),
+                PlayerEventTypeID: 1,
+                EventText: "Signed from [teamid=22222]".to_string(),
+            },
+            PlayerEvent {
+                EventDate: "2024-02-10 14:00:00".to_string(),
+                PlayerEventTypeID: 5,
+                EventText: "Scored a hattrick in [matchid=987654]".to_string(),
+            },
+        ];
+        save_player_events(&mut conn, 42, &events, dl).unwrap();
+
+        let loaded = get_player_events(&mut conn, 42).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].EventDate, "2024-02-10 14:00:00");
+        assert_eq!(loaded[0].PlayerEventTypeID, 5);
+        assert_eq!(loaded[0].EventText, "Scored a hattrick in [matchid=987654]");
+        assert_eq!(loaded[1].EventText, "Signed from [teamid=22222]");
+
+        assert!(get_player_events(&mut conn, 43).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+/* preferences.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::ChppScope;
+use crate::db::schema::preferences;
+use crate::error::NutmegError;
+use crate::utils::number_format::NumberFormat;
+use diesel::prelude::*;
+
+const LAST_SELECTED_TEAM: &str = "last_selected_team";
+const CHPP_SCOPES: &str = "chpp_scopes";
+const NUMBER_FORMAT: &str = "number_format";
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = preferences)]
+struct PreferenceEntity {
+    key: String,
+    value: String,
+}
+
+pub fn get_preference(
+    conn: &mut SqliteConnection,
+    name: &str,
+) -> Result<Option<String>, NutmegError> {
+    use crate::db::schema::preferences::dsl::*;
+
+    preferences
+        .filter(key.eq(name))
+        .select(value)
+        .first::<String>(conn)
+        .optional()
+        .map_err(|e| NutmegError::Db(format!("Failed to get preference: {}", e)).or_locked(&e))
+}
+
+/// Store a preference, replacing any previous value.
+pub fn set_preference(
+    conn: &mut SqliteConnection,
+    name: &str,
+    val: &str,
+) -> Result<(), NutmegError> {
+    let entity = PreferenceEntity {
+        key: name.to_string(),
+        value: val.to_string(),
+    };
+    diesel::replace_into(preferences::table)
+        .values(&entity)
+        .execute(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to save preference: {}", e)).or_locked(&e))?;
+    Ok(())
+}
+
+/// Team the user was last looking at. An unparseable value reads as unset.
+pub fn get_last_selected_team(conn: &mut SqliteConnection) -> Result<Option<u32>, NutmegError> {
+    Ok(get_preference(conn, LAST_SELECTED_TEAM)?.and_then(|v| v.parse().ok()))
+}
+
+pub fn set_last_selected_team(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<(), NutmegError> {
+    set_preference(conn, LAST_SELECTED_TEAM, &team_id.to_string())
+}
+
+/// CHPP scopes to request when authorizing. Unset means read-only access;
+/// unknown scope names are skipped.
+pub fn get_chpp_scopes(conn: &mut SqliteConnection) -> Result<Vec<ChppScope>, NutmegError> {
+    Ok(get_preference(conn, CHPP_SCOPES)?
+        .map(|v| v.split(',').filter_map(ChppScope::from_name).collect())
+        .unwrap_or_default())
+}
+
+pub fn set_chpp_scopes(
+    conn: &mut SqliteConnection,
+    scopes: &[ChppScope],
+) -> Result<(), NutmegError> {
+    let names: Vec<&str> = scopes.iter().map(ChppScope::as_str).collect();
+    set_preference(conn, CHPP_SCOPES, &names.join(","))
+}
+
+/// Digit grouping for displayed numbers. Unset or unknown values read as
+/// the system format.
+pub fn get_number_format(conn: &mut SqliteConnection) -> Result<NumberFormat, NutmegError> {
+    Ok(get_preference(conn, NUMBER_FORMAT)?
+        .and_then(|v| NumberFormat::from_name(&v))
+        .unwrap_or_default())
+}
+
+pub fn set_number_format(
+    conn: &mut SqliteConnection,
+    format: NumberFormat,
+) -> Result<(), NutmegError> {
+    set_preference(conn, NUMBER_FORMAT, format.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_last_selected_team_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+
+        assert_eq!(get_last_selected_team(&mut conn).unwrap(), None);
+
+        set_last_selected_team(&mut conn, 1000).unwrap();
+        set_last_selected_team(&mut conn, 2000).unwrap();
+        assert_eq!(get_last_selected_team(&mut conn).unwrap(), Some(2000));
+
+        set_preference(&mut conn, LAST_SELECTED_TEAM, "not a team").unwrap();
+        assert_eq!(get_last_selected_team(&mut conn).unwrap(), None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_chpp_scopes_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+
+        assert!(get_chpp_scopes(&mut conn).unwrap().is_empty());
+
+        let scopes = [ChppScope::SetMatchOrder, ChppScope::ManageChallenges];
+        set_chpp_scopes(&mut conn, &scopes).unwrap();
+        assert_eq!(get_chpp_scopes(&mut conn).unwrap(), scopes);
+
+        set_chpp_scopes(&mut conn, &[]).unwrap();
+        assert!(get_chpp_scopes(&mut conn).unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_number_format_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+
+        assert_eq!(get_number_format(&mut conn).unwrap(), NumberFormat::System);
+
+        set_number_format(&mut conn, NumberFormat::Space).unwrap();
+        assert_eq!(get_number_format(&mut conn).unwrap(), NumberFormat::Space);
+
+        set_preference(&mut conn, NUMBER_FORMAT, "semicolon").unwrap();
+        assert_eq!(get_number_format(&mut conn).unwrap(), NumberFormat::System);
+    }
+}
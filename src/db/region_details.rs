@@ -0,0 +1,145 @@
+/* region_details.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{RegionDetails, RegionInfo};
+use crate::db::schema::region_details;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = region_details)]
+struct RegionDetailsEntity {
+    region_id: i32,
+    download_id: i32,
+    league_id: i32,
+    region_name: String,
+    weather_id: Option<i32>,
+    tomorrow_weather_id: Option<i32>,
+    number_of_users: Option<i32>,
+    number_of_online: Option<i32>,
+}
+
+pub fn save_region_details(
+    conn: &mut SqliteConnection,
+    details: &RegionDetails,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let region = &details.League.Region;
+    let entity = RegionDetailsEntity {
+        region_id: region.RegionID as i32,
+        download_id,
+        league_id: details.League.LeagueID as i32,
+        region_name: region.RegionName.clone(),
+        weather_id: region.WeatherID.map(|v| v as i32),
+        tomorrow_weather_id: region.TomorrowWeatherID.map(|v| v as i32),
+        number_of_users: region.NumberOfUsers.map(|v| v as i32),
+        number_of_online: region.NumberOfOnline.map(|v| v as i32),
+    };
+
+    diesel::insert_or_ignore_into(region_details::table)
+        .values(&entity)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save region details: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Latest stored details for a region, returned with the league it belongs to.
+pub fn get_region_details(
+    conn: &mut SqliteConnection,
+    rid: u32,
+) -> Result<Option<(u32, RegionInfo)>, NutmegError> {
+    use crate::db::schema::region_details::dsl::*;
+
+    let entity = region_details
+        .filter(region_id.eq(rid as i32))
+        .order(download_id.desc())
+        .first::<RegionDetailsEntity>(conn)
+        .optional()
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get region details: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(entity.map(|e| {
+        (
+            e.league_id as u32,
+            RegionInfo {
+                RegionID: e.region_id as u32,
+                RegionName: e.region_name,
+                WeatherID: e.weather_id.map(|v| v as u32),
+                TomorrowWeatherID: e.tomorrow_weather_id.map(|v| v as u32),
+                NumberOfUsers: e.number_of_users.map(|v| v as u32),
+                NumberOfOnline: e.number_of_online.map(|v| v as u32),
+            },
+        )
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::RegionLeague;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn details(users: u32, weather: u32) -> RegionDetails {
+        RegionDetails {
+            League: RegionLeague {
+                LeagueID: 21,
+                LeagueName: "Ireland".to_string(),
+                Region: RegionInfo {
+                    RegionID: 1040,
+                    RegionName: "Dublin".to_string(),
+                    WeatherID: Some(weather),
+                    TomorrowWeatherID: Some(3),
+                    NumberOfUsers: Some(users),
+                    NumberOfOnline: Some(17),
+                },
+            },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_region_details_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-01-02T00:00:00Z", "completed").unwrap();
+
+        assert!(get_region_details(&mut conn, 1040).unwrap().is_none());
+
+        save_region_details(&mut conn, &details(400, 0), dl1).unwrap();
+        save_region_details(&mut conn, &details(412, 2), dl2).unwrap();
+
+        let (league_id, region) = get_region_details(&mut conn, 1040)
+            .unwrap()
+            .expect("region details");
+        assert_eq!(league_id, 21);
+        assert_eq!(region.RegionName, "Dublin");
+        assert_eq!(region.NumberOfUsers, Some(412));
+        assert_eq!(region.NumberOfOnline, Some(17));
+        assert_eq!(region.WeatherID, Some(2));
+        assert_eq!(region.TomorrowWeatherID, Some(3));
+    }
+}
@@ -0,0 +1,98 @@
+/* retry.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Retry helper for database operations hitting SQLite lock contention.
+
+use crate::error::NutmegError;
+use log::warn;
+use std::thread;
+use std::time::Duration;
+
+/// Number of retries after the initial attempt when the database is locked.
+pub const DB_LOCK_RETRIES: u32 = 3;
+
+/// Pause between attempts when the database is locked.
+pub const DB_LOCK_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Run a blocking database operation, retrying it while it fails with
+/// `NutmegError::DbLocked`. Any other error is returned immediately.
+///
+/// This sleeps the current thread, so only call it from blocking contexts
+/// such as `spawn_blocking`.
+pub fn retry_db<T, F>(mut f: F) -> Result<T, NutmegError>
+where
+    F: FnMut() -> Result<T, NutmegError>,
+{
+    let mut attempt = 0;
+    loop {
+        match f() {
+            Err(e) if e.is_retryable_db() && attempt < DB_LOCK_RETRIES => {
+                attempt += 1;
+                warn!(
+                    "Database locked (attempt {}/{}): {}",
+                    attempt, DB_LOCK_RETRIES, e
+                );
+                thread::sleep(DB_LOCK_BACKOFF * attempt);
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_db_retries_locked_errors() {
+        let mut calls = 0;
+        let result = retry_db(|| {
+            calls += 1;
+            if calls < 3 {
+                Err(NutmegError::DbLocked("database is locked".to_string()))
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_retry_db_gives_up_after_max_retries() {
+        let mut calls = 0;
+        let result: Result<(), NutmegError> = retry_db(|| {
+            calls += 1;
+            Err(NutmegError::DbLocked("database is locked".to_string()))
+        });
+        assert!(matches!(result, Err(NutmegError::DbLocked(_))));
+        assert_eq!(calls, DB_LOCK_RETRIES + 1);
+    }
+
+    #[test]
+    fn test_retry_db_does_not_retry_other_errors() {
+        let mut calls = 0;
+        let result: Result<(), NutmegError> = retry_db(|| {
+            calls += 1;
+            Err(NutmegError::Db("constraint failed".to_string()))
+        });
+        assert!(matches!(result, Err(NutmegError::Db(_))));
+        assert_eq!(calls, 1);
+    }
+}
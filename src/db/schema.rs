@@ -206,6 +206,7 @@ diesel::table! {
         assists_current_team -> Nullable<Integer>,
         career_assists -> Nullable<Integer>,
         gender_id -> Integer,
+        transfer_deadline -> Nullable<Text>,
     }
 }
 
@@ -271,6 +272,7 @@ diesel::table! {
         youth_team_id -> Nullable<Integer>,
         youth_team_name -> Nullable<Text>,
         gender_id -> Integer,
+        guestbook_items -> Nullable<Integer>,
     }
 }
 
@@ -311,20 +313,340 @@ diesel::table! {
 
 diesel::joinable!(match_ratings -> downloads (download_id));
 
+diesel::table! {
+    current_bids (player_id, download_id) {
+        player_id -> Integer,
+        download_id -> Integer,
+        side -> Text,
+        transfer_id -> Nullable<Integer>,
+        player_name -> Text,
+        highest_bid -> Nullable<Integer>,
+        deadline -> Text,
+        bidder_team_id -> Nullable<Integer>,
+        bidder_team_name -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    region_details (region_id, download_id) {
+        region_id -> Integer,
+        download_id -> Integer,
+        league_id -> Integer,
+        region_name -> Text,
+        weather_id -> Nullable<Integer>,
+        tomorrow_weather_id -> Nullable<Integer>,
+        number_of_users -> Nullable<Integer>,
+        number_of_online -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    player_events (player_id, download_id, event_date) {
+        player_id -> Integer,
+        download_id -> Integer,
+        event_date -> Text,
+        event_type_id -> Integer,
+        event_text -> Text,
+    }
+}
+
+diesel::table! {
+    player_transfers (transfer_id, download_id) {
+        transfer_id -> Integer,
+        player_id -> Integer,
+        download_id -> Integer,
+        deadline -> Text,
+        buyer_team_id -> Integer,
+        buyer_team_name -> Text,
+        seller_team_id -> Integer,
+        seller_team_name -> Text,
+        price -> Integer,
+        tsi -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    cup_matches (match_id, download_id) {
+        match_id -> Integer,
+        download_id -> Integer,
+        cup_id -> Integer,
+        season -> Nullable<Integer>,
+        cup_round -> Nullable<Integer>,
+        match_date -> Text,
+        home_team_id -> Integer,
+        home_team_name -> Text,
+        away_team_id -> Integer,
+        away_team_name -> Text,
+        home_goals -> Nullable<Integer>,
+        away_goals -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    training_events (player_id, event_date, skill_id) {
+        player_id -> Integer,
+        event_date -> Text,
+        skill_id -> Integer,
+        download_id -> Integer,
+        old_level -> Integer,
+        new_level -> Integer,
+        season -> Nullable<Integer>,
+        match_round -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    arena_details (arena_id, download_id) {
+        arena_id -> Integer,
+        download_id -> Integer,
+        arena_name -> Text,
+        team_id -> Nullable<Integer>,
+        team_name -> Nullable<Text>,
+        terraces -> Integer,
+        basic -> Integer,
+        roof -> Integer,
+        vip -> Integer,
+        expansion_date -> Nullable<Text>,
+        expanded_terraces -> Nullable<Integer>,
+        expanded_basic -> Nullable<Integer>,
+        expanded_roof -> Nullable<Integer>,
+        expanded_vip -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    national_teams (user_id, nt_team_id) {
+        user_id -> Integer,
+        nt_team_id -> Integer,
+        download_id -> Integer,
+        nt_team_name -> Nullable<Text>,
+        league_id -> Nullable<Integer>,
+        role -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    supporters (owner_id, direction, team_id) {
+        owner_id -> Integer,
+        direction -> Text,
+        team_id -> Integer,
+        download_id -> Integer,
+        team_name -> Text,
+        user_id -> Integer,
+        login_name -> Text,
+        league_id -> Nullable<Integer>,
+        league_name -> Nullable<Text>,
+        league_unit_id -> Nullable<Integer>,
+        league_unit_name -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    achievements (user_id, achievement_id) {
+        user_id -> Integer,
+        achievement_id -> Integer,
+        download_id -> Integer,
+        title -> Text,
+        category_id -> Integer,
+        points -> Integer,
+        event_date -> Text,
+        number_of_events -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    tournaments (tournament_id) {
+        tournament_id -> Integer,
+        download_id -> Integer,
+        name -> Text,
+        tournament_type -> Integer,
+        season -> Integer,
+        logo_url -> Nullable<Text>,
+        trophy_type -> Nullable<Integer>,
+        number_of_teams -> Integer,
+        number_of_groups -> Integer,
+        last_match_round -> Nullable<Integer>,
+        first_match_round_date -> Nullable<Text>,
+        next_match_round_date -> Nullable<Text>,
+        is_matches_ongoing -> Bool,
+        creator_user_id -> Nullable<Integer>,
+        creator_login_name -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    challenges (training_match_id, download_id) {
+        training_match_id -> Integer,
+        download_id -> Integer,
+        team_id -> Integer,
+        is_weekend -> Bool,
+        side -> Text,
+        match_time -> Text,
+        friendly_type -> Integer,
+        opponent_team_id -> Integer,
+        opponent_name -> Text,
+        arena_id -> Nullable<Integer>,
+        arena_name -> Nullable<Text>,
+        is_agreed -> Bool,
+    }
+}
+
+diesel::table! {
+    bookmarks (bookmark_type, target_id) {
+        bookmark_type -> Integer,
+        target_id -> Integer,
+        bookmark_id -> Integer,
+        download_id -> Integer,
+        text -> Text,
+        text2 -> Nullable<Text>,
+        target_id2 -> Nullable<Integer>,
+        comment -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    hof_players (player_id, download_id) {
+        player_id -> Integer,
+        download_id -> Integer,
+        team_id -> Integer,
+        first_name -> Text,
+        nick_name -> Nullable<Text>,
+        last_name -> Text,
+        age -> Integer,
+        arrival_date -> Nullable<Text>,
+        hof_date -> Text,
+        hof_age -> Nullable<Integer>,
+        expert_type -> Integer,
+        keeper_skill -> Nullable<Integer>,
+        playmaker_skill -> Nullable<Integer>,
+        scorer_skill -> Nullable<Integer>,
+        passing_skill -> Nullable<Integer>,
+        winger_skill -> Nullable<Integer>,
+        defender_skill -> Nullable<Integer>,
+        set_pieces_skill -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    world_cup_matches (cup_id, season, match_id) {
+        cup_id -> Integer,
+        season -> Integer,
+        match_id -> Integer,
+        download_id -> Integer,
+        match_round -> Integer,
+        cup_series_unit_id -> Integer,
+        home_team_id -> Integer,
+        home_team_name -> Text,
+        away_team_id -> Integer,
+        away_team_name -> Text,
+        match_date -> Text,
+        home_goals -> Nullable<Integer>,
+        away_goals -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    translations (language_id, item_type, item_id) {
+        language_id -> Integer,
+        item_type -> Text,
+        item_id -> Integer,
+        download_id -> Integer,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    team_transfers (transfer_id, team_id, download_id) {
+        transfer_id -> Integer,
+        team_id -> Integer,
+        download_id -> Integer,
+        deadline -> Text,
+        transfer_type -> Text,
+        player_id -> Integer,
+        player_name -> Text,
+        buyer_team_id -> Integer,
+        buyer_team_name -> Text,
+        seller_team_id -> Integer,
+        seller_team_name -> Text,
+        price -> Integer,
+        tsi -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    league_fixtures (lku_id, season, round, home_team_id) {
+        lku_id -> Integer,
+        season -> Integer,
+        round -> Integer,
+        home_team_id -> Integer,
+        match_id -> Integer,
+        download_id -> Integer,
+        home_team_name -> Text,
+        away_team_id -> Integer,
+        away_team_name -> Text,
+        match_date -> Text,
+        home_goals -> Nullable<Integer>,
+        away_goals -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    matches_archive (team_id, match_id, download_id) {
+        team_id -> Integer,
+        match_id -> Integer,
+        download_id -> Integer,
+        home_team_id -> Integer,
+        home_team_name -> Text,
+        away_team_id -> Integer,
+        away_team_name -> Text,
+        match_date -> Text,
+        match_type -> Integer,
+        match_context_id -> Nullable<Integer>,
+        home_goals -> Nullable<Integer>,
+        away_goals -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    preferences (key) {
+        key -> Text,
+        value -> Text,
+    }
+}
+
+diesel::joinable!(achievements -> downloads (download_id));
+diesel::joinable!(arena_details -> downloads (download_id));
 diesel::joinable!(avatars -> downloads (download_id));
+diesel::joinable!(bookmarks -> downloads (download_id));
+diesel::joinable!(challenges -> downloads (download_id));
 diesel::joinable!(countries -> downloads (download_id));
+diesel::joinable!(cup_matches -> downloads (download_id));
 diesel::joinable!(cups -> downloads (download_id));
 diesel::joinable!(currencies -> downloads (download_id));
+diesel::joinable!(current_bids -> downloads (download_id));
 diesel::joinable!(download_entries -> downloads (download_id));
+diesel::joinable!(hof_players -> downloads (download_id));
 diesel::joinable!(languages -> downloads (download_id));
+diesel::joinable!(league_fixtures -> downloads (download_id));
 diesel::joinable!(league_unit_teams -> downloads (download_id));
 diesel::joinable!(league_units -> downloads (download_id));
 diesel::joinable!(leagues -> downloads (download_id));
 diesel::joinable!(matches -> downloads (download_id));
+diesel::joinable!(matches_archive -> downloads (download_id));
+diesel::joinable!(national_teams -> downloads (download_id));
+diesel::joinable!(player_events -> downloads (download_id));
+diesel::joinable!(player_transfers -> downloads (download_id));
 diesel::joinable!(players -> downloads (download_id));
+diesel::joinable!(region_details -> downloads (download_id));
 diesel::joinable!(regions -> downloads (download_id));
+diesel::joinable!(supporters -> downloads (download_id));
+diesel::joinable!(team_transfers -> downloads (download_id));
 diesel::joinable!(teams -> downloads (download_id));
+diesel::joinable!(tournaments -> downloads (download_id));
+diesel::joinable!(training_events -> downloads (download_id));
+diesel::joinable!(translations -> downloads (download_id));
 diesel::joinable!(users -> downloads (download_id));
+diesel::joinable!(world_cup_matches -> downloads (download_id));
 diesel::joinable!(staff -> downloads (download_id));
 
 diesel::table! {
@@ -340,22 +662,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    economy (team_id, download_id) {
+        team_id -> Integer,
+        download_id -> Integer,
+        cash -> BigInt,
+        expected_cash -> Nullable<BigInt>,
+        fan_club_size -> Nullable<Integer>,
+        income_spectators -> Nullable<BigInt>,
+        income_sponsors -> Nullable<BigInt>,
+        income_sum -> Nullable<BigInt>,
+        costs_sum -> Nullable<BigInt>,
+        expected_weeks_total -> Nullable<BigInt>,
+    }
+}
+
+diesel::joinable!(economy -> downloads (download_id));
+
 diesel::allow_tables_to_appear_in_same_query!(
+    achievements,
+    arena_details,
     avatars,
+    bookmarks,
+    challenges,
     countries,
+    cup_matches,
     cups,
     currencies,
+    current_bids,
     download_entries,
     downloads,
+    economy,
+    hof_players,
     languages,
+    league_fixtures,
     league_unit_teams,
     league_units,
     leagues,
     match_ratings,
     matches,
+    matches_archive,
+    national_teams,
+    player_events,
+    player_transfers,
     players,
+    preferences,
+    region_details,
     regions,
     staff,
+    supporters,
+    team_transfers,
     teams,
+    tournaments,
+    training_events,
+    translations,
     users,
+    world_cup_matches,
 );
@@ -500,6 +500,30 @@ pub fn get_upcoming_opponents_from_db(
     Ok(opponents)
 }
 
+/// Returns the latest standings of a league unit, ordered by position.
+pub fn get_standings(
+    conn: &mut SqliteConnection,
+    lku_id: u32,
+) -> Result<Vec<LeagueUnitTeam>, NutmegError> {
+    use crate::db::schema::league_unit_teams::dsl;
+
+    let latest_download_id: Option<i32> = league_unit_teams::table
+        .filter(dsl::unit_id.eq(lku_id as i32))
+        .select(diesel::dsl::max(dsl::download_id))
+        .first(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to query latest standings: {}", e)))?;
+
+    match latest_download_id {
+        Some(dl_id) => league_unit_teams::table
+            .filter(dsl::unit_id.eq(lku_id as i32))
+            .filter(dsl::download_id.eq(dl_id))
+            .order(dsl::position.asc())
+            .load::<LeagueUnitTeam>(conn)
+            .map_err(|e| NutmegError::Db(format!("Failed to load standings: {}", e))),
+        None => Ok(vec![]),
+    }
+}
+
 /// Returns all teams belonging to a specific league unit version.
 pub fn get_league_unit_teams(
     conn: &mut SqliteConnection,
@@ -785,4 +809,58 @@ mod tests {
         assert_eq!(upcoming[0].team_id, 4);
         assert_eq!(upcoming[0].team_name, "Team D");
     }
+
+    #[test]
+    #[serial]
+    fn test_get_standings_ordered_by_position() {
+        let mut conn = establish_connection();
+
+        for _ in 0..2 {
+            diesel::insert_into(downloads::table)
+                .values(NewDownload {
+                    timestamp: "2026-02-15T12:00:00Z".to_string(),
+                    status: "completed".to_string(),
+                })
+                .execute(&mut conn)
+                .expect("Failed to create download");
+        }
+
+        let team = |id: u32, position: u32| crate::chpp::model::LeagueTeam {
+            UserId: None,
+            TeamID: id.to_string(),
+            TeamName: format!("Team {}", id),
+            Position: position,
+            PositionChange: 0,
+            Matches: 14,
+            GoalsFor: 20,
+            GoalsAgainst: 20,
+            Points: 30 - position,
+            Won: 8,
+            Draws: 0,
+            Lost: 6,
+        };
+        let mut league_data = LeagueDetailsData {
+            LeagueID: 0,
+            LeagueName: "Test League".to_string(),
+            LeagueLevel: 4,
+            MaxLevel: Some(8),
+            LeagueLevelUnitID: 100,
+            LeagueLevelUnitName: "IV.10".to_string(),
+            CurrentMatchRound: Some(14),
+            Rank: None,
+            Teams: vec![team(1, 2), team(2, 1)],
+        };
+        save_league_details(&mut conn, 1, &league_data).expect("Failed to save download 1");
+
+        // The latest download swaps the top two.
+        league_data.Teams = vec![team(1, 1), team(3, 3), team(2, 2)];
+        save_league_details(&mut conn, 2, &league_data).expect("Failed to save download 2");
+
+        let standings = get_standings(&mut conn, 100).expect("Failed to get standings");
+        let order: Vec<(i32, i32)> = standings.iter().map(|t| (t.team_id, t.position)).collect();
+        assert_eq!(order, vec![(1, 1), (2, 2), (3, 3)]);
+        assert!(standings.iter().all(|t| t.download_id == 2));
+
+        assert!(get_standings(&mut conn, 999).unwrap().is_empty());
+    }
 }
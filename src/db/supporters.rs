@@ -0,0 +1,204 @@
+/* supporters.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{SupporterTeam, Supporters};
+use crate::db::schema::supporters;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+/// Teams supporting the owner team (`MySupporters`).
+pub const DIRECTION_SUPPORTER: &str = "supporter";
+/// Teams the owner user supports (`SupportedTeams`).
+pub const DIRECTION_SUPPORTED: &str = "supported";
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = supporters)]
+struct SupporterEntity {
+    owner_id: i32,
+    direction: String,
+    team_id: i32,
+    download_id: i32,
+    team_name: String,
+    user_id: i32,
+    login_name: String,
+    league_id: Option<i32>,
+    league_name: Option<String>,
+    league_unit_id: Option<i32>,
+    league_unit_name: Option<String>,
+}
+
+impl SupporterEntity {
+    fn new(team: &SupporterTeam, owner_id: u32, direction: &str, download_id: i32) -> Self {
+        SupporterEntity {
+            owner_id: owner_id as i32,
+            direction: direction.to_string(),
+            team_id: team.TeamId as i32,
+            download_id,
+            team_name: team.TeamName.clone(),
+            user_id: team.UserId as i32,
+            login_name: team.LoginName.clone(),
+            league_id: team.LeagueID.map(|v| v as i32),
+            league_name: team.LeagueName.clone(),
+            league_unit_id: team.LeagueLevelUnitID.map(|v| v as i32),
+            league_unit_name: team.LeagueLevelUnitName.clone(),
+        }
+    }
+}
+
+impl From<SupporterEntity> for SupporterTeam {
+    fn from(entity: SupporterEntity) -> Self {
+        SupporterTeam {
+            UserId: entity.user_id as u32,
+            LoginName: entity.login_name,
+            TeamId: entity.team_id as u32,
+            TeamName: entity.team_name,
+            LeagueID: entity.league_id.map(|v| v as u32),
+            LeagueName: entity.league_name,
+            LeagueLevelUnitID: entity.league_unit_id.map(|v| v as u32),
+            LeagueLevelUnitName: entity.league_unit_name,
+        }
+    }
+}
+
+/// Store the supporter lists present in `data` for `owner_id`: the team
+/// id for `MySupporters`, the user id for `SupportedTeams`. Each list
+/// replaces the previously stored one of the same direction.
+pub fn save_supporters(
+    conn: &mut SqliteConnection,
+    data: &Supporters,
+    owner_id: u32,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let mut lists: Vec<(&str, Vec<SupporterEntity>)> = Vec::new();
+    if let Some(list) = &data.MySupporters {
+        let rows = list
+            .Teams
+            .iter()
+            .map(|t| SupporterEntity::new(t, owner_id, DIRECTION_SUPPORTER, download_id))
+            .collect();
+        lists.push((DIRECTION_SUPPORTER, rows));
+    }
+    if let Some(list) = &data.SupportedTeams {
+        let rows = list
+            .Teams
+            .iter()
+            .map(|t| SupporterEntity::new(t, owner_id, DIRECTION_SUPPORTED, download_id))
+            .collect();
+        lists.push((DIRECTION_SUPPORTED, rows));
+    }
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        for (direction, rows) in &lists {
+            diesel::delete(
+                supporters::table
+                    .filter(supporters::owner_id.eq(owner_id as i32))
+                    .filter(supporters::direction.eq(*direction)),
+            )
+            .execute(conn)?;
+            diesel::insert_into(supporters::table)
+                .values(rows)
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+    .map_err(|e| NutmegError::Db(format!("Failed to save supporters: {}", e)).or_locked(&e))
+}
+
+/// Stored teams of one direction for `owner_id`, by team name.
+pub fn get_supporters(
+    conn: &mut SqliteConnection,
+    owner_id: u32,
+    direction: &str,
+) -> Result<Vec<SupporterTeam>, NutmegError> {
+    let rows = supporters::table
+        .filter(supporters::owner_id.eq(owner_id as i32))
+        .filter(supporters::direction.eq(direction))
+        .order(supporters::team_name.asc())
+        .load::<SupporterEntity>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load supporters: {}", e)).or_locked(&e))?;
+
+    Ok(rows.into_iter().map(SupporterTeam::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{MySupporterList, SupportedTeamList};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn team(id: u32, name: &str) -> SupporterTeam {
+        SupporterTeam {
+            UserId: id + 1000,
+            LoginName: format!("user{}", id),
+            TeamId: id,
+            TeamName: name.to_string(),
+            LeagueID: Some(5),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_supporters_round_trip_per_direction() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-09T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+
+        let both = Supporters {
+            MySupporters: Some(MySupporterList {
+                Teams: vec![team(2, "Zebra FC"), team(1, "Acorn Town")],
+                ..Default::default()
+            }),
+            SupportedTeams: Some(SupportedTeamList {
+                Teams: vec![team(3, "Rival United")],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        save_supporters(&mut conn, &both, 7, dl1).unwrap();
+
+        let supporters = get_supporters(&mut conn, 7, DIRECTION_SUPPORTER).unwrap();
+        let names: Vec<&str> = supporters.iter().map(|t| t.TeamName.as_str()).collect();
+        assert_eq!(names, vec!["Acorn Town", "Zebra FC"]);
+        assert_eq!(supporters[0].LoginName, "user1");
+        assert_eq!(supporters[0].LeagueID, Some(5));
+
+        // A later mysupporters fetch replaces that list only.
+        let only_supporters = Supporters {
+            MySupporters: Some(MySupporterList {
+                Teams: vec![team(4, "Newcomers")],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        save_supporters(&mut conn, &only_supporters, 7, dl2).unwrap();
+
+        let supporters = get_supporters(&mut conn, 7, DIRECTION_SUPPORTER).unwrap();
+        assert_eq!(supporters.len(), 1);
+        assert_eq!(supporters[0].TeamId, 4);
+        let supported = get_supporters(&mut conn, 7, DIRECTION_SUPPORTED).unwrap();
+        assert_eq!(supported.len(), 1);
+        assert_eq!(supported[0].TeamName, "Rival United");
+    }
+}
@@ -0,0 +1,193 @@
+/* team_cache.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::Team;
+use crate::db::schema::teams;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Number of teams kept by the shared cache: the user's teams plus a
+/// handful of opponents is plenty for one session.
+const DEFAULT_CAPACITY: usize = 16;
+
+/// Small LRU cache of deserialised teams, keyed on `(team_id, download_id)`.
+///
+/// `get_team` only runs a cheap `MAX(download_id)` lookup before answering
+/// from the cache, so a newer download for a team is never shadowed by a stale
+/// entry. `invalidate` drops everything and is called after each sync.
+pub struct TeamCache {
+    capacity: usize,
+    // Most recently used entry first.
+    entries: Mutex<VecDeque<((u32, i32), Team)>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl TeamCache {
+    pub fn new(capacity: usize) -> Self {
+        TeamCache {
+            capacity: capacity.max(1),
+            entries: Mutex::new(VecDeque::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Cached equivalent of `crate::db::teams::get_team`.
+    pub fn get_team(
+        &self,
+        conn: &mut SqliteConnection,
+        team_id: u32,
+    ) -> Result<Option<Team>, NutmegError> {
+        let latest: Option<i32> = teams::table
+            .filter(teams::id.eq(team_id as i32))
+            .select(diesel::dsl::max(teams::download_id))
+            .first::<Option<i32>>(conn)
+            .map_err(|e| {
+                NutmegError::Db(format!("Failed to get team download: {}", e)).or_locked(&e)
+            })?;
+        let Some(download_id) = latest else {
+            return Ok(None);
+        };
+        let key = (team_id, download_id);
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            if let Some(pos) = entries.iter().position(|(k, _)| *k == key) {
+                let entry = entries.remove(pos).expect("position is in range");
+                let team = entry.1.clone();
+                entries.push_front(entry);
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(Some(team));
+            }
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let team = crate::db::teams::get_team(conn, team_id)?;
+        if let Some(team) = &team {
+            let mut entries = self.entries.lock().unwrap();
+            entries.push_front((key, team.clone()));
+            entries.truncate(self.capacity);
+        }
+        Ok(team)
+    }
+
+    /// Drop every cached team.
+    pub fn invalidate(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for TeamCache {
+    fn default() -> Self {
+        TeamCache::new(DEFAULT_CAPACITY)
+    }
+}
+
+/// Process-wide cache shared by the UI context and controllers.
+pub fn team_cache() -> &'static TeamCache {
+    static CACHE: OnceLock<TeamCache> = OnceLock::new();
+    CACHE.get_or_init(TeamCache::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::User;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use crate::db::teams::save_team;
+    use serial_test::serial;
+
+    fn team(id: u32) -> Team {
+        Team {
+            TeamID: id.to_string(),
+            TeamName: format!("Team {}", id),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_team_deserialises_once() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+        let user = User {
+            UserID: 1,
+            ..Default::default()
+        };
+        save_team(&mut conn, &team(42), &user, dl, true).unwrap();
+
+        let cache = TeamCache::new(2);
+        let first = cache.get_team(&mut conn, 42).unwrap().expect("team");
+        let second = cache.get_team(&mut conn, 42).unwrap().expect("team");
+        assert_eq!(first.TeamName, second.TeamName);
+        assert_eq!((cache.misses(), cache.hits()), (1, 1));
+
+        assert!(cache.get_team(&mut conn, 7).unwrap().is_none());
+
+        cache.invalidate();
+        cache.get_team(&mut conn, 42).unwrap();
+        assert_eq!((cache.misses(), cache.hits()), (2, 1));
+    }
+
+    #[test]
+    #[serial]
+    fn test_newer_download_and_eviction() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+        let user = User::default();
+        for id in [1, 2, 3] {
+            save_team(&mut conn, &team(id), &user, dl1, true).unwrap();
+        }
+
+        let cache = TeamCache::new(2);
+        cache.get_team(&mut conn, 1).unwrap();
+        cache.get_team(&mut conn, 2).unwrap();
+        cache.get_team(&mut conn, 3).unwrap(); // evicts team 1
+        cache.get_team(&mut conn, 1).unwrap();
+        assert_eq!(cache.misses(), 4);
+
+        // A new download for team 1 is a different key.
+        let dl2 = create_download(&mut conn, "2026-03-11T00:00:00Z", "completed").unwrap();
+        let mut renamed = team(1);
+        renamed.TeamName = "Renamed".to_string();
+        save_team(&mut conn, &renamed, &user, dl2, true).unwrap();
+        let fetched = cache.get_team(&mut conn, 1).unwrap().expect("team");
+        assert_eq!(fetched.TeamName, "Renamed");
+        assert_eq!((cache.misses(), cache.hits()), (5, 0));
+    }
+}
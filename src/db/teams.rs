@@ -18,14 +18,16 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-use crate::error::NutmegError;
+use crate::error::{Context, NutmegError};
 use crate::chpp::model::{
-    Country, Cup, Currency, Language, League, Region, SupporterTier, Team, User, WorldDetails,
+    Country, Cup, Currency, Language, League, NationalTeams, Region, SupporterTier, Team, User,
+    WorldDetails, WorldLanguages,
 };
 use crate::db::schema::{
     avatars, countries, cups, currencies, downloads, languages, leagues, players, regions, teams,
     users,
 };
+use chrono::{DateTime, NaiveDateTime, Utc};
 use diesel::prelude::*;
 use diesel::sqlite::SqliteConnection;
 
@@ -213,6 +215,7 @@ struct TeamEntity {
     youth_team_id: Option<i32>,
     youth_team_name: Option<String>,
     gender_id: i32,
+    guestbook_items: Option<i32>,
 }
 
 #[derive(Queryable, Insertable)]
@@ -223,8 +226,8 @@ pub struct AvatarEntity {
     pub image: Vec<u8>,
 }
 
-#[derive(Queryable, Insertable)]
-#[diesel(table_name = players)]
+#[derive(Queryable, Insertable, AsChangeset)]
+#[diesel(table_name = players, treat_none_as_null = true)]
 struct PlayerEntity {
     id: i32,
     download_id: i32,
@@ -286,6 +289,7 @@ struct PlayerEntity {
     assists_current_team: Option<i32>,
     career_assists: Option<i32>,
     gender_id: i32,
+    transfer_deadline: Option<String>,
 }
 
 pub fn save_world_details(
@@ -382,136 +386,216 @@ pub fn save_world_details(
     })
 }
 
+/// Most players per INSERT statement: ~60 bound columns each keeps a chunk
+/// under SQLite's historical limit of 999 variables per statement.
+const PLAYER_INSERT_CHUNK: usize = 16;
+
+fn player_entity(
+    player: &crate::chpp::model::Player,
+    team_id: u32,
+    download_id: i32,
+) -> PlayerEntity {
+    PlayerEntity {
+        id: player.PlayerID as i32,
+        download_id,
+        team_id: team_id as i32,
+        first_name: player.FirstName.clone(),
+        nick_name: player.NickName.clone(),
+        last_name: player.LastName.clone(),
+        player_number: player.PlayerNumber.unwrap_or(100) as i32,
+        age: player.Age as i32,
+        age_days: player.AgeDays.map(|v| v as i32),
+        tsi: player.TSI as i32,
+        player_form: player.PlayerForm as i32,
+        statement: player.Statement.clone(),
+        experience: player.Experience as i32,
+        loyalty: player.Loyalty as i32,
+        mother_club_bonus: player.MotherClubBonus,
+        leadership: player.Leadership as i32,
+        salary: player.Salary as i32,
+        is_abroad: player.IsAbroad,
+        agreeability: player.Agreeability as i32,
+        aggressiveness: player.Aggressiveness as i32,
+        honesty: player.Honesty as i32,
+        league_goals: player.LeagueGoals.map(|v| v as i32),
+        cup_goals: player.CupGoals.map(|v| v as i32),
+        friendlies_goals: player.FriendliesGoals.map(|v| v as i32),
+        career_goals: player.CareerGoals.map(|v| v as i32),
+        career_hattricks: player.CareerHattricks.map(|v| v as i32),
+        transfer_listed: player.TransferListed,
+        national_team_id: player.NationalTeamID.map(|v| v as i32),
+        country_id: player.CountryID.unwrap_or(0) as i32,
+        caps: player.Caps.map(|v| v as i32),
+        caps_u20: player.CapsU20.map(|v| v as i32),
+        cards: player.Cards.map(|v| v as i32),
+        injury_level: player.InjuryLevel,
+        specialty: player.Specialty.map(|v| v as i32),
+        // Skills
+        stamina_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.StaminaSkill as i32),
+        keeper_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.KeeperSkill as i32),
+        playmaker_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.PlaymakerSkill as i32),
+        scorer_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.ScorerSkill as i32),
+        passing_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.PassingSkill as i32),
+        winger_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.WingerSkill as i32),
+        defender_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.DefenderSkill as i32),
+        set_pieces_skill: player
+            .PlayerSkills
+            .as_ref()
+            .map(|skills| skills.SetPiecesSkill as i32),
+        // Last Match
+        last_match_date: player
+            .LastMatch
+            .as_ref()
+            .map(|match_data| match_data.Date.clone()),
+        last_match_id: player
+            .LastMatch
+            .as_ref()
+            .map(|match_data| match_data.MatchId as i32),
+        last_match_position_code: player
+            .LastMatch
+            .as_ref()
+            .map(|match_data| match_data.PositionCode as i32),
+        last_match_played_minutes: player
+            .LastMatch
+            .as_ref()
+            .map(|match_data| match_data.PlayedMinutes as i32),
+        last_match_rating: player
+            .LastMatch
+            .as_ref()
+            .and_then(|match_data| match_data.Rating.map(|v| v as i32)),
+        last_match_rating_end_of_match: player
+            .LastMatch
+            .as_ref()
+            .and_then(|match_data| match_data.RatingEndOfMatch.map(|v| v as i32)),
+        arrival_date: player.ArrivalDate.clone(),
+        player_category_id: player.PlayerCategoryId.map(|v| v as i32),
+        mother_club_team_id: player
+            .MotherClub
+            .as_ref()
+            .map(|mother_club| mother_club.TeamID as i32),
+        mother_club_team_name: player
+            .MotherClub
+            .as_ref()
+            .map(|mother_club| mother_club.TeamName.clone()),
+        native_country_id: player.NativeCountryID.map(|v| v as i32),
+        native_league_id: player.NativeLeagueID.map(|v| v as i32),
+        native_league_name: player.NativeLeagueName.clone(),
+        matches_current_team: player.MatchesCurrentTeam.map(|v| v as i32),
+        goals_current_team: player.GoalsCurrentTeam.map(|v| v as i32),
+        assists_current_team: player.AssistsCurrentTeam.map(|v| v as i32),
+        career_assists: player.CareerAssists.map(|v| v as i32),
+        gender_id: player.GenderID.unwrap_or(1) as i32,
+        transfer_deadline: player
+            .TransferDetails
+            .as_ref()
+            .map(|details| details.Deadline.clone()),
+    }
+}
+
+/// Save a squad in one transaction with batched inserts, so either every
+/// player is stored or none is.
 pub fn save_players(
     conn: &mut SqliteConnection,
     players_list: &[crate::chpp::model::Player],
     team_id: u32,
     download_id: i32,
 ) -> Result<(), NutmegError> {
-    for player in players_list {
-        let player_id = player.PlayerID as i32;
+    let entities: Vec<PlayerEntity> = players_list
+        .iter()
+        .map(|player| player_entity(player, team_id, download_id))
+        .collect();
 
-        let entity = PlayerEntity {
-            id: player_id,
-            download_id,
-            team_id: team_id as i32,
-            first_name: player.FirstName.clone(),
-            nick_name: player.NickName.clone(),
-            last_name: player.LastName.clone(),
-            player_number: player.PlayerNumber.unwrap_or(100) as i32,
-            age: player.Age as i32,
-            age_days: player.AgeDays.map(|v| v as i32),
-            tsi: player.TSI as i32,
-            player_form: player.PlayerForm as i32,
-            statement: player.Statement.clone(),
-            experience: player.Experience as i32,
-            loyalty: player.Loyalty as i32,
-            mother_club_bonus: player.MotherClubBonus,
-            leadership: player.Leadership as i32,
-            salary: player.Salary as i32,
-            is_abroad: player.IsAbroad,
-            agreeability: player.Agreeability as i32,
-            aggressiveness: player.Aggressiveness as i32,
-            honesty: player.Honesty as i32,
-            league_goals: player.LeagueGoals.map(|v| v as i32),
-            cup_goals: player.CupGoals.map(|v| v as i32),
-            friendlies_goals: player.FriendliesGoals.map(|v| v as i32),
-            career_goals: player.CareerGoals.map(|v| v as i32),
-            career_hattricks: player.CareerHattricks.map(|v| v as i32),
-            transfer_listed: player.TransferListed,
-            national_team_id: player.NationalTeamID.map(|v| v as i32),
-            country_id: player.CountryID.unwrap_or(0) as i32,
-            caps: player.Caps.map(|v| v as i32),
-            caps_u20: player.CapsU20.map(|v| v as i32),
-            cards: player.Cards.map(|v| v as i32),
-            injury_level: player.InjuryLevel,
-            specialty: player.Specialty.map(|v| v as i32),
-            // Skills
-            stamina_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.StaminaSkill as i32),
-            keeper_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.KeeperSkill as i32),
-            playmaker_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.PlaymakerSkill as i32),
-            scorer_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.ScorerSkill as i32),
-            passing_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.PassingSkill as i32),
-            winger_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.WingerSkill as i32),
-            defender_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.DefenderSkill as i32),
-            set_pieces_skill: player
-                .PlayerSkills
-                .as_ref()
-                .map(|skills| skills.SetPiecesSkill as i32),
-            // Last Match
-            last_match_date: player
-                .LastMatch
-                .as_ref()
-                .map(|match_data| match_data.Date.clone()),
-            last_match_id: player
-                .LastMatch
-                .as_ref()
-                .map(|match_data| match_data.MatchId as i32),
-            last_match_position_code: player
-                .LastMatch
-                .as_ref()
-                .map(|match_data| match_data.PositionCode as i32),
-            last_match_played_minutes: player
-                .LastMatch
-                .as_ref()
-                .map(|match_data| match_data.PlayedMinutes as i32),
-            last_match_rating: player
-                .LastMatch
-                .as_ref()
-                .and_then(|match_data| match_data.Rating.map(|v| v as i32)),
-            last_match_rating_end_of_match: player
-                .LastMatch
-                .as_ref()
-                .and_then(|match_data| match_data.RatingEndOfMatch.map(|v| v as i32)),
-            arrival_date: player.ArrivalDate.clone(),
-            player_category_id: player.PlayerCategoryId.map(|v| v as i32),
-            mother_club_team_id: player
-                .MotherClub
-                .as_ref()
-                .map(|mother_club| mother_club.TeamID as i32),
-            mother_club_team_name: player
-                .MotherClub
-                .as_ref()
-                .map(|mother_club| mother_club.TeamName.clone()),
-            native_country_id: player.NativeCountryID.map(|v| v as i32),
-            native_league_id: player.NativeLeagueID.map(|v| v as i32),
-            native_league_name: player.NativeLeagueName.clone(),
-            matches_current_team: player.MatchesCurrentTeam.map(|v| v as i32),
-            goals_current_team: player.GoalsCurrentTeam.map(|v| v as i32),
-            assists_current_team: player.AssistsCurrentTeam.map(|v| v as i32),
-            career_assists: player.CareerAssists.map(|v| v as i32),
-            gender_id: player.GenderID.unwrap_or(1) as i32,
-        };
-        diesel::insert_or_ignore_into(players::table)
-            .values(&entity)
-            .execute(conn)
-            .map_err(|e| NutmegError::Io(format!("Database error saving player {}: {}", player_id, e)))?;
-    }
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        for chunk in entities.chunks(PLAYER_INSERT_CHUNK) {
+            // A player already saved for this download keeps its row, but skills
+            // and the transfer deadline are filled in when the new data has them
+            // and kept when it does not, so basic-only data never erases
+            // previously fetched details.
+            diesel::insert_into(players::table)
+                .values(chunk)
+                .on_conflict((players::id, players::download_id))
+                .do_update()
+                .set((
+                    players::stamina_skill.eq(keep_skill("stamina_skill")),
+                    players::keeper_skill.eq(keep_skill("keeper_skill")),
+                    players::playmaker_skill.eq(keep_skill("playmaker_skill")),
+                    players::scorer_skill.eq(keep_skill("scorer_skill")),
+                    players::passing_skill.eq(keep_skill("passing_skill")),
+                    players::winger_skill.eq(keep_skill("winger_skill")),
+                    players::defender_skill.eq(keep_skill("defender_skill")),
+                    players::set_pieces_skill.eq(keep_skill("set_pieces_skill")),
+                    players::transfer_deadline.eq(keep_text("transfer_deadline")),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+    .context(&format!("Failed to save players of team {}", team_id))
+}
 
+/// Rewrite every column of a player already stored in `download_id` with
+/// `player`, e.g. after refreshing them from playerdetails. Unlike
+/// `save_players`, missing values are stored as missing.
+pub fn update_player(
+    conn: &mut SqliteConnection,
+    player: &crate::chpp::model::Player,
+    team_id: u32,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entity = player_entity(player, team_id, download_id);
+    let updated = diesel::update(
+        players::table
+            .filter(players::id.eq(entity.id))
+            .filter(players::download_id.eq(download_id)),
+    )
+    .set(&entity)
+    .execute(conn)
+    .context(&format!("Failed to update player {}", player.PlayerID))?;
+
+    if updated == 0 {
+        return Err(NutmegError::Db(format!(
+            "Player {} is not stored in download {}",
+            player.PlayerID, download_id
+        )));
+    }
     Ok(())
 }
 
+/// Upsert value for a skill column: the incoming value unless it is NULL.
+fn keep_skill(
+    column: &str,
+) -> diesel::expression::SqlLiteral<diesel::sql_types::Nullable<diesel::sql_types::Integer>> {
+    diesel::dsl::sql(&format!("COALESCE(excluded.{column}, players.{column})"))
+}
+
+/// Same as `keep_skill`, for a text column.
+fn keep_text(
+    column: &str,
+) -> diesel::expression::SqlLiteral<diesel::sql_types::Nullable<diesel::sql_types::Text>> {
+    diesel::dsl::sql(&format!("COALESCE(excluded.{column}, players.{column})"))
+}
+
 pub fn save_avatars(
     conn: &mut SqliteConnection,
     avatars_list: &[(u32, Vec<u8>)],
@@ -529,7 +613,10 @@ pub fn save_avatars(
         diesel::insert_or_ignore_into(avatars::table)
             .values(&entity)
             .execute(conn)
-            .map_err(|e| NutmegError::Io(format!("Database error saving avatar for {}: {}", p_id, e)))?;
+            .map_err(|e| {
+                NutmegError::Io(format!("Database error saving avatar for {}: {}", p_id, e))
+                    .or_locked(&e)
+            })?;
     }
     Ok(())
 }
@@ -538,6 +625,21 @@ pub fn save_avatars(
 // Inserts a new versioned row only when the entity is brand new or its name
 // has changed since the last stored version.  If the latest stored row matches
 // the incoming data the insert is skipped — no redundant row is created.
+/// Store every language from the worldlanguages endpoint, independently of
+/// the languages referenced by world details.
+pub fn save_world_languages(
+    conn: &mut SqliteConnection,
+    world_languages: &WorldLanguages,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    conn.transaction::<_, NutmegError, _>(|conn| {
+        for language in &world_languages.LanguageList.Languages {
+            save_language(conn, language, download_id)?;
+        }
+        Ok(())
+    })
+}
+
 pub fn save_language(
     conn: &mut SqliteConnection,
     language: &Language,
@@ -551,7 +653,7 @@ pub fn save_language(
     diesel::insert_or_ignore_into(languages::table)
         .values(&entity)
         .execute(conn)
-        .map_err(|e| NutmegError::Io(format!("Database error saving language: {}", e)))?;
+        .io_context("Failed to save language")?;
     Ok(())
 }
 
@@ -573,7 +675,7 @@ pub fn save_currency(
     diesel::insert_or_ignore_into(currencies::table)
         .values(&entity)
         .execute(conn)
-        .map_err(|e| NutmegError::Io(format!("Database error saving currency: {}", e)))?;
+        .io_context("Failed to save currency")?;
     Ok(())
 }
 
@@ -612,7 +714,16 @@ fn save_user(
     diesel::insert_or_ignore_into(users::table)
         .values(&entity)
         .execute(conn)
-        .map_err(|e| NutmegError::Io(format!("Database error saving user: {}", e)))?;
+        .io_context("Failed to save user")?;
+
+    if let Some(national_teams) = &user.NationalTeams {
+        crate::db::national_teams::save_national_teams(
+            conn,
+            national_teams,
+            user.UserID,
+            download_id,
+        )?;
+    }
     Ok(())
 }
 
@@ -637,7 +748,7 @@ pub fn save_country(
     diesel::insert_or_ignore_into(countries::table)
         .values(&entity)
         .execute(conn)
-        .map_err(|e| NutmegError::Io(format!("Database error saving country: {}", e)))?;
+        .io_context("Failed to save country")?;
     Ok(())
 }
 
@@ -659,7 +770,7 @@ fn save_region(
         diesel::insert_or_ignore_into(regions::table)
             .values(&entity)
             .execute(conn)
-            .map_err(|e| NutmegError::Io(format!("Database error saving region: {}", e)))?;
+            .io_context("Failed to save region")?;
     }
 
     Ok(())
@@ -695,7 +806,7 @@ fn save_league(
     diesel::insert_or_ignore_into(leagues::table)
         .values(&entity)
         .execute(conn)
-        .map_err(|e| NutmegError::Io(format!("Database error saving league: {}", e)))?;
+        .io_context("Failed to save league")?;
     Ok(())
 }
 
@@ -716,7 +827,7 @@ fn save_cup(conn: &mut SqliteConnection, cup: &Cup, download_id: i32) -> Result<
         diesel::insert_or_ignore_into(cups::table)
             .values(&entity)
             .execute(conn)
-            .map_err(|e| NutmegError::Io(format!("Database error saving cup: {}", e)))?;
+            .io_context("Failed to save cup")?;
     }
     Ok(())
 }
@@ -748,8 +859,7 @@ pub fn save_team(
         .parse::<i32>()
         .map_err(|e| NutmegError::Parse(format!("Invalid TeamID: {}", e)))?;
 
-    let json_data = serde_json::to_string(team)
-        .map_err(|e| NutmegError::Parse(format!("Failed to serialize team: {}", e)))?;
+    let json_data = serde_json::to_string(team).context("Failed to serialize team")?;
 
     let entity = TeamEntity {
         download_id,
@@ -860,12 +970,16 @@ pub fn save_team(
         youth_team_id: team.YouthTeamID.map(|v| v as i32),
         youth_team_name: team.YouthTeamName.clone(),
         gender_id: team.GenderID.unwrap_or(1) as i32,
+        guestbook_items: team
+            .Guestbook
+            .as_ref()
+            .map(|guestbook| guestbook.NumberOfGuestbookItems as i32),
     };
 
     diesel::insert_or_ignore_into(teams::table)
         .values(&entity)
         .execute(conn)
-        .map_err(|e| NutmegError::Io(format!("Database error: {}", e)))?;
+        .io_context("Failed to save team")?;
 
     Ok(())
 }
@@ -910,7 +1024,7 @@ pub fn get_teams_summary(
 
     let results = sql_query(query)
         .load::<TeamSummaryRow>(conn)
-        .map_err(|e| NutmegError::Db(format!("Failed to load authenticated teams summary: {}", e)))?;
+        .context("Failed to load authenticated teams summary")?;
 
     Ok(results
         .into_iter()
@@ -930,7 +1044,7 @@ pub fn get_latest_country(
         .order(download_id.desc())
         .first::<CountryEntity>(conn)
         .optional()
-        .map_err(|e| NutmegError::Db(format!("Failed to get country: {}", e)))?;
+        .context("Failed to get country")?;
 
     if let Some(e) = entity {
         // Fetch currency if available
@@ -965,7 +1079,7 @@ pub fn get_latest_currency(
         .order(download_id.desc())
         .first::<CurrencyEntity>(conn)
         .optional()
-        .map_err(|e| NutmegError::Db(format!("Failed to get currency: {}", e)))?;
+        .context("Failed to get currency")?;
 
     Ok(entity.map(|e| crate::chpp::model::Currency {
         CurrencyID: e.id as u32,
@@ -987,7 +1101,7 @@ pub fn get_latest_region(
         .order(download_id.desc())
         .first::<RegionEntity>(conn)
         .optional()
-        .map_err(|e| NutmegError::Db(format!("Failed to get region: {}", e)))?;
+        .context("Failed to get region")?;
 
     Ok(entity.map(|e| crate::chpp::model::Region {
         RegionID: e.id as u32,
@@ -995,6 +1109,42 @@ pub fn get_latest_region(
     }))
 }
 
+/// Most recent stored league with its season fields, which only world
+/// details carry.
+pub fn get_latest_league(
+    conn: &mut SqliteConnection,
+    league_id_val: u32,
+) -> Result<Option<League>, NutmegError> {
+    use crate::db::schema::leagues::dsl::*;
+
+    let entity: Option<LeagueEntity> = leagues
+        .filter(id.eq(league_id_val as i32))
+        .filter(season.is_not_null())
+        .order(download_id.desc())
+        .first::<LeagueEntity>(conn)
+        .optional()
+        .context("Failed to get league")?;
+
+    Ok(entity.map(|e| League {
+        LeagueID: e.id as u32,
+        LeagueName: e.name,
+        ShortName: e.short_name,
+        Continent: e.continent,
+        Season: e.season.map(|v| v as u32),
+        SeasonOffset: e.season_offset,
+        MatchRound: e.match_round.map(|v| v as u32),
+        ZoneName: e.zone_name,
+        EnglishName: e.english_name,
+        LanguageID: e.language_id.map(|v| v as u32),
+        NationalTeamId: e.national_team_id.map(|v| v as u32),
+        U20TeamId: e.u20_team_id.map(|v| v as u32),
+        ActiveTeams: e.active_teams.map(|v| v as u32),
+        ActiveUsers: e.active_users.map(|v| v as u32),
+        NumberOfLevels: e.number_of_levels.map(|v| v as u32),
+        LeagueSystemId: Some(e.league_system_id as u32),
+    }))
+}
+
 #[allow(dead_code)]
 pub fn get_latest_user(
     conn: &mut SqliteConnection,
@@ -1007,7 +1157,7 @@ pub fn get_latest_user(
         .order(download_id.desc())
         .first::<UserEntity>(conn)
         .optional()
-        .map_err(|e| NutmegError::Db(format!("Failed to get user: {}", e)))?;
+        .context("Failed to get user")?;
 
     if let Some(e) = entity {
         // We need language for User model
@@ -1036,13 +1186,56 @@ pub fn get_latest_user(
             LastLoginDate: e.last_login_date.unwrap_or_default(),
             HasManagerLicense: e.has_manager_license.unwrap_or(false),
             Language: Some(lang),
+            NationalTeams: Some(NationalTeams {
+                NationalTeams: crate::db::national_teams::get_national_teams(conn, e.id as u32)?,
+            }),
         }))
     } else {
         Ok(None)
     }
 }
 
-#[allow(dead_code)]
+/// When the league's season and match round returned by
+/// [`get_latest_league`] were downloaded.
+pub fn get_league_synced_at(
+    conn: &mut SqliteConnection,
+    league_id: u32,
+) -> Result<Option<DateTime<Utc>>, NutmegError> {
+    let timestamp = leagues::table
+        .inner_join(downloads::table)
+        .filter(leagues::id.eq(league_id as i32))
+        .filter(leagues::season.is_not_null())
+        .order(leagues::download_id.desc())
+        .select(downloads::timestamp)
+        .first::<String>(conn)
+        .optional()
+        .context("Failed to get league download time")?;
+
+    timestamp
+        .map(|ts| {
+            DateTime::parse_from_rfc3339(&ts)
+                .map(|at| at.with_timezone(&Utc))
+                .map_err(|e| {
+                    NutmegError::Parse(format!("Invalid download timestamp {}: {}", ts, e))
+                })
+        })
+        .transpose()
+}
+
+/// Hattrick language id of the signed-in user, as of their latest download.
+pub fn get_current_user_language_id(
+    conn: &mut SqliteConnection,
+) -> Result<Option<u32>, NutmegError> {
+    users::table
+        .filter(users::is_current_authenticated_user.eq(true))
+        .order(users::download_id.desc())
+        .select(users::language_id)
+        .first::<Option<i32>>(conn)
+        .optional()
+        .context("Failed to get the user's language")
+        .map(|res| res.flatten().map(|v| v as u32))
+}
+
 pub fn get_user_id_for_team(
     conn: &mut SqliteConnection,
     team_id_val: i32,
@@ -1055,10 +1248,80 @@ pub fn get_user_id_for_team(
         .select(user_id)
         .first::<Option<i32>>(conn)
         .optional()
-        .map_err(|e| NutmegError::Db(format!("Failed to get user id for team: {}", e)))
+        .context("Failed to get user id for team")
         .map(|res| res.flatten())
 }
 
+/// Name and flag of every known country, keyed by country id, taken from
+/// the most recent download that saved each country.
+///
+/// World details are usually fetched for the user's league only, so the
+/// latest download holds just their own country. Merging per country keeps
+/// the foreign ones from the last full fetch.
+pub fn country_lookup(
+    conn: &mut SqliteConnection,
+) -> Result<std::collections::HashMap<i32, (String, Option<String>)>, NutmegError> {
+    use diesel::sql_query;
+    use diesel::sql_types::{Integer, Nullable, Text};
+
+    #[derive(QueryableByName)]
+    struct CountryRow {
+        #[diesel(sql_type = Integer)]
+        id: i32,
+        #[diesel(sql_type = Text)]
+        name: String,
+        #[diesel(sql_type = Nullable<Text>)]
+        flag: Option<String>,
+    }
+
+    let query = "
+        SELECT c.id, c.name, c.flag
+        FROM countries c
+        INNER JOIN (
+            SELECT id, MAX(download_id) as max_dl
+            FROM countries
+            GROUP BY id
+        ) latest ON c.id = latest.id AND c.download_id = latest.max_dl
+    ";
+
+    Ok(sql_query(query)
+        .load::<CountryRow>(conn)
+        .context("Failed to load countries")?
+        .into_iter()
+        .map(|row| (row.id, (row.name, row.flag)))
+        .collect())
+}
+
+/// Download holding the most recent full country list, if any. A
+/// league-filtered worlddetails fetch saves a single country.
+pub fn last_full_country_list(conn: &mut SqliteConnection) -> Result<Option<i32>, NutmegError> {
+    use diesel::dsl::count_star;
+
+    countries::table
+        .group_by(countries::download_id)
+        .having(count_star().gt(1))
+        .select(countries::download_id)
+        .order(countries::download_id.desc())
+        .first::<i32>(conn)
+        .optional()
+        .context("Failed to find the last full country list")
+}
+
+/// Whether the next world details fetch should cover every league rather
+/// than the user's own: true when no stored download has a full country
+/// list, or the latest one is more than `max_downloads` downloads older
+/// than `download_id`.
+pub fn needs_full_world_details(
+    conn: &mut SqliteConnection,
+    download_id: i32,
+    max_downloads: i32,
+) -> Result<bool, NutmegError> {
+    Ok(match last_full_country_list(conn)? {
+        Some(last_full) => download_id - last_full > max_downloads,
+        None => true,
+    })
+}
+
 pub fn get_players_for_team(
     conn: &mut SqliteConnection,
     team_id_in: u32,
@@ -1072,51 +1335,20 @@ pub fn get_players_for_team(
         .filter(players::team_id.eq(team_id_in as i32))
         .select(diesel::dsl::max(players::download_id))
         .first::<Option<i32>>(conn)
-        .map_err(|e| NutmegError::Db(format!("Failed to get max player download_id: {}", e)))?;
+        .context("Failed to get max player download_id")?;
 
     let download_id_filter = match player_download_id_opt {
         Some(id) => id,
         None => return Ok(Vec::new()),
     };
 
-    // Fetch all countries for this download to map flags
-    let country_list = countries::table
-        .filter(countries::download_id.eq(download_id_filter))
-        .select((countries::id, countries::flag.nullable()))
-        .load::<(i32, Option<String>)>(conn)
-        .unwrap_or_default();
-
-    let country_list_latest = if country_list.is_empty() {
-        // The worlddetails download_id can differ from the players download_id.
-        // Fall back to the latest country data we have.
-        use diesel::dsl::max;
-        let latest_country_dl: Option<i32> = countries::table
-            .select(max(countries::download_id))
-            .first::<Option<i32>>(conn)
-            .unwrap_or(None);
-        if let Some(latest) = latest_country_dl {
-            countries::table
-                .filter(countries::download_id.eq(latest))
-                .select((countries::id, countries::flag.nullable()))
-                .load::<(i32, Option<String>)>(conn)
-                .unwrap_or_default()
-        } else {
-            vec![]
-        }
-    } else {
-        country_list
-    };
-
-    let country_map: std::collections::HashMap<i32, String> = country_list_latest
-        .into_iter()
-        .filter_map(|(id, flag)| flag.map(|flag_emoji| (id, flag_emoji)))
-        .collect();
+    let country_map = country_lookup(conn)?;
 
     let results: Vec<PlayerEntity> = players::table
         .filter(players::team_id.eq(team_id_in as i32))
         .filter(players::download_id.eq(download_id_filter))
         .load::<PlayerEntity>(conn)
-        .map_err(|e| NutmegError::Db(format!("Failed to load players: {}", e)))?;
+        .context("Failed to load players")?;
 
     let player_ids: Vec<i32> = results.iter().map(|p| p.id).collect();
 
@@ -1127,7 +1359,7 @@ pub fn get_players_for_team(
         .order((avatars::player_id.asc(), avatars::download_id.desc()))
         .select((avatars::player_id, avatars::image))
         .load::<(i32, Vec<u8>)>(conn)
-        .map_err(|e| NutmegError::Db(format!("Failed to load avatars: {}", e)))?;
+        .context("Failed to load avatars")?;
 
     let mut avatar_map = std::collections::HashMap::new();
     for (pid, img) in avatars_rows {
@@ -1136,10 +1368,13 @@ pub fn get_players_for_team(
 
     let mut players = Vec::new();
     for entity in results {
-        let flag = country_map.get(&entity.country_id).cloned();
+        let flag = country_map
+            .get(&entity.country_id)
+            .and_then(|(_, flag)| flag.clone());
         let native_flag = entity
             .native_country_id
-            .and_then(|id| country_map.get(&id).cloned());
+            .and_then(|id| country_map.get(&id))
+            .and_then(|(_, flag)| flag.clone());
 
         let avatar_blob = avatar_map.get(&entity.id).cloned();
 
@@ -1230,12 +1465,98 @@ pub fn get_players_for_team(
             MatchesCurrentTeam: entity.matches_current_team.map(|v| v as u32),
             GoalsCurrentTeam: entity.goals_current_team.map(|v| v as u32),
             AssistsCurrentTeam: entity.assists_current_team.map(|v| v as u32),
+            TransferDetails: entity.transfer_deadline.map(|deadline| {
+                crate::chpp::model::PlayerTransferDetails {
+                    Deadline: deadline,
+                    ..Default::default()
+                }
+            }),
         });
     }
 
     Ok(players)
 }
 
+/// Players of the team who arrived after `since`, most recent arrival first.
+///
+/// `ArrivalDate` is stored as sent by CHPP (`YYYY-MM-DD HH:MM:SS`, Hattrick time).
+/// Players with a missing or unparseable arrival date are left out.
+pub fn get_recent_arrivals(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+    since: DateTime<Utc>,
+) -> Result<Vec<crate::chpp::model::Player>, NutmegError> {
+    let mut arrivals: Vec<(DateTime<Utc>, crate::chpp::model::Player)> =
+        get_players_for_team(conn, team_id)?
+            .into_iter()
+            .filter_map(|p| {
+                let arrived = p
+                    .ArrivalDate
+                    .as_deref()
+                    .and_then(|d| NaiveDateTime::parse_from_str(d, "%Y-%m-%d %H:%M:%S").ok())?
+                    .and_utc();
+                (arrived > since).then_some((arrived, p))
+            })
+            .collect();
+
+    arrivals.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(arrivals.into_iter().map(|(_, p)| p).collect())
+}
+
+/// One row of [`players_by_country`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountryCount {
+    pub country_id: u32,
+    /// Empty when the country is missing from the countries table.
+    pub name: String,
+    pub flag: Option<String>,
+    pub count: usize,
+}
+
+/// Number of players per country in the team's latest squad, most
+/// represented country first. A country missing from the countries table
+/// is still listed, with an empty name.
+pub fn players_by_country(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<Vec<CountryCount>, NutmegError> {
+    use diesel::dsl::{count_star, max};
+
+    let Some(player_download_id) = players::table
+        .filter(players::team_id.eq(team_id as i32))
+        .select(max(players::download_id))
+        .first::<Option<i32>>(conn)
+        .context("Failed to get max player download_id")?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let counts: Vec<(i32, i64)> = players::table
+        .filter(players::team_id.eq(team_id as i32))
+        .filter(players::download_id.eq(player_download_id))
+        .group_by(players::country_id)
+        .select((players::country_id, count_star()))
+        .load(conn)
+        .context("Failed to count players by country")?;
+
+    let mut lookup = country_lookup(conn)?;
+
+    let mut breakdown: Vec<CountryCount> = counts
+        .into_iter()
+        .map(|(id, count)| {
+            let (name, flag) = lookup.remove(&id).unwrap_or_default();
+            CountryCount {
+                country_id: id as u32,
+                name,
+                flag,
+                count: count as usize,
+            }
+        })
+        .collect();
+    breakdown.sort_by(|a, b| b.count.cmp(&a.count).then(a.country_id.cmp(&b.country_id)));
+    Ok(breakdown)
+}
+
 pub fn get_team(conn: &mut SqliteConnection, team_id: u32) -> Result<Option<Team>, NutmegError> {
     use crate::db::schema::teams::dsl::*;
 
@@ -1248,13 +1569,12 @@ pub fn get_team(conn: &mut SqliteConnection, team_id: u32) -> Result<Option<Team
         .order(download_id.desc())
         .first::<TeamEntity>(conn)
         .optional()
-        .map_err(|e| NutmegError::Io(format!("Database error: {}", e)))?;
+        .io_context("Failed to load team")?;
 
     match result {
         Some(entity) => {
-            let team: Team = serde_json::from_str(&entity.raw_data).map_err(|e| {
-                NutmegError::Parse(format!("Failed to deserialise team data from DB: {}", e))
-            })?;
+            let team: Team = serde_json::from_str(&entity.raw_data)
+                .context("Failed to deserialise team data from DB")?;
             Ok(Some(team))
         }
         None => Ok(None),
@@ -1275,11 +1595,82 @@ pub fn get_existing_team_ids(
         .select(teams::id)
         .distinct()
         .load::<i32>(conn)
-        .map_err(|e| NutmegError::Db(format!("Failed to load team IDs: {}", e)))?;
+        .context("Failed to load team IDs")?;
 
     Ok(rows.into_iter().collect())
 }
 
+/// Details shown alongside a team in the team list, from its latest
+/// download.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamListExtras {
+    /// `(background, primary)` kit colours.
+    pub colors: Option<(String, String)>,
+    pub guestbook_items: Option<u32>,
+}
+
+/// Returns a map of `team_id → TeamListExtras` for the given team IDs,
+/// read from the row with the highest download_id of each team. Teams with
+/// no stored row are omitted.
+pub fn get_team_list_extras(
+    conn: &mut SqliteConnection,
+    team_ids: &[i32],
+) -> Result<std::collections::HashMap<i32, TeamListExtras>, NutmegError> {
+    use diesel::sql_query;
+    use diesel::sql_types::{Integer, Nullable, Text};
+
+    if team_ids.is_empty() {
+        return Ok(std::collections::HashMap::new());
+    }
+
+    #[derive(QueryableByName)]
+    struct TeamExtrasRow {
+        #[diesel(sql_type = Integer)]
+        id: i32,
+        #[diesel(sql_type = Nullable<Text>)]
+        color_background: Option<String>,
+        #[diesel(sql_type = Nullable<Text>)]
+        color_primary: Option<String>,
+        #[diesel(sql_type = Nullable<Integer>)]
+        guestbook_items: Option<i32>,
+    }
+
+    let placeholders = vec!["?"; team_ids.len()].join(", ");
+    let query = format!(
+        "
+        SELECT t.id, t.color_background, t.color_primary, t.guestbook_items
+        FROM teams t
+        INNER JOIN (
+            SELECT id, MAX(download_id) as max_dl
+            FROM teams
+            WHERE id IN ({})
+            GROUP BY id
+        ) latest_teams ON t.id = latest_teams.id AND t.download_id = latest_teams.max_dl
+    ",
+        placeholders
+    );
+
+    let mut query = sql_query(query).into_boxed();
+    for team_id in team_ids {
+        query = query.bind::<Integer, _>(*team_id);
+    }
+    let rows = query
+        .load::<TeamExtrasRow>(conn)
+        .context("Failed to load team list details")?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let colors = row.color_background.zip(row.color_primary);
+            let extras = TeamListExtras {
+                colors,
+                guestbook_items: row.guestbook_items.map(|v| v as u32),
+            };
+            (row.id, extras)
+        })
+        .collect())
+}
+
 /// Returns a map of `team_id → logo_url` for the given team IDs, using the
 /// latest available download for each team.  Teams with no stored logo URL are
 /// omitted from the map (the caller should treat a missing key as `None`).
@@ -1299,7 +1690,7 @@ pub fn get_logo_urls_for_teams(
         .order((teams::id.asc(), teams::download_id.desc()))
         .select((teams::id, teams::logo_url))
         .load::<(i32, Option<String>)>(conn)
-        .map_err(|e| NutmegError::Db(format!("Failed to load logo URLs: {}", e)))?;
+        .context("Failed to load logo URLs")?;
 
     // Dedup: the first row per team_id is the most recent (ORDER BY download_id DESC).
     let mut map = std::collections::HashMap::new();
@@ -1339,6 +1730,7 @@ mod tests {
             ActivationDate: "2000-01-02".to_string(),
             LastLoginDate: "2023-01-01".to_string(),
             HasManagerLicense: true,
+            NationalTeams: None,
             Language: Some(Language {
                 LanguageID: 1,
                 LanguageName: "English".to_string(),
@@ -1374,6 +1766,9 @@ mod tests {
                     <RegionRanking>2</RegionRanking>
                     <PowerRating>1500</PowerRating>
                 </PowerRating>
+                <Guestbook>
+                    <NumberOfGuestbookItems>12</NumberOfGuestbookItems>
+                </Guestbook>
             </Team>
         "#;
         let team: Team = serde_xml_rs::from_str(team_xml).expect("Failed to parse team XML");
@@ -1401,6 +1796,35 @@ mod tests {
         assert_eq!(saved_team.ShortTeamName, Some("PTT".to_string()));
         assert_eq!(saved_team.Trainer.unwrap().PlayerID, 888);
         assert_eq!(saved_team.PowerRating.unwrap().PowerRating, 1500);
+        assert_eq!(saved_team.Guestbook.unwrap().NumberOfGuestbookItems, 12);
+
+        let extras = get_team_list_extras(&mut conn, &[99999, 1]).unwrap();
+        assert_eq!(extras[&99999].guestbook_items, Some(12));
+        assert_eq!(extras[&99999].colors, None);
+        assert_eq!(extras.len(), 1);
+
+        // A later download of the team wins.
+        insert_download(&mut conn, 101);
+        let repainted = Team {
+            TeamColors: Some(crate::chpp::model::TeamColors {
+                BackgroundColor: "288032".to_string(),
+                Color: "ffffff".to_string(),
+            }),
+            Guestbook: Some(crate::chpp::model::Guestbook {
+                NumberOfGuestbookItems: 13,
+            }),
+            ..team
+        };
+        save_team(&mut conn, &repainted, &user, 101, true).expect("Failed to save team");
+
+        let extras = get_team_list_extras(&mut conn, &[99999]).unwrap();
+        assert_eq!(
+            extras[&99999],
+            TeamListExtras {
+                colors: Some(("288032".to_string(), "ffffff".to_string())),
+                guestbook_items: Some(13),
+            }
+        );
     }
 
     #[test]
@@ -1416,6 +1840,7 @@ mod tests {
             ActivationDate: "2020-01-02".to_string(),
             LastLoginDate: "2023-01-01".to_string(),
             HasManagerLicense: true,
+            NationalTeams: None,
             Language: Some(Language {
                 LanguageID: 2,
                 LanguageName: "Swedish".to_string(),
@@ -1534,6 +1959,40 @@ mod tests {
         assert_eq!(tms[0].league_id, Some(1000));
     }
 
+    #[test]
+    fn test_get_current_user_language_id() {
+        let mut conn = establish_connection();
+        assert_eq!(get_current_user_language_id(&mut conn).unwrap(), None);
+
+        insert_download(&mut conn, 1);
+        insert_download(&mut conn, 2);
+        let user = |user_id: i32, dl: i32, language: i32, current: bool| UserEntity {
+            id: user_id,
+            download_id: dl,
+            name: "User".to_string(),
+            login_name: "user".to_string(),
+            supporter_tier: "None".to_string(),
+            signup_date: None,
+            activation_date: None,
+            last_login_date: None,
+            has_manager_license: None,
+            language_id: Some(language),
+            language_name: None,
+            is_current_authenticated_user: Some(current),
+            is_bot: Some(false),
+        };
+        diesel::insert_into(users::table)
+            .values(&vec![
+                user(1, 1, 2, true),
+                user(1, 2, 5, true),
+                user(9, 2, 7, false),
+            ])
+            .execute(&mut conn)
+            .unwrap();
+
+        assert_eq!(get_current_user_language_id(&mut conn).unwrap(), Some(5));
+    }
+
     #[test]
     fn test_flag_emoji() {
         assert_eq!(Some("🇸🇪".to_string()), get_flag_emoji(Some("SE")));
@@ -1579,6 +2038,7 @@ mod tests {
             ActivationDate: "".to_string(),
             LastLoginDate: "".to_string(),
             HasManagerLicense: false,
+            NationalTeams: None,
             Language: Some(Language {
                 LanguageID: 1,
                 LanguageName: "en".to_string(),
@@ -1735,4 +2195,492 @@ mod tests {
         );
         assert_eq!(players_post[0].PlayerID, 300);
     }
+
+    #[test]
+    fn test_get_recent_arrivals() {
+        let mut conn = establish_connection();
+
+        let download = DownloadEntity {
+            id: 1,
+            timestamp: "2024-03-01T00:00:00Z".to_string(),
+            status: "completed".to_string(),
+        };
+        diesel::insert_or_ignore_into(crate::db::schema::downloads::table)
+            .values(&download)
+            .execute(&mut conn)
+            .unwrap();
+
+        let player = |id: u32, arrival: &str| Player {
+            PlayerID: id,
+            FirstName: "Player".to_string(),
+            LastName: id.to_string(),
+            ArrivalDate: Some(arrival.to_string()),
+            ..Default::default()
+        };
+        let squad = vec![
+            player(1, "2023-06-10 08:00:00"),
+            player(2, "2024-01-15 12:00:00"),
+            player(3, "2024-02-20 18:30:00"),
+        ];
+        save_players(&mut conn, &squad, 200, 1).unwrap();
+
+        let cutoff = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let arrivals = get_recent_arrivals(&mut conn, 200, cutoff).unwrap();
+
+        let ids: Vec<u32> = arrivals.iter().map(|p| p.PlayerID).collect();
+        assert_eq!(ids, vec![3, 2]);
+        assert_eq!(
+            arrivals[0].ArrivalDate.as_deref(),
+            Some("2024-02-20 18:30:00")
+        );
+    }
+
+    fn squad_of(ids: std::ops::RangeInclusive<u32>) -> Vec<Player> {
+        ids.map(|id| Player {
+            PlayerID: id,
+            FirstName: "Player".to_string(),
+            LastName: id.to_string(),
+            ..Default::default()
+        })
+        .collect()
+    }
+
+    fn insert_download(conn: &mut SqliteConnection, download_id: i32) {
+        let download = DownloadEntity {
+            id: download_id,
+            timestamp: "2024-03-01T00:00:00Z".to_string(),
+            status: "completed".to_string(),
+        };
+        diesel::insert_or_ignore_into(crate::db::schema::downloads::table)
+            .values(&download)
+            .execute(conn)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_save_players_batches_full_squad() {
+        let mut conn = establish_connection();
+        insert_download(&mut conn, 1);
+
+        let squad = squad_of(1..=25);
+        save_players(&mut conn, &squad, 200, 1).unwrap();
+
+        let mut ids: Vec<u32> = get_players_for_team(&mut conn, 200)
+            .unwrap()
+            .iter()
+            .map(|p| p.PlayerID)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, (1..=25).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_save_players_rolls_back_on_mid_batch_failure() {
+        let mut conn = establish_connection();
+        insert_download(&mut conn, 1);
+        // Reject one player in the second chunk.
+        diesel::sql_query(
+            "CREATE TRIGGER reject_player BEFORE INSERT ON players
+             WHEN NEW.id = 20 BEGIN SELECT RAISE(ABORT, 'rejected'); END",
+        )
+        .execute(&mut conn)
+        .unwrap();
+
+        let result = save_players(&mut conn, &squad_of(1..=25), 200, 1);
+        assert!(result.is_err());
+        assert!(get_players_for_team(&mut conn, 200).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_save_players_keeps_skills_when_resaved_without() {
+        let mut conn = establish_connection();
+
+        let download = DownloadEntity {
+            id: 1,
+            timestamp: "2024-03-01T00:00:00Z".to_string(),
+            status: "completed".to_string(),
+        };
+        diesel::insert_or_ignore_into(crate::db::schema::downloads::table)
+            .values(&download)
+            .execute(&mut conn)
+            .unwrap();
+
+        let detailed = Player {
+            PlayerID: 42,
+            FirstName: "Alex".to_string(),
+            LastName: "Morgan".to_string(),
+            PlayerSkills: Some(crate::chpp::model::PlayerSkills {
+                StaminaSkill: 7,
+                KeeperSkill: 1,
+                PlaymakerSkill: 8,
+                ScorerSkill: 5,
+                PassingSkill: 6,
+                WingerSkill: 4,
+                DefenderSkill: 3,
+                SetPiecesSkill: 2,
+            }),
+            ..Default::default()
+        };
+        let basic = Player {
+            PlayerSkills: None,
+            ..detailed.clone()
+        };
+
+        save_players(&mut conn, &[detailed], 300, 1).unwrap();
+        save_players(&mut conn, &[basic], 300, 1).unwrap();
+
+        let players = get_players_for_team(&mut conn, 300).unwrap();
+        assert_eq!(players.len(), 1);
+        let skills = players[0].PlayerSkills.as_ref().expect("skills retained");
+        assert_eq!(skills.StaminaSkill, 7);
+        assert_eq!(skills.PlaymakerSkill, 8);
+        assert_eq!(skills.SetPiecesSkill, 2);
+    }
+
+    #[test]
+    fn test_female_player_round_trip() {
+        let mut conn = establish_connection();
+
+        let download = DownloadEntity {
+            id: 1,
+            timestamp: "2024-03-01T00:00:00Z".to_string(),
+            status: "completed".to_string(),
+        };
+        diesel::insert_or_ignore_into(crate::db::schema::downloads::table)
+            .values(&download)
+            .execute(&mut conn)
+            .unwrap();
+
+        let player = Player {
+            PlayerID: 42,
+            FirstName: "Alex".to_string(),
+            LastName: "Morgan".to_string(),
+            GenderID: Some(2),
+            ..Default::default()
+        };
+        save_players(&mut conn, &[player], 300, 1).unwrap();
+
+        let players = get_players_for_team(&mut conn, 300).unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].GenderID, Some(2));
+        #[cfg(feature = "gui")]
+        assert_eq!(
+            crate::ui::player_display::gender_emoji(players[0].GenderID),
+            "♀️"
+        );
+    }
+
+    #[test]
+    fn test_transfer_deadline_kept_when_resaved_without() {
+        let mut conn = establish_connection();
+        insert_download(&mut conn, 1);
+
+        let listed = Player {
+            PlayerID: 8,
+            FirstName: "Player".to_string(),
+            LastName: "8".to_string(),
+            TransferListed: true,
+            TransferDetails: Some(crate::chpp::model::PlayerTransferDetails {
+                Deadline: "2024-03-03 18:30:00".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let basic = Player {
+            TransferDetails: None,
+            ..listed.clone()
+        };
+        save_players(&mut conn, &[listed], 300, 1).unwrap();
+        save_players(&mut conn, &[basic], 300, 1).unwrap();
+
+        let players = get_players_for_team(&mut conn, 300).unwrap();
+        assert_eq!(
+            players[0]
+                .TransferDetails
+                .as_ref()
+                .map(|d| d.Deadline.as_str()),
+            Some("2024-03-03 18:30:00")
+        );
+        assert!(players[0].transfer_deadline().is_some());
+    }
+
+    #[test]
+    fn test_native_country_flag_joined_from_countries() {
+        let mut conn = establish_connection();
+
+        let download = DownloadEntity {
+            id: 1,
+            timestamp: "2024-03-01T00:00:00Z".to_string(),
+            status: "completed".to_string(),
+        };
+        diesel::insert_or_ignore_into(crate::db::schema::downloads::table)
+            .values(&download)
+            .execute(&mut conn)
+            .unwrap();
+
+        let country = |country_id: i32, country_name: &str, code: &str| CountryEntity {
+            id: country_id,
+            download_id: 1,
+            name: country_name.to_string(),
+            currency_id: None,
+            country_code: Some(code.to_string()),
+            date_format: None,
+            time_format: None,
+            flag: get_flag_emoji(Some(code)),
+        };
+        diesel::insert_into(countries::table)
+            .values(&vec![
+                country(1, "Sverige", "SE"),
+                country(2, "Ireland", "IE"),
+            ])
+            .execute(&mut conn)
+            .unwrap();
+
+        let player = Player {
+            PlayerID: 7,
+            FirstName: "Liam".to_string(),
+            LastName: "Andersson".to_string(),
+            CountryID: Some(1),
+            NativeCountryID: Some(2),
+            ..Default::default()
+        };
+        save_players(&mut conn, &[player], 400, 1).unwrap();
+
+        let players = get_players_for_team(&mut conn, 400).unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].Flag.as_deref(), Some("🇸🇪"));
+        assert_eq!(players[0].NativeCountryFlag.as_deref(), Some("🇮🇪"));
+    }
+
+    #[test]
+    fn test_flags_kept_after_league_filtered_world_details() {
+        let mut conn = establish_connection();
+        insert_download(&mut conn, 1);
+        insert_download(&mut conn, 2);
+
+        let country = |country_id: i32, dl: i32, code: &str| CountryEntity {
+            id: country_id,
+            download_id: dl,
+            name: code.to_string(),
+            currency_id: None,
+            country_code: Some(code.to_string()),
+            date_format: None,
+            time_format: None,
+            flag: get_flag_emoji(Some(code)),
+        };
+        // A full fetch first, then one filtered to the user's league.
+        diesel::insert_into(countries::table)
+            .values(&vec![
+                country(1, 1, "SE"),
+                country(2, 1, "IE"),
+                country(1, 2, "SE"),
+            ])
+            .execute(&mut conn)
+            .unwrap();
+
+        let player = Player {
+            PlayerID: 9,
+            FirstName: "Sean".to_string(),
+            LastName: "Murphy".to_string(),
+            CountryID: Some(2),
+            NativeCountryID: Some(1),
+            ..Default::default()
+        };
+        save_players(&mut conn, &[player], 600, 2).unwrap();
+
+        let players = get_players_for_team(&mut conn, 600).unwrap();
+        assert_eq!(players[0].Flag.as_deref(), Some("🇮🇪"));
+        assert_eq!(players[0].NativeCountryFlag.as_deref(), Some("🇸🇪"));
+
+        assert!(!needs_full_world_details(&mut conn, 2, 10).unwrap());
+        assert!(needs_full_world_details(&mut conn, 12, 10).unwrap());
+    }
+
+    #[test]
+    fn test_needs_full_world_details_without_full_list() {
+        let mut conn = establish_connection();
+        assert!(needs_full_world_details(&mut conn, 1, 10).unwrap());
+
+        insert_download(&mut conn, 1);
+        diesel::insert_into(countries::table)
+            .values(&CountryEntity {
+                id: 1,
+                download_id: 1,
+                name: "Sverige".to_string(),
+                currency_id: None,
+                country_code: Some("SE".to_string()),
+                date_format: None,
+                time_format: None,
+                flag: get_flag_emoji(Some("SE")),
+            })
+            .execute(&mut conn)
+            .unwrap();
+        assert!(needs_full_world_details(&mut conn, 1, 10).unwrap());
+    }
+
+    #[test]
+    fn test_players_by_country() {
+        let mut conn = establish_connection();
+        insert_download(&mut conn, 1);
+
+        let country = |country_id: i32, country_name: &str, code: &str| CountryEntity {
+            id: country_id,
+            download_id: 1,
+            name: country_name.to_string(),
+            currency_id: None,
+            country_code: Some(code.to_string()),
+            date_format: None,
+            time_format: None,
+            flag: get_flag_emoji(Some(code)),
+        };
+        diesel::insert_into(countries::table)
+            .values(&vec![
+                country(1, "Sverige", "SE"),
+                country(2, "Ireland", "IE"),
+            ])
+            .execute(&mut conn)
+            .unwrap();
+
+        let squad: Vec<Player> = [(1, 1), (2, 1), (3, 1), (4, 2), (5, 2), (6, 99)]
+            .into_iter()
+            .map(|(id, country_id)| Player {
+                PlayerID: id,
+                FirstName: "Player".to_string(),
+                LastName: id.to_string(),
+                CountryID: Some(country_id),
+                ..Default::default()
+            })
+            .collect();
+        save_players(&mut conn, &squad, 500, 1).unwrap();
+
+        let breakdown: Vec<(u32, String, Option<String>, usize)> =
+            players_by_country(&mut conn, 500)
+                .unwrap()
+                .into_iter()
+                .map(|c| (c.country_id, c.name, c.flag, c.count))
+                .collect();
+        assert_eq!(
+            breakdown,
+            vec![
+                (1, "Sverige".to_string(), get_flag_emoji(Some("SE")), 3),
+                (2, "Ireland".to_string(), get_flag_emoji(Some("IE")), 2),
+                (99, String::new(), None, 1),
+            ]
+        );
+
+        assert!(players_by_country(&mut conn, 501).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_save_world_languages() {
+        let mut conn = establish_connection();
+
+        let download = DownloadEntity {
+            id: 1,
+            timestamp: "2024-03-01T00:00:00Z".to_string(),
+            status: "completed".to_string(),
+        };
+        diesel::insert_or_ignore_into(crate::db::schema::downloads::table)
+            .values(&download)
+            .execute(&mut conn)
+            .unwrap();
+
+        let language = |id: u32, language_name: &str| Language {
+            LanguageID: id,
+            LanguageName: language_name.to_string(),
+        };
+        let world_languages = WorldLanguages {
+            LanguageList: crate::chpp::model::LanguageList {
+                Languages: vec![
+                    language(1, "Svenska"),
+                    language(2, "English (UK)"),
+                    language(5, "Français"),
+                ],
+            },
+        };
+        save_world_languages(&mut conn, &world_languages, 1).unwrap();
+        // Saving the same download again is a no-op
+        save_world_languages(&mut conn, &world_languages, 1).unwrap();
+
+        let stored: Vec<(i32, String)> = languages::table
+            .filter(languages::download_id.eq(1))
+            .order(languages::id.asc())
+            .select((languages::id, languages::name))
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(
+            stored,
+            vec![
+                (1, "Svenska".to_string()),
+                (2, "English (UK)".to_string()),
+                (5, "Français".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_latest_league_prefers_world_details() {
+        let mut conn = establish_connection();
+
+        let world = League {
+            LeagueID: 21,
+            LeagueName: "Ireland".to_string(),
+            Season: Some(76),
+            SeasonOffset: Some(-17),
+            MatchRound: Some(7),
+            ..Default::default()
+        };
+        save_league(&mut conn, &world, Some(16), 1).unwrap();
+        // Team details only carry the league's ID and name.
+        let from_team = League {
+            LeagueID: 21,
+            LeagueName: "Ireland".to_string(),
+            ..Default::default()
+        };
+        save_league(&mut conn, &from_team, Some(16), 2).unwrap();
+
+        let league = get_latest_league(&mut conn, 21).unwrap().unwrap();
+        assert_eq!(league.Season, Some(76));
+        assert_eq!(league.SeasonOffset, Some(-17));
+        assert_eq!(league.MatchRound, Some(7));
+
+        assert!(get_latest_league(&mut conn, 99).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_current_season_week_from_synced_league() {
+        let mut conn = establish_connection();
+        insert_download(&mut conn, 1);
+        insert_download(&mut conn, 2);
+
+        let world = League {
+            LeagueID: 21,
+            LeagueName: "Ireland".to_string(),
+            Season: Some(76),
+            SeasonOffset: Some(-17),
+            MatchRound: Some(7),
+            ..Default::default()
+        };
+        save_league(&mut conn, &world, Some(16), 1).unwrap();
+        let from_team = League {
+            LeagueID: 21,
+            LeagueName: "Ireland".to_string(),
+            ..Default::default()
+        };
+        save_league(&mut conn, &from_team, Some(16), 2).unwrap();
+
+        let league = get_latest_league(&mut conn, 21).unwrap().unwrap();
+        // Downloaded on Friday 1 March 2024.
+        let synced_at = get_league_synced_at(&mut conn, 21).unwrap().unwrap();
+        assert_eq!(synced_at.to_rfc3339(), "2024-03-01T00:00:00+00:00");
+
+        let one_week_on = synced_at + chrono::Duration::days(7);
+        assert_eq!(
+            crate::chpp::model::current_season_week(&league, synced_at, one_week_on),
+            Some((76, 8))
+        );
+        assert!(get_league_synced_at(&mut conn, 99).unwrap().is_none());
+    }
 }
@@ -0,0 +1,196 @@
+/* tournaments.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Tournament, TournamentCreator};
+use crate::db::schema::tournaments;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = tournaments)]
+struct TournamentEntity {
+    tournament_id: i32,
+    download_id: i32,
+    name: String,
+    tournament_type: i32,
+    season: i32,
+    logo_url: Option<String>,
+    trophy_type: Option<i32>,
+    number_of_teams: i32,
+    number_of_groups: i32,
+    last_match_round: Option<i32>,
+    first_match_round_date: Option<String>,
+    next_match_round_date: Option<String>,
+    is_matches_ongoing: bool,
+    creator_user_id: Option<i32>,
+    creator_login_name: Option<String>,
+}
+
+impl TournamentEntity {
+    fn new(t: &Tournament, download_id: i32) -> Self {
+        TournamentEntity {
+            tournament_id: t.TournamentId as i32,
+            download_id,
+            name: t.Name.clone(),
+            tournament_type: t.TournamentType as i32,
+            season: t.Season as i32,
+            logo_url: t.LogoUrl.clone(),
+            trophy_type: t.TrophyType.map(|v| v as i32),
+            number_of_teams: t.NumberOfTeams as i32,
+            number_of_groups: t.NumberOfGroups as i32,
+            last_match_round: t.LastMatchRound.map(|v| v as i32),
+            first_match_round_date: t.FirstMatchRoundDate.clone(),
+            next_match_round_date: t.NextMatchRoundDate.clone(),
+            is_matches_ongoing: t.IsMatchesOngoing,
+            creator_user_id: t.Creator.as_ref().map(|c| c.UserId as i32),
+            creator_login_name: t.Creator.as_ref().map(|c| c.Loginname.clone()),
+        }
+    }
+}
+
+impl From<TournamentEntity> for Tournament {
+    fn from(entity: TournamentEntity) -> Self {
+        let creator = match (entity.creator_user_id, entity.creator_login_name) {
+            (Some(id), Some(login)) => Some(TournamentCreator {
+                UserId: id as u32,
+                Loginname: login,
+            }),
+            _ => None,
+        };
+        Tournament {
+            TournamentId: entity.tournament_id as u32,
+            Name: entity.name,
+            TournamentType: entity.tournament_type as u32,
+            Season: entity.season as u32,
+            LogoUrl: entity.logo_url,
+            TrophyType: entity.trophy_type.map(|v| v as u32),
+            NumberOfTeams: entity.number_of_teams as u32,
+            NumberOfGroups: entity.number_of_groups as u32,
+            LastMatchRound: entity.last_match_round.map(|v| v as u32),
+            FirstMatchRoundDate: entity.first_match_round_date,
+            NextMatchRoundDate: entity.next_match_round_date,
+            IsMatchesOngoing: entity.is_matches_ongoing,
+            Creator: creator,
+        }
+    }
+}
+
+/// Store tournaments from either `tournamentlist` or `tournamentdetails`.
+/// The latest fetch of a tournament replaces the stored one.
+pub fn save_tournaments(
+    conn: &mut SqliteConnection,
+    items: &[Tournament],
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<TournamentEntity> = items
+        .iter()
+        .map(|t| TournamentEntity::new(t, download_id))
+        .collect();
+
+    diesel::replace_into(tournaments::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to save tournaments: {}", e)).or_locked(&e))?;
+    Ok(())
+}
+
+/// All stored tournaments, most recent season first, then by name.
+pub fn get_tournaments(conn: &mut SqliteConnection) -> Result<Vec<Tournament>, NutmegError> {
+    let rows = tournaments::table
+        .order((tournaments::season.desc(), tournaments::name.asc()))
+        .load::<TournamentEntity>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load tournaments: {}", e)).or_locked(&e))?;
+
+    Ok(rows.into_iter().map(Tournament::from).collect())
+}
+
+pub fn get_tournament(
+    conn: &mut SqliteConnection,
+    tournament_id: u32,
+) -> Result<Option<Tournament>, NutmegError> {
+    let row = tournaments::table
+        .find(tournament_id as i32)
+        .first::<TournamentEntity>(conn)
+        .optional()
+        .map_err(|e| NutmegError::Db(format!("Failed to load tournament: {}", e)).or_locked(&e))?;
+
+    Ok(row.map(Tournament::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn tournament(id: u32, name: &str, season: u32, round: Option<u32>) -> Tournament {
+        Tournament {
+            TournamentId: id,
+            Name: name.to_string(),
+            TournamentType: 3,
+            Season: season,
+            NumberOfTeams: 8,
+            NumberOfGroups: 1,
+            LastMatchRound: round,
+            Creator: Some(TournamentCreator {
+                UserId: 42,
+                Loginname: "organiser".to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_tournaments_round_trip_and_replace() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-03-09T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-10T00:00:00Z", "completed").unwrap();
+
+        save_tournaments(
+            &mut conn,
+            &[
+                tournament(1, "Spring Cup", 91, None),
+                tournament(2, "Autumn League", 90, Some(14)),
+                tournament(3, "Friday League", 91, Some(6)),
+            ],
+            dl1,
+        )
+        .unwrap();
+        save_tournaments(
+            &mut conn,
+            &[tournament(3, "Friday League", 91, Some(7))],
+            dl2,
+        )
+        .unwrap();
+
+        let all = get_tournaments(&mut conn).unwrap();
+        let names: Vec<&str> = all.iter().map(|t| t.Name.as_str()).collect();
+        assert_eq!(names, vec!["Friday League", "Spring Cup", "Autumn League"]);
+
+        let friday = get_tournament(&mut conn, 3).unwrap().expect("tournament");
+        assert_eq!(friday.LastMatchRound, Some(7));
+        assert_eq!(friday.Creator.unwrap().Loginname, "organiser");
+        assert!(get_tournament(&mut conn, 99).unwrap().is_none());
+    }
+}
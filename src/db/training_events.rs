@@ -0,0 +1,162 @@
+/* training_events.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::TrainingEvent;
+use crate::db::schema::training_events;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = training_events)]
+struct TrainingEventEntity {
+    player_id: i32,
+    event_date: String,
+    skill_id: i32,
+    download_id: i32,
+    old_level: i32,
+    new_level: i32,
+    season: Option<i32>,
+    match_round: Option<i32>,
+}
+
+impl From<TrainingEventEntity> for TrainingEvent {
+    fn from(entity: TrainingEventEntity) -> Self {
+        TrainingEvent {
+            EventDate: entity.event_date,
+            SkillID: entity.skill_id as u32,
+            OldLevel: entity.old_level as u32,
+            NewLevel: entity.new_level as u32,
+            Season: entity.season.map(|v| v as u32),
+            MatchRound: entity.match_round.map(|v| v as u32),
+        }
+    }
+}
+
+/// Store a player's skill-ups. Events already stored by an earlier download
+/// are left untouched.
+pub fn save_training_events(
+    conn: &mut SqliteConnection,
+    player_id: u32,
+    events: &[TrainingEvent],
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<TrainingEventEntity> = events
+        .iter()
+        .map(|e| TrainingEventEntity {
+            player_id: player_id as i32,
+            event_date: e.EventDate.clone(),
+            skill_id: e.SkillID as i32,
+            download_id,
+            old_level: e.OldLevel as i32,
+            new_level: e.NewLevel as i32,
+            season: e.Season.map(|v| v as i32),
+            match_round: e.MatchRound.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(training_events::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save training events: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// A player's full skill history, oldest event first.
+pub fn get_training_events(
+    conn: &mut SqliteConnection,
+    pid: u32,
+) -> Result<Vec<TrainingEvent>, NutmegError> {
+    use crate::db::schema::training_events::dsl::*;
+
+    let rows = training_events
+        .filter(player_id.eq(pid as i32))
+        .order((event_date.asc(), skill_id.asc()))
+        .load::<TrainingEventEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load training events: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(rows.into_iter().map(TrainingEvent::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn event(date: &str, skill: u32, old: u32) -> TrainingEvent {
+        TrainingEvent {
+            EventDate: date.to_string(),
+            SkillID: skill,
+            OldLevel: old,
+            NewLevel: old + 1,
+            Season: Some(92),
+            MatchRound: None,
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_training_events_round_trip() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+
+        save_training_events(
+            &mut conn,
+            42,
+            &[
+                event("2026-02-12 06:00:00", 8, 5),
+                event("2026-01-08 06:00:00", 4, 6),
+            ],
+            dl1,
+        )
+        .unwrap();
+        // A later download repeats the older events and adds a new one.
+        save_training_events(
+            &mut conn,
+            42,
+            &[
+                event("2026-01-08 06:00:00", 4, 6),
+                event("2026-02-12 06:00:00", 4, 7),
+                event("2026-02-12 06:00:00", 8, 5),
+            ],
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_training_events(&mut conn, 42).unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded[0].EventDate, "2026-01-08 06:00:00");
+        assert_eq!((loaded[0].OldLevel, loaded[0].NewLevel), (6, 7));
+        assert_eq!(loaded[1].SkillID, 4);
+        assert_eq!(loaded[1].NewLevel, 8);
+        assert_eq!(loaded[2].SkillID, 8);
+        assert_eq!(loaded[2].Season, Some(92));
+
+        assert!(get_training_events(&mut conn, 43).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,451 @@
+/* transfers.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{
+    BidItem, CurrentBids, PlayerTransfer, TeamTransferPlayer, Transfer, TransferBuyer,
+    TransferSeller, TransferType,
+};
+use crate::db::schema::{current_bids, player_transfers, team_transfers};
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = player_transfers)]
+struct PlayerTransferEntity {
+    transfer_id: i32,
+    player_id: i32,
+    download_id: i32,
+    deadline: String,
+    buyer_team_id: i32,
+    buyer_team_name: String,
+    seller_team_id: i32,
+    seller_team_name: String,
+    price: i32,
+    tsi: Option<i32>,
+}
+
+impl From<PlayerTransferEntity> for PlayerTransfer {
+    fn from(entity: PlayerTransferEntity) -> Self {
+        PlayerTransfer {
+            TransferID: entity.transfer_id as u32,
+            Deadline: entity.deadline,
+            Buyer: TransferBuyer {
+                BuyerTeamID: entity.buyer_team_id as u32,
+                BuyerTeamName: entity.buyer_team_name,
+            },
+            Seller: TransferSeller {
+                SellerTeamID: entity.seller_team_id as u32,
+                SellerTeamName: entity.seller_team_name,
+            },
+            Price: entity.price as u32,
+            TSI: entity.tsi.map(|v| v as u32),
+        }
+    }
+}
+
+pub fn save_player_transfers(
+    conn: &mut SqliteConnection,
+    player_id: u32,
+    transfers: &[PlayerTransfer],
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<PlayerTransferEntity> = transfers
+        .iter()
+        .map(|t| PlayerTransferEntity {
+            transfer_id: t.TransferID as i32,
+            player_id: player_id as i32,
+            download_id,
+            deadline: t.Deadline.clone(),
+            buyer_team_id: t.Buyer.BuyerTeamID as i32,
+            buyer_team_name: t.Buyer.BuyerTeamName.clone(),
+            seller_team_id: t.Seller.SellerTeamID as i32,
+            seller_team_name: t.Seller.SellerTeamName.clone(),
+            price: t.Price as i32,
+            tsi: t.TSI.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(player_transfers::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save player transfers: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Load a player's transfer history, oldest transfer first. Only the latest
+/// download of each transfer is kept.
+pub fn get_player_transfers(
+    conn: &mut SqliteConnection,
+    player_id: u32,
+) -> Result<Vec<PlayerTransfer>, NutmegError> {
+    let rows: Vec<PlayerTransferEntity> = player_transfers::table
+        .filter(player_transfers::player_id.eq(player_id as i32))
+        .order((
+            player_transfers::deadline.asc(),
+            player_transfers::transfer_id.asc(),
+            player_transfers::download_id.desc(),
+        ))
+        .load::<PlayerTransferEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load player transfers: {}", e)).or_locked(&e)
+        })?;
+
+    let mut seen = std::collections::HashSet::new();
+    Ok(rows
+        .into_iter()
+        .filter(|r| seen.insert(r.transfer_id))
+        .map(PlayerTransfer::from)
+        .collect())
+}
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = team_transfers)]
+struct TeamTransferEntity {
+    transfer_id: i32,
+    team_id: i32,
+    download_id: i32,
+    deadline: String,
+    transfer_type: String,
+    player_id: i32,
+    player_name: String,
+    buyer_team_id: i32,
+    buyer_team_name: String,
+    seller_team_id: i32,
+    seller_team_name: String,
+    price: i32,
+    tsi: Option<i32>,
+}
+
+impl From<TeamTransferEntity> for Transfer {
+    fn from(entity: TeamTransferEntity) -> Self {
+        Transfer {
+            TransferID: entity.transfer_id as u32,
+            Deadline: entity.deadline,
+            TransferType: TransferType::from_code(&entity.transfer_type).unwrap_or_default(),
+            Player: TeamTransferPlayer {
+                PlayerID: entity.player_id as u32,
+                PlayerName: entity.player_name,
+            },
+            Buyer: TransferBuyer {
+                BuyerTeamID: entity.buyer_team_id as u32,
+                BuyerTeamName: entity.buyer_team_name,
+            },
+            Seller: TransferSeller {
+                SellerTeamID: entity.seller_team_id as u32,
+                SellerTeamName: entity.seller_team_name,
+            },
+            Price: entity.price as u32,
+            TSI: entity.tsi.map(|v| v as u32),
+        }
+    }
+}
+
+/// Save one page of a team's transfer history.
+pub fn save_team_transfers(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+    transfers: &[Transfer],
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<TeamTransferEntity> = transfers
+        .iter()
+        .map(|t| TeamTransferEntity {
+            transfer_id: t.TransferID as i32,
+            team_id: team_id as i32,
+            download_id,
+            deadline: t.Deadline.clone(),
+            transfer_type: t.TransferType.code().to_string(),
+            player_id: t.Player.PlayerID as i32,
+            player_name: t.Player.PlayerName.clone(),
+            buyer_team_id: t.Buyer.BuyerTeamID as i32,
+            buyer_team_name: t.Buyer.BuyerTeamName.clone(),
+            seller_team_id: t.Seller.SellerTeamID as i32,
+            seller_team_name: t.Seller.SellerTeamName.clone(),
+            price: t.Price as i32,
+            tsi: t.TSI.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::insert_or_ignore_into(team_transfers::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save team transfers: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Load a team's transfer history, most recent transfer first. Only the
+/// latest download of each transfer is kept.
+pub fn get_team_transfers(
+    conn: &mut SqliteConnection,
+    team_id: u32,
+) -> Result<Vec<Transfer>, NutmegError> {
+    let rows: Vec<TeamTransferEntity> = team_transfers::table
+        .filter(team_transfers::team_id.eq(team_id as i32))
+        .order((
+            team_transfers::deadline.desc(),
+            team_transfers::transfer_id.desc(),
+            team_transfers::download_id.desc(),
+        ))
+        .load::<TeamTransferEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load team transfers: {}", e)).or_locked(&e)
+        })?;
+
+    let mut seen = std::collections::HashSet::new();
+    Ok(rows
+        .into_iter()
+        .filter(|r| seen.insert(r.transfer_id))
+        .map(Transfer::from)
+        .collect())
+}
+
+/// Side of a transfer a current bid belongs to.
+pub const BID_SIDE_BUYING: &str = "buying";
+pub const BID_SIDE_SELLING: &str = "selling";
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = current_bids)]
+pub struct CurrentBidEntry {
+    pub player_id: i32,
+    pub download_id: i32,
+    /// `BID_SIDE_BUYING` or `BID_SIDE_SELLING`.
+    pub side: String,
+    pub transfer_id: Option<i32>,
+    pub player_name: String,
+    pub highest_bid: Option<i32>,
+    pub deadline: String,
+    pub bidder_team_id: Option<i32>,
+    pub bidder_team_name: Option<String>,
+}
+
+impl CurrentBidEntry {
+    fn from_item(item: &BidItem, side: &str, download_id: i32) -> Self {
+        CurrentBidEntry {
+            player_id: item.PlayerID as i32,
+            download_id,
+            side: side.to_string(),
+            transfer_id: item.TransferID.map(|v| v as i32),
+            player_name: item.PlayerName.clone(),
+            highest_bid: item.HighestBid.map(|v| v as i32),
+            deadline: item.Deadline.clone(),
+            bidder_team_id: item.BidderTeam.as_ref().map(|t| t.TeamID as i32),
+            bidder_team_name: item.BidderTeam.as_ref().map(|t| t.TeamName.clone()),
+        }
+    }
+}
+
+pub fn save_current_bids(
+    conn: &mut SqliteConnection,
+    bids: &CurrentBids,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let buying = bids
+        .BiddingPlayers
+        .Items
+        .iter()
+        .map(|item| CurrentBidEntry::from_item(item, BID_SIDE_BUYING, download_id));
+    let selling = bids
+        .SellingPlayers
+        .Items
+        .iter()
+        .map(|item| CurrentBidEntry::from_item(item, BID_SIDE_SELLING, download_id));
+    let entries: Vec<CurrentBidEntry> = buying.chain(selling).collect();
+
+    diesel::insert_or_ignore_into(current_bids::table)
+        .values(&entries)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save current bids: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Load the bids from the latest download of current bids, soonest deadline
+/// first.
+pub fn get_current_bids(conn: &mut SqliteConnection) -> Result<Vec<CurrentBidEntry>, NutmegError> {
+    let latest: Option<i32> = current_bids::table
+        .select(diesel::dsl::max(current_bids::download_id))
+        .first::<Option<i32>>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to get current bids download: {}", e)).or_locked(&e)
+        })?;
+
+    let Some(latest) = latest else {
+        return Ok(Vec::new());
+    };
+
+    current_bids::table
+        .filter(current_bids::download_id.eq(latest))
+        .order(current_bids::deadline.asc())
+        .load::<CurrentBidEntry>(conn)
+        .map_err(|e| NutmegError::Db(format!("Failed to load current bids: {}", e)).or_locked(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::BidItemList;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn transfer(id: u32, deadline: &str, price: u32) -> PlayerTransfer {
+        PlayerTransfer {
+            TransferID: id,
+            Deadline: deadline.to_string(),
+            Buyer: TransferBuyer {
+                BuyerTeamID: 2,
+                BuyerTeamName: "Buyer".to_string(),
+            },
+            Seller: TransferSeller {
+                SellerTeamID: 1,
+                SellerTeamName: "Seller".to_string(),
+            },
+            Price: price,
+            TSI: Some(1000),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_player_transfers_ordered_by_date() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+
+        let transfers = vec![
+            transfer(20, "2024-05-01 20:00:00", 500_000),
+            transfer(10, "2022-09-12 18:30:00", 75_000),
+        ];
+        save_player_transfers(&mut conn, 42, &transfers, dl1).unwrap();
+        // Re-downloading the same history must not duplicate results
+        save_player_transfers(&mut conn, 42, &transfers, dl2).unwrap();
+        save_player_transfers(
+            &mut conn,
+            43,
+            &[transfer(30, "2023-01-01 12:00:00", 1)],
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_player_transfers(&mut conn, 42).unwrap();
+        let ids: Vec<u32> = loaded.iter().map(|t| t.TransferID).collect();
+        assert_eq!(ids, vec![10, 20]);
+        assert_eq!(loaded[1].Price, 500_000);
+        assert_eq!(loaded[1].Buyer.BuyerTeamName, "Buyer");
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_team_transfers_latest_first() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+
+        let team_transfer = |id: u32, deadline: &str, kind: TransferType| Transfer {
+            TransferID: id,
+            Deadline: deadline.to_string(),
+            TransferType: kind,
+            Player: TeamTransferPlayer {
+                PlayerID: id * 10,
+                PlayerName: format!("Player {}", id),
+            },
+            Price: 1000,
+            ..Default::default()
+        };
+
+        let page = vec![
+            team_transfer(2, "2025-06-01 20:00:00", TransferType::Sell),
+            team_transfer(1, "2025-01-01 20:00:00", TransferType::Buy),
+        ];
+        save_team_transfers(&mut conn, 7, &page, dl1).unwrap();
+        save_team_transfers(&mut conn, 7, &page, dl2).unwrap();
+        save_team_transfers(
+            &mut conn,
+            8,
+            &[team_transfer(3, "2025-03-01 20:00:00", TransferType::Buy)],
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_team_transfers(&mut conn, 7).unwrap();
+        let ids: Vec<u32> = loaded.iter().map(|t| t.TransferID).collect();
+        assert_eq!(ids, vec![2, 1]);
+        assert_eq!(loaded[0].TransferType, TransferType::Sell);
+        assert_eq!(loaded[1].TransferType, TransferType::Buy);
+        assert_eq!(loaded[1].Player.PlayerName, "Player 1");
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_current_bids_returns_latest_download() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-01-02T00:00:00Z", "completed").unwrap();
+
+        let item = |id: u32, bid: Option<u32>, deadline: &str| BidItem {
+            TransferID: None,
+            PlayerID: id,
+            PlayerName: format!("Player {}", id),
+            HighestBid: bid,
+            Deadline: deadline.to_string(),
+            BidderTeam: None,
+        };
+
+        let old = CurrentBids {
+            TeamID: Some(1),
+            BiddingPlayers: BidItemList {
+                Items: vec![item(1, Some(1000), "2026-01-01 20:00:00")],
+            },
+            SellingPlayers: BidItemList::default(),
+        };
+        save_current_bids(&mut conn, &old, dl1).unwrap();
+
+        let new = CurrentBids {
+            TeamID: Some(1),
+            BiddingPlayers: BidItemList {
+                Items: vec![item(2, Some(5000), "2026-01-03 20:00:00")],
+            },
+            SellingPlayers: BidItemList {
+                Items: vec![item(3, None, "2026-01-02 18:00:00")],
+            },
+        };
+        save_current_bids(&mut conn, &new, dl2).unwrap();
+
+        let bids = get_current_bids(&mut conn).unwrap();
+        assert_eq!(bids.len(), 2);
+        assert_eq!(bids[0].player_id, 3);
+        assert_eq!(bids[0].side, BID_SIDE_SELLING);
+        assert_eq!(bids[0].highest_bid, None);
+        assert_eq!(bids[1].player_id, 2);
+        assert_eq!(bids[1].side, BID_SIDE_BUYING);
+        assert_eq!(bids[1].highest_bid, Some(5000));
+    }
+}
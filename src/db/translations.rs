@@ -0,0 +1,230 @@
+/* translations.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{TranslationItem, Translations};
+use crate::db::schema::translations;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+/// Kinds of denominations cached from the translations endpoint.
+pub const ITEM_SKILL_LEVEL: &str = "skill_level";
+pub const ITEM_SPECIALTY: &str = "specialty";
+pub const ITEM_POSITION: &str = "position";
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = translations)]
+struct TranslationEntity {
+    language_id: i32,
+    item_type: String,
+    item_id: i32,
+    download_id: i32,
+    text: String,
+}
+
+/// Cache the denominations of one language, replacing earlier fetches.
+pub fn save_translations(
+    conn: &mut SqliteConnection,
+    data: &Translations,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let texts = &data.Texts;
+    let language_id = texts.LanguageID as i32;
+    let entity = |item_type: &str, item: &TranslationItem| TranslationEntity {
+        language_id,
+        item_type: item_type.to_string(),
+        item_id: item.Value as i32,
+        download_id,
+        text: item.Text.clone(),
+    };
+    let entities: Vec<TranslationEntity> = texts
+        .SkillLevels
+        .Levels
+        .iter()
+        .map(|i| entity(ITEM_SKILL_LEVEL, i))
+        .chain(
+            texts
+                .PlayerSpecialties
+                .Items
+                .iter()
+                .map(|i| entity(ITEM_SPECIALTY, i)),
+        )
+        .chain(
+            texts
+                .MatchPositions
+                .Items
+                .iter()
+                .map(|i| entity(ITEM_POSITION, i)),
+        )
+        .collect();
+
+    diesel::replace_into(translations::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save translations: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+pub fn get_translation(
+    conn: &mut SqliteConnection,
+    language_id: u32,
+    item_type: &str,
+    item_id: u32,
+) -> Result<Option<String>, NutmegError> {
+    translations::table
+        .filter(translations::language_id.eq(language_id as i32))
+        .filter(translations::item_type.eq(item_type))
+        .filter(translations::item_id.eq(item_id as i32))
+        .select(translations::text)
+        .first::<String>(conn)
+        .optional()
+        .map_err(|e| NutmegError::Db(format!("Failed to get translation: {}", e)).or_locked(&e))
+}
+
+/// Whether denominations for `language_id` have already been cached.
+pub fn has_translations(
+    conn: &mut SqliteConnection,
+    language_id: u32,
+) -> Result<bool, NutmegError> {
+    let count: i64 = translations::table
+        .filter(translations::language_id.eq(language_id as i32))
+        .count()
+        .get_result(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to count translations: {}", e)).or_locked(&e)
+        })?;
+    Ok(count > 0)
+}
+
+/// Move the cached denominations of `language_id` onto `download_id`, so a
+/// sync that reuses them keeps them from being pruned with the download that
+/// first fetched them.
+pub fn touch_translations(
+    conn: &mut SqliteConnection,
+    language_id: u32,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    diesel::update(translations::table.filter(translations::language_id.eq(language_id as i32)))
+        .set(translations::download_id.eq(download_id))
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to update translations: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::{SkillLevelList, TranslationItemList, TranslationTexts};
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn item(value: u32, text: &str) -> TranslationItem {
+        TranslationItem {
+            Value: value,
+            Text: text.to_string(),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_translations_replaces_cached_text() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+
+        assert!(!has_translations(&mut conn, 5).unwrap());
+
+        let mut data = Translations {
+            Texts: TranslationTexts {
+                LanguageID: 5,
+                SkillLevels: SkillLevelList {
+                    Levels: vec![item(7, "bon"), item(8, "excellent")],
+                },
+                PlayerSpecialties: TranslationItemList {
+                    Items: vec![item(8, "Soutien")],
+                },
+                ..Default::default()
+            },
+        };
+        save_translations(&mut conn, &data, dl1).unwrap();
+        data.Texts.SkillLevels.Levels[0].Text = "honorable".to_string();
+        save_translations(&mut conn, &data, dl2).unwrap();
+
+        assert!(has_translations(&mut conn, 5).unwrap());
+        assert!(!has_translations(&mut conn, 2).unwrap());
+        assert_eq!(
+            get_translation(&mut conn, 5, ITEM_SKILL_LEVEL, 7).unwrap(),
+            Some("honorable".to_string())
+        );
+        // Same code, different kind of denomination
+        assert_eq!(
+            get_translation(&mut conn, 5, ITEM_SPECIALTY, 8).unwrap(),
+            Some("Soutien".to_string())
+        );
+        assert_eq!(
+            get_translation(&mut conn, 5, ITEM_POSITION, 8).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_touched_translations_survive_pruning() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let fetched = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+
+        let data = Translations {
+            Texts: TranslationTexts {
+                LanguageID: 5,
+                SkillLevels: SkillLevelList {
+                    Levels: vec![item(7, "bon")],
+                },
+                ..Default::default()
+            },
+        };
+        save_translations(&mut conn, &data, fetched).unwrap();
+
+        // Later syncs reuse the cache instead of fetching it again.
+        for day in 2..=4 {
+            let download = create_download(
+                &mut conn,
+                &format!("2026-01-0{}T00:00:00Z", day),
+                "completed",
+            )
+            .unwrap();
+            touch_translations(&mut conn, 5, download).unwrap();
+        }
+
+        crate::db::download_entries::prune_old_downloads(&mut conn, 2).unwrap();
+        assert!(has_translations(&mut conn, 5).unwrap());
+        assert_eq!(
+            get_translation(&mut conn, 5, ITEM_SKILL_LEVEL, 7).unwrap(),
+            Some("bon".to_string())
+        );
+    }
+}
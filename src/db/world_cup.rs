@@ -0,0 +1,198 @@
+/* world_cup.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{FixtureAwayTeam, FixtureHomeTeam, WorldCup, WorldCupMatch};
+use crate::db::schema::world_cup_matches;
+use crate::error::NutmegError;
+use diesel::prelude::*;
+
+#[derive(Insertable, Queryable, Debug, Clone)]
+#[diesel(table_name = world_cup_matches)]
+struct WorldCupMatchEntity {
+    cup_id: i32,
+    season: i32,
+    match_id: i32,
+    download_id: i32,
+    match_round: i32,
+    cup_series_unit_id: i32,
+    home_team_id: i32,
+    home_team_name: String,
+    away_team_id: i32,
+    away_team_name: String,
+    match_date: String,
+    home_goals: Option<i32>,
+    away_goals: Option<i32>,
+}
+
+impl From<WorldCupMatchEntity> for WorldCupMatch {
+    fn from(entity: WorldCupMatchEntity) -> Self {
+        WorldCupMatch {
+            MatchID: entity.match_id as u32,
+            MatchRound: entity.match_round as u32,
+            CupSeriesUnitID: entity.cup_series_unit_id as u32,
+            HomeTeam: FixtureHomeTeam {
+                HomeTeamID: entity.home_team_id as u32,
+                HomeTeamName: entity.home_team_name,
+            },
+            AwayTeam: FixtureAwayTeam {
+                AwayTeamID: entity.away_team_id as u32,
+                AwayTeamName: entity.away_team_name,
+            },
+            MatchDate: entity.match_date,
+            HomeGoals: entity.home_goals.map(|v| v as u32),
+            AwayGoals: entity.away_goals.map(|v| v as u32),
+        }
+    }
+}
+
+/// Store the matches of a World Cup season. A later fetch replaces the
+/// earlier one and fills in results.
+pub fn save_world_cup_matches(
+    conn: &mut SqliteConnection,
+    wc: &WorldCup,
+    download_id: i32,
+) -> Result<(), NutmegError> {
+    let entities: Vec<WorldCupMatchEntity> = wc
+        .Matches
+        .Matches
+        .iter()
+        .map(|m| WorldCupMatchEntity {
+            cup_id: wc.CupID as i32,
+            season: wc.Season as i32,
+            match_id: m.MatchID as i32,
+            download_id,
+            match_round: m.MatchRound as i32,
+            cup_series_unit_id: m.CupSeriesUnitID as i32,
+            home_team_id: m.HomeTeam.HomeTeamID as i32,
+            home_team_name: m.HomeTeam.HomeTeamName.clone(),
+            away_team_id: m.AwayTeam.AwayTeamID as i32,
+            away_team_name: m.AwayTeam.AwayTeamName.clone(),
+            match_date: m.MatchDate.clone(),
+            home_goals: m.HomeGoals.map(|v| v as i32),
+            away_goals: m.AwayGoals.map(|v| v as i32),
+        })
+        .collect();
+
+    diesel::replace_into(world_cup_matches::table)
+        .values(&entities)
+        .execute(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to save world cup matches: {}", e)).or_locked(&e)
+        })?;
+    Ok(())
+}
+
+/// Matches of a World Cup season, by round, then group, then match.
+pub fn get_world_cup_matches(
+    conn: &mut SqliteConnection,
+    cup_id: u32,
+    season: u32,
+) -> Result<Vec<WorldCupMatch>, NutmegError> {
+    let rows = world_cup_matches::table
+        .filter(world_cup_matches::cup_id.eq(cup_id as i32))
+        .filter(world_cup_matches::season.eq(season as i32))
+        .order((
+            world_cup_matches::match_round.asc(),
+            world_cup_matches::cup_series_unit_id.asc(),
+            world_cup_matches::match_id.asc(),
+        ))
+        .load::<WorldCupMatchEntity>(conn)
+        .map_err(|e| {
+            NutmegError::Db(format!("Failed to load world cup matches: {}", e)).or_locked(&e)
+        })?;
+
+    Ok(rows.into_iter().map(WorldCupMatch::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::WorldCupMatchList;
+    use crate::db::download_entries::create_download;
+    use crate::db::manager::DbManager;
+    use serial_test::serial;
+
+    fn wc_match(id: u32, round: u32, group: u32, goals: Option<(u32, u32)>) -> WorldCupMatch {
+        WorldCupMatch {
+            MatchID: id,
+            MatchRound: round,
+            CupSeriesUnitID: group,
+            HomeTeam: FixtureHomeTeam {
+                HomeTeamID: id * 10,
+                HomeTeamName: format!("Home {}", id),
+            },
+            AwayTeam: FixtureAwayTeam {
+                AwayTeamID: id * 10 + 1,
+                AwayTeamName: format!("Away {}", id),
+            },
+            MatchDate: format!("2026-02-{:02} 18:00:00", round * 7),
+            HomeGoals: goals.map(|g| g.0),
+            AwayGoals: goals.map(|g| g.1),
+        }
+    }
+
+    fn world_cup(season: u32, matches: Vec<WorldCupMatch>) -> WorldCup {
+        WorldCup {
+            CupID: 137,
+            Season: season,
+            Matches: WorldCupMatchList { Matches: matches },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_world_cup_matches_ordered_and_updated() {
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl1 = create_download(&mut conn, "2026-02-01T00:00:00Z", "completed").unwrap();
+        let dl2 = create_download(&mut conn, "2026-02-15T00:00:00Z", "completed").unwrap();
+
+        let first = vec![
+            wc_match(3, 2, 101, None),
+            wc_match(2, 1, 102, None),
+            wc_match(1, 1, 101, None),
+        ];
+        save_world_cup_matches(&mut conn, &world_cup(91, first), dl1).unwrap();
+        save_world_cup_matches(
+            &mut conn,
+            &world_cup(90, vec![wc_match(9, 1, 101, None)]),
+            dl1,
+        )
+        .unwrap();
+        save_world_cup_matches(
+            &mut conn,
+            &world_cup(91, vec![wc_match(1, 1, 101, Some((2, 1)))]),
+            dl2,
+        )
+        .unwrap();
+
+        let loaded = get_world_cup_matches(&mut conn, 137, 91).unwrap();
+        let ids: Vec<u32> = loaded.iter().map(|m| m.MatchID).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(loaded[0].HomeGoals, Some(2));
+        assert_eq!(loaded[0].AwayTeam.AwayTeamName, "Away 1");
+        assert_eq!(loaded[1].CupSeriesUnitID, 102);
+        assert_eq!(loaded[2].HomeGoals, None);
+
+        assert_eq!(get_world_cup_matches(&mut conn, 137, 90).unwrap().len(), 1);
+    }
+}
@@ -18,11 +18,13 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
+#[cfg(feature = "gui")]
 use gtk::glib;
 use thiserror::Error;
 
-#[derive(Clone, Error, Debug, glib::Boxed)]
-#[boxed_type(name = "NutmegError")]
+#[derive(Clone, Error, Debug)]
+#[cfg_attr(feature = "gui", derive(glib::Boxed))]
+#[cfg_attr(feature = "gui", boxed_type(name = "NutmegError"))]
 pub enum NutmegError {
     #[error("Network error: {0}")]
     Network(String),
@@ -36,7 +38,7 @@ pub enum NutmegError {
     #[error("Authentication error: {0}")]
     Auth(String),
 
-    #[error("CHPP API error {code}: {message}")]
+    #[error("CHPP error {code}: {message}")]
     ChppApi {
         code: u32,
         message: String,
@@ -50,8 +52,14 @@ pub enum NutmegError {
     #[error("Database error: {0}")]
     Db(String),
 
+    #[error("Database is locked: {0}")]
+    DbLocked(String),
+
     #[error("Application error: {0}")]
     Application(String),
+
+    #[error("Operation cancelled")]
+    Cancelled,
 }
 
 impl From<reqwest::Error> for NutmegError {
@@ -66,6 +74,18 @@ impl From<serde_xml_rs::Error> for NutmegError {
     }
 }
 
+impl From<serde_json::Error> for NutmegError {
+    fn from(err: serde_json::Error) -> Self {
+        NutmegError::Parse(err.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for NutmegError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        NutmegError::Io(format!("Join error: {}", err))
+    }
+}
+
 impl From<std::io::Error> for NutmegError {
     fn from(err: std::io::Error) -> Self {
         NutmegError::Io(err.to_string())
@@ -74,7 +94,110 @@ impl From<std::io::Error> for NutmegError {
 
 impl From<diesel::result::Error> for NutmegError {
     fn from(err: diesel::result::Error) -> Self {
-        NutmegError::Db(err.to_string())
+        if is_database_locked(&err) {
+            NutmegError::DbLocked(err.to_string())
+        } else {
+            NutmegError::Db(err.to_string())
+        }
+    }
+}
+
+/// Whether a Diesel error is SQLite reporting `SQLITE_BUSY`/`SQLITE_LOCKED`.
+///
+/// Diesel does not expose the SQLite result code, so this relies on the
+/// message SQLite attaches to those codes.
+pub fn is_database_locked(err: &diesel::result::Error) -> bool {
+    match err {
+        diesel::result::Error::DatabaseError(_, info) => {
+            let message = info.message().to_lowercase();
+            message.contains("database is locked")
+                || message.contains("database table is locked")
+                || message.contains("sqlite_busy")
+        }
+        _ => false,
+    }
+}
+
+impl NutmegError {
+    /// Turn this error into `DbLocked` when the underlying Diesel error is a
+    /// lock contention, keeping the original message.
+    pub fn or_locked(self, err: &diesel::result::Error) -> Self {
+        if !is_database_locked(err) {
+            return self;
+        }
+        match self {
+            NutmegError::Io(msg) | NutmegError::Db(msg) => NutmegError::DbLocked(msg),
+            other => NutmegError::DbLocked(other.to_string()),
+        }
+    }
+
+    pub fn is_retryable_db(&self) -> bool {
+        matches!(self, NutmegError::DbLocked(_))
+    }
+
+    /// Prefix the message with `context`, keeping the variant.
+    pub fn prefixed(self, context: &str) -> Self {
+        let prefix = |msg: String| format!("{}: {}", context, msg);
+        match self {
+            NutmegError::Network(msg) => NutmegError::Network(prefix(msg)),
+            NutmegError::Parse(msg) => NutmegError::Parse(prefix(msg)),
+            NutmegError::Xml(msg) => NutmegError::Xml(prefix(msg)),
+            NutmegError::Auth(msg) => NutmegError::Auth(prefix(msg)),
+            NutmegError::ChppApi {
+                code,
+                message,
+                error_guid,
+                request,
+            } => NutmegError::ChppApi {
+                code,
+                message: prefix(message),
+                error_guid,
+                request,
+            },
+            NutmegError::Io(msg) => NutmegError::Io(prefix(msg)),
+            NutmegError::Db(msg) => NutmegError::Db(prefix(msg)),
+            NutmegError::DbLocked(msg) => NutmegError::DbLocked(prefix(msg)),
+            NutmegError::Application(msg) => NutmegError::Application(prefix(msg)),
+            NutmegError::Cancelled => NutmegError::Cancelled,
+        }
+    }
+}
+
+/// Convert an error with `From` and say what was being done, e.g.
+/// `query.load(conn).context("Failed to load players")?`. Diesel lock
+/// errors still become `DbLocked`.
+///
+/// `io_context` and `db_context` pin the variant for call sites whose
+/// callers expect `Io` or `Db` regardless of the source: the save functions
+/// in `db/teams.rs` report `Io`, and a pool error (`Io` from
+/// `get_connection`) is reported as `Db` by the sync service.
+pub trait Context<T> {
+    fn context(self, context: &str) -> Result<T, NutmegError>;
+
+    /// Like `context`, reporting a `Db` error as `Io`.
+    fn io_context(self, context: &str) -> Result<T, NutmegError>;
+
+    /// Like `context`, reporting an `Io` error as `Db`.
+    fn db_context(self, context: &str) -> Result<T, NutmegError>;
+}
+
+impl<T, E: Into<NutmegError>> Context<T> for Result<T, E> {
+    fn context(self, context: &str) -> Result<T, NutmegError> {
+        self.map_err(|e| e.into().prefixed(context))
+    }
+
+    fn io_context(self, context: &str) -> Result<T, NutmegError> {
+        self.context(context).map_err(|e| match e {
+            NutmegError::Db(msg) => NutmegError::Io(msg),
+            other => other,
+        })
+    }
+
+    fn db_context(self, context: &str) -> Result<T, NutmegError> {
+        self.context(context).map_err(|e| match e {
+            NutmegError::Io(msg) => NutmegError::Db(msg),
+            other => other,
+        })
     }
 }
 
@@ -89,3 +212,220 @@ impl From<&str> for NutmegError {
         NutmegError::Application(s.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::result::{DatabaseErrorInformation, DatabaseErrorKind, Error as DieselError};
+
+    struct Info(&'static str);
+
+    impl DatabaseErrorInformation for Info {
+        fn message(&self) -> &str {
+            self.0
+        }
+        fn details(&self) -> Option<&str> {
+            None
+        }
+        fn hint(&self) -> Option<&str> {
+            None
+        }
+        fn table_name(&self) -> Option<&str> {
+            None
+        }
+        fn column_name(&self) -> Option<&str> {
+            None
+        }
+        fn constraint_name(&self) -> Option<&str> {
+            None
+        }
+        fn statement_position(&self) -> Option<i32> {
+            None
+        }
+    }
+
+    fn db_error(message: &'static str) -> DieselError {
+        DieselError::DatabaseError(DatabaseErrorKind::Unknown, Box::new(Info(message)))
+    }
+
+    #[test]
+    fn test_busy_error_is_classified_as_locked() {
+        let err = db_error("database is locked");
+        assert!(is_database_locked(&err));
+        assert!(matches!(NutmegError::from(err), NutmegError::DbLocked(_)));
+    }
+
+    #[test]
+    fn test_other_database_errors_are_not_locked() {
+        let err = db_error("UNIQUE constraint failed: players.id");
+        assert!(!is_database_locked(&err));
+        assert!(matches!(NutmegError::from(err), NutmegError::Db(_)));
+        assert!(!is_database_locked(&DieselError::NotFound));
+    }
+
+    #[test]
+    fn test_or_locked_keeps_message() {
+        let err = db_error("database is locked");
+        let mapped =
+            NutmegError::Io(format!("Database error saving player 1: {}", err)).or_locked(&err);
+        match mapped {
+            NutmegError::DbLocked(msg) => {
+                assert_eq!(msg, "Database error saving player 1: database is locked")
+            }
+            other => panic!("Expected DbLocked, got {:?}", other),
+        }
+
+        let err = DieselError::NotFound;
+        let mapped = NutmegError::Db(format!("Failed: {}", err)).or_locked(&err);
+        assert!(matches!(mapped, NutmegError::Db(_)));
+    }
+
+    #[test]
+    fn test_display_of_each_variant() {
+        let cases = [
+            (
+                NutmegError::Network("timeout".into()),
+                "Network error: timeout",
+            ),
+            (NutmegError::Parse("bad int".into()), "Parse error: bad int"),
+            (
+                NutmegError::Xml("unclosed tag".into()),
+                "XML error: unclosed tag",
+            ),
+            (
+                NutmegError::Auth("unauthorized".into()),
+                "Authentication error: unauthorized",
+            ),
+            (
+                NutmegError::ChppApi {
+                    code: 50,
+                    message: "Unknown file".into(),
+                    error_guid: Some("abc".into()),
+                    request: None,
+                },
+                "CHPP error 50: Unknown file",
+            ),
+            (NutmegError::Io("disk full".into()), "IO error: disk full"),
+            (
+                NutmegError::Db("no table".into()),
+                "Database error: no table",
+            ),
+            (
+                NutmegError::DbLocked("busy".into()),
+                "Database is locked: busy",
+            ),
+            (
+                NutmegError::Application("oops".into()),
+                "Application error: oops",
+            ),
+            (NutmegError::Cancelled, "Operation cancelled"),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(err.to_string(), expected);
+            let as_std: &dyn std::error::Error = &err;
+            assert_eq!(as_std.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_question_mark_converts_library_errors() {
+        fn from_reqwest() -> Result<(), NutmegError> {
+            reqwest::Client::new().get("not a url").build()?;
+            Ok(())
+        }
+        fn from_xml() -> Result<u32, NutmegError> {
+            Ok(serde_xml_rs::from_str::<u32>("<Value>")?)
+        }
+        fn from_diesel() -> Result<(), NutmegError> {
+            let lookup: Result<(), DieselError> = Err(DieselError::NotFound);
+            lookup?;
+            Ok(())
+        }
+
+        assert!(matches!(from_reqwest(), Err(NutmegError::Network(_))));
+        assert!(matches!(from_xml(), Err(NutmegError::Parse(_))));
+        assert!(matches!(from_diesel(), Err(NutmegError::Db(_))));
+    }
+
+    #[test]
+    fn test_from_conversions_pick_variant() {
+        let json = serde_json::from_str::<u32>("not json").unwrap_err();
+        assert!(matches!(NutmegError::from(json), NutmegError::Parse(_)));
+
+        let xml = serde_xml_rs::from_str::<u32>("<Value>").unwrap_err();
+        assert!(matches!(NutmegError::from(xml), NutmegError::Parse(_)));
+
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "gone");
+        assert!(matches!(NutmegError::from(io), NutmegError::Io(_)));
+
+        assert!(matches!(
+            NutmegError::from(DieselError::NotFound),
+            NutmegError::Db(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_join_error_converts_to_io() {
+        let join = tokio::task::spawn(async { panic!("boom") })
+            .await
+            .unwrap_err();
+        match NutmegError::from(join) {
+            NutmegError::Io(msg) => assert!(msg.starts_with("Join error: ")),
+            other => panic!("Expected Io, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_context_prefixes_message_and_keeps_lock_detection() {
+        let missing: Result<(), DieselError> = Err(DieselError::NotFound);
+        match missing.context("Failed to load players") {
+            Err(NutmegError::Db(msg)) => {
+                assert_eq!(msg, "Failed to load players: Record not found")
+            }
+            other => panic!("Expected Db, got {:?}", other),
+        }
+
+        let locked: Result<(), DieselError> = Err(db_error("database is locked"));
+        assert!(matches!(
+            locked.context("Failed to save team"),
+            Err(NutmegError::DbLocked(_))
+        ));
+
+        let api: Result<(), NutmegError> = Err(NutmegError::ChppApi {
+            code: 50,
+            message: "Unknown file".into(),
+            error_guid: None,
+            request: None,
+        });
+        assert_eq!(
+            api.context("players").unwrap_err().to_string(),
+            "CHPP error 50: players: Unknown file"
+        );
+    }
+
+    #[test]
+    fn test_io_and_db_context_pin_the_variant() {
+        let missing: Result<(), DieselError> = Err(DieselError::NotFound);
+        match missing.io_context("Failed to save user") {
+            Err(NutmegError::Io(msg)) => assert_eq!(msg, "Failed to save user: Record not found"),
+            other => panic!("Expected Io, got {:?}", other),
+        }
+
+        let locked: Result<(), DieselError> = Err(db_error("database is locked"));
+        assert!(matches!(
+            locked.io_context("Failed to save user"),
+            Err(NutmegError::DbLocked(_))
+        ));
+
+        let pool: Result<(), NutmegError> = Err(NutmegError::Io("pool timed out".into()));
+        match pool.db_context("Failed to get database connection") {
+            Err(NutmegError::Db(msg)) => {
+                assert_eq!(msg, "Failed to get database connection: pool timed out")
+            }
+            other => panic!("Expected Db, got {:?}", other),
+        }
+
+        let parse: Result<(), NutmegError> = Err(NutmegError::Parse("bad".into()));
+        assert!(matches!(parse.db_context("x"), Err(NutmegError::Parse(_))));
+    }
+}
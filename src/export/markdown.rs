@@ -0,0 +1,154 @@
+/* markdown.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Player, PlayerSkills};
+
+const HEADER: [&str; 12] = [
+    "Name",
+    "Age",
+    "TSI",
+    "Form",
+    "Stamina",
+    "Keeper",
+    "Defending",
+    "Playmaking",
+    "Winger",
+    "Passing",
+    "Scoring",
+    "Set Pieces",
+];
+
+/// Pipes would end the cell early, and a newline would end the row.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+fn skill_cells(skills: Option<&PlayerSkills>) -> Vec<String> {
+    match skills {
+        Some(s) => [
+            s.StaminaSkill,
+            s.KeeperSkill,
+            s.DefenderSkill,
+            s.PlaymakerSkill,
+            s.WingerSkill,
+            s.PassingSkill,
+            s.ScorerSkill,
+            s.SetPiecesSkill,
+        ]
+        .iter()
+        .map(u32::to_string)
+        .collect(),
+        // Skills are hidden for other teams' players.
+        None => vec!["-".to_string(); 8],
+    }
+}
+
+fn row(cells: &[String]) -> String {
+    format!("| {} |", cells.join(" | "))
+}
+
+/// The squad as a Markdown table, one row per player in the given order,
+/// ready to paste into a forum post.
+pub fn export_squad_markdown(players: &[Player]) -> String {
+    let header: Vec<String> = HEADER.iter().map(|h| h.to_string()).collect();
+    let separator: Vec<String> = HEADER.iter().map(|_| "---".to_string()).collect();
+
+    let mut lines = vec![row(&header), row(&separator)];
+    for p in players {
+        let mut cells = vec![
+            escape_cell(&format!("{} {}", p.FirstName, p.LastName)),
+            format!("{}.{}", p.Age, p.AgeDays.unwrap_or(0)),
+            p.TSI.to_string(),
+            p.PlayerForm.to_string(),
+        ];
+        cells.extend(skill_cells(p.PlayerSkills.as_ref()));
+        lines.push(row(&cells));
+    }
+
+    let mut table = lines.join("\n");
+    table.push('\n');
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(first: &str, last: &str) -> Player {
+        Player {
+            FirstName: first.to_string(),
+            LastName: last.to_string(),
+            Age: 24,
+            AgeDays: Some(17),
+            TSI: 4520,
+            PlayerForm: 6,
+            PlayerSkills: Some(PlayerSkills {
+                KeeperSkill: 1,
+                ScorerSkill: 9,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_header_row() {
+        let table = export_squad_markdown(&[]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| Name | Age | TSI | Form | Stamina | Keeper | Defending | Playmaking | Winger | Passing | Scoring | Set Pieces |"
+        );
+        assert_eq!(lines[1], format!("|{}", " --- |".repeat(HEADER.len())));
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn test_player_row() {
+        let table = export_squad_markdown(&[player("John", "Doe")]);
+        assert_eq!(
+            table.lines().nth(2),
+            Some("| John Doe | 24.17 | 4520 | 6 | 0 | 1 | 0 | 0 | 0 | 0 | 9 | 0 |")
+        );
+    }
+
+    #[test]
+    fn test_pipe_in_name_is_escaped() {
+        let table = export_squad_markdown(&[player("Jo|hn", "Doe")]);
+        let row = table.lines().nth(2).unwrap();
+        assert!(row.starts_with("| Jo\\|hn Doe | "), "{}", row);
+        // The escaped pipe must not add a column.
+        assert_eq!(
+            row.matches('|').count() - row.matches("\\|").count(),
+            HEADER.len() + 1
+        );
+    }
+
+    #[test]
+    fn test_row_count_matches_players() {
+        let mut hidden = player("Jane", "Roe");
+        hidden.PlayerSkills = None;
+        let players = vec![player("John", "Doe"), hidden, player("Max", "Mustermann")];
+
+        let table = export_squad_markdown(&players);
+        assert_eq!(table.lines().count(), players.len() + 2);
+        assert!(table.lines().nth(3).unwrap().ends_with("| - | - | - | - |"));
+    }
+}
@@ -0,0 +1,51 @@
+/* lib.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Core Nutmeg library: CHPP client, persistence and services, usable
+//! without a display. The GTK front end is only built with the `gui`
+//! feature (enabled by default).
+
+pub mod chpp;
+pub mod cli;
+pub mod config;
+pub mod db;
+pub mod domain;
+pub mod error;
+pub mod export;
+pub mod rating;
+pub mod service;
+pub mod squad;
+#[cfg(test)]
+mod test_support;
+pub mod training;
+pub mod utils;
+
+#[cfg(feature = "gui")]
+pub mod application;
+#[cfg(feature = "gui")]
+pub mod opponent_analysis;
+#[cfg(feature = "gui")]
+pub mod series;
+#[cfg(feature = "gui")]
+pub mod setup_window;
+#[cfg(feature = "gui")]
+pub mod ui;
+#[cfg(feature = "gui")]
+pub mod window;
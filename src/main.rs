@@ -18,29 +18,12 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-mod application;
-mod chpp;
-mod config;
-mod db;
-mod domain;
-mod error;
-mod opponent_analysis;
-mod rating;
-mod series;
-mod service;
-mod setup_window;
-mod squad;
-mod training;
-mod ui;
-mod utils;
-mod window;
-
-use self::application::NutmegApplication;
-
-use config::{GETTEXT_PACKAGE, LOCALEDIR};
 use gettextrs::{bind_textdomain_codeset, bindtextdomain, textdomain};
 use gtk::prelude::*;
 use gtk::{gio, glib};
+use nutmeg::application::NutmegApplication;
+use nutmeg::cli::{self, Command};
+use nutmeg::config::{self, GETTEXT_PACKAGE, LOCALEDIR};
 
 use tokio::runtime::Runtime;
 
@@ -51,7 +34,7 @@ fn main() -> glib::ExitCode {
         Err(e) => println!("INFO: Could not load .env: {}", e),
     }
 
-    let consumer_key = crate::config::consumer_key();
+    let consumer_key = config::consumer_key();
     if !consumer_key.is_empty() {
         println!(
             "INFO: HT_CONSUMER_KEY found (length: {})",
@@ -61,7 +44,7 @@ fn main() -> glib::ExitCode {
         println!("ERROR: HT_CONSUMER_KEY not found in compile environment or runtime env");
     }
 
-    let consumer_secret = crate::config::consumer_secret();
+    let consumer_secret = config::consumer_secret();
     if !consumer_secret.is_empty() {
         println!(
             "INFO: HT_CONSUMER_SECRET found (length: {})",
@@ -73,6 +56,15 @@ fn main() -> glib::ExitCode {
 
     env_logger::init();
 
+    match cli::parse_args(std::env::args()) {
+        Ok(Command::Gui) => {}
+        Ok(Command::HeadlessSync) => return run_headless_sync(),
+        Err(usage) => {
+            eprintln!("{}", usage);
+            return glib::ExitCode::FAILURE;
+        }
+    }
+
     // Set up gettext translations
     bindtextdomain(GETTEXT_PACKAGE, LOCALEDIR).expect("Unable to bind the text domain");
     bind_textdomain_codeset(GETTEXT_PACKAGE, "UTF-8")
@@ -98,6 +90,20 @@ fn main() -> glib::ExitCode {
         let _ = dotenvy::from_filename(".zshrc");
     }
 
-    crate::chpp::authenticator::perform_cli_auth()
+    nutmeg::chpp::authenticator::perform_cli_auth()
     */
 }
+
+fn run_headless_sync() -> glib::ExitCode {
+    let runtime = Runtime::new().expect("Unable to create Tokio runtime");
+    match runtime.block_on(cli::run_headless_sync()) {
+        Ok(summary) => {
+            println!("{}", summary);
+            glib::ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("ERROR: Headless sync failed: {}", e);
+            glib::ExitCode::FAILURE
+        }
+    }
+}
@@ -13,4 +13,5 @@ pub use types::*;
 pub mod controller;
 pub mod match_predictor;
 pub mod optimiser;
+#[cfg(feature = "gui")]
 pub mod ui;
@@ -695,6 +695,7 @@ mod tests {
             MatchesCurrentTeam: None,
             GoalsCurrentTeam: None,
             AssistsCurrentTeam: None,
+            TransferDetails: None,
             LastMatch: None,
         }
     }
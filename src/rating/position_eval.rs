@@ -349,6 +349,7 @@ mod tests {
             MatchesCurrentTeam: Some(0),
             GoalsCurrentTeam: Some(0),
             AssistsCurrentTeam: Some(0),
+            TransferDetails: None,
             LastMatch: None,
         }
     }
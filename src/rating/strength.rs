@@ -22,6 +22,16 @@ pub fn calc_loyalty(player: &Player) -> f64 {
     }
 }
 
+/// Estimated skill bonus from loyalty and the mother club, in hundredths of
+/// a skill level
+/// Loyalty adds up to 1.0 (loyalty_rating / 19), the mother club a flat 0.5,
+/// so a home grown player at divine loyalty gets the 1.5 used above
+pub fn form_style_bonus(player: &Player) -> u32 {
+    let loyalty = (calc_skill_rating(player.Loyalty as f64) / 19.0).min(1.0);
+    let mother_club = if player.MotherClubBonus { 0.5 } else { 0.0 };
+    ((loyalty + mother_club) * 100.0).round() as u32
+}
+
 /// Calculate player's form impact on rating
 /// Form range: 0.5 .. 7.0
 /// Formula: 0.378 * sqrt(min(7, skill_rating))
@@ -144,6 +154,7 @@ mod tests {
             MatchesCurrentTeam: Some(0),
             GoalsCurrentTeam: Some(0),
             AssistsCurrentTeam: Some(0),
+            TransferDetails: None,
             LastMatch: None,
         }
     }
@@ -176,6 +187,20 @@ mod tests {
         assert!((loyalty - 0.368).abs() < 0.001);
     }
 
+    #[test]
+    fn test_form_style_bonus() {
+        let home_grown = create_test_player(7, 5, 20, true, 8, 10);
+        let new_signing = create_test_player(7, 5, 1, false, 8, 10);
+
+        assert_eq!(form_style_bonus(&home_grown), 150);
+        assert_eq!(form_style_bonus(&new_signing), 0);
+        assert!(form_style_bonus(&home_grown) > form_style_bonus(&new_signing));
+
+        // Loyalty 8 (excellent) is 7/19 of a level
+        let loyal = create_test_player(7, 5, 8, false, 8, 10);
+        assert_eq!(form_style_bonus(&loyal), 37);
+    }
+
     #[test]
     fn test_calc_loyalty_low() {
         // Loyalty = 1 (Disastrous)
@@ -5,11 +5,12 @@ use crate::rating::optimiser::{Formation, OptimisedLineup};
 use crate::rating::types::{
     Attitude, Behaviour, Location, PositionId, RatingSector, TacticType, Weather,
 };
+use crate::ui::player_display::preferred_number_format;
+use crate::utils::number_format::format_decimal;
 use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gtk::{glib, CompositeTemplate};
 use log::{debug, error, info};
-use num_format::{Buffer, SystemLocale};
 use std::cell::RefCell;
 use std::collections::HashMap;
 
@@ -279,30 +280,10 @@ impl FormationOptimiserWidget {
     // Formatting helpers
     // ─────────────────────────────────────────────────────────────────────
 
-    /// Returns the system locale for number formatting, falling back to "C" locale.
-    fn get_locale() -> SystemLocale {
-        SystemLocale::default().unwrap_or_else(|_| {
-            SystemLocale::from_name("C").expect("The \"C\" locale should always be available")
-        })
-    }
-
-    /// Formats a HatStats value with locale-aware thousand separators.
+    /// Formats a HatStats value with locale-aware thousand and decimal
+    /// separators.
     fn format_hatstats(value: f64) -> String {
-        let locale = Self::get_locale();
-        let whole = value as i64;
-        let mut buf = Buffer::default();
-        buf.write_formatted(&whole, &locale);
-
-        let decimal_part = value - whole as f64;
-        if decimal_part.abs() > 0.001 {
-            format!(
-                "{}.{}",
-                buf.as_str(),
-                format!("{:.1}", decimal_part).trim_start_matches("0.")
-            )
-        } else {
-            buf.as_str().to_string()
-        }
+        format_decimal(value, &preferred_number_format())
     }
 
     /// Creates a dotted leader that stretches to fill all available space
@@ -5,8 +5,8 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
+use crate::chpp::client::{ChppClient, HattrickClient};
 use crate::chpp::model::{LeagueDetailsData, MatchesData};
-use crate::chpp::request::{league_details_request, matches_request, team_details_request};
 use crate::service::secret::{SecretStorageService, SystemSecretService};
 
 use std::error::Error;
@@ -33,7 +33,7 @@ impl SeriesController {
 
         // Serve from the local cache when possible; credentials are only needed
         // on a cache miss when the CHPP API must be called.
-        let team_in_db = crate::db::teams::get_team(&mut conn, team_id)?;
+        let team_in_db = crate::db::team_cache::team_cache().get_team(&mut conn, team_id)?;
         if let Some(ref team) = team_in_db {
             if let Some(ref unit) = team.LeagueLevelUnit {
                 let league_unit_id = unit.LeagueLevelUnitID;
@@ -137,6 +137,7 @@ impl SeriesController {
             .collect();
 
         // Fetch missing archived matches for opponents
+        let client = HattrickClient::new();
         for &tid in &team_ids {
             if tid as u32 == team_id {
                 continue;
@@ -146,12 +147,9 @@ impl SeriesController {
             let (oauth_data1, signing_key1) =
                 crate::chpp::oauth::create_oauth_context(&key, &secret, &token, &token_secret);
 
-            if let Ok(team_data) = crate::chpp::request::team_details_request(
-                oauth_data1,
-                signing_key1,
-                Some(tid as u32),
-            )
-            .await
+            if let Ok(team_data) = client
+                .team_details(oauth_data1, signing_key1, Some(tid as u32))
+                .await
             {
                 if let Some(team) = team_data.Teams.Teams.first() {
                     let _ = crate::db::teams::save_team(
@@ -166,14 +164,9 @@ impl SeriesController {
 
             let (oauth_data2, signing_key2) =
                 crate::chpp::oauth::create_oauth_context(&key, &secret, &token, &token_secret);
-            if let Ok(archived) = crate::chpp::request::matches_archive_request(
-                oauth_data2,
-                signing_key2,
-                Some(tid as u32),
-                None,
-                None,
-            )
-            .await
+            if let Ok(archived) = client
+                .matches_archive(oauth_data2, signing_key2, Some(tid as u32), None, None)
+                .await
             {
                 let mut match_list = archived.Team.MatchList.clone();
                 for m in &mut match_list.Matches {
@@ -217,7 +210,7 @@ impl SeriesController {
         token_secret: &str,
     ) -> Result<u32, Box<dyn Error>> {
         // Try DB first
-        let team_details_opt = crate::db::teams::get_team(conn, team_id)?;
+        let team_details_opt = crate::db::team_cache::team_cache().get_team(conn, team_id)?;
         if let Some(team) = team_details_opt {
             if let Some(unit) = team.LeagueLevelUnit {
                 log::debug!(
@@ -239,8 +232,9 @@ impl SeriesController {
         log::debug!("Fetching team details from API for team_id: {}", team_id);
         let (oauth_data, signing_key) =
             crate::chpp::oauth::create_oauth_context(key, secret, token, token_secret);
-        let team_details_data =
-            team_details_request(oauth_data, signing_key, Some(team_id)).await?;
+        let team_details_data = HattrickClient::new()
+            .team_details(oauth_data, signing_key, Some(team_id))
+            .await?;
 
         let team_str = team_id.to_string();
         let team = team_details_data
@@ -276,8 +270,9 @@ impl SeriesController {
             crate::chpp::oauth::create_oauth_context(key, secret, token, token_secret);
 
         log::debug!("Fetching league details for unit: {}", league_unit_id);
-        let league_details =
-            league_details_request(oauth_data, signing_key, league_unit_id).await?;
+        let league_details = HattrickClient::new()
+            .league_details(oauth_data, signing_key, league_unit_id)
+            .await?;
 
         crate::db::series::save_league_details(conn, download_id, &league_details)?;
         Ok(league_details)
@@ -296,7 +291,10 @@ impl SeriesController {
             crate::chpp::oauth::create_oauth_context(key, secret, token, token_secret);
 
         log::debug!("Fetching upcoming matches for team: {}", team_id);
-        let upcoming_matches = matches_request(oauth_data, signing_key, Some(team_id)).await?;
+        let client = HattrickClient::new();
+        let upcoming_matches = client
+            .matches(oauth_data, signing_key, Some(team_id))
+            .await?;
         log::debug!(
             "Fetched {} upcoming matches",
             upcoming_matches.Team.MatchList.Matches.len()
@@ -307,14 +305,9 @@ impl SeriesController {
             crate::chpp::oauth::create_oauth_context(key, secret, token, token_secret);
 
         log::debug!("Fetching archived matches for team: {}", team_id);
-        let archived_matches_res = crate::chpp::request::matches_archive_request(
-            oauth_data,
-            signing_key,
-            Some(team_id),
-            None,
-            None,
-        )
-        .await;
+        let archived_matches_res = client
+            .matches_archive(oauth_data, signing_key, Some(team_id), None, None)
+            .await;
 
         let mut matches = upcoming_matches;
 
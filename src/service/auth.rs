@@ -18,7 +18,10 @@
  * SPDX-License-Identifier: GPL-3.0-or-later
  */
 
-use crate::chpp::{exchange_verification_code, get_request_token_url, OauthSettings};
+use crate::chpp::{
+    exchange_verification_code, exchange_verification_code_async, get_authorization_url_async,
+    get_request_token_url, ChppScope, OauthSettings,
+};
 use crate::error::NutmegError;
 use crate::config::{consumer_key, consumer_secret};
 
@@ -36,11 +39,44 @@ pub trait AuthenticationService {
     ) -> Result<(String, String), NutmegError>;
 }
 
-pub struct HattrickAuthService;
+pub struct HattrickAuthService {
+    scopes: Vec<ChppScope>,
+}
 
 impl HattrickAuthService {
+    /// Service requesting read-only access.
     pub fn new() -> Self {
-        Self
+        Self::with_scopes(Vec::new())
+    }
+
+    /// Service asking the user to grant `scopes` on top of read access.
+    pub fn with_scopes(scopes: Vec<ChppScope>) -> Self {
+        Self { scopes }
+    }
+
+    /// Non-blocking version of `get_authorization_url`.
+    pub async fn get_authorization_url_async(
+        &self,
+    ) -> Result<(String, String, String), NutmegError> {
+        get_authorization_url_async(&consumer_key(), &consumer_secret(), &self.scopes).await
+    }
+
+    /// Non-blocking version of `verify_user`.
+    pub async fn verify_user_async(
+        &self,
+        verification_code: &str,
+        request_token: &str,
+        request_token_secret: &str,
+    ) -> Result<(String, String), NutmegError> {
+        let settings = OauthSettings {
+            client_id: consumer_key(),
+            client_secret: consumer_secret(),
+            request_token: request_token.to_string(),
+            oauth_secret_token: request_token_secret.to_string(),
+            ..Default::default()
+        };
+
+        exchange_verification_code_async(verification_code, &settings).await
     }
 }
 
@@ -52,15 +88,9 @@ impl Default for HattrickAuthService {
 
 impl AuthenticationService for HattrickAuthService {
     fn get_authorization_url(&self) -> Result<(String, String, String), NutmegError> {
-        let settings = OauthSettings::default();
-        let key = consumer_key();
-        let secret = consumer_secret();
-        let url = get_request_token_url(&settings, &key, &secret)?;
-
-        let token = settings.request_token.take();
-        let secret = settings.oauth_secret_token.take();
-
-        Ok((url, token, secret))
+        let (url, settings) =
+            get_request_token_url(&consumer_key(), &consumer_secret(), &self.scopes)?;
+        Ok((url, settings.request_token, settings.oauth_secret_token))
     }
 
     fn verify_user(
@@ -70,13 +100,13 @@ impl AuthenticationService for HattrickAuthService {
         request_token_secret: &str,
     ) -> Result<(String, String), NutmegError> {
         // Reconstruct settings for the exchange
-        let settings = OauthSettings::default();
-        settings.client_id.replace(consumer_key());
-        settings.client_secret.replace(consumer_secret());
-        settings.request_token.replace(request_token.to_string());
-        settings
-            .oauth_secret_token
-            .replace(request_token_secret.to_string());
+        let settings = OauthSettings {
+            client_id: consumer_key(),
+            client_secret: consumer_secret(),
+            request_token: request_token.to_string(),
+            oauth_secret_token: request_token_secret.to_string(),
+            ..Default::default()
+        };
 
         exchange_verification_code(verification_code, &settings)
     }
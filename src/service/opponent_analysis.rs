@@ -10,7 +10,9 @@ use crate::error::NutmegError;
 use crate::chpp::model::Player;
 use crate::db::manager::DbManager;
 use crate::db::match_ratings::{MatchRating, NewMatchRating};
+#[cfg(feature = "gui")]
 use crate::rating::types::{Behaviour, PositionId};
+#[cfg(feature = "gui")]
 use crate::ui::components::pitch_view::PitchPlayer;
 use oauth_1a::{OAuthData, SigningKey};
 use std::collections::HashMap;
@@ -249,6 +251,7 @@ impl OpponentAnalysisService {
         })
     }
 
+    #[cfg(feature = "gui")]
     pub async fn get_opponent_match_lineup<F>(
         &self,
         get_auth: &F,
@@ -337,6 +340,7 @@ mod tests {
             &self,
             _data: OAuthData,
             _key: SigningKey,
+            _league_id: Option<u32>,
         ) -> Result<WorldDetails, NutmegError> {
             unimplemented!()
         }
@@ -508,8 +512,8 @@ mod tests {
             _data: OAuthData,
             _key: SigningKey,
             _team_id: Option<u32>,
-            _first_match_date: Option<String>,
-            _last_match_date: Option<String>,
+            _first_match_date: Option<chrono::NaiveDate>,
+            _last_match_date: Option<chrono::NaiveDate>,
         ) -> Result<MatchesArchiveData, NutmegError> {
             Ok(MatchesArchiveData {
                 Team: MatchesArchiveTeamWrapper {
@@ -588,6 +592,243 @@ mod tests {
         ) -> Result<StaffListData, NutmegError> {
             unimplemented!()
         }
+
+        async fn alliances(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _search_name: &str,
+        ) -> Result<AllianceSearchResults, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn alliance_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _alliance_id: u32,
+        ) -> Result<AllianceDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn transfers_player(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _player_id: u32,
+        ) -> Result<PlayerTransfers, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn player_events(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _player_id: u32,
+        ) -> Result<PlayerEvents, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn current_bids(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+        ) -> Result<CurrentBids, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _search_type: SearchType,
+            _query: &str,
+        ) -> Result<SearchResults, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn region_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _region_id: u32,
+        ) -> Result<RegionDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn world_languages(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+        ) -> Result<WorldLanguages, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn manager_compendium(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _user_id: Option<u32>,
+        ) -> Result<ManagerCompendium, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn cup_matches(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: Option<u32>,
+        ) -> Result<CupMatches, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn training_events(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _player_id: u32,
+        ) -> Result<TrainingEvents, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn arena_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _arena_id: Option<u32>,
+        ) -> Result<ArenaDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn economy(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: Option<u32>,
+        ) -> Result<Economy, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn league_fixtures(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _league_level_unit_id: u32,
+            _season: Option<u32>,
+        ) -> Result<LeagueFixtures, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn transfers_team(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: u32,
+            _page: u32,
+        ) -> Result<TeamTransfers, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn translations(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _language_id: u32,
+        ) -> Result<Translations, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn world_cup(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _cup_id: u32,
+            _season: Option<u32>,
+        ) -> Result<WorldCup, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn hof_players(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: Option<u32>,
+        ) -> Result<HofPlayers, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn bookmarks(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+        ) -> Result<Bookmarks, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn challenges(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: u32,
+            _is_weekend: bool,
+        ) -> Result<Challenges, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn tournament_list(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _user_id: Option<u32>,
+        ) -> Result<TournamentList, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn tournament_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _tournament_id: u32,
+        ) -> Result<TournamentDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn achievements(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _user_id: Option<u32>,
+        ) -> Result<Achievements, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn supporters(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: Option<u32>,
+        ) -> Result<Supporters, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn live(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _match_ids: &[u32],
+            _action_type: LiveActionType,
+        ) -> Result<LiveData, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn transfer_search(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _criteria: TransferSearchCriteria,
+        ) -> Result<TransferSearchResults, NutmegError> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
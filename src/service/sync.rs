@@ -20,30 +20,118 @@
 
 use crate::chpp::client::HattrickClient;
 use crate::chpp::metadata::ChppEndpoints;
-use crate::chpp::model::{MatchesData, MatchesListWrapper, MatchesTeamWrapper};
+use crate::chpp::model::{MatchesData, MatchesListWrapper, MatchesTeamWrapper, Player, Team};
 use crate::chpp::{
     create_oauth_context, retry_with_default_config, ChppClient,
 };
-use crate::error::NutmegError;
-use crate::db::download_entries::{create_download_entry, update_entry_status, NewDownloadEntry};
+use crate::error::{Context, NutmegError};
+use crate::db::achievements::save_achievements;
+use crate::db::bookmarks::save_bookmarks;
+use crate::db::download_entries::{
+    record_download_entry, should_fetch_world_details, update_entry_status,
+};
+use crate::db::economy::save_economy;
+use crate::db::hof_players::save_hof_players;
 use crate::db::manager::DbManager;
-use crate::db::schema::downloads;
+use crate::db::retry::retry_db;
+use crate::db::schema::{downloads, players};
 use crate::db::series::{save_league_details, save_matches};
 use crate::db::staff::save_staff;
-use crate::db::teams::{save_avatars, save_players, save_team, save_world_details};
+use crate::db::teams::{
+    get_players_for_team, needs_full_world_details, save_avatars, save_players, save_team,
+    save_world_details, update_player,
+};
+use crate::db::translations::{has_translations, save_translations, touch_translations};
 use crate::service::avatar::AvatarService;
 use crate::service::secret::{SecretStorageService, SystemSecretService};
 use chrono::Utc;
 use diesel::prelude::*;
-use log::{debug, info, warn};
+use log::debug;
 use oauth_1a::{OAuthData, SigningKey};
 use std::future::Future;
+use std::ops::Range;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
 
 pub type ProgressCallback = Box<dyn Fn(f64, &str) + Send + Sync>;
 
+/// Outcome of the player fetches of a sync: how many players came back with
+/// full details, and which ones had to fall back to the basic `players` data.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    pub total: usize,
+    pub detailed: usize,
+    pub fallback_ids: Vec<u32>,
+}
+
+impl SyncReport {
+    pub fn is_partial(&self) -> bool {
+        !self.fallback_ids.is_empty()
+    }
+
+    fn absorb(&mut self, other: SyncReport) {
+        self.total += other.total;
+        self.detailed += other.detailed;
+        self.fallback_ids.extend(other.fallback_ids);
+    }
+}
+
+/// How long downloaded world details are reused before fetching them again.
+pub const DEFAULT_WORLD_DETAILS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// World details are normally fetched for the user's league only. Every
+/// league is fetched again when the last full country list is more than this
+/// many downloads old, so foreign players keep their country and flag.
+const FULL_WORLD_DETAILS_MAX_DOWNLOADS: i32 = 10;
+
+/// Pick the team to sync from a user's team list.
+///
+/// Teams whose `TeamID` does not parse to a non-zero id are ignored.  The
+/// team flagged as primary club is preferred; otherwise the first valid team
+/// is used.
+pub fn select_primary_team(teams: &[Team]) -> Option<&Team> {
+    let is_valid = |t: &&Team| matches!(t.TeamID.parse::<u32>(), Ok(id) if id != 0);
+
+    teams
+        .iter()
+        .filter(is_valid)
+        .find(|t| t.IsPrimaryClub == Some(true))
+        .or_else(|| teams.iter().find(is_valid))
+}
+
+/// The league all of a user's teams play in, used to fetch world details
+/// for that league only. `None` when the teams span several leagues (or a
+/// league is unknown), so every league is fetched.
+pub fn shared_league_id(teams: &[Team]) -> Option<u32> {
+    let mut leagues = teams.iter().map(|t| t.League.as_ref().map(|l| l.LeagueID));
+    let first = leagues.next()??;
+    leagues.all(|l| l == Some(first)).then_some(first)
+}
+
+/// Prefix a sync log message with the run it belongs to. A run is identified
+/// by its download id, so the lines of overlapping syncs can be told apart.
+fn log_scope(download_id: i32, msg: &str) -> String {
+    format!("[sync {}] {}", download_id, msg)
+}
+
+/// `log` call for a sync run: `sync_log!(info, download_id, "fmt", args...)`.
+macro_rules! sync_log {
+    ($level:ident, $download_id:expr, $($arg:tt)+) => {
+        log::$level!("{}", log_scope($download_id, &format!($($arg)+)))
+    };
+}
+
+/// Bail out with `NutmegError::Cancelled` once the sync has been cancelled.
+fn check_cancelled(cancel: &CancellationToken) -> Result<(), NutmegError> {
+    if cancel.is_cancelled() {
+        Err(NutmegError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
 pub trait DataSyncService {
     fn perform_initial_sync(
         &self,
@@ -52,7 +140,8 @@ pub trait DataSyncService {
         access_token: String,
         access_secret: String,
         on_progress: ProgressCallback,
-    ) -> Pin<Box<dyn Future<Output = Result<(u32, i32), NutmegError>> + Send + '_>>;
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Future<Output = Result<(u32, i32, SyncReport), NutmegError>> + Send + '_>>;
 
     fn perform_sync_with_stored_secrets(
         &self,
@@ -82,6 +171,7 @@ pub struct SyncService {
     db_manager: Arc<DbManager>,
     client: Arc<dyn ChppClient>,
     secret_service: Arc<dyn SecretStorageService>,
+    world_details_ttl: Duration,
 }
 
 impl SyncService {
@@ -90,9 +180,20 @@ impl SyncService {
             db_manager,
             client: Arc::new(HattrickClient::new()),
             secret_service: Arc::new(SystemSecretService::new()),
+            world_details_ttl: DEFAULT_WORLD_DETAILS_TTL,
         }
     }
 
+    /// Set how long world details are reused before being fetched again.
+    pub fn with_world_details_ttl(mut self, ttl: Duration) -> Self {
+        self.world_details_ttl = ttl;
+        self
+    }
+
+    pub fn world_details_ttl(&self) -> Duration {
+        self.world_details_ttl
+    }
+
     #[cfg(test)]
     pub fn new_with_client(
         db_manager: Arc<DbManager>,
@@ -103,6 +204,7 @@ impl SyncService {
             db_manager,
             client,
             secret_service,
+            world_details_ttl: DEFAULT_WORLD_DETAILS_TTL,
         }
     }
 }
@@ -115,19 +217,24 @@ impl DataSyncService for SyncService {
         access_token: String,
         access_secret: String,
         on_progress: ProgressCallback,
-    ) -> Pin<Box<dyn Future<Output = Result<(u32, i32), NutmegError>> + Send + '_>> {
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Future<Output = Result<(u32, i32, SyncReport), NutmegError>> + Send + '_>>
+    {
         let db_manager = self.db_manager.clone();
         let client = self.client.clone();
+        let world_details_ttl = self.world_details_ttl;
 
         Box::pin(async move {
             let res = Self::do_full_sync(
                 db_manager,
                 client,
+                world_details_ttl,
                 consumer_key,
                 consumer_secret,
                 access_token,
                 access_secret,
                 on_progress,
+                cancel,
             )
             .await?;
 
@@ -144,6 +251,7 @@ impl DataSyncService for SyncService {
         let db_manager = self.db_manager.clone();
         let client = self.client.clone();
         let secret_service = self.secret_service.clone();
+        let world_details_ttl = self.world_details_ttl;
 
         Box::pin(async move {
             let access_token = match secret_service.get_secret("access_token").await {
@@ -161,14 +269,16 @@ impl DataSyncService for SyncService {
             Self::do_full_sync(
                 db_manager,
                 client,
+                world_details_ttl,
                 consumer_key,
                 consumer_secret,
                 access_token,
                 access_secret,
                 on_progress,
+                CancellationToken::new(),
             )
             .await
-            .map(Some)
+            .map(|(team_id, download_id, _report)| Some((team_id, download_id)))
         })
     }
 
@@ -257,38 +367,136 @@ impl DataSyncService for SyncService {
 }
 
 impl SyncService {
+    /// Re-fetches one player's details, e.g. when a partial sync left them
+    /// with basic data only, and stores the merged player in the team's
+    /// current snapshot rather than creating a new download.
+    pub async fn sync_single_player(
+        db_manager: Arc<DbManager>,
+        client: Arc<dyn ChppClient>,
+        secret_service: Arc<dyn SecretStorageService>,
+        team_id: u32,
+        player_id: u32,
+    ) -> Result<Player, NutmegError> {
+        let access_token = match secret_service.get_secret("access_token").await {
+            Ok(Some(token)) => token,
+            Ok(None) => return Err(NutmegError::Io("Missing access token".to_owned())),
+            Err(e) => return Err(NutmegError::Io(e.to_string())),
+        };
+
+        let access_secret = match secret_service.get_secret("access_secret").await {
+            Ok(Some(secret)) => secret,
+            Ok(None) => return Err(NutmegError::Io("Missing access secret".to_owned())),
+            Err(e) => return Err(NutmegError::Io(e.to_string())),
+        };
+
+        let db = db_manager.clone();
+        let (download_id, basic_player) = tokio::task::spawn_blocking(move || {
+            let mut conn = db.get_connection()?;
+            let download_id: Option<i32> = players::table
+                .filter(players::team_id.eq(team_id as i32))
+                .select(diesel::dsl::max(players::download_id))
+                .first::<Option<i32>>(&mut conn)
+                .context("Failed to get current player download")?;
+            let download_id = download_id.ok_or_else(|| {
+                NutmegError::Db(format!("No players stored for team {}", team_id))
+            })?;
+            let basic_player = get_players_for_team(&mut conn, team_id)?
+                .into_iter()
+                .find(|p| p.PlayerID == player_id)
+                .ok_or_else(|| {
+                    NutmegError::Db(format!(
+                        "Player {} not found in team {}",
+                        player_id, team_id
+                    ))
+                })?;
+            Ok::<_, NutmegError>((download_id, basic_player))
+        })
+        .await??;
+
+        let consumer_key = crate::config::consumer_key();
+        let consumer_secret = crate::config::consumer_secret();
+        let get_auth = || {
+            create_oauth_context(
+                &consumer_key,
+                &consumer_secret,
+                &access_token,
+                &access_secret,
+            )
+        };
+
+        let operation_name = format!("player_details({})", player_id);
+        let detailed_player = retry_with_default_config(&operation_name, get_auth, |data, key| {
+            client.player_details(data, key, player_id)
+        })
+        .await?;
+        let player = basic_player.merge_player_data(Some(detailed_player));
+
+        let db = db_manager.clone();
+        let to_save = player.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut conn = db.get_connection()?;
+            update_player(&mut conn, &to_save, team_id, download_id)
+        })
+        .await??;
+
+        debug!(
+            "Refreshed player {} of team {} in download {}",
+            player_id, team_id, download_id
+        );
+        Ok(player)
+    }
+
+    /// `sync_single_player` with this service's database, client and
+    /// stored credentials.
+    pub async fn refresh_player(
+        &self,
+        team_id: u32,
+        player_id: u32,
+    ) -> Result<Player, NutmegError> {
+        Self::sync_single_player(
+            self.db_manager.clone(),
+            self.client.clone(),
+            self.secret_service.clone(),
+            team_id,
+            player_id,
+        )
+        .await
+    }
+
     async fn create_download_record(db_manager: Arc<DbManager>) -> Result<i32, NutmegError> {
         let db = db_manager.clone();
         tokio::task::spawn_blocking(move || {
             let mut conn = db
                 .get_connection()
-                .map_err(|e| NutmegError::Db(format!("Failed to get database connection: {}", e)))?;
+                .db_context("Failed to get database connection")?;
 
             let timestamp = Utc::now().to_rfc3339();
 
-            diesel::insert_into(downloads::table)
-                .values((
-                    downloads::timestamp.eq(&timestamp),
-                    downloads::status.eq("in_progress"),
-                ))
-                .execute(&mut conn)
-                .map_err(|e| NutmegError::Db(format!("Failed to create download record: {}", e)))?;
+            retry_db(|| {
+                diesel::insert_into(downloads::table)
+                    .values((
+                        downloads::timestamp.eq(&timestamp),
+                        downloads::status.eq("in_progress"),
+                    ))
+                    .execute(&mut conn)
+                    .context("Failed to create download record")
+            })?;
 
             let id: i32 = downloads::table
                 .select(downloads::id)
                 .order(downloads::id.desc())
                 .first(&mut conn)
-                .map_err(|e| NutmegError::Db(format!("Failed to get download ID: {}", e)))?;
+                .context("Failed to get download ID")?;
 
             Ok(id)
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))?
+        .await?
     }
 
-    async fn complete_download_record(
+    async fn set_download_status(
         db_manager: Arc<DbManager>,
         download_id: i32,
+        new_status: &'static str,
     ) -> Result<(), NutmegError> {
         let db = db_manager.clone();
         tokio::task::spawn_blocking(move || {
@@ -296,14 +504,13 @@ impl SyncService {
             use crate::db::schema::downloads::dsl::*;
 
             diesel::update(downloads.filter(id.eq(download_id)))
-                .set(status.eq("completed"))
+                .set(status.eq(new_status))
                 .execute(&mut conn)
-                .map_err(|e| NutmegError::Io(format!("Failed to update download status: {}", e)))?;
+                .io_context("Failed to update download status")?;
 
             Ok::<(), NutmegError>(())
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+        .await??;
         Ok(())
     }
 
@@ -318,29 +525,25 @@ impl SyncService {
         let db = db_manager.clone();
         let endpoint = endpoint.to_string();
         let version = version.to_string();
-        let fetched_date = Utc::now().to_rfc3339();
 
         tokio::task::spawn_blocking(move || {
             let mut conn = db
                 .get_connection()
-                .map_err(|e| NutmegError::Db(format!("Failed to get database connection: {}", e)))?;
+                .db_context("Failed to get database connection")?;
 
-            let entry = NewDownloadEntry {
+            record_download_entry(
+                &mut conn,
                 download_id,
-                endpoint,
-                version,
+                &endpoint,
+                &version,
                 user_id,
-                status: "in_progress".to_string(),
-                fetched_date,
-                error_message: None,
-                retry_count: 0,
-            };
-
-            create_download_entry(&mut conn, entry)
-                .map_err(|e| NutmegError::Db(format!("Failed to create download entry: {}", e)))
+                "in_progress",
+                None,
+                0,
+            )
+            .context("Failed to create download entry")
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))?
+        .await?
     }
 
     /// Update download entry status (success or error).
@@ -362,27 +565,77 @@ impl SyncService {
         tokio::task::spawn_blocking(move || {
             let mut conn = db
                 .get_connection()
-                .map_err(|e| NutmegError::Db(format!("Failed to get database connection: {}", e)))?;
+                .db_context("Failed to get database connection")?;
 
             update_entry_status(&mut conn, entry_id, &status, error_msg, false)
-                .map_err(|e| NutmegError::Db(format!("Failed to update download entry: {}", e)))?;
+                .context("Failed to update download entry")?;
 
             Ok::<(), NutmegError>(())
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))?
+        .await?
     }
 
     /// Downloads user data, including Teams details.
+    ///
+    /// Returns the primary team id, its series id, the ids of all the user's
+    /// teams (primary first) and the user's language.
     async fn fetch_and_save_user_data<F>(
         db_manager: Arc<DbManager>,
         client: Arc<dyn ChppClient>,
         get_auth: &F,
         download_id: i32,
-    ) -> Result<(u32, Option<u32>), NutmegError>
+    ) -> Result<(u32, Option<u32>, Vec<u32>, Option<u32>, Option<u32>), NutmegError>
     where
         F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
     {
+        // The compendium lists every club the manager owns, including any
+        // missing from team details.
+        let compendium_entry_id = Self::log_download_entry(
+            db_manager.clone(),
+            download_id,
+            ChppEndpoints::MANAGER_COMPENDIUM.name,
+            ChppEndpoints::MANAGER_COMPENDIUM.version,
+            None,
+        )
+        .await?;
+
+        let (data, key) = get_auth();
+        let compendium_team_ids = match client.manager_compendium(data, key, None).await {
+            Ok(compendium) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    compendium_entry_id,
+                    "success",
+                    None,
+                )
+                .await?;
+                sync_log!(
+                    info,
+                    download_id,
+                    "Manager {} owns teams {:?}",
+                    compendium.Manager.Loginname,
+                    compendium.Manager.team_ids()
+                );
+                compendium.Manager.team_ids()
+            }
+            Err(e) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    compendium_entry_id,
+                    "error",
+                    Some(e.to_string()),
+                )
+                .await?;
+                sync_log!(
+                    warn,
+                    download_id,
+                    "Failed to fetch manager compendium, falling back to team details: {}",
+                    e
+                );
+                Vec::new()
+            }
+        };
+
         // Log download entry for team_details
         let entry_id = Self::log_download_entry(
             db_manager.clone(),
@@ -411,19 +664,40 @@ impl SyncService {
             }
         };
 
-        info!(
+        sync_log!(
+            info,
+            download_id,
             "User: {} ({})",
-            hattrick_data.User.UserID, hattrick_data.User.Loginname
+            hattrick_data.User.UserID,
+            hattrick_data.User.Loginname
         );
 
         let user = hattrick_data.User;
+        let language_id = user.Language.as_ref().map(|l| l.LanguageID);
         let teams = hattrick_data.Teams.Teams;
 
-        // Uses first team only; multi-team accounts are not currently supported.
-        let team_id: u32 = teams
-            .first()
-            .and_then(|t| t.TeamID.parse().ok())
-            .unwrap_or(0);
+        let primary_team = select_primary_team(&teams)
+            .ok_or_else(|| NutmegError::Parse("No valid primary team found".to_string()))?;
+        let team_id: u32 = primary_team.TeamID.parse().unwrap_or_default();
+        let league_unit_id_opt = primary_team
+            .LeagueLevelUnit
+            .as_ref()
+            .map(|unit| unit.LeagueLevelUnitID);
+
+        let league_id = shared_league_id(&teams);
+
+        let mut team_ids = vec![team_id];
+        let fallback_ids = teams.iter().filter_map(|t| t.TeamID.parse::<u32>().ok());
+        let candidates: Vec<u32> = if compendium_team_ids.is_empty() {
+            fallback_ids.collect()
+        } else {
+            compendium_team_ids
+        };
+        for tid in candidates {
+            if tid != 0 && !team_ids.contains(&tid) {
+                team_ids.push(tid);
+            }
+        }
 
         let db = db_manager.clone();
         let teams_clone = teams.clone();
@@ -431,26 +705,105 @@ impl SyncService {
             let mut conn = db.get_connection()?;
             conn.transaction::<_, NutmegError, _>(|conn| {
                 for team in &teams_clone {
-                    info!("Saving team: {} ({})", team.TeamName, team.TeamID);
+                    sync_log!(
+                        info,
+                        download_id,
+                        "Saving team: {} ({})",
+                        team.TeamName,
+                        team.TeamID
+                    );
                     save_team(conn, team, &user, download_id, true)?;
                 }
                 Ok(())
             })
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
-
-        let league_unit_id_opt = teams
-            .first()
-            .and_then(|t| t.LeagueLevelUnit.as_ref())
-            .map(|unit| unit.LeagueLevelUnitID);
+        .await??;
 
         match league_unit_id_opt {
-            Some(series) => info!("Team {} belongs to series {}", team_id, series),
-            None => warn!("No series found for team {}", team_id),
+            Some(series) => sync_log!(
+                info,
+                download_id,
+                "Team {} belongs to series {}",
+                team_id,
+                series
+            ),
+            None => sync_log!(warn, download_id, "No series found for team {}", team_id),
+        }
+
+        Ok((
+            team_id,
+            league_unit_id_opt,
+            team_ids,
+            language_id,
+            league_id,
+        ))
+    }
+
+    /// Cache Hattrick's denominations for the user's language. They rarely
+    /// change, so a language is only fetched once.
+    async fn fetch_and_save_translations<F>(
+        db_manager: Arc<DbManager>,
+        client: Arc<dyn ChppClient>,
+        get_auth: &F,
+        language_id: u32,
+        download_id: i32,
+    ) -> Result<(), NutmegError>
+    where
+        F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
+    {
+        let db = db_manager.clone();
+        let cached = tokio::task::spawn_blocking(move || {
+            let mut conn = db.get_connection()?;
+            let cached = has_translations(&mut conn, language_id)?;
+            if cached {
+                touch_translations(&mut conn, language_id, download_id)?;
+            }
+            Ok::<_, NutmegError>(cached)
+        })
+        .await??;
+        if cached {
+            sync_log!(
+                debug,
+                download_id,
+                "translations for language {} already cached",
+                language_id
+            );
+            return Ok(());
+        }
+
+        let entry_id = Self::log_download_entry(
+            db_manager.clone(),
+            download_id,
+            ChppEndpoints::TRANSLATIONS.name,
+            ChppEndpoints::TRANSLATIONS.version,
+            None,
+        )
+        .await?;
+
+        let (data, key) = get_auth();
+        match client.translations(data, key, language_id).await {
+            Ok(translations) => {
+                Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
+                let db = db_manager.clone();
+                tokio::task::spawn_blocking(move || {
+                    let mut conn = db.get_connection()?;
+                    save_translations(&mut conn, &translations, download_id)
+                })
+                .await??;
+            }
+            Err(e) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    entry_id,
+                    "error",
+                    Some(e.to_string()),
+                )
+                .await?;
+                sync_log!(warn, download_id, "Failed to fetch translations: {}", e);
+            }
         }
 
-        Ok((team_id, league_unit_id_opt))
+        Ok(())
     }
 
     async fn fetch_and_save_match_data<F>(
@@ -486,8 +839,7 @@ impl SyncService {
                             save_league_details(conn, download_id, &league_details)
                         })
                     })
-                    .await
-                    .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+                    .await??;
                 }
                 Err(e) => {
                     Self::update_download_entry(
@@ -497,7 +849,7 @@ impl SyncService {
                         Some(e.to_string()),
                     )
                     .await?;
-                    warn!("Failed to fetch league details: {}", e);
+                    sync_log!(warn, download_id, "Failed to fetch league details: {}", e);
                 }
             }
         }
@@ -519,8 +871,10 @@ impl SyncService {
             client.matches(data_up, key_up, Some(team_id)),
             client.matches_archive(data_arc, key_arc, Some(team_id), None, None)
         );
-        info!(
-            "[sync] matches fetch (upcoming+archived) for team {}: {:.2}s",
+        sync_log!(
+            info,
+            download_id,
+            "matches fetch (upcoming+archived) for team {}: {:.2}s",
             team_id,
             t_fetch.elapsed().as_secs_f64()
         );
@@ -546,21 +900,28 @@ impl SyncService {
                     Some(e.to_string()),
                 )
                 .await?;
-                warn!(
+                sync_log!(
+                    warn,
+                    download_id,
                     "Failed to fetch upcoming matches for team {}: {}",
-                    team_id, e
+                    team_id,
+                    e
                 );
             }
         }
 
         // 2. Process archived matches
+        let mut archive_to_save = None;
         match archived_res {
             Ok(archived_data) => {
-                debug!(
-                    "[sync] Found {} archived matches for team {}",
+                sync_log!(
+                    debug,
+                    download_id,
+                    "Found {} archived matches for team {}",
                     archived_data.Team.MatchList.Matches.len(),
                     team_id
                 );
+                archive_to_save = Some(archived_data.clone());
                 for mut m in archived_data.Team.MatchList.Matches {
                     if m.Status.is_empty() {
                         m.Status = "FINISHED".to_string();
@@ -571,9 +932,12 @@ impl SyncService {
                 }
             }
             Err(e) => {
-                warn!(
-                    "[sync] Failed to fetch archived matches for team {}: {}",
-                    team_id, e
+                sync_log!(
+                    warn,
+                    download_id,
+                    "Failed to fetch archived matches for team {}: {}",
+                    team_id,
+                    e
                 );
             }
         }
@@ -596,11 +960,18 @@ impl SyncService {
             tokio::task::spawn_blocking(move || {
                 let mut conn = db.get_connection()?;
                 conn.transaction::<_, NutmegError, _>(|conn| {
-                    save_matches(conn, download_id, &matches_to_save)
+                    save_matches(conn, download_id, &matches_to_save)?;
+                    if let Some(archive) = &archive_to_save {
+                        crate::db::matches_archive::save_matches_archive(
+                            conn,
+                            archive,
+                            download_id,
+                        )?;
+                    }
+                    Ok(())
                 })
             })
-            .await
-            .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+            .await??;
         }
 
         Ok(())
@@ -616,8 +987,10 @@ impl SyncService {
     where
         F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
     {
-        info!(
-            "[sync] Starting background form sync for series {}",
+        sync_log!(
+            info,
+            download_id,
+            "Starting background form sync for series {}",
             unit_id
         );
 
@@ -627,12 +1000,13 @@ impl SyncService {
                 let mut conn = db.get_connection()?;
                 crate::db::series::get_league_unit_teams(&mut conn, unit_id)
             })
-            .await
-            .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??
+            .await??
         };
 
-        info!(
-            "[sync] Fetching form matches for {} teams in series {}",
+        sync_log!(
+            info,
+            download_id,
+            "Fetching form matches for {} teams in series {}",
             team_ids.len(),
             unit_id
         );
@@ -652,9 +1026,12 @@ impl SyncService {
                     )
                     .await
                     {
-                        warn!(
-                            "[sync] Failed to fetch form matches for team {}: {}",
-                            tid.0, e
+                        sync_log!(
+                            warn,
+                            download_id,
+                            "Failed to fetch form matches for team {}: {}",
+                            tid.0,
+                            e
                         );
                     }
                 }
@@ -721,8 +1098,7 @@ impl SyncService {
                 save_matches(conn, download_id, &matches_to_save)
             })
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+        .await??;
 
         Ok(())
     }
@@ -731,6 +1107,7 @@ impl SyncService {
         db_manager: Arc<DbManager>,
         client: Arc<dyn ChppClient>,
         get_auth: &F,
+        league_id: Option<u32>,
         download_id: i32,
     ) -> Result<(), NutmegError>
     where
@@ -747,7 +1124,7 @@ impl SyncService {
         .await?;
 
         let (data, key) = get_auth();
-        let world_details = match client.world_details(data, key).await {
+        let world_details = match client.world_details(data, key, league_id).await {
             Ok(data) => {
                 Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
                 data
@@ -764,7 +1141,9 @@ impl SyncService {
             }
         };
 
-        info!(
+        sync_log!(
+            info,
+            download_id,
             "Fetched world details with {} leagues",
             world_details.LeagueList.Leagues.len()
         );
@@ -776,19 +1155,24 @@ impl SyncService {
             let mut conn = db.get_connection()?;
             save_world_details(&mut conn, &wd, download_id)
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+        .await??;
 
         Ok(())
     }
 
+    /// Fetch the team's players and their details, reporting progress from
+    /// `progress.start` to `progress.end` as each player completes.
+    #[allow(clippy::too_many_arguments)]
     async fn fetch_and_save_players<F>(
         db_manager: Arc<DbManager>,
         client: Arc<dyn ChppClient>,
         get_auth: &F,
         team_id: u32,
         download_id: i32,
-    ) -> Result<(), NutmegError>
+        cancel: &CancellationToken,
+        on_progress: &(dyn Fn(f64, &str) + Send + Sync),
+        progress: Range<f64>,
+    ) -> Result<SyncReport, NutmegError>
     where
         // Send is for concurrency, F safe to be sent to another thread, Sync means muliple threads can safely access
         F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
@@ -805,7 +1189,7 @@ impl SyncService {
 
         // Get Players for the team
         let (data, key) = get_auth();
-        let players_resp = match client.players(data, key, None).await {
+        let players_resp = match client.players(data, key, Some(team_id)).await {
             Ok(data) => {
                 Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
                 data
@@ -825,14 +1209,16 @@ impl SyncService {
         let player_list = if let Some(pl) = players_resp.Team.PlayerList {
             pl
         } else {
-            warn!("No player list found for team");
+            sync_log!(warn, download_id, "No player list found for team");
             return Err(NutmegError::Parse("No player list in response".to_string()));
         };
 
-        let players_list = {
+        let (players_list, mut report) = {
             let player_count = player_list.players.len();
-            info!(
-                "[sync] Fetching detailed data for {} players (concurrency=8)",
+            sync_log!(
+                info,
+                download_id,
+                "Fetching detailed data for {} players (concurrency=8)",
                 player_count
             );
             let player_detail_start = Instant::now();
@@ -840,12 +1226,19 @@ impl SyncService {
             use futures::stream::{self, StreamExt};
 
             let mut merged_players = Vec::new();
+            let mut report = SyncReport {
+                total: player_count,
+                ..Default::default()
+            };
 
             let futures = player_list.players.into_iter().map(|basic_player| {
                 let db_manager = db_manager.clone();
                 let client = client.clone();
 
                 async move {
+                    if cancel.is_cancelled() {
+                        return None;
+                    }
                     let player_id = basic_player.PlayerID;
                     let t = Instant::now();
 
@@ -863,7 +1256,7 @@ impl SyncService {
                     {
                         Ok(id) => id,
                         Err(e) => {
-                            warn!("Failed to log download entry: {}", e);
+                            sync_log!(warn, download_id, "Failed to log download entry: {}", e);
                             0
                         }
                     };
@@ -901,23 +1294,30 @@ impl SyncService {
 
                     match result {
                         Ok(detailed_player) => {
-                            debug!(
-                                "[sync] player_details({}): {:.2}s",
+                            sync_log!(
+                                debug,
+                                download_id,
+                                "player_details({}): {:.2}s",
                                 player_id,
                                 t.elapsed().as_secs_f64()
                             );
-                            basic_player
-                                .clone()
-                                .merge_player_data(Some(detailed_player))
+                            Some((
+                                basic_player
+                                    .clone()
+                                    .merge_player_data(Some(detailed_player)),
+                                true,
+                            ))
                         }
                         Err(e) => {
-                            warn!(
-                                "[sync] player_details({}) failed in {:.2}s: {}. Using basic data.",
+                            sync_log!(
+                                warn,
+                                download_id,
+                                "player_details({}) failed in {:.2}s: {}. Using basic data.",
                                 player_id,
                                 t.elapsed().as_secs_f64(),
                                 e
                             );
-                            basic_player.clone().merge_player_data(None)
+                            Some((basic_player.clone().merge_player_data(None), false))
                         }
                     }
                 }
@@ -927,16 +1327,60 @@ impl SyncService {
             // Raising further may trigger HTTP 429 from the CHPP API.
             let mut stream = stream::iter(futures).buffer_unordered(8);
             while let Some(merged) = stream.next().await {
-                merged_players.push(merged);
+                if let Some((player, detailed)) = merged {
+                    if detailed {
+                        report.detailed += 1;
+                    } else {
+                        report.fallback_ids.push(player.PlayerID);
+                    }
+                    let done = merged_players.len() + 1;
+                    on_progress(
+                        progress.start
+                            + (progress.end - progress.start) * done as f64 / player_count as f64,
+                        &format!(
+                            "Fetching player {}/{} ({})",
+                            done, player_count, player.LastName
+                        ),
+                    );
+                    merged_players.push(player);
+                }
             }
+            check_cancelled(cancel)?;
 
-            info!(
-                "[sync] Fetched details for {} players in {:.2}s",
+            sync_log!(
+                info,
+                download_id,
+                "Fetched details for {} players in {:.2}s",
                 player_count,
                 player_detail_start.elapsed().as_secs_f64()
             );
-            merged_players
+            (merged_players, report)
         };
+        report.fallback_ids.sort_unstable();
+
+        if report.is_partial() {
+            let ids: Vec<String> = report
+                .fallback_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect();
+            sync_log!(
+                warn,
+                download_id,
+                "{} of {} players of team {} have basic data only: {}",
+                report.fallback_ids.len(),
+                report.total,
+                team_id,
+                ids.join(", ")
+            );
+            Self::update_download_entry(
+                db_manager.clone(),
+                entry_id,
+                "partial",
+                Some(format!("Basic data only for players {}", ids.join(", "))),
+            )
+            .await?;
+        }
 
         // Save players
         let db = db_manager.clone();
@@ -946,10 +1390,9 @@ impl SyncService {
                 save_players(conn, &players_list, team_id, download_id)
             })
         })
-        .await
-        .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+        .await??;
 
-        Ok(())
+        Ok(report)
     }
 
     pub async fn fetch_and_save_avatars_lazily<F>(
@@ -962,13 +1405,20 @@ impl SyncService {
     where
         F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
     {
-        info!("[sync] Starting avatar fetch for team {}", team_id);
+        sync_log!(
+            info,
+            download_id,
+            "Starting avatar fetch for team {}",
+            team_id
+        );
         let t = Instant::now();
         let (data, key) = get_auth();
 
         let avatars = client.avatars(data, key, Some(team_id)).await?;
-        info!(
-            "[sync] avatars manifest for team {}: {:.2}s ({} players)",
+        sync_log!(
+            info,
+            download_id,
+            "avatars manifest for team {}: {:.2}s ({} players)",
             team_id,
             t.elapsed().as_secs_f64(),
             avatars.team.players.players.len()
@@ -1010,8 +1460,10 @@ impl SyncService {
                 avatars_to_save.push(avatar);
             }
         }
-        info!(
-            "[sync] composited {}/{} avatars for team {} in {:.2}s",
+        sync_log!(
+            info,
+            download_id,
+            "composited {}/{} avatars for team {} in {:.2}s",
             avatars_to_save.len(),
             player_count,
             team_id,
@@ -1025,8 +1477,7 @@ impl SyncService {
                     save_avatars(conn, &avatars_to_save, download_id)
                 })
             })
-            .await
-            .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+            .await??;
         }
 
         let _ = Self::update_download_entry(db_manager, entry_id, "success", None).await;
@@ -1067,13 +1518,11 @@ impl SyncService {
 
                 tokio::task::spawn_blocking(move || {
                     let mut conn = db.get_connection()?;
-                    save_staff(&mut conn, &sl, team_id, download_id)
-                        .map_err(|e| NutmegError::Db(format!("Failed to save staff: {}", e)))
+                    save_staff(&mut conn, &sl, team_id, download_id).context("Failed to save staff")
                 })
-                .await
-                .map_err(|e| NutmegError::Io(format!("Join error: {}", e)))??;
+                .await??;
 
-                info!("Saved {} staff members", staff_count);
+                sync_log!(info, download_id, "Saved {} staff members", staff_count);
             }
             Err(e) => {
                 Self::update_download_entry(
@@ -1083,119 +1532,500 @@ impl SyncService {
                     Some(e.to_string()),
                 )
                 .await?;
-                warn!("Failed to fetch staff list: {}", e);
+                sync_log!(warn, download_id, "Failed to fetch staff list: {}", e);
             }
         }
 
         Ok(())
     }
 
-    async fn do_full_sync(
+    /// Cash and income for the primary team. Failures only skip the
+    /// economy snapshot; they do not fail the sync.
+    async fn fetch_and_save_economy<F>(
         db_manager: Arc<DbManager>,
         client: Arc<dyn ChppClient>,
-        consumer_key: String,
-        consumer_secret: String,
-        access_token: String,
-        access_secret: String,
-        on_progress: ProgressCallback,
-    ) -> Result<(u32, i32), NutmegError> {
-        on_progress(0.0, "Checking credentials...");
-
-        debug!("consumer_key: {}", consumer_key);
-        debug!("access_token length: {}", access_token.len());
-        debug!("access_secret length: {}", access_secret.len());
-
-        let get_auth = || {
-            create_oauth_context(
-                &consumer_key,
-                &consumer_secret,
-                &access_token,
-                &access_secret,
-            )
-        };
-
-        let sync_start = Instant::now();
-        info!("[sync] Starting full sync");
-
-        on_progress(0.05, "Creating download record...");
-        let download_id = Self::create_download_record(db_manager.clone()).await?;
-
-        on_progress(
-            0.1,
-            "Fetching world details (countries, leagues, currencies)...",
-        );
-        let t = Instant::now();
-        Self::fetch_and_save_world_details(
+        get_auth: &F,
+        team_id: u32,
+        download_id: i32,
+    ) -> Result<(), NutmegError>
+    where
+        F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
+    {
+        let entry_id = Self::log_download_entry(
             db_manager.clone(),
-            client.clone(),
-            &get_auth,
             download_id,
+            ChppEndpoints::ECONOMY.name,
+            ChppEndpoints::ECONOMY.version,
+            Some(team_id as i32),
         )
         .await?;
-        info!("[sync] world_details: {:.2}s", t.elapsed().as_secs_f64());
 
-        on_progress(0.5, "Fetching user data...");
-        let t = Instant::now();
-        let (team_id, league_unit_id_opt) = Self::fetch_and_save_user_data(
-            db_manager.clone(),
-            client.clone(),
-            &get_auth,
-            download_id,
-        )
-        .await?;
-        info!(
-            "[sync] user_data (team {}): {:.2}s",
-            team_id,
-            t.elapsed().as_secs_f64()
-        );
+        let (data, key) = get_auth();
+        match client.economy(data, key, Some(team_id)).await {
+            Ok(economy) => {
+                Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
 
-        on_progress(0.6, "Fetching players...");
-        let t = Instant::now();
-        Self::fetch_and_save_players(
-            db_manager.clone(),
-            client.clone(),
-            &get_auth,
-            team_id,
-            download_id,
-        )
-        .await?;
-        info!("[sync] players: {:.2}s", t.elapsed().as_secs_f64());
+                let db = db_manager.clone();
+                tokio::task::spawn_blocking(move || {
+                    let mut conn = db.get_connection()?;
+                    save_economy(&mut conn, &economy, team_id, download_id)
+                        .context("Failed to save economy")
+                })
+                .await??;
 
-        on_progress(0.7, "Fetching staff...");
-        let t = Instant::now();
-        Self::fetch_and_save_staff(
-            db_manager.clone(),
-            client.clone(),
-            &get_auth,
-            team_id,
-            download_id,
-        )
+                sync_log!(info, download_id, "Saved economy for team {}", team_id);
+            }
+            Err(e) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    entry_id,
+                    "error",
+                    Some(e.to_string()),
+                )
+                .await?;
+                sync_log!(warn, download_id, "Failed to fetch economy: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hall of Fame of the primary team. Failures only skip the Hall of
+    /// Fame; they do not fail the sync.
+    async fn fetch_and_save_hof_players<F>(
+        db_manager: Arc<DbManager>,
+        client: Arc<dyn ChppClient>,
+        get_auth: &F,
+        team_id: u32,
+        download_id: i32,
+    ) -> Result<(), NutmegError>
+    where
+        F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
+    {
+        let entry_id = Self::log_download_entry(
+            db_manager.clone(),
+            download_id,
+            ChppEndpoints::HOF_PLAYERS.name,
+            ChppEndpoints::HOF_PLAYERS.version,
+            Some(team_id as i32),
+        )
         .await?;
-        info!("[sync] staff: {:.2}s", t.elapsed().as_secs_f64());
 
-        on_progress(0.8, "Fetching series and matches...");
-        let t = Instant::now();
-        Self::fetch_and_save_match_data(
+        let (data, key) = get_auth();
+        match client.hof_players(data, key, Some(team_id)).await {
+            Ok(hof) => {
+                Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
+
+                let db = db_manager.clone();
+                let hof_count = hof.PlayerList.Players.len();
+                tokio::task::spawn_blocking(move || {
+                    let mut conn = db.get_connection()?;
+                    save_hof_players(&mut conn, &hof, download_id)
+                        .context("Failed to save Hall of Fame")
+                })
+                .await??;
+
+                sync_log!(
+                    info,
+                    download_id,
+                    "Saved {} Hall of Fame players",
+                    hof_count
+                );
+            }
+            Err(e) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    entry_id,
+                    "error",
+                    Some(e.to_string()),
+                )
+                .await?;
+                sync_log!(warn, download_id, "Failed to fetch Hall of Fame: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Achievements of the authenticated user. Failures only skip the
+    /// achievements; they do not fail the sync.
+    async fn fetch_and_save_achievements<F>(
+        db_manager: Arc<DbManager>,
+        client: Arc<dyn ChppClient>,
+        get_auth: &F,
+        download_id: i32,
+    ) -> Result<(), NutmegError>
+    where
+        F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
+    {
+        let entry_id = Self::log_download_entry(
+            db_manager.clone(),
+            download_id,
+            ChppEndpoints::ACHIEVEMENTS.name,
+            ChppEndpoints::ACHIEVEMENTS.version,
+            None,
+        )
+        .await?;
+
+        let (data, key) = get_auth();
+        match client.achievements(data, key, None).await {
+            Ok(achievements) => {
+                Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
+
+                let db = db_manager.clone();
+                let count = achievements.AchievementList.Achievements.len();
+                tokio::task::spawn_blocking(move || {
+                    let mut conn = db.get_connection()?;
+                    save_achievements(&mut conn, &achievements, achievements.UserID, download_id)
+                        .context("Failed to save achievements")
+                })
+                .await??;
+
+                sync_log!(info, download_id, "Saved {} achievements", count);
+            }
+            Err(e) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    entry_id,
+                    "error",
+                    Some(e.to_string()),
+                )
+                .await?;
+                sync_log!(warn, download_id, "Failed to fetch achievements: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Bookmarks of the authenticated user, replacing the stored ones.
+    /// Failures only skip the bookmarks; they do not fail the sync.
+    async fn fetch_and_save_bookmarks<F>(
+        db_manager: Arc<DbManager>,
+        client: Arc<dyn ChppClient>,
+        get_auth: &F,
+        download_id: i32,
+    ) -> Result<(), NutmegError>
+    where
+        F: Fn() -> (OAuthData, SigningKey) + Send + Sync,
+    {
+        let entry_id = Self::log_download_entry(
             db_manager.clone(),
-            client.clone(),
-            &get_auth,
-            team_id,
-            league_unit_id_opt,
             download_id,
+            ChppEndpoints::BOOKMARKS.name,
+            ChppEndpoints::BOOKMARKS.version,
+            None,
         )
         .await?;
-        info!("[sync] match_data: {:.2}s", t.elapsed().as_secs_f64());
 
-        on_progress(0.9, "Finalizing download...");
-        Self::complete_download_record(db_manager.clone(), download_id).await?;
+        let (data, key) = get_auth();
+        match client.bookmarks(data, key).await {
+            Ok(bookmarks) => {
+                Self::update_download_entry(db_manager.clone(), entry_id, "success", None).await?;
+
+                let db = db_manager.clone();
+                let count = bookmarks.BookmarkList.Bookmarks.len();
+                tokio::task::spawn_blocking(move || {
+                    let mut conn = db.get_connection()?;
+                    save_bookmarks(&mut conn, &bookmarks, download_id)
+                        .context("Failed to save bookmarks")
+                })
+                .await??;
+
+                sync_log!(info, download_id, "Saved {} bookmarks", count);
+            }
+            Err(e) => {
+                Self::update_download_entry(
+                    db_manager.clone(),
+                    entry_id,
+                    "error",
+                    Some(e.to_string()),
+                )
+                .await?;
+                sync_log!(warn, download_id, "Failed to fetch bookmarks: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn do_full_sync(
+        db_manager: Arc<DbManager>,
+        client: Arc<dyn ChppClient>,
+        world_details_ttl: Duration,
+        consumer_key: String,
+        consumer_secret: String,
+        access_token: String,
+        access_secret: String,
+        on_progress: ProgressCallback,
+        cancel: CancellationToken,
+    ) -> Result<(u32, i32, SyncReport), NutmegError> {
+        on_progress(0.0, "Checking credentials...");
+
+        debug!("consumer_key: {}", consumer_key);
+        debug!("access_token length: {}", access_token.len());
+        debug!("access_secret length: {}", access_secret.len());
+
+        let get_auth = || {
+            create_oauth_context(
+                &consumer_key,
+                &consumer_secret,
+                &access_token,
+                &access_secret,
+            )
+        };
+
+        let sync_start = Instant::now();
+        on_progress(0.05, "Creating download record...");
+        let download_id = Self::create_download_record(db_manager.clone()).await?;
+        sync_log!(info, download_id, "Starting full sync");
+
+        let result = async {
+            check_cancelled(&cancel)?;
+            on_progress(0.1, "Fetching user data...");
+            let t = Instant::now();
+            let (team_id, league_unit_id_opt, team_ids, language_id, league_id) =
+                Self::fetch_and_save_user_data(
+                    db_manager.clone(),
+                    client.clone(),
+                    &get_auth,
+                    download_id,
+                )
+                .await?;
+            sync_log!(
+                info,
+                download_id,
+                "user_data (team {}): {:.2}s",
+                team_id,
+                t.elapsed().as_secs_f64()
+            );
+
+            check_cancelled(&cancel)?;
+            on_progress(
+                0.5,
+                "Fetching world details (countries, leagues, currencies)...",
+            );
+            let t = Instant::now();
+            let db = db_manager.clone();
+            let (fetch_world, fetch_all_leagues) = tokio::task::spawn_blocking(move || {
+                let mut conn = db.get_connection()?;
+                Ok::<_, NutmegError>((
+                    should_fetch_world_details(&mut conn, world_details_ttl)?,
+                    needs_full_world_details(
+                        &mut conn,
+                        download_id,
+                        FULL_WORLD_DETAILS_MAX_DOWNLOADS,
+                    )?,
+                ))
+            })
+            .await??;
+            if fetch_world || fetch_all_leagues {
+                Self::fetch_and_save_world_details(
+                    db_manager.clone(),
+                    client.clone(),
+                    &get_auth,
+                    league_id.filter(|_| !fetch_all_leagues),
+                    download_id,
+                )
+                .await?;
+                sync_log!(
+                    info,
+                    download_id,
+                    "world_details: {:.2}s",
+                    t.elapsed().as_secs_f64()
+                );
+            } else {
+                sync_log!(
+                    info,
+                    download_id,
+                    "world_details still fresh, reusing last snapshot"
+                );
+            }
+
+            if let Some(language_id) = language_id {
+                check_cancelled(&cancel)?;
+                Self::fetch_and_save_translations(
+                    db_manager.clone(),
+                    client.clone(),
+                    &get_auth,
+                    language_id,
+                    download_id,
+                )
+                .await?;
+            }
+
+            let mut report = SyncReport::default();
+            let team_count = team_ids.len();
+            for (index, tid) in team_ids.into_iter().enumerate() {
+                check_cancelled(&cancel)?;
+                let start = 0.6 + 0.3 * index as f64 / team_count as f64;
+                let end = 0.6 + 0.3 * (index + 1) as f64 / team_count as f64;
+                on_progress(
+                    start,
+                    &format!("Fetching players (team {}/{})...", index + 1, team_count),
+                );
+                let t = Instant::now();
+                let team_report = Self::fetch_and_save_players(
+                    db_manager.clone(),
+                    client.clone(),
+                    &get_auth,
+                    tid,
+                    download_id,
+                    &cancel,
+                    on_progress.as_ref(),
+                    start..end,
+                )
+                .await?;
+                report.absorb(team_report);
+                sync_log!(
+                    info,
+                    download_id,
+                    "players (team {}): {:.2}s",
+                    tid,
+                    t.elapsed().as_secs_f64()
+                );
+            }
+
+            check_cancelled(&cancel)?;
+            on_progress(0.9, "Fetching staff...");
+            let t = Instant::now();
+            Self::fetch_and_save_staff(
+                db_manager.clone(),
+                client.clone(),
+                &get_auth,
+                team_id,
+                download_id,
+            )
+            .await?;
+            sync_log!(
+                info,
+                download_id,
+                "staff: {:.2}s",
+                t.elapsed().as_secs_f64()
+            );
 
-        on_progress(1.0, "Done.");
-        info!(
-            "[sync] Completed (download_id={}) in {:.2}s",
+            check_cancelled(&cancel)?;
+            let t = Instant::now();
+            Self::fetch_and_save_economy(
+                db_manager.clone(),
+                client.clone(),
+                &get_auth,
+                team_id,
+                download_id,
+            )
+            .await?;
+            sync_log!(
+                info,
+                download_id,
+                "economy: {:.2}s",
+                t.elapsed().as_secs_f64()
+            );
+
+            check_cancelled(&cancel)?;
+            let t = Instant::now();
+            Self::fetch_and_save_hof_players(
+                db_manager.clone(),
+                client.clone(),
+                &get_auth,
+                team_id,
+                download_id,
+            )
+            .await?;
+            sync_log!(
+                info,
+                download_id,
+                "hof_players: {:.2}s",
+                t.elapsed().as_secs_f64()
+            );
+
+            check_cancelled(&cancel)?;
+            let t = Instant::now();
+            Self::fetch_and_save_achievements(
+                db_manager.clone(),
+                client.clone(),
+                &get_auth,
+                download_id,
+            )
+            .await?;
+            sync_log!(
+                info,
+                download_id,
+                "achievements: {:.2}s",
+                t.elapsed().as_secs_f64()
+            );
+
+            check_cancelled(&cancel)?;
+            let t = Instant::now();
+            Self::fetch_and_save_bookmarks(
+                db_manager.clone(),
+                client.clone(),
+                &get_auth,
+                download_id,
+            )
+            .await?;
+            sync_log!(
+                info,
+                download_id,
+                "bookmarks: {:.2}s",
+                t.elapsed().as_secs_f64()
+            );
+
+            check_cancelled(&cancel)?;
+            on_progress(0.93, "Fetching series and matches...");
+            let t = Instant::now();
+            Self::fetch_and_save_match_data(
+                db_manager.clone(),
+                client.clone(),
+                &get_auth,
+                team_id,
+                league_unit_id_opt,
+                download_id,
+            )
+            .await?;
+            sync_log!(
+                info,
+                download_id,
+                "match_data: {:.2}s",
+                t.elapsed().as_secs_f64()
+            );
+
+            Ok::<_, NutmegError>((team_id, report))
+        }
+        .await;
+
+        let (team_id, report) = match result {
+            Ok(outcome) => outcome,
+            Err(NutmegError::Cancelled) => {
+                sync_log!(info, download_id, "Cancelled");
+                Self::set_download_status(db_manager.clone(), download_id, "cancelled").await?;
+                return Err(NutmegError::Cancelled);
+            }
+            Err(e) => return Err(e),
+        };
+
+        on_progress(0.97, "Finalizing download...");
+        Self::set_download_status(db_manager.clone(), download_id, "completed").await?;
+
+        if report.is_partial() {
+            on_progress(
+                1.0,
+                &format!(
+                    "Done, {} of {} players with basic data only.",
+                    report.fallback_ids.len(),
+                    report.total
+                ),
+            );
+        } else {
+            on_progress(1.0, "Done.");
+        }
+        sync_log!(
+            info,
             download_id,
+            "Completed in {:.2}s",
             sync_start.elapsed().as_secs_f64()
         );
-        Ok((team_id, download_id))
+        Ok((team_id, download_id, report))
     }
 }
 
@@ -1206,10 +2036,25 @@ mod tests {
     use crate::db::manager::DbManager;
     use async_trait::async_trait;
     use oauth_1a::{OAuthData, SigningKey};
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use crate::service::secret::MockSecretService;
 
-    struct MockChppClient;
+    #[derive(Default)]
+    struct MockChppClient {
+        player_details_calls: AtomicUsize,
+        /// When set, cancelled as soon as `player_details` is first called.
+        cancel_on_player_details: Option<CancellationToken>,
+        /// Additional players returned for the primary team.
+        extra_players: u32,
+        /// Skills returned by `player_details`.
+        detailed_skills: Option<PlayerSkills>,
+        /// When set, the compendium lists the secondary team first.
+        compendium_secondary_first: bool,
+    }
+
+    /// `player_details` fails for this player (the secondary team's only player).
+    const UNAVAILABLE_PLAYER_ID: u32 = 2000;
 
     #[async_trait]
     impl ChppClient for MockChppClient {
@@ -1217,6 +2062,7 @@ mod tests {
             &self,
             _data: OAuthData,
             _key: SigningKey,
+            _league_id: Option<u32>,
         ) -> Result<WorldDetails, NutmegError> {
             Ok(WorldDetails {
                 LeagueList: WorldLeagueList {
@@ -1266,6 +2112,7 @@ mod tests {
                     ActivationDate: "2000-01-01 00:00:00".to_string(),
                     LastLoginDate: "2020-01-01 00:00:00".to_string(),
                     HasManagerLicense: false,
+                    NationalTeams: None,
                     SupporterTier: SupporterTier::None,
                     Language: Some(Language {
                         LanguageID: 2,
@@ -1273,41 +2120,51 @@ mod tests {
                     }),
                 },
                 Teams: Teams {
-                    Teams: vec![Team {
-                        TeamID: "54321".to_string(),
-                        TeamName: "Test Team".to_string(),
-                        ShortTeamName: Some("TT".to_string()),
-                        IsPrimaryClub: Some(true),
-                        FoundedDate: None,
-                        IsDeactivated: None,
-                        Arena: None,
-                        League: None,
-                        Country: None,
-                        Region: None,
-                        Trainer: None,
-                        HomePage: None,
-                        Cup: None,
-                        PowerRating: None,
-                        FriendlyTeamID: None,
-                        LeagueLevelUnit: None,
-                        NumberOfVictories: None,
-                        NumberOfUndefeated: None,
-                        Fanclub: None,
-                        LogoURL: None,
-                        TeamColors: None,
-                        DressURI: None,
-                        DressAlternateURI: None,
-                        BotStatus: None,
-                        TeamRank: None,
-                        YouthTeamID: None,
-                        YouthTeamName: None,
-                        NumberOfVisits: None,
-                        //                      TrophyList: None,
-                        PlayerList: None,
-                        PossibleToChallengeMidweek: None,
-                        PossibleToChallengeWeekend: None,
-                        GenderID: Some(1),
-                    }],
+                    Teams: vec![
+                        Team {
+                            TeamID: "54321".to_string(),
+                            TeamName: "Test Team".to_string(),
+                            ShortTeamName: Some("TT".to_string()),
+                            IsPrimaryClub: Some(true),
+                            FoundedDate: None,
+                            IsDeactivated: None,
+                            Arena: None,
+                            League: None,
+                            Country: None,
+                            Region: None,
+                            Trainer: None,
+                            HomePage: None,
+                            Cup: None,
+                            PowerRating: None,
+                            FriendlyTeamID: None,
+                            LeagueLevelUnit: None,
+                            NumberOfVictories: None,
+                            NumberOfUndefeated: None,
+                            Fanclub: None,
+                            LogoURL: None,
+                            TeamColors: None,
+                            DressURI: None,
+                            DressAlternateURI: None,
+                            BotStatus: None,
+                            TeamRank: None,
+                            YouthTeamID: None,
+                            YouthTeamName: None,
+                            NumberOfVisits: None,
+                            //                      TrophyList: None,
+                            PlayerList: None,
+                            PossibleToChallengeMidweek: None,
+                            PossibleToChallengeWeekend: None,
+                            GenderID: Some(1),
+                            Guestbook: None,
+                        },
+                        Team {
+                            TeamID: "54322".to_string(),
+                            TeamName: "Test Team II".to_string(),
+                            IsPrimaryClub: Some(false),
+                            GenderID: Some(1),
+                            ..Default::default()
+                        },
+                    ],
                 },
             })
         }
@@ -1316,11 +2173,16 @@ mod tests {
             &self,
             _data: OAuthData,
             _key: SigningKey,
-            _team_id: Option<u32>,
+            team_id: Option<u32>,
         ) -> Result<PlayersData, NutmegError> {
-            Ok(PlayersData {
+            // One distinct player per team so multi-team syncs can be told apart.
+            let player_id = match team_id {
+                Some(54322) => 2000,
+                _ => 1000,
+            };
+            let mut data = PlayersData {
                 Team: Team {
-                    TeamID: "123".to_string(),
+                    TeamID: team_id.unwrap_or(123).to_string(),
                     TeamName: "Test FC".to_string(),
                     ShortTeamName: Some("TFC".to_string()),
                     IsPrimaryClub: Some(true),
@@ -1350,9 +2212,10 @@ mod tests {
                     NumberOfVisits: None,
                     //               TrophyList: None,
                     GenderID: Some(1),
+                    Guestbook: None,
                     PlayerList: Some(PlayerList {
                         players: vec![Player {
-                            PlayerID: 1000,
+                            PlayerID: player_id,
                             FirstName: "Test".to_string(),
                             LastMatch: None,
                             AvatarBlob: None,
@@ -1398,6 +2261,7 @@ mod tests {
                             MatchesCurrentTeam: None,
                             GoalsCurrentTeam: None,
                             AssistsCurrentTeam: None,
+                            TransferDetails: None,
                             CareerAssists: None,
                             ArrivalDate: None,
                             PlayerSkills: None,
@@ -1406,17 +2270,36 @@ mod tests {
                     PossibleToChallengeMidweek: None,
                     PossibleToChallengeWeekend: None,
                 },
-            })
+            };
+            if team_id != Some(54322) {
+                if let Some(list) = data.Team.PlayerList.as_mut() {
+                    let base = list.players[0].clone();
+                    for i in 1..=self.extra_players {
+                        list.players.push(Player {
+                            PlayerID: player_id + i,
+                            ..base.clone()
+                        });
+                    }
+                }
+            }
+            Ok(data)
         }
 
         async fn player_details(
             &self,
             _data: OAuthData,
             _key: SigningKey,
-            _player_id: u32,
+            player_id: u32,
         ) -> Result<Player, NutmegError> {
+            self.player_details_calls.fetch_add(1, Ordering::SeqCst);
+            if let Some(cancel) = &self.cancel_on_player_details {
+                cancel.cancel();
+            }
+            if player_id == UNAVAILABLE_PLAYER_ID {
+                return Err(NutmegError::Parse("Player not available".to_string()));
+            }
             Ok(Player {
-                PlayerID: 1001,
+                PlayerID: player_id,
                 FirstName: "John".to_string(),
                 LastName: "Doe".to_string(),
                 LastMatch: None,
@@ -1462,8 +2345,9 @@ mod tests {
                 MatchesCurrentTeam: None,
                 GoalsCurrentTeam: None,
                 AssistsCurrentTeam: None,
+                TransferDetails: None,
                 CareerAssists: None,
-                PlayerSkills: None,
+                PlayerSkills: self.detailed_skills.clone(),
                 ArrivalDate: None,
             })
         }
@@ -1549,8 +2433,8 @@ mod tests {
             _data: OAuthData,
             _key: SigningKey,
             _team_id: Option<u32>,
-            _first_match_date: Option<String>,
-            _last_match_date: Option<String>,
+            _first_match_date: Option<chrono::NaiveDate>,
+            _last_match_date: Option<chrono::NaiveDate>,
         ) -> Result<MatchesArchiveData, NutmegError> {
             // Return empty archive in tests
             Ok(MatchesArchiveData {
@@ -1583,6 +2467,391 @@ mod tests {
         ) -> Result<MatchLineupData, NutmegError> {
             unimplemented!()
         }
+
+        async fn alliances(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _search_name: &str,
+        ) -> Result<AllianceSearchResults, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn alliance_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _alliance_id: u32,
+        ) -> Result<AllianceDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn transfers_player(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _player_id: u32,
+        ) -> Result<PlayerTransfers, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn player_events(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _player_id: u32,
+        ) -> Result<PlayerEvents, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn current_bids(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+        ) -> Result<CurrentBids, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _search_type: SearchType,
+            _query: &str,
+        ) -> Result<SearchResults, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn region_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _region_id: u32,
+        ) -> Result<RegionDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn world_languages(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+        ) -> Result<WorldLanguages, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn manager_compendium(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _user_id: Option<u32>,
+        ) -> Result<ManagerCompendium, NutmegError> {
+            let mut teams = vec![
+                CompendiumTeam {
+                    TeamId: 54321,
+                    TeamName: "Test Team".to_string(),
+                    ..Default::default()
+                },
+                CompendiumTeam {
+                    TeamId: 54322,
+                    TeamName: "Second Team".to_string(),
+                    ..Default::default()
+                },
+            ];
+            if self.compendium_secondary_first {
+                teams.reverse();
+            }
+            Ok(ManagerCompendium {
+                Manager: Manager {
+                    UserId: 999,
+                    Loginname: "testuser".to_string(),
+                    Teams: CompendiumTeamList { Teams: teams },
+                    ..Default::default()
+                },
+            })
+        }
+
+        async fn cup_matches(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: Option<u32>,
+        ) -> Result<CupMatches, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn training_events(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _player_id: u32,
+        ) -> Result<TrainingEvents, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn arena_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _arena_id: Option<u32>,
+        ) -> Result<ArenaDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn economy(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            team_id: Option<u32>,
+        ) -> Result<Economy, NutmegError> {
+            Ok(Economy {
+                Team: EconomyTeam {
+                    TeamID: team_id.unwrap_or_default(),
+                    TeamName: "Mock Team".to_string(),
+                    Cash: 1_000_000,
+                    ..Default::default()
+                },
+            })
+        }
+
+        async fn league_fixtures(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _league_level_unit_id: u32,
+            _season: Option<u32>,
+        ) -> Result<LeagueFixtures, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn transfers_team(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: u32,
+            _page: u32,
+        ) -> Result<TeamTransfers, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn translations(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            language_id: u32,
+        ) -> Result<Translations, NutmegError> {
+            Ok(Translations {
+                Texts: TranslationTexts {
+                    LanguageID: language_id,
+                    ..Default::default()
+                },
+            })
+        }
+
+        async fn world_cup(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _cup_id: u32,
+            _season: Option<u32>,
+        ) -> Result<WorldCup, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn hof_players(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            team_id: Option<u32>,
+        ) -> Result<HofPlayers, NutmegError> {
+            Ok(HofPlayers {
+                TeamID: team_id.unwrap_or(54321),
+                PlayerList: HofPlayerList {
+                    Players: vec![HofPlayer {
+                        PlayerId: 900,
+                        FirstName: "Old".to_string(),
+                        LastName: "Legend".to_string(),
+                        Age: 40,
+                        HofDate: "2025-06-14 08:00:00".to_string(),
+                        KeeperSkill: Some(17),
+                        ..Default::default()
+                    }],
+                },
+            })
+        }
+
+        async fn bookmarks(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+        ) -> Result<Bookmarks, NutmegError> {
+            Ok(Bookmarks {
+                BookmarkList: BookmarkList {
+                    Bookmarks: vec![Bookmark {
+                        BookmarkID: 1,
+                        BookmarkTypeID: 1,
+                        Text: "Rival FC".to_string(),
+                        ObjectID: 777,
+                        ..Default::default()
+                    }],
+                },
+            })
+        }
+
+        async fn challenges(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: u32,
+            _is_weekend: bool,
+        ) -> Result<Challenges, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn tournament_list(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _user_id: Option<u32>,
+        ) -> Result<TournamentList, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn tournament_details(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _tournament_id: u32,
+        ) -> Result<TournamentDetails, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn achievements(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _user_id: Option<u32>,
+        ) -> Result<Achievements, NutmegError> {
+            let achievement = |id: u32, points: u32| Achievement {
+                AchievementTypeID: id,
+                AchievementTitle: format!("Achievement {}", id),
+                CategoryID: 1,
+                EventDate: "2026-01-10 12:00:00".to_string(),
+                Points: points,
+                ..Default::default()
+            };
+            Ok(Achievements {
+                UserID: 12345,
+                MaxPoints: None,
+                AchievementList: AchievementList {
+                    Achievements: vec![achievement(1, 50), achievement(2, 25)],
+                },
+            })
+        }
+
+        async fn supporters(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _team_id: Option<u32>,
+        ) -> Result<Supporters, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn live(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _match_ids: &[u32],
+            _action_type: LiveActionType,
+        ) -> Result<LiveData, NutmegError> {
+            unimplemented!()
+        }
+
+        async fn transfer_search(
+            &self,
+            _data: OAuthData,
+            _key: SigningKey,
+            _criteria: TransferSearchCriteria,
+        ) -> Result<TransferSearchResults, NutmegError> {
+            unimplemented!()
+        }
+    }
+
+    fn team(id: &str, primary: Option<bool>) -> Team {
+        Team {
+            TeamID: id.to_string(),
+            IsPrimaryClub: primary,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_select_primary_team_prefers_primary_club() {
+        let teams = vec![team("100", Some(false)), team("200", Some(true))];
+        let selected = select_primary_team(&teams).expect("Expected a team");
+        assert_eq!(selected.TeamID, "200");
+    }
+
+    #[test]
+    fn test_select_primary_team_falls_back_to_first() {
+        let teams = vec![
+            team("abc", Some(true)),
+            team("100", None),
+            team("200", None),
+        ];
+        let selected = select_primary_team(&teams).expect("Expected a team");
+        assert_eq!(selected.TeamID, "100");
+    }
+
+    #[test]
+    fn test_log_scope_prefixes_run_id() {
+        assert_eq!(
+            log_scope(42, "players (team 1000): 1.20s"),
+            "[sync 42] players (team 1000): 1.20s"
+        );
+    }
+
+    fn team_in_league(id: &str, league_id: Option<u32>) -> Team {
+        Team {
+            League: league_id.map(|lid| League {
+                LeagueID: lid,
+                ..Default::default()
+            }),
+            ..team(id, None)
+        }
+    }
+
+    #[test]
+    fn test_shared_league_id() {
+        assert_eq!(shared_league_id(&[]), None);
+        assert_eq!(
+            shared_league_id(&[team_in_league("100", Some(21))]),
+            Some(21)
+        );
+        assert_eq!(
+            shared_league_id(&[
+                team_in_league("100", Some(21)),
+                team_in_league("200", Some(21))
+            ]),
+            Some(21)
+        );
+        assert_eq!(
+            shared_league_id(&[
+                team_in_league("100", Some(21)),
+                team_in_league("200", Some(5))
+            ]),
+            None
+        );
+        assert_eq!(
+            shared_league_id(&[team_in_league("100", Some(21)), team_in_league("200", None)]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_select_primary_team_empty() {
+        assert!(select_primary_team(&[]).is_none());
+        assert!(select_primary_team(&[team("0", Some(true))]).is_none());
     }
 
     #[tokio::test]
@@ -1590,7 +2859,7 @@ mod tests {
         let db_manager = Arc::new(DbManager::from_url(":memory:"));
         db_manager.run_migrations().expect("Migrations failed");
 
-        let client = Arc::new(MockChppClient);
+        let client = Arc::new(MockChppClient::default());
         let service = SyncService::new_with_client(
             db_manager.clone(),
             client,
@@ -1604,6 +2873,7 @@ mod tests {
                 "dummy_token".into(),
                 "dummy_secret_val".into(),
                 Box::new(|_, _| {}),
+                CancellationToken::new(),
             )
             .await;
 
@@ -1615,4 +2885,463 @@ mod tests {
 
         // Could verify more details here if needed, like specific data presence
     }
+
+    #[tokio::test]
+    async fn test_sync_fetches_players_for_all_teams() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient::default()),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let (team_id, _, _) = service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+        assert_eq!(team_id, 54321);
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let primary = crate::db::teams::get_players_for_team(&mut conn, 54321)
+            .expect("Failed to load primary team players");
+        let secondary = crate::db::teams::get_players_for_team(&mut conn, 54322)
+            .expect("Failed to load secondary team players");
+
+        assert_eq!(primary.len(), 1);
+        assert_eq!(primary[0].PlayerID, 1000);
+        assert_eq!(secondary.len(), 1);
+        assert_eq!(secondary[0].PlayerID, 2000);
+    }
+
+    #[tokio::test]
+    async fn test_sync_primary_team_from_primary_club_flag() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient {
+                compendium_secondary_first: true,
+                ..Default::default()
+            }),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let (team_id, _, _) = service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+        assert_eq!(team_id, 54321);
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let secondary = crate::db::teams::get_players_for_team(&mut conn, 54322)
+            .expect("Failed to load secondary team players");
+        assert_eq!(secondary.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_saves_hall_of_fame() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient::default()),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let (team_id, _, _) = service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let hof = crate::db::hof_players::get_hof_players(&mut conn, team_id)
+            .expect("Failed to load Hall of Fame");
+        assert_eq!(hof.len(), 1);
+        assert_eq!(hof[0].PlayerId, 900);
+        assert_eq!(hof[0].KeeperSkill, Some(17));
+    }
+
+    #[tokio::test]
+    async fn test_sync_saves_achievements() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient::default()),
+            Arc::new(MockSecretService::new()),
+        );
+
+        service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let points = crate::db::achievements::total_achievement_points(&mut conn, 12345)
+            .expect("Failed to sum achievement points");
+        assert_eq!(points, 75);
+    }
+
+    #[tokio::test]
+    async fn test_sync_saves_bookmarks() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient::default()),
+            Arc::new(MockSecretService::new()),
+        );
+
+        service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let bookmarks =
+            crate::db::bookmarks::get_bookmarks(&mut conn).expect("Failed to load bookmarks");
+        assert_eq!(bookmarks.len(), 1);
+        assert_eq!(bookmarks[0].ObjectID, 777);
+    }
+
+    #[tokio::test]
+    async fn test_sync_single_player_persists_skills() {
+        use crate::db::download_entries::create_download;
+
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let download_id = {
+            let mut conn = db_manager.get_connection().expect("conn");
+            let download_id =
+                create_download(&mut conn, "2026-03-01T00:00:00Z", "completed").unwrap();
+            let basic = Player {
+                PlayerID: 7,
+                FirstName: "John".to_string(),
+                LastName: "Doe".to_string(),
+                ..Default::default()
+            };
+            save_players(&mut conn, &[basic], 300, download_id).unwrap();
+            download_id
+        };
+
+        let secrets = Arc::new(MockSecretService::new());
+        secrets.store_secret("access_token", "token").await.unwrap();
+        secrets
+            .store_secret("access_secret", "secret")
+            .await
+            .unwrap();
+
+        let client = Arc::new(MockChppClient {
+            detailed_skills: Some(PlayerSkills {
+                ScorerSkill: 7,
+                PlaymakerSkill: 5,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let player =
+            SyncService::sync_single_player(db_manager.clone(), client.clone(), secrets, 300, 7)
+                .await
+                .expect("Refresh failed");
+        assert_eq!(client.player_details_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(player.PlayerSkills.as_ref().map(|s| s.ScorerSkill), Some(7));
+
+        let mut conn = db_manager.get_connection().expect("conn");
+        let stored = get_players_for_team(&mut conn, 300).unwrap();
+        assert_eq!(stored.len(), 1);
+        let skills = stored[0].PlayerSkills.as_ref().expect("Skills persisted");
+        assert_eq!(skills.ScorerSkill, 7);
+        assert_eq!(skills.PlaymakerSkill, 5);
+        // Every refreshed field is stored, not only the skills.
+        assert_eq!(stored[0].TSI, 1000);
+        assert_eq!(stored[0].PlayerForm, 5);
+        assert_eq!(stored[0].Salary, 500);
+
+        // Updated in place: no new snapshot was created.
+        let downloads: Vec<i32> = players::table
+            .select(players::download_id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(downloads, vec![download_id]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_single_player_unknown_player() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let secrets = Arc::new(MockSecretService::new());
+        secrets.store_secret("access_token", "token").await.unwrap();
+        secrets
+            .store_secret("access_secret", "secret")
+            .await
+            .unwrap();
+        let client = Arc::new(MockChppClient::default());
+
+        let result =
+            SyncService::sync_single_player(db_manager, client.clone(), secrets, 300, 7).await;
+        assert!(matches!(result, Err(NutmegError::Db(_))));
+        assert_eq!(client.player_details_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_player_details_recorded_as_error() {
+        use crate::db::download_entries::get_entries_for_download;
+
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient::default()),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let (_, download_id, _) = service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let entries =
+            get_entries_for_download(&mut conn, download_id).expect("Failed to load entries");
+
+        let details: Vec<_> = entries
+            .iter()
+            .filter(|e| e.endpoint == ChppEndpoints::PLAYER_DETAILS.name)
+            .collect();
+        assert_eq!(details.len(), 2);
+        assert!(details
+            .iter()
+            .all(|e| e.version == ChppEndpoints::PLAYER_DETAILS.version));
+
+        let failed: Vec<_> = details.iter().filter(|e| e.status == "error").collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(
+            failed[0].error_message.as_deref(),
+            Some("Parse error: Player not available")
+        );
+
+        for endpoint in [
+            ChppEndpoints::WORLD_DETAILS.name,
+            ChppEndpoints::MANAGER_COMPENDIUM.name,
+            ChppEndpoints::TEAM_DETAILS.name,
+            ChppEndpoints::PLAYERS.name,
+        ] {
+            assert!(
+                entries
+                    .iter()
+                    .any(|e| e.endpoint == endpoint && e.status == "success"),
+                "Missing successful entry for {}",
+                endpoint
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_report_lists_fallback_players() {
+        use crate::db::download_entries::get_entries_for_download;
+
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient {
+                extra_players: 2,
+                ..Default::default()
+            }),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let (_, download_id, report) = service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+
+        assert_eq!(
+            report,
+            SyncReport {
+                total: 4,
+                detailed: 3,
+                fallback_ids: vec![UNAVAILABLE_PLAYER_ID],
+            }
+        );
+        assert!(report.is_partial());
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let entries =
+            get_entries_for_download(&mut conn, download_id).expect("Failed to load entries");
+        let partial: Vec<_> = entries
+            .iter()
+            .filter(|e| e.endpoint == ChppEndpoints::PLAYERS.name && e.status == "partial")
+            .collect();
+        assert_eq!(partial.len(), 1);
+        assert_eq!(
+            partial[0].error_message.as_deref(),
+            Some("Basic data only for players 2000")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stops_player_details_calls() {
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let cancel = CancellationToken::new();
+        let client = Arc::new(MockChppClient {
+            cancel_on_player_details: Some(cancel.clone()),
+            ..Default::default()
+        });
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            client.clone(),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let res = service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(|_, _| {}),
+                cancel,
+            )
+            .await;
+
+        assert!(matches!(res, Err(NutmegError::Cancelled)));
+        // Each team has a single player, so the second team is never fetched.
+        assert_eq!(client.player_details_calls.load(Ordering::SeqCst), 1);
+
+        let mut conn = db_manager
+            .get_connection()
+            .expect("Failed to get connection");
+        let status: String = downloads::table
+            .select(downloads::status)
+            .first(&mut conn)
+            .expect("Failed to load download");
+        assert_eq!(status, "cancelled");
+    }
+
+    #[tokio::test]
+    async fn test_player_fetch_reports_progress() {
+        use std::sync::Mutex;
+
+        let db_manager = Arc::new(DbManager::from_url(":memory:"));
+        db_manager.run_migrations().expect("Migrations failed");
+
+        let service = SyncService::new_with_client(
+            db_manager.clone(),
+            Arc::new(MockChppClient {
+                extra_players: 2,
+                ..Default::default()
+            }),
+            Arc::new(MockSecretService::new()),
+        );
+
+        let events = Arc::new(Mutex::new(Vec::<(f64, String)>::new()));
+        let recorder = events.clone();
+        service
+            .perform_initial_sync(
+                "dummy_key".into(),
+                "dummy_secret".into(),
+                "dummy_token".into(),
+                "dummy_secret_val".into(),
+                Box::new(move |fraction, message| {
+                    recorder
+                        .lock()
+                        .unwrap()
+                        .push((fraction, message.to_string()));
+                }),
+                CancellationToken::new(),
+            )
+            .await
+            .expect("Sync failed");
+
+        let events = events.lock().unwrap();
+        assert!(events.windows(2).all(|w| w[0].0 <= w[1].0 + 1e-9));
+
+        let player_events: Vec<_> = events
+            .iter()
+            .filter(|(_, message)| message.starts_with("Fetching player "))
+            .collect();
+        // Three players on the primary team, one on the secondary team.
+        assert_eq!(player_events.len(), 4);
+        assert!(player_events.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(player_events[2].1, "Fetching player 3/3 (Doe)");
+        assert_eq!(player_events[3].1, "Fetching player 1/1 (Player)");
+        assert!((player_events[3].0 - 0.9).abs() < 1e-9);
+    }
 }
@@ -20,14 +20,16 @@
 
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
-use gtk::{glib, Button, Entry, Label, ProgressBar, Stack};
-use log::{debug, error, info};
+use gtk::{glib, Button, CheckButton, Entry, Label, ProgressBar, Stack};
+use log::{debug, error, info, warn};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 use crate::config::{consumer_key, consumer_secret};
 use crate::db::manager::DbManager;
+use crate::error::NutmegError;
 use crate::service::sync::SyncService;
 use crate::window::NutmegWindow;
 
@@ -46,6 +48,8 @@ mod imp {
         #[template_child]
         pub btn_browser: TemplateChild<Button>,
         #[template_child]
+        pub chk_match_orders: TemplateChild<CheckButton>,
+        #[template_child]
         pub entry_code: TemplateChild<Entry>,
         #[template_child]
         pub btn_verify: TemplateChild<Button>,
@@ -53,6 +57,8 @@ mod imp {
         pub lbl_status: TemplateChild<Label>,
         #[template_child]
         pub progress_bar: TemplateChild<ProgressBar>,
+        #[template_child]
+        pub btn_cancel: TemplateChild<Button>,
     }
 
     #[glib::object_subclass]
@@ -92,7 +98,8 @@ impl SetupWindow {
     }
 
     pub fn setup_signals(&self) {
-        use crate::service::auth::{AuthenticationService, HattrickAuthService};
+        use crate::chpp::{normalize_verification_code, ChppScope};
+        use crate::service::auth::HattrickAuthService;
         use crate::service::secret::{SecretStorageService, SystemSecretService};
         use crate::service::sync::DataSyncService;
 
@@ -111,22 +118,36 @@ impl SetupWindow {
         // Btn Browser -> Page 3
         let auth_state_clone = auth_state.clone();
         let stack = imp.stack.clone();
+        let chk_match_orders = imp.chk_match_orders.clone();
 
         imp.btn_browser.connect_clicked(move |_| {
             debug!("Browser button clicked");
             let state = auth_state_clone.clone();
             let stack = stack.clone();
 
+            // Read-only unless the user opted in to match orders.
+            let scopes = if chk_match_orders.is_active() {
+                vec![ChppScope::SetMatchOrder]
+            } else {
+                Vec::new()
+            };
+            match DbManager::new().get_connection() {
+                Ok(mut conn) => {
+                    if let Err(e) = crate::db::preferences::set_chpp_scopes(&mut conn, &scopes) {
+                        warn!("Failed to save CHPP scopes: {}", e);
+                    }
+                }
+                Err(e) => warn!("Failed to save CHPP scopes: {}", e),
+            }
+
             glib::MainContext::default().spawn_local(async move {
                 // Call AuthService
-                let res = tokio::task::spawn_blocking(move || {
-                    let service = HattrickAuthService;
-                    service.get_authorization_url()
-                })
-                .await;
+                let res = HattrickAuthService::with_scopes(scopes)
+                    .get_authorization_url_async()
+                    .await;
 
                 match res {
-                    Ok(Ok((url, rt, rs))) => {
+                    Ok((url, rt, rs)) => {
                         info!("Got auth URL: {}", url);
                         let mut data = state.borrow_mut();
                         data.0 = Some(rt);
@@ -138,8 +159,7 @@ impl SetupWindow {
                         }
                         stack.set_visible_child_name("page3");
                     }
-                    Ok(Err(e)) => error!("Auth error: {}", e),
-                    Err(e) => error!("Task join error: {}", e),
+                    Err(e) => error!("Auth error: {}", e),
                 }
             });
         });
@@ -151,15 +171,39 @@ impl SetupWindow {
         let window = self.clone();
         let progress_bar = imp.progress_bar.clone();
         let lbl_status = imp.lbl_status.clone();
+        let running_sync: Rc<RefCell<Option<CancellationToken>>> = Rc::new(RefCell::new(None));
+
+        // Btn Cancel -> abort the running sync, back to Page 3
+        let running_sync_clone = running_sync.clone();
+        imp.btn_cancel.connect_clicked(move |_| {
+            if let Some(cancel) = running_sync_clone.borrow().as_ref() {
+                info!("Cancelling initial sync");
+                cancel.cancel();
+            }
+        });
 
         imp.btn_verify.connect_clicked(move |_| {
             debug!("Verify button clicked");
-            let code = entry.text().to_string();
+            let code = match normalize_verification_code(&entry.text()) {
+                Ok(code) => {
+                    entry.remove_css_class("error");
+                    entry.set_tooltip_text(None);
+                    code
+                }
+                Err(e) => {
+                    warn!("Invalid verification code: {}", e);
+                    entry.add_css_class("error");
+                    entry.set_tooltip_text(Some(&e.to_string()));
+                    return;
+                }
+            };
             let state = auth_state_clone2.clone();
             let stack = stack.clone();
             let win = window.clone();
             let progress_bar = progress_bar.clone();
             let lbl_status = lbl_status.clone();
+            let cancel = CancellationToken::new();
+            running_sync.replace(Some(cancel.clone()));
 
             stack.set_visible_child_name("page4");
 
@@ -178,17 +222,13 @@ impl SetupWindow {
                     }
                 };
 
-                let code_clone = code.clone();
-
                 // Exchange Code
-                let verify_res = tokio::task::spawn_blocking(move || {
-                    let service = HattrickAuthService;
-                    service.verify_user(&code_clone, &rt, &rs)
-                })
-                .await;
+                let verify_res = HattrickAuthService::new()
+                    .verify_user_async(&code, &rt, &rs)
+                    .await;
 
                 match verify_res {
-                    Ok(Ok((access_token, access_secret))) => {
+                    Ok((access_token, access_secret)) => {
                         let secret_service = SystemSecretService::new();
                         if let Err(e) = secret_service
                             .store_secret("access_token", &access_token)
@@ -231,29 +271,38 @@ impl SetupWindow {
                                 access_token,
                                 access_secret,
                                 progress_callback,
+                                cancel,
                             )
                             .await
                         {
-                            Ok(_) => {
+                            Ok((_, _, report)) => {
                                 info!("Initial sync successful");
+                                if report.is_partial() {
+                                    warn!(
+                                        "Initial sync incomplete: {} of {} players have basic data only ({:?})",
+                                        report.fallback_ids.len(),
+                                        report.total,
+                                        report.fallback_ids
+                                    );
+                                }
                                 if let Some(app) = win.application() {
                                     let main_win = NutmegWindow::new(&app);
                                     main_win.present();
                                 }
                                 win.close();
                             }
+                            Err(NutmegError::Cancelled) => {
+                                info!("Initial sync cancelled");
+                                stack.set_visible_child_name("page3");
+                            }
                             Err(e) => {
                                 error!("Sync Error: {}", e);
                                 stack.set_visible_child_name("page3");
                             }
                         }
                     }
-                    Ok(Err(e)) => {
-                        error!("Verification Error: {}", e);
-                        stack.set_visible_child_name("page3");
-                    }
                     Err(e) => {
-                        error!("Join Error: {}", e);
+                        error!("Verification Error: {}", e);
                         stack.set_visible_child_name("page3");
                     }
                 }
@@ -0,0 +1,175 @@
+/* compare.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::Player;
+use crate::rating::types::PlayerSkill;
+
+/// Which of the two compared players is better at a skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillWinner {
+    A,
+    B,
+    Tie,
+    /// At least one of the players has the skill hidden.
+    Incomparable,
+}
+
+/// One row of a side-by-side comparison. Values are `None` when the skill is
+/// not visible for that player (players outside the user's own team).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillComparison {
+    pub skill: PlayerSkill,
+    pub a_value: Option<u32>,
+    pub b_value: Option<u32>,
+    pub winner: SkillWinner,
+}
+
+const COMPARED_SKILLS: [PlayerSkill; 11] = [
+    PlayerSkill::Keeper,
+    PlayerSkill::Defending,
+    PlayerSkill::Playmaking,
+    PlayerSkill::Winger,
+    PlayerSkill::Passing,
+    PlayerSkill::Scoring,
+    PlayerSkill::SetPieces,
+    PlayerSkill::Stamina,
+    PlayerSkill::Form,
+    PlayerSkill::Experience,
+    PlayerSkill::Loyalty,
+];
+
+fn skill_value(player: &Player, skill: PlayerSkill) -> Option<u32> {
+    let skills = player.PlayerSkills.as_ref();
+    match skill {
+        PlayerSkill::Keeper => skills.map(|s| s.KeeperSkill),
+        PlayerSkill::Defending => skills.map(|s| s.DefenderSkill),
+        PlayerSkill::Playmaking => skills.map(|s| s.PlaymakerSkill),
+        PlayerSkill::Winger => skills.map(|s| s.WingerSkill),
+        PlayerSkill::Passing => skills.map(|s| s.PassingSkill),
+        PlayerSkill::Scoring => skills.map(|s| s.ScorerSkill),
+        PlayerSkill::SetPieces => skills.map(|s| s.SetPiecesSkill),
+        PlayerSkill::Stamina => skills.map(|s| s.StaminaSkill),
+        PlayerSkill::Form => Some(player.PlayerForm),
+        PlayerSkill::Experience => Some(player.Experience),
+        PlayerSkill::Loyalty => Some(player.Loyalty),
+    }
+}
+
+/// Compare two players skill by skill, in the order shown in the squad view.
+pub fn compare_players(a: &Player, b: &Player) -> Vec<SkillComparison> {
+    COMPARED_SKILLS
+        .iter()
+        .map(|&skill| {
+            let a_value = skill_value(a, skill);
+            let b_value = skill_value(b, skill);
+            let winner = match (a_value, b_value) {
+                (Some(x), Some(y)) if x > y => SkillWinner::A,
+                (Some(x), Some(y)) if x < y => SkillWinner::B,
+                (Some(_), Some(_)) => SkillWinner::Tie,
+                _ => SkillWinner::Incomparable,
+            };
+            SkillComparison {
+                skill,
+                a_value,
+                b_value,
+                winner,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chpp::model::PlayerSkills;
+
+    fn player(skills: Option<PlayerSkills>, form: u32) -> Player {
+        Player {
+            PlayerSkills: skills,
+            PlayerForm: form,
+            ..Default::default()
+        }
+    }
+
+    fn row(rows: &[SkillComparison], skill: PlayerSkill) -> SkillComparison {
+        *rows
+            .iter()
+            .find(|r| r.skill == skill)
+            .expect("skill in comparison")
+    }
+
+    #[test]
+    fn test_compare_players_winner_cases() {
+        let a = player(
+            Some(PlayerSkills {
+                KeeperSkill: 9,
+                ScorerSkill: 3,
+                PassingSkill: 5,
+                ..Default::default()
+            }),
+            6,
+        );
+        let b = player(
+            Some(PlayerSkills {
+                KeeperSkill: 2,
+                ScorerSkill: 8,
+                PassingSkill: 5,
+                ..Default::default()
+            }),
+            6,
+        );
+
+        let rows = compare_players(&a, &b);
+        assert_eq!(rows.len(), COMPARED_SKILLS.len());
+
+        let keeper = row(&rows, PlayerSkill::Keeper);
+        assert_eq!((keeper.a_value, keeper.b_value), (Some(9), Some(2)));
+        assert_eq!(keeper.winner, SkillWinner::A);
+        assert_eq!(row(&rows, PlayerSkill::Scoring).winner, SkillWinner::B);
+        assert_eq!(row(&rows, PlayerSkill::Passing).winner, SkillWinner::Tie);
+        assert_eq!(row(&rows, PlayerSkill::Form).winner, SkillWinner::Tie);
+    }
+
+    #[test]
+    fn test_compare_players_missing_skills_are_incomparable() {
+        let own = player(
+            Some(PlayerSkills {
+                DefenderSkill: 7,
+                ..Default::default()
+            }),
+            7,
+        );
+        let scouted = player(None, 5);
+
+        let rows = compare_players(&own, &scouted);
+        let defending = row(&rows, PlayerSkill::Defending);
+        assert_eq!((defending.a_value, defending.b_value), (Some(7), None));
+        assert_eq!(defending.winner, SkillWinner::Incomparable);
+
+        // Form is always visible, so it still has a winner.
+        assert_eq!(row(&rows, PlayerSkill::Form).winner, SkillWinner::A);
+
+        let both_hidden = compare_players(&scouted, &scouted);
+        assert_eq!(
+            row(&both_hidden, PlayerSkill::Keeper).winner,
+            SkillWinner::Incomparable
+        );
+    }
+}
@@ -0,0 +1,178 @@
+/* filter.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::Player;
+
+/// Fully fit: no injury at all (`InjuryLevel` -1 or unknown). A bruised
+/// player (0) can play but is not fully fit.
+pub fn is_fit(player: &Player) -> bool {
+    matches!(player.InjuryLevel, None | Some(-1))
+}
+
+/// Sort key ordering players by injury severity: fit players first, then
+/// bruised, then by weeks out.
+pub fn injury_sort_key(level: Option<i32>) -> i32 {
+    level.unwrap_or(-1).max(-1)
+}
+
+/// Sort key listing numbered players by shirt number, then the unnumbered
+/// ones (no number, or Hattrick's 100 sentinel).
+pub fn player_number_sort_key(p: &Player) -> u32 {
+    match p.PlayerNumber {
+        Some(n) if n != 100 => n,
+        _ => u32::MAX,
+    }
+}
+
+/// Sort key listing transfer-listed players by deadline, soonest first,
+/// then everyone without a known deadline.
+pub fn transfer_deadline_sort_key(p: &Player) -> i64 {
+    p.transfer_deadline()
+        .map(|deadline| deadline.timestamp())
+        .unwrap_or(i64::MAX)
+}
+
+/// Criteria the squad view applies before listing players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlayerFilter {
+    /// Hide injured players.
+    pub fit_only: bool,
+    /// With `fit_only`, still show bruised players.
+    pub allow_bruised: bool,
+}
+
+impl PlayerFilter {
+    pub fn matches(&self, player: &Player) -> bool {
+        !self.fit_only || is_fit(player) || (self.allow_bruised && player.InjuryLevel == Some(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: u32, injury: Option<i32>) -> Player {
+        Player {
+            PlayerID: id,
+            InjuryLevel: injury,
+            ..Default::default()
+        }
+    }
+
+    const STRICT: PlayerFilter = PlayerFilter {
+        fit_only: true,
+        allow_bruised: false,
+    };
+
+    const LENIENT: PlayerFilter = PlayerFilter {
+        fit_only: true,
+        allow_bruised: true,
+    };
+
+    #[test]
+    fn test_is_fit() {
+        assert!(is_fit(&player(1, None)));
+        assert!(is_fit(&player(1, Some(-1))));
+        assert!(!is_fit(&player(1, Some(0))));
+        assert!(!is_fit(&player(1, Some(3))));
+    }
+
+    #[test]
+    fn test_bruised_player_depends_on_flag() {
+        let bruised = player(1, Some(0));
+        assert!(!STRICT.matches(&bruised));
+        assert!(LENIENT.matches(&bruised));
+    }
+
+    #[test]
+    fn test_injured_player_is_never_fit() {
+        let injured = player(1, Some(3));
+        assert!(!STRICT.matches(&injured));
+        assert!(!LENIENT.matches(&injured));
+        assert!(PlayerFilter::default().matches(&injured));
+    }
+
+    #[test]
+    fn test_matches_squad() {
+        let players = [player(1, Some(-1)), player(2, Some(0)), player(3, Some(3))];
+        let ids = |filter: PlayerFilter| -> Vec<u32> {
+            players
+                .iter()
+                .filter(|p| filter.matches(p))
+                .map(|p| p.PlayerID)
+                .collect()
+        };
+        assert_eq!(ids(PlayerFilter::default()), vec![1, 2, 3]);
+        assert_eq!(ids(LENIENT), vec![1, 2]);
+        assert_eq!(ids(STRICT), vec![1]);
+    }
+
+    #[test]
+    fn test_player_number_sort_key() {
+        let numbered = |id: u32, number: Option<u32>| Player {
+            PlayerID: id,
+            PlayerNumber: number,
+            ..Default::default()
+        };
+        let mut players = vec![
+            numbered(1, None),
+            numbered(2, Some(11)),
+            numbered(3, Some(100)),
+            numbered(4, Some(1)),
+            numbered(5, Some(7)),
+        ];
+        players.sort_by_key(player_number_sort_key);
+
+        let ids: Vec<u32> = players.iter().map(|p| p.PlayerID).collect();
+        // Unnumbered players keep their relative order at the end.
+        assert_eq!(ids, vec![4, 5, 2, 1, 3]);
+        assert_eq!(player_number_sort_key(&players[3]), u32::MAX);
+    }
+
+    #[test]
+    fn test_transfer_deadline_sort_key() {
+        let listed = |id: u32, deadline: &str| Player {
+            PlayerID: id,
+            TransferListed: true,
+            TransferDetails: Some(crate::chpp::model::PlayerTransferDetails {
+                Deadline: deadline.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut players = vec![
+            player(1, None),
+            listed(2, "2024-03-04 10:00:00"),
+            listed(3, "2024-03-02 20:00:00"),
+        ];
+        players.sort_by_key(transfer_deadline_sort_key);
+
+        let ids: Vec<u32> = players.iter().map(|p| p.PlayerID).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_injury_sort_key() {
+        assert_eq!(injury_sort_key(None), -1);
+        assert_eq!(injury_sort_key(Some(-1)), -1);
+        assert_eq!(injury_sort_key(Some(0)), 0);
+        assert_eq!(injury_sort_key(Some(3)), 3);
+    }
+}
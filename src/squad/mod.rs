@@ -1 +1,5 @@
+pub mod compare;
+pub mod filter;
+pub mod summary;
+#[cfg(feature = "gui")]
 pub mod ui;
@@ -0,0 +1,168 @@
+/* summary.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Player, PlayerSkills};
+use crate::rating::types::PlayerSkill;
+
+/// Average and best level of one skill across the squad.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SkillStats {
+    pub average: f64,
+    pub max: u32,
+}
+
+/// At-a-glance squad strength. Skill figures only count players whose
+/// skills are visible (own players); the counters cover the whole squad.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SquadSummary {
+    pub skills: Vec<(PlayerSkill, SkillStats)>,
+    pub with_skills: usize,
+    pub injured: usize,
+    pub transfer_listed: usize,
+}
+
+const SUMMARY_SKILLS: [PlayerSkill; 7] = [
+    PlayerSkill::Keeper,
+    PlayerSkill::Defending,
+    PlayerSkill::Playmaking,
+    PlayerSkill::Winger,
+    PlayerSkill::Passing,
+    PlayerSkill::Scoring,
+    PlayerSkill::SetPieces,
+];
+
+fn skill_level(skills: &PlayerSkills, skill: PlayerSkill) -> u32 {
+    match skill {
+        PlayerSkill::Keeper => skills.KeeperSkill,
+        PlayerSkill::Defending => skills.DefenderSkill,
+        PlayerSkill::Playmaking => skills.PlaymakerSkill,
+        PlayerSkill::Winger => skills.WingerSkill,
+        PlayerSkill::Passing => skills.PassingSkill,
+        PlayerSkill::Scoring => skills.ScorerSkill,
+        PlayerSkill::SetPieces => skills.SetPiecesSkill,
+        PlayerSkill::Stamina => skills.StaminaSkill,
+        PlayerSkill::Form | PlayerSkill::Experience | PlayerSkill::Loyalty => 0,
+    }
+}
+
+pub fn squad_summary(players: &[Player]) -> SquadSummary {
+    let with_skills: Vec<&PlayerSkills> = players
+        .iter()
+        .filter_map(|p| p.PlayerSkills.as_ref())
+        .collect();
+
+    let skills = SUMMARY_SKILLS
+        .iter()
+        .map(|&skill| {
+            let levels = with_skills.iter().map(|s| skill_level(s, skill));
+            let stats = if with_skills.is_empty() {
+                SkillStats::default()
+            } else {
+                SkillStats {
+                    average: levels.clone().sum::<u32>() as f64 / with_skills.len() as f64,
+                    max: levels.max().unwrap_or(0),
+                }
+            };
+            (skill, stats)
+        })
+        .collect();
+
+    SquadSummary {
+        skills,
+        with_skills: with_skills.len(),
+        // -1 means healthy; bruised (0) players are counted as injured.
+        injured: players
+            .iter()
+            .filter(|p| p.InjuryLevel.is_some_and(|l| l >= 0))
+            .count(),
+        transfer_listed: players.iter().filter(|p| p.TransferListed).count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: u32, skills: Option<PlayerSkills>, injury: Option<i32>) -> Player {
+        Player {
+            PlayerID: id,
+            PlayerSkills: skills,
+            InjuryLevel: injury,
+            ..Default::default()
+        }
+    }
+
+    fn skills(keeper: u32, scorer: u32) -> PlayerSkills {
+        PlayerSkills {
+            KeeperSkill: keeper,
+            ScorerSkill: scorer,
+            ..Default::default()
+        }
+    }
+
+    fn stats(summary: &SquadSummary, skill: PlayerSkill) -> SkillStats {
+        summary
+            .skills
+            .iter()
+            .find(|(s, _)| *s == skill)
+            .map(|(_, stats)| *stats)
+            .expect("skill in summary")
+    }
+
+    #[test]
+    fn test_squad_summary_ignores_players_without_skills() {
+        let players = vec![
+            player(1, Some(skills(8, 2)), Some(-1)),
+            player(2, Some(skills(2, 7)), Some(0)),
+            player(3, None, Some(3)),
+            player(4, None, None),
+        ];
+
+        let summary = squad_summary(&players);
+        assert_eq!(summary.with_skills, 2);
+        assert_eq!(
+            stats(&summary, PlayerSkill::Keeper),
+            SkillStats {
+                average: 5.0,
+                max: 8
+            }
+        );
+        assert_eq!(
+            stats(&summary, PlayerSkill::Scoring),
+            SkillStats {
+                average: 4.5,
+                max: 7
+            }
+        );
+        assert_eq!(summary.injured, 2);
+        assert_eq!(summary.transfer_listed, 0);
+    }
+
+    #[test]
+    fn test_squad_summary_counts_transfer_listed() {
+        let mut listed = player(1, None, Some(-1));
+        listed.TransferListed = true;
+        let summary = squad_summary(&[listed, player(2, None, Some(-1))]);
+
+        assert_eq!(summary.transfer_listed, 1);
+        assert_eq!(summary.injured, 0);
+        assert_eq!(stats(&summary, PlayerSkill::Keeper), SkillStats::default());
+    }
+}
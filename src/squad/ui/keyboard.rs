@@ -0,0 +1,92 @@
+/* keyboard.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use gtk::gdk;
+
+/// What a key press in the squad list should do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SquadKeyAction {
+    /// Open the details panel for the selected player.
+    ShowDetails,
+    /// Move focus to the search entry.
+    FocusSearch,
+}
+
+/// Map a key press on the player list to an action. Arrow keys are left to
+/// the tree view's own selection handling.
+pub fn squad_key_action(key: gdk::Key, modifiers: gdk::ModifierType) -> Option<SquadKeyAction> {
+    let accel_mods = gdk::ModifierType::CONTROL_MASK
+        | gdk::ModifierType::ALT_MASK
+        | gdk::ModifierType::SUPER_MASK;
+    if modifiers.intersects(accel_mods) {
+        return None;
+    }
+
+    match key {
+        gdk::Key::Return | gdk::Key::KP_Enter | gdk::Key::ISO_Enter => {
+            Some(SquadKeyAction::ShowDetails)
+        }
+        gdk::Key::slash | gdk::Key::KP_Divide => Some(SquadKeyAction::FocusSearch),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enter_shows_details() {
+        let none = gdk::ModifierType::empty();
+        for key in [gdk::Key::Return, gdk::Key::KP_Enter, gdk::Key::ISO_Enter] {
+            assert_eq!(
+                squad_key_action(key, none),
+                Some(SquadKeyAction::ShowDetails)
+            );
+        }
+        assert_eq!(
+            squad_key_action(gdk::Key::Return, gdk::ModifierType::SHIFT_MASK),
+            Some(SquadKeyAction::ShowDetails)
+        );
+    }
+
+    #[test]
+    fn test_slash_focuses_search() {
+        assert_eq!(
+            squad_key_action(gdk::Key::slash, gdk::ModifierType::empty()),
+            Some(SquadKeyAction::FocusSearch)
+        );
+    }
+
+    #[test]
+    fn test_other_keys_are_ignored() {
+        let none = gdk::ModifierType::empty();
+        assert_eq!(squad_key_action(gdk::Key::Down, none), None);
+        assert_eq!(squad_key_action(gdk::Key::a, none), None);
+        assert_eq!(
+            squad_key_action(gdk::Key::Return, gdk::ModifierType::CONTROL_MASK),
+            None
+        );
+        assert_eq!(
+            squad_key_action(gdk::Key::slash, gdk::ModifierType::ALT_MASK),
+            None
+        );
+    }
+}
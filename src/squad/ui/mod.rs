@@ -1,2 +1,3 @@
+pub mod keyboard;
 pub mod player_details;
 pub mod player_list;
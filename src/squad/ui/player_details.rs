@@ -1,4 +1,11 @@
+use crate::rating::types::PlayerSkill;
+use crate::ui::denomination::SkillDenominations;
+use crate::ui::player_display::{
+    format_injury, gender_emoji, preferred_number_format, primary_skills_for, specialty_name,
+    statement_text,
+};
 use crate::ui::player_object::PlayerObject;
+use crate::utils::number_format::format_number;
 use gettextrs::gettext;
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
@@ -23,6 +30,10 @@ mod imp {
         pub details_gender: TemplateChild<gtk::Label>,
         #[template_child]
         pub details_avatar: TemplateChild<gtk::Image>,
+        #[template_child]
+        pub details_statement_box: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub details_statement: TemplateChild<gtk::Label>,
 
         // Category
         #[template_child]
@@ -41,6 +52,10 @@ mod imp {
         pub label_injury_title: TemplateChild<gtk::Label>,
         #[template_child]
         pub details_specialty: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub details_experience: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub details_leadership: TemplateChild<gtk::Label>,
 
         // Skills
         #[template_child]
@@ -133,11 +148,7 @@ impl SquadPlayerDetails {
             }
 
             // Gender
-            let gender_emoji = match p.GenderID {
-                Some(2) => "♀️", // Female
-                _ => "♂️",       // Male (default)
-            };
-            imp.details_gender.set_label(gender_emoji);
+            imp.details_gender.set_label(gender_emoji(p.GenderID));
 
             // Avatar
             if let Some(blob) = &p.AvatarBlob {
@@ -161,6 +172,15 @@ impl SquadPlayerDetails {
                     .set_icon_name(Some("avatar-default-symbolic"));
             }
 
+            // Statement
+            match statement_text(p.Statement.as_deref()) {
+                Some(statement) => {
+                    imp.details_statement.set_label(statement);
+                    imp.details_statement_box.set_visible(true);
+                }
+                None => imp.details_statement_box.set_visible(false),
+            }
+
             // Category
             let cat_str = if let Some(pref) = preferred_position {
                 pref
@@ -178,94 +198,92 @@ impl SquadPlayerDetails {
             imp.details_category.set_label(&cat_str);
 
             // Level
-            imp.details_form.set_label(&p.PlayerForm.to_string());
+            let names = SkillDenominations::for_current_user();
+            set_skill_label(&imp.details_form, Some(p.PlayerForm), &names);
 
-            let stamina = p
-                .PlayerSkills
-                .as_ref()
-                .map(|s| s.StaminaSkill.to_string())
-                .unwrap_or_else(|| "-".to_string());
-            imp.details_stamina.set_label(&stamina);
+            set_skill_label(
+                &imp.details_stamina,
+                p.PlayerSkills.as_ref().map(|s| s.StaminaSkill),
+                &names,
+            );
 
             // TSI
-            let locale = num_format::SystemLocale::default()
-                .unwrap_or_else(|_| num_format::SystemLocale::from_name("C").unwrap());
-            let mut buf_tsi = num_format::Buffer::default();
-            buf_tsi.write_formatted(&p.TSI, &locale);
-            imp.details_tsi.set_label(buf_tsi.as_str());
+            imp.details_tsi
+                .set_label(&format_number(p.TSI.into(), &preferred_number_format()));
 
             // Injury
-            if let Some(injury_level) = p.InjuryLevel {
-                if injury_level == -1 {
-                    imp.details_injury.set_visible(false);
-                    imp.label_injury_title.set_visible(false);
-                } else {
-                    imp.details_injury.set_visible(true);
-                    imp.label_injury_title.set_visible(true);
-                    let injury_str = if injury_level == 0 {
-                        "🩹".to_string()
-                    } else {
-                        format!("🚑 {} w", injury_level)
-                    };
-                    imp.details_injury.set_label(&injury_str);
-                }
+            if let Some(injury_str) = format_injury(p.InjuryLevel) {
+                imp.details_injury.set_visible(true);
+                imp.label_injury_title.set_visible(true);
+                imp.details_injury.set_label(&injury_str);
             } else {
                 imp.details_injury.set_visible(false);
                 imp.label_injury_title.set_visible(false);
             }
 
             // Specialty
-            let specialty_str = match p.Specialty {
-                Some(0) => gettext("No specialty"),
-                Some(1) => gettext("Technical"),
-                Some(2) => gettext("Quick"),
-                Some(3) => gettext("Powerful"),
-                Some(4) => gettext("Unpredictable"),
-                Some(5) => gettext("Head specialist"),
-                Some(6) => gettext("Resilient"),
-                Some(8) => gettext("Support"),
-                _ => "".to_string(),
-            };
-            imp.details_specialty.set_label(&specialty_str);
+            imp.details_specialty
+                .set_label(&specialty_name(p.Specialty));
+
+            set_skill_label(&imp.details_experience, Some(p.Experience), &names);
+            set_skill_label(&imp.details_leadership, Some(p.Leadership), &names);
 
             // Skills
             let skills = p.PlayerSkills.as_ref();
-            imp.details_skill_keeper.set_label(
-                &skills
-                    .map(|s| s.KeeperSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_keeper,
+                skills.map(|s| s.KeeperSkill),
+                &names,
             );
-            imp.details_skill_defender.set_label(
-                &skills
-                    .map(|s| s.DefenderSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_defender,
+                skills.map(|s| s.DefenderSkill),
+                &names,
             );
-            imp.details_skill_playmaker.set_label(
-                &skills
-                    .map(|s| s.PlaymakerSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_playmaker,
+                skills.map(|s| s.PlaymakerSkill),
+                &names,
             );
-            imp.details_skill_winger.set_label(
-                &skills
-                    .map(|s| s.WingerSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_winger,
+                skills.map(|s| s.WingerSkill),
+                &names,
             );
-            imp.details_skill_passing.set_label(
-                &skills
-                    .map(|s| s.PassingSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_passing,
+                skills.map(|s| s.PassingSkill),
+                &names,
             );
-            imp.details_skill_scorer.set_label(
-                &skills
-                    .map(|s| s.ScorerSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_scorer,
+                skills.map(|s| s.ScorerSkill),
+                &names,
             );
-            imp.details_skill_set_pieces.set_label(
-                &skills
-                    .map(|s| s.SetPiecesSkill.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
+            set_skill_label(
+                &imp.details_skill_set_pieces,
+                skills.map(|s| s.SetPiecesSkill),
+                &names,
             );
 
+            // Bold the skills that matter for the player's category.
+            let primary = primary_skills_for(p.PlayerCategoryId);
+            for (skill, label) in [
+                (PlayerSkill::Keeper, &imp.details_skill_keeper),
+                (PlayerSkill::Defending, &imp.details_skill_defender),
+                (PlayerSkill::Playmaking, &imp.details_skill_playmaker),
+                (PlayerSkill::Winger, &imp.details_skill_winger),
+                (PlayerSkill::Passing, &imp.details_skill_passing),
+                (PlayerSkill::Scoring, &imp.details_skill_scorer),
+                (PlayerSkill::SetPieces, &imp.details_skill_set_pieces),
+            ] {
+                if primary.contains(&skill) {
+                    label.add_css_class("strong");
+                } else {
+                    label.remove_css_class("strong");
+                }
+            }
+
             // Career / Club
             imp.details_career_goals.set_label(
                 &p.CareerGoals
@@ -332,6 +350,21 @@ impl SquadPlayerDetails {
     }
 }
 
+/// Show a level as its number, with the denomination as tooltip, or "-"
+/// when the level is unknown.
+fn set_skill_label(label: &gtk::Label, value: Option<u32>, names: &SkillDenominations) {
+    match value {
+        Some(level) => {
+            label.set_label(&level.to_string());
+            label.set_tooltip_text(Some(&names.tooltip(level)));
+        }
+        None => {
+            label.set_label("-");
+            label.set_tooltip_text(None);
+        }
+    }
+}
+
 impl Default for SquadPlayerDetails {
     fn default() -> Self {
         Self::new()
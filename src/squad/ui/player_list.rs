@@ -1,7 +1,16 @@
+use crate::chpp::model::Player;
+use crate::db::teams::CountryCount;
+use crate::rating::types::PlayerSkill;
+use crate::squad::compare::{compare_players, SkillWinner};
+use crate::squad::filter::{
+    injury_sort_key, player_number_sort_key, transfer_deadline_sort_key, PlayerFilter,
+};
+use crate::squad::summary::SquadSummary;
 use gettextrs::gettext;
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
 use gtk::{glib, CompositeTemplate};
+use std::cell::{Cell, RefCell};
 
 mod imp {
     use super::*;
@@ -11,6 +20,16 @@ mod imp {
     pub struct SquadPlayerList {
         #[template_child]
         pub view_players: TemplateChild<gtk::TreeView>,
+        #[template_child]
+        pub summary_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub countries_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub comparison_grid: TemplateChild<gtk::Grid>,
+        #[template_child]
+        pub fit_only_check: TemplateChild<gtk::CheckButton>,
+        pub player_filter: Cell<PlayerFilter>,
+        pub filter_model: RefCell<Option<gtk::TreeModelFilter>>,
     }
 
     #[glib::object_subclass]
@@ -33,6 +52,7 @@ mod imp {
             self.parent_constructed();
             let obj = self.obj();
             obj.setup_tree_view();
+            obj.setup_fit_only_toggle();
         }
     }
     impl WidgetImpl for SquadPlayerList {}
@@ -54,10 +74,158 @@ impl SquadPlayerList {
         self.imp().view_players.clone()
     }
 
+    /// Fill the header strip above the list with the squad summary, or
+    /// clear it when no squad is loaded.
+    /// Shows `store` through a filter model (for the fit-only toggle) and a
+    /// sort model on top, so that clicking column headers still sorts.
+    pub fn set_players(&self, store: Option<&gtk::ListStore>) {
+        let imp = self.imp();
+        let Some(store) = store else {
+            imp.filter_model.replace(None);
+            imp.view_players.set_model(None::<&gtk::TreeModel>);
+            return;
+        };
+
+        #[allow(deprecated)]
+        let filter = gtk::TreeModelFilter::new(store, None);
+        let weak = self.downgrade();
+        #[allow(deprecated)]
+        filter.set_visible_func(move |model, iter| {
+            let Some(list) = weak.upgrade() else {
+                return true;
+            };
+            model
+                .get_value(iter, 18)
+                .get::<PlayerObject>()
+                .map(|obj| list.imp().player_filter.get().matches(&obj.player()))
+                .unwrap_or(true)
+        });
+
+        #[allow(deprecated)]
+        let sorted = gtk::TreeModelSort::with_model(&filter);
+        imp.view_players.set_model(Some(&sorted));
+        imp.filter_model.replace(Some(filter));
+    }
+
+    fn setup_fit_only_toggle(&self) {
+        let weak = self.downgrade();
+        self.imp().fit_only_check.connect_toggled(move |check| {
+            let Some(list) = weak.upgrade() else {
+                return;
+            };
+            // Bruised players can still play, only hide actual injuries.
+            list.imp().player_filter.set(PlayerFilter {
+                fit_only: check.is_active(),
+                allow_bruised: true,
+            });
+            if let Some(filter) = list.imp().filter_model.borrow().as_ref() {
+                #[allow(deprecated)]
+                filter.refilter();
+            }
+        });
+    }
+
+    pub fn set_summary(&self, summary: Option<&SquadSummary>) {
+        let Some(summary) = summary else {
+            self.imp().summary_label.set_label("");
+            return;
+        };
+        let mut parts: Vec<String> = Vec::new();
+        if summary.with_skills > 0 {
+            parts.extend(summary.skills.iter().map(|(skill, stats)| {
+                format!(
+                    "{} {:.1} ({})",
+                    skill_short_name(*skill),
+                    stats.average,
+                    stats.max
+                )
+            }));
+        }
+        parts.push(format!("{}: {}", gettext("Injured"), summary.injured));
+        parts.push(format!(
+            "{}: {}",
+            gettext("Transfer listed"),
+            summary.transfer_listed
+        ));
+        self.imp().summary_label.set_label(&parts.join(" · "));
+    }
+
+    /// Show how many players come from each country, with its flag, as
+    /// returned by `players_by_country`. Hidden when the squad is empty.
+    pub fn set_country_breakdown(&self, breakdown: &[CountryCount]) {
+        let label = &self.imp().countries_label;
+        let parts: Vec<String> = breakdown
+            .iter()
+            .map(|country| {
+                let name = if country.name.is_empty() {
+                    gettext("Unknown")
+                } else {
+                    country.name.clone()
+                };
+                match &country.flag {
+                    Some(flag) => format!("{} {} {}", flag, name, country.count),
+                    None => format!("{} {}", name, country.count),
+                }
+            })
+            .collect();
+        label.set_label(&parts.join(" · "));
+        label.set_visible(!parts.is_empty());
+    }
+
+    /// Show the side-by-side comparison of two selected players, or hide it
+    /// when `players` is `None`. The better value of each skill is
+    /// highlighted; hidden skills are shown as "?".
+    pub fn set_comparison(&self, players: Option<(&Player, &Player)>) {
+        let grid = &self.imp().comparison_grid;
+        while let Some(child) = grid.first_child() {
+            grid.remove(&child);
+        }
+        let Some((a, b)) = players else {
+            grid.set_visible(false);
+            return;
+        };
+
+        let header = |text: &str, column: i32| {
+            let label = gtk::Label::new(Some(text));
+            label.set_xalign(0.0);
+            label.add_css_class("heading");
+            grid.attach(&label, column, 0, 1, 1);
+        };
+        header(&gettext("Skill"), 0);
+        header(&full_name(a), 1);
+        header(&full_name(b), 2);
+
+        for (row, comparison) in compare_players(a, b).iter().enumerate() {
+            let row = row as i32 + 1;
+            let name = gtk::Label::new(Some(&skill_name(comparison.skill)));
+            name.set_xalign(0.0);
+            grid.attach(&name, 0, row, 1, 1);
+
+            let cells = [
+                (comparison.a_value, SkillWinner::A, 1),
+                (comparison.b_value, SkillWinner::B, 2),
+            ];
+            for (value, winner, column) in cells {
+                let text = value.map_or_else(|| "?".to_string(), |v| v.to_string());
+                let label = gtk::Label::new(Some(&text));
+                label.set_xalign(0.0);
+                if comparison.winner == winner {
+                    label.add_css_class("comparison-winner");
+                }
+                grid.attach(&label, column, row, 1, 1);
+            }
+        }
+        grid.set_visible(true);
+    }
+
     fn setup_tree_view(&self) {
         let imp = self.imp();
         let view = &imp.view_players;
 
+        // Ctrl/Shift-click selects several players; two selected players
+        // are shown in the comparison panel.
+        view.selection().set_mode(gtk::SelectionMode::Multiple);
+
         // Helper to add a text column
         let add_column = |title: &str, col_id: i32| {
             let renderer = gtk::CellRendererText::new();
@@ -69,21 +237,28 @@ impl SquadPlayerList {
             column.add_attribute(&renderer, "text", col_id);
             column.add_attribute(&renderer, "cell-background", 13); // BG Color is now at index 13
             view.append_column(&column);
+            column
         };
 
         // Columns:
         // 0: Name, 1: Flag, 2: Number, 3: Age, 4: Form, 5: TSI
         // 6: Salary, 7: Specialty, 8: Experience, 9: Leadership, 10: Loyalty
         // 11: Best Pos, 12: Last Pos, 13: BG Color, 14: Stamina, 15: Injured, 16: Cards, 17: Mother Club
-        // 18: PlayerObj
+        // 18: PlayerObj, 19: Gender, 20: Value (TSI/salary), 21: Value sort key
+        // 22: Loyalty/mother club bonus, 23: Injury sort key, 24: Number sort key
+        // 25: Transfer deadline, 26: Transfer deadline sort key
 
         add_column(&gettext("Name"), 0);
         add_column(&gettext("Flag"), 1);
-        add_column(&gettext("No."), 2);
+        add_column(&gettext("Gender"), 19);
+        // Numbered players first, by number.
+        add_column(&gettext("No."), 2).set_sort_column_id(24);
         add_column(&gettext("Age"), 3);
         add_column(&gettext("Form"), 4);
         add_column(&gettext("TSI"), 5);
         add_column(&gettext("Salary"), 6);
+        // Sort on the numeric ratio rather than its text.
+        add_column(&gettext("Value"), 20).set_sort_column_id(21);
         add_column(&gettext("Specialty"), 7);
         add_column(&gettext("XP"), 8);
         add_column(&gettext("Lead"), 9);
@@ -92,9 +267,49 @@ impl SquadPlayerList {
         add_column(&gettext("Last Pos"), 12);
         // BG Color is 13, not displayed as column
         add_column(&gettext("Stamina"), 14);
-        add_column(&gettext("Injured"), 15);
+        // Sort by severity rather than the displayed text.
+        add_column(&gettext("Injured"), 15).set_sort_column_id(23);
         add_column(&gettext("Cards"), 16);
         add_column(&gettext("Mother Club"), 17);
+        add_column(&gettext("Bonus"), 22);
+        // Soonest deadline first rather than by the countdown text.
+        add_column(&gettext("Deadline"), 25).set_sort_column_id(26);
+    }
+}
+
+fn full_name(player: &Player) -> String {
+    format!("{} {}", player.FirstName, player.LastName)
+}
+
+fn skill_name(skill: PlayerSkill) -> String {
+    match skill {
+        PlayerSkill::Keeper => gettext("Keeper"),
+        PlayerSkill::Defending => gettext("Defending"),
+        PlayerSkill::Playmaking => gettext("Playmaking"),
+        PlayerSkill::Winger => gettext("Winger"),
+        PlayerSkill::Passing => gettext("Passing"),
+        PlayerSkill::Scoring => gettext("Scoring"),
+        PlayerSkill::SetPieces => gettext("Set Pieces"),
+        PlayerSkill::Stamina => gettext("Stamina"),
+        PlayerSkill::Form => gettext("Form"),
+        PlayerSkill::Experience => gettext("Experience"),
+        PlayerSkill::Loyalty => gettext("Loyalty"),
+    }
+}
+
+fn skill_short_name(skill: PlayerSkill) -> String {
+    match skill {
+        PlayerSkill::Keeper => gettext("GK"),
+        PlayerSkill::Defending => gettext("DEF"),
+        PlayerSkill::Playmaking => gettext("PM"),
+        PlayerSkill::Winger => gettext("WG"),
+        PlayerSkill::Passing => gettext("PS"),
+        PlayerSkill::Scoring => gettext("SC"),
+        PlayerSkill::SetPieces => gettext("SP"),
+        PlayerSkill::Stamina => gettext("ST"),
+        PlayerSkill::Form => gettext("Form"),
+        PlayerSkill::Experience => gettext("XP"),
+        PlayerSkill::Loyalty => gettext("Loyalty"),
     }
 }
 
@@ -104,9 +319,8 @@ impl Default for SquadPlayerList {
     }
 }
 
-use crate::ui::player_display::PlayerDisplay;
+use crate::ui::player_display::{preferred_number_format, tsi_per_salary, PlayerDisplay};
 use crate::ui::player_object::PlayerObject;
-use num_format::SystemLocale;
 
 pub fn create_player_model(players: &[crate::chpp::model::Player]) -> gtk::ListStore {
     #[allow(deprecated)]
@@ -130,13 +344,21 @@ pub fn create_player_model(players: &[crate::chpp::model::Player]) -> gtk::ListS
         glib::Type::STRING, // 16 Cards
         glib::Type::STRING, // 17 Mother Club
         glib::Type::OBJECT, // 18 PlayerObject
+        glib::Type::STRING, // 19 Gender
+        glib::Type::STRING, // 20 Value
+        glib::Type::F64,    // 21 Value sort key
+        glib::Type::STRING, // 22 Bonus
+        glib::Type::I32,    // 23 Injury sort key
+        glib::Type::U32,    // 24 Number sort key
+        glib::Type::STRING, // 25 Transfer deadline
+        glib::Type::I64,    // 26 Transfer deadline sort key
     ]);
 
-    let locale = SystemLocale::default().unwrap_or_else(|_| SystemLocale::from_name("C").unwrap());
+    let number_format = preferred_number_format();
 
     for p in players {
         let obj = PlayerObject::new(p.clone());
-        let display = PlayerDisplay::new(p, &locale, None);
+        let display = PlayerDisplay::new(p, &number_format, None);
 
         let bg = if p.MotherClubBonus {
             // FIXME: still haven't figured out to use the CSS class
@@ -168,6 +390,15 @@ pub fn create_player_model(players: &[crate::chpp::model::Player]) -> gtk::ListS
                 (16, &display.cards),
                 (17, &display.mother_club),
                 (18, &obj),
+                (19, &display.gender),
+                (20, &display.value),
+                // Players without a salary sort below everyone else.
+                (21, &tsi_per_salary(p).unwrap_or(-1.0)),
+                (22, &display.bonus),
+                (23, &injury_sort_key(p.InjuryLevel)),
+                (24, &player_number_sort_key(p)),
+                (25, &display.deadline),
+                (26, &transfer_deadline_sort_key(p)),
             ],
         );
     }
@@ -1,4 +1,5 @@
 pub mod calculator;
 pub mod cycle;
 pub mod service;
+#[cfg(feature = "gui")]
 pub mod ui;
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use gtk::prelude::*;
+
+/// Space kept free around the line so end points are not clipped.
+const MARGIN: f64 = 12.0;
+
+/// A line chart of a team's cash across downloads, oldest on the left.
+pub struct CashChart;
+
+impl CashChart {
+    pub fn create(series: &[(DateTime<Utc>, i64)]) -> gtk::DrawingArea {
+        let area = gtk::DrawingArea::builder()
+            .content_height(200)
+            .hexpand(true)
+            .vexpand(true)
+            .build();
+
+        let series = series.to_vec();
+        area.set_draw_func(move |widget, cr, width, height| {
+            let points = chart_points(&series, width as f64, height as f64);
+            let color = widget.color();
+            cr.set_source_rgba(
+                color.red() as f64,
+                color.green() as f64,
+                color.blue() as f64,
+                color.alpha() as f64,
+            );
+
+            cr.set_line_width(2.0);
+            for (i, &(x, y)) in points.iter().enumerate() {
+                if i == 0 {
+                    cr.move_to(x, y);
+                } else {
+                    cr.line_to(x, y);
+                }
+            }
+            let _ = cr.stroke();
+
+            for &(x, y) in &points {
+                cr.arc(x, y, 3.0, 0.0, std::f64::consts::TAU);
+                let _ = cr.fill();
+            }
+        });
+
+        area
+    }
+}
+
+/// Positions of the series in a `width` x `height` area: x follows time,
+/// y puts the highest cash at the top. A flat or single-point series sits
+/// on the middle line.
+pub fn chart_points(series: &[(DateTime<Utc>, i64)], width: f64, height: f64) -> Vec<(f64, f64)> {
+    let (Some(first), Some(last)) = (series.first(), series.last()) else {
+        return Vec::new();
+    };
+    let min = series.iter().map(|&(_, cash)| cash).min().unwrap_or(0);
+    let max = series.iter().map(|&(_, cash)| cash).max().unwrap_or(0);
+    let span = (last.0 - first.0).num_seconds();
+    let plot_width = (width - 2.0 * MARGIN).max(0.0);
+    let plot_height = (height - 2.0 * MARGIN).max(0.0);
+
+    series
+        .iter()
+        .map(|&(at, cash)| {
+            let x = if span > 0 {
+                MARGIN + plot_width * (at - first.0).num_seconds() as f64 / span as f64
+            } else {
+                width / 2.0
+            };
+            let y = if max > min {
+                MARGIN + plot_height * (max - cash) as f64 / (max - min) as f64
+            } else {
+                height / 2.0
+            };
+            (x, y)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, d, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_chart_points_empty() {
+        assert!(chart_points(&[], 200.0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn test_chart_points_single_snapshot_is_centred() {
+        assert_eq!(
+            chart_points(&[(day(1), 500)], 200.0, 100.0),
+            vec![(100.0, 50.0)]
+        );
+    }
+
+    #[test]
+    fn test_chart_points_follow_time_and_cash() {
+        let series = [(day(1), 1_000), (day(3), -1_000), (day(9), 3_000)];
+        let points = chart_points(&series, 224.0, 124.0);
+
+        // 200 x 100 plot inside a 12px margin; day 3 is a quarter of the way.
+        assert_eq!(points, vec![(12.0, 62.0), (62.0, 112.0), (212.0, 12.0)]);
+    }
+}
@@ -1,2 +1,3 @@
+pub mod cash_chart;
 pub mod pitch_view;
 pub mod sector_ratings_view;
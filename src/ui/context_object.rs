@@ -307,9 +307,14 @@ impl ContextObject {
         // 1. Players
         match crate::db::teams::get_players_for_team(&mut conn, team_id) {
             Ok(players) => {
+                let trends = crate::db::diff::latest_player_trends(&mut conn, team_id)
+                    .unwrap_or_else(|e| {
+                        log::warn!("ContextObject: Failed to compute trends: {}", e);
+                        Default::default()
+                    });
                 let store =
                     crate::ui::controllers::squad_tab::SquadTabController::create_player_list_store(
-                        &players,
+                        &players, &trends,
                     );
                 self.set_players(Some(store));
 
@@ -333,7 +338,7 @@ impl ContextObject {
 
         // 2. Series Data
         let mut league_unit_id = None;
-        if let Ok(Some(t)) = crate::db::teams::get_team(&mut conn, team_id) {
+        if let Ok(Some(t)) = crate::db::team_cache::team_cache().get_team(&mut conn, team_id) {
             if let Some(unit) = t.LeagueLevelUnit {
                 league_unit_id = Some(unit.LeagueLevelUnitID);
             }
@@ -3,7 +3,7 @@ use crate::ui::context_object::ContextObject;
 use crate::ui::controllers::opponent_tab::OpponentTabController;
 use crate::ui::controllers::series_tab::SeriesTabController;
 use crate::ui::controllers::squad_tab::SquadTabController;
-use crate::ui::team_object::TeamObject;
+use crate::ui::team_object::{TeamData, TeamObject};
 use gtk::prelude::*;
 use std::rc::Rc;
 
@@ -42,6 +42,7 @@ impl MainController {
             .connect_notify_local(Some("selected-team"), move |ctx, _| {
                 if let Some(ctrl) = weak_self.upgrade() {
                     if let Some(team) = ctx.property::<Option<TeamObject>>("selected-team") {
+                        ctrl.remember_selected_team(team.team_data().id);
                         ctrl.load_team_data(team);
                     } else {
                         ctrl.clear_team_data();
@@ -50,17 +51,54 @@ impl MainController {
             });
     }
 
-    pub fn refresh_all_teams(&self) {
+    /// Reload the team list and return it as `(id, name)` pairs, in model order.
+    pub fn refresh_all_teams(&self) -> Vec<(u32, String)> {
+        let mut loaded = Vec::new();
         let db = DbManager::new();
         if let Ok(mut conn) = db.get_connection() {
             if let Ok(teams) = crate::db::teams::get_teams_summary(&mut conn) {
+                let ids: Vec<i32> = teams.iter().map(|(id, _, _)| *id as i32).collect();
+                let mut extras = crate::db::teams::get_team_list_extras(&mut conn, &ids)
+                    .unwrap_or_else(|e| {
+                        log::warn!("Could not load team colours and guestbook counts: {}", e);
+                        Default::default()
+                    });
                 let model = gtk::gio::ListStore::new::<TeamObject>();
                 for (id, name, logo_url) in teams {
-                    model.append(&TeamObject::new(id, name, logo_url));
+                    loaded.push((id, name.clone()));
+                    let extra = extras.remove(&(id as i32)).unwrap_or_default();
+                    model.append(&TeamObject::new(TeamData {
+                        id,
+                        name,
+                        logo_url,
+                        colors: extra.colors,
+                        guestbook_items: extra.guestbook_items,
+                    }));
                 }
                 self.context.set_all_teams(Some(model));
             }
         }
+        loaded
+    }
+
+    /// The team selected before the last shutdown or refresh, if any.
+    pub fn last_selected_team(&self) -> Option<u32> {
+        let db = DbManager::new();
+        let mut conn = db.get_connection().ok()?;
+        crate::db::preferences::get_last_selected_team(&mut conn).unwrap_or_else(|e| {
+            log::warn!("Could not read last selected team: {}", e);
+            None
+        })
+    }
+
+    fn remember_selected_team(&self, team_id: u32) {
+        let db = DbManager::new();
+        if let Err(e) = db
+            .get_connection()
+            .and_then(|mut conn| crate::db::preferences::set_last_selected_team(&mut conn, team_id))
+        {
+            log::warn!("Could not save selected team {}: {}", team_id, e);
+        }
     }
 
     fn load_team_data(&self, _team: TeamObject) {
@@ -71,3 +109,36 @@ impl MainController {
         // No-op: ContextObject now clears itself via refresh_from_db() -> clear_context().
     }
 }
+
+/// Dropdown position of the `preferred` team, or 0 when it is unset or no
+/// longer in the list.
+pub fn select_team_index(teams: &[(u32, String)], preferred: Option<u32>) -> usize {
+    preferred
+        .and_then(|id| teams.iter().position(|(team_id, _)| *team_id == id))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn teams() -> Vec<(u32, String)> {
+        vec![(1000, "Team A".to_string()), (2000, "Team B".to_string())]
+    }
+
+    #[test]
+    fn test_select_team_index_present() {
+        assert_eq!(select_team_index(&teams(), Some(2000)), 1);
+    }
+
+    #[test]
+    fn test_select_team_index_absent() {
+        assert_eq!(select_team_index(&teams(), Some(3000)), 0);
+        assert_eq!(select_team_index(&teams(), None), 0);
+    }
+
+    #[test]
+    fn test_select_team_index_empty() {
+        assert_eq!(select_team_index(&[], Some(1000)), 0);
+    }
+}
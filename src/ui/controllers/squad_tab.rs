@@ -1,12 +1,16 @@
+use crate::db::diff::PlayerTrend;
 use crate::rating::model::{Lineup, RatingPredictionModel, Team};
 use crate::rating::position_eval::evaluate_all_positions;
 use crate::rating::types::{Attitude, Location, TacticType, Weather};
+use crate::squad::filter::{injury_sort_key, player_number_sort_key, transfer_deadline_sort_key};
 use crate::ui::context_object::ContextObject;
-use crate::ui::player_display::PlayerDisplay;
+use crate::ui::player_display::{preferred_number_format, tsi_per_salary, PlayerDisplay};
 use crate::ui::player_object::PlayerObject;
+use crate::utils::number_format::NumberFormat;
 use gtk::glib;
+use gtk::prelude::*;
 use log::{debug, warn};
-use num_format::SystemLocale;
+use std::collections::HashMap;
 
 pub struct SquadTabController {
     context: ContextObject,
@@ -106,7 +110,12 @@ impl SquadTabController {
         }
     }
 
-    pub fn create_player_list_store(players: &[crate::chpp::model::Player]) -> gtk::ListStore {
+    /// Build the squad list store. When a trend is known for a player, its
+    /// arrow is appended to the form and TSI values.
+    pub fn create_player_list_store(
+        players: &[crate::chpp::model::Player],
+        trends: &HashMap<u32, PlayerTrend>,
+    ) -> gtk::ListStore {
         #[allow(deprecated)]
         let store = gtk::ListStore::new(&[
             glib::Type::STRING, // 0 Name
@@ -128,47 +137,108 @@ impl SquadTabController {
             glib::Type::STRING, // 16 Cards
             glib::Type::STRING, // 17 Mother Club
             glib::Type::OBJECT, // 18 PlayerObject
+            glib::Type::STRING, // 19 Gender
+            glib::Type::STRING, // 20 Value
+            glib::Type::F64,    // 21 Value sort key
+            glib::Type::STRING, // 22 Bonus
+            glib::Type::I32,    // 23 Injury sort key
+            glib::Type::U32,    // 24 Number sort key
+            glib::Type::STRING, // 25 Transfer deadline
+            glib::Type::I64,    // 26 Transfer deadline sort key
         ]);
 
-        let locale =
-            SystemLocale::default().unwrap_or_else(|_| SystemLocale::from_name("C").unwrap());
+        let number_format = preferred_number_format();
 
         for p in players {
-            let obj = PlayerObject::new(p.clone());
-            let preferred_pos = Self::calculate_preferred_position(p);
-            let display = PlayerDisplay::new(p, &locale, Some(&preferred_pos));
-
-            let bg = if p.MotherClubBonus {
-                Some("rgba(64, 224, 208, 0.3)".to_string())
-            } else {
-                None
-            };
-
-            store.insert_with_values(
-                None,
-                &[
-                    (0, &display.name),
-                    (1, &display.flag),
-                    (2, &display.number),
-                    (3, &display.age),
-                    (4, &display.form),
-                    (5, &display.tsi),
-                    (6, &display.salary),
-                    (7, &display.specialty),
-                    (8, &display.xp),
-                    (9, &display.leadership),
-                    (10, &display.loyalty),
-                    (11, &display.best_pos),
-                    (12, &display.last_pos),
-                    (13, &bg),
-                    (14, &display.stamina),
-                    (15, &display.injured),
-                    (16, &display.cards),
-                    (17, &display.mother_club),
-                    (18, &obj),
-                ],
-            );
+            #[allow(deprecated)]
+            let iter = store.append();
+            Self::set_player_row(&store, &iter, p, trends.get(&p.PlayerID), &number_format);
         }
         store
     }
+
+    /// Replace a player's row after their details were re-fetched, keeping
+    /// the trend arrows as `create_player_list_store` shows them.
+    /// Returns false when the player is not in the store.
+    #[allow(deprecated)]
+    pub fn update_player_row(
+        store: &gtk::ListStore,
+        player: &crate::chpp::model::Player,
+        trend: Option<&PlayerTrend>,
+    ) -> bool {
+        let Some(iter) = store.iter_first() else {
+            return false;
+        };
+        loop {
+            let row_player = store
+                .get_value(&iter, 18)
+                .get::<PlayerObject>()
+                .ok()
+                .map(|obj| obj.player().PlayerID);
+            if row_player == Some(player.PlayerID) {
+                Self::set_player_row(store, &iter, player, trend, &preferred_number_format());
+                return true;
+            }
+            if !store.iter_next(&iter) {
+                return false;
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    fn set_player_row(
+        store: &gtk::ListStore,
+        iter: &gtk::TreeIter,
+        p: &crate::chpp::model::Player,
+        trend: Option<&PlayerTrend>,
+        number_format: &NumberFormat,
+    ) {
+        let obj = PlayerObject::new(p.clone());
+        let preferred_pos = Self::calculate_preferred_position(p);
+        let mut display = PlayerDisplay::new(p, number_format, Some(&preferred_pos));
+        if let Some(trend) = trend {
+            display.form = format!("{} {}", display.form, trend.form.arrow());
+            display.tsi = format!("{} {}", display.tsi, trend.tsi.arrow());
+        }
+
+        let bg = if p.MotherClubBonus {
+            Some("rgba(64, 224, 208, 0.3)".to_string())
+        } else {
+            None
+        };
+
+        store.set(
+            iter,
+            &[
+                (0, &display.name),
+                (1, &display.flag),
+                (2, &display.number),
+                (3, &display.age),
+                (4, &display.form),
+                (5, &display.tsi),
+                (6, &display.salary),
+                (7, &display.specialty),
+                (8, &display.xp),
+                (9, &display.leadership),
+                (10, &display.loyalty),
+                (11, &display.best_pos),
+                (12, &display.last_pos),
+                (13, &bg),
+                (14, &display.stamina),
+                (15, &display.injured),
+                (16, &display.cards),
+                (17, &display.mother_club),
+                (18, &obj),
+                (19, &display.gender),
+                (20, &display.value),
+                // Players without a salary sort below everyone else.
+                (21, &tsi_per_salary(p).unwrap_or(-1.0)),
+                (22, &display.bonus),
+                (23, &injury_sort_key(p.InjuryLevel)),
+                (24, &player_number_sort_key(p)),
+                (25, &display.deadline),
+                (26, &transfer_deadline_sort_key(p)),
+            ],
+        );
+    }
 }
@@ -10,6 +10,7 @@ use gtk::glib;
 
 use log::{error, info, warn};
 use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 pub struct SyncController;
 
@@ -92,21 +93,23 @@ impl SyncController {
         });
     }
 
-    /// Performs the sync flow.
+    /// Performs the sync flow and returns what the window should reload.
     ///
     /// 1. Tries to sync with stored secrets.
-    /// 2. If that fails due to auth, starts the OAuth flow (Open Browser -> Get Code -> Verify -> Store).
-    /// 3. Retries sync.
-    /// 4. Reports progress via the provided sender.
+    /// 2. If no credentials are stored, hands over to the setup window.
+    /// 3. If that fails due to auth, starts the OAuth flow (Open Browser -> Get Code -> Verify -> Store).
+    /// 4. Retries sync.
+    /// 5. Reports progress via the provided sender.
     pub async fn perform_sync(
         window_weak: glib::WeakRef<NutmegWindow>,
         context: ContextObject,
         sender: tokio::sync::mpsc::UnboundedSender<(f64, String)>,
-    ) {
+    ) -> PostSyncAction {
         let db = Arc::new(DbManager::new());
         let sync = SyncService::new(db.clone());
         let key = crate::config::consumer_key();
         let secret = crate::config::consumer_secret();
+        let selected_team = context.selected_team().map(|t| t.team_data().id);
 
         // Progress callback adapter
         let sender_clone = sender.clone();
@@ -116,13 +119,15 @@ impl SyncController {
 
         let mut initial_fail_msg = None;
 
-        match sync
+        let result = sync
             .perform_sync_with_stored_secrets(key.clone(), secret.clone(), progress_cb.clone())
-            .await
-        {
+            .await;
+        let mut action = post_sync_action(&result, selected_team);
+
+        match result {
             Ok(Some((team_id, download_id))) => {
                 info!("Sync completed successfully");
-                context.refresh_from_db();
+                crate::db::team_cache::team_cache().invalidate();
                 Self::spawn_avatar_refresh(
                     db.clone(),
                     context.clone(),
@@ -133,22 +138,11 @@ impl SyncController {
                 );
             }
             Ok(None) => {
-                warn!("Sync failed: No credentials found, starting OAuth flow...");
-                // OAuth Flow
-                if let Err(e) = Self::start_oauth_flow(
-                    window_weak,
-                    context,
-                    &key,
-                    &secret,
-                    &sync,
-                    db.clone(),
-                    progress_cb,
-                )
-                .await
-                {
-                    error!("OAuth flow failed: {}", e);
-                    initial_fail_msg = Some(format!("Auth failed: {}", e));
-                }
+                warn!("Sync skipped: No credentials found, reopening setup");
+            }
+            Err(e) if needs_reauthentication(&e) => {
+                warn!("Sync failed due to auth ({}), starting OAuth flow...", e);
+                action = Self::reauthenticate(window_weak, context, sender.clone()).await;
             }
             Err(e) => {
                 error!("Sync failed: {}", e);
@@ -159,6 +153,44 @@ impl SyncController {
         if let Some(msg) = initial_fail_msg {
             let _ = sender.send((0.0, msg));
         }
+        action
+    }
+
+    /// Runs the OAuth flow again, replaces the stored access token and syncs.
+    ///
+    /// The local database is kept: the sync appends a new download like any
+    /// other refresh.
+    pub async fn reauthenticate(
+        window_weak: glib::WeakRef<NutmegWindow>,
+        context: ContextObject,
+        sender: tokio::sync::mpsc::UnboundedSender<(f64, String)>,
+    ) -> PostSyncAction {
+        let db = Arc::new(DbManager::new());
+        let sync = SyncService::new(db.clone());
+        let key = crate::config::consumer_key();
+        let secret = crate::config::consumer_secret();
+        let selected_team = context.selected_team().map(|t| t.team_data().id);
+
+        let sender_clone = sender.clone();
+        let progress_cb = Box::new(move |p: f64, msg: &str| {
+            let _ = sender_clone.send((p, msg.to_string()));
+        });
+
+        match Self::start_oauth_flow(window_weak, context, &key, &secret, &sync, db, progress_cb)
+            .await
+        {
+            Ok(team_id) => {
+                crate::db::team_cache::team_cache().invalidate();
+                PostSyncAction::Reload {
+                    team_id: selected_team.unwrap_or(team_id),
+                }
+            }
+            Err(e) => {
+                error!("OAuth flow failed: {}", e);
+                let _ = sender.send((0.0, format!("Auth failed: {}", e)));
+                PostSyncAction::KeepCurrent
+            }
+        }
     }
 
     async fn start_oauth_flow(
@@ -169,8 +201,12 @@ impl SyncController {
         sync: &SyncService,
         db: Arc<DbManager>,
         progress_cb: ProgressCallback,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let auth_service = HattrickAuthService::new();
+    ) -> Result<u32, Box<dyn std::error::Error>> {
+        let scopes = {
+            let mut conn = db.get_connection()?;
+            crate::db::preferences::get_chpp_scopes(&mut conn)?
+        };
+        let auth_service = HattrickAuthService::with_scopes(scopes);
         let secret_service = SystemSecretService::new();
 
         // 1. Get Auth URL
@@ -198,6 +234,7 @@ impl SyncController {
 
         if let Some(code) = code_opt {
             // 4. Verify Code
+            let code = crate::chpp::normalize_verification_code(&code)?;
             let (token, token_secret) = tokio::task::spawn_blocking(move || {
                 let auth_service = HattrickAuthService::new();
                 auth_service.verify_user(&code, &rt, &rs)
@@ -219,12 +256,15 @@ impl SyncController {
                     token,
                     token_secret,
                     progress_cb,
+                    CancellationToken::new(),
                 )
                 .await
             {
-                Ok((team_id, download_id)) => {
+                Ok((team_id, download_id, report)) => {
                     info!("Retry sync successful");
-                    context.refresh_from_db();
+                    if report.is_partial() {
+                        warn!("Players with basic data only: {:?}", report.fallback_ids);
+                    }
                     Self::spawn_avatar_refresh(
                         db,
                         context.clone(),
@@ -233,15 +273,46 @@ impl SyncController {
                         team_id,
                         download_id,
                     );
+                    Ok(team_id)
                 }
-                Err(e) => return Err(format!("Retry sync error: {}", e).into()),
+                Err(e) => Err(format!("Retry sync error: {}", e).into()),
             }
         } else {
             warn!("User cancelled OAuth dialog");
-            return Err("Cancelled".into());
+            Err("Cancelled".into())
         }
+    }
+}
+
+/// What the main window should do once a refresh has finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostSyncAction {
+    /// Reload the team dropdown and show the players of `team_id`.
+    Reload { team_id: u32 },
+    /// No credentials are stored: reopen the setup window.
+    OpenSetup,
+    /// The sync failed: keep the current view untouched.
+    KeepCurrent,
+}
 
-        Ok(())
+/// Whether a failed sync should send the user through the OAuth flow again.
+/// The client reports rejected or expired access tokens as `Auth` errors.
+pub fn needs_reauthentication(error: &NutmegError) -> bool {
+    matches!(error, NutmegError::Auth(_))
+}
+
+/// Decides what to reload after a sync. The team the user was looking at
+/// stays selected; without a selection the freshly synced team is shown.
+pub fn post_sync_action(
+    result: &Result<Option<(u32, i32)>, NutmegError>,
+    selected_team: Option<u32>,
+) -> PostSyncAction {
+    match result {
+        Ok(Some((synced_team, _))) => PostSyncAction::Reload {
+            team_id: selected_team.unwrap_or(*synced_team),
+        },
+        Ok(None) => PostSyncAction::OpenSetup,
+        Err(_) => PostSyncAction::KeepCurrent,
     }
 }
 
@@ -254,4 +325,55 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<SyncController>();
     }
+
+    #[test]
+    fn test_post_sync_action_keeps_selected_team() {
+        let result = Ok(Some((1000, 7)));
+        assert_eq!(
+            post_sync_action(&result, Some(2000)),
+            PostSyncAction::Reload { team_id: 2000 }
+        );
+    }
+
+    #[test]
+    fn test_post_sync_action_without_selection_shows_synced_team() {
+        let result = Ok(Some((1000, 7)));
+        assert_eq!(
+            post_sync_action(&result, None),
+            PostSyncAction::Reload { team_id: 1000 }
+        );
+    }
+
+    #[test]
+    fn test_post_sync_action_not_configured_opens_setup() {
+        assert_eq!(
+            post_sync_action(&Ok(None), Some(2000)),
+            PostSyncAction::OpenSetup
+        );
+    }
+
+    #[test]
+    fn test_post_sync_action_failure_keeps_view() {
+        let result = Err(NutmegError::Network("timeout".to_string()));
+        assert_eq!(
+            post_sync_action(&result, Some(2000)),
+            PostSyncAction::KeepCurrent
+        );
+    }
+
+    #[test]
+    fn test_needs_reauthentication_only_on_auth_errors() {
+        assert!(needs_reauthentication(&NutmegError::Auth(
+            "invalid_token".to_string()
+        )));
+        assert!(!needs_reauthentication(&NutmegError::Network(
+            "timeout".to_string()
+        )));
+        assert!(!needs_reauthentication(&NutmegError::ChppApi {
+            code: 50,
+            message: "Server overloaded".to_string(),
+            error_guid: None,
+            request: None,
+        }));
+    }
 }
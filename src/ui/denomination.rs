@@ -0,0 +1,206 @@
+/* denomination.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::db::manager::DbManager;
+use crate::db::teams::get_current_user_language_id;
+use crate::db::translations::{get_translation, has_translations, ITEM_SKILL_LEVEL};
+use diesel::SqliteConnection;
+use gettextrs::gettext;
+use log::warn;
+
+// Hattrick's textual denominations for skill levels.
+
+const SKILL_LEVEL_NAMES: [&str; 21] = [
+    "non-existent",
+    "disastrous",
+    "wretched",
+    "poor",
+    "weak",
+    "inadequate",
+    "passable",
+    "solid",
+    "excellent",
+    "formidable",
+    "outstanding",
+    "brilliant",
+    "magnificent",
+    "world class",
+    "supernatural",
+    "titanic",
+    "extra-terrestrial",
+    "mythical",
+    "magical",
+    "utopian",
+    "divine",
+];
+
+/// Map a level on Hattrick's 0-20 scale to its denomination.
+///
+/// Levels above 20 are clamped to "divine". The returned string is the
+/// untranslated msgid; wrap it in `gettext` before displaying it.
+pub fn skill_level_name(level: u32) -> &'static str {
+    let index = (level as usize).min(SKILL_LEVEL_NAMES.len() - 1);
+    SKILL_LEVEL_NAMES[index]
+}
+
+/// Number followed by its translated denomination, e.g. "14 — supernatural".
+pub fn skill_tooltip(level: u32) -> String {
+    format!("{} — {}", level, gettext(skill_level_name(level)))
+}
+
+/// Denomination of a skill level in the user's language, as Hattrick itself
+/// words it when the translations have been cached, otherwise the translated
+/// built-in name.
+pub fn translated_skill_level(conn: &mut SqliteConnection, language_id: u32, level: u32) -> String {
+    let level = level.min(SKILL_LEVEL_NAMES.len() as u32 - 1);
+    match get_translation(conn, language_id, ITEM_SKILL_LEVEL, level) {
+        Ok(Some(text)) => text,
+        Ok(None) => gettext(skill_level_name(level)),
+        Err(e) => {
+            warn!("Could not read skill level translation: {}", e);
+            gettext(skill_level_name(level))
+        }
+    }
+}
+
+/// Skill denominations in the language of the signed-in user.
+pub struct SkillDenominations {
+    /// One name per level, `None` when Hattrick's translations for the
+    /// user's language have not been cached.
+    names: Option<Vec<String>>,
+}
+
+impl SkillDenominations {
+    /// The gettext names, used when no translations are available.
+    pub fn builtin() -> Self {
+        Self { names: None }
+    }
+
+    pub fn for_language(conn: &mut SqliteConnection, language_id: u32) -> Self {
+        match has_translations(conn, language_id) {
+            Ok(true) => Self {
+                names: Some(
+                    (0..SKILL_LEVEL_NAMES.len() as u32)
+                        .map(|level| translated_skill_level(conn, language_id, level))
+                        .collect(),
+                ),
+            },
+            Ok(false) => Self::builtin(),
+            Err(e) => {
+                warn!("Could not read skill level translations: {}", e);
+                Self::builtin()
+            }
+        }
+    }
+
+    /// Read the user's language from the database and load its
+    /// denominations.
+    pub fn for_current_user() -> Self {
+        let db = DbManager::new();
+        let Ok(mut conn) = db.get_connection() else {
+            return Self::builtin();
+        };
+        match get_current_user_language_id(&mut conn) {
+            Ok(Some(language_id)) => Self::for_language(&mut conn, language_id),
+            Ok(None) => Self::builtin(),
+            Err(e) => {
+                warn!("Could not read the user's language: {}", e);
+                Self::builtin()
+            }
+        }
+    }
+
+    /// Number followed by its denomination, as `skill_tooltip`.
+    pub fn tooltip(&self, level: u32) -> String {
+        match &self.names {
+            Some(names) => {
+                let index = (level as usize).min(names.len() - 1);
+                format!("{} — {}", level, names[index])
+            }
+            None => skill_tooltip(level),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_skill_level_name_boundaries() {
+        assert_eq!(skill_level_name(0), "non-existent");
+        assert_eq!(skill_level_name(1), "disastrous");
+        assert_eq!(skill_level_name(8), "excellent");
+        assert_eq!(skill_level_name(20), "divine");
+    }
+
+    #[test]
+    fn test_skill_level_name_out_of_range_is_clamped() {
+        assert_eq!(skill_level_name(25), "divine");
+    }
+
+    #[test]
+    fn test_skill_tooltip() {
+        assert_eq!(skill_tooltip(0), "0 — non-existent");
+        assert_eq!(skill_tooltip(14), "14 — supernatural");
+        assert_eq!(skill_tooltip(22), "22 — divine");
+    }
+
+    #[test]
+    #[serial]
+    fn test_translated_skill_level_prefers_cached_text() {
+        use crate::chpp::model::{SkillLevelList, TranslationItem, TranslationTexts, Translations};
+        use crate::db::download_entries::create_download;
+        use crate::db::manager::DbManager;
+        use crate::db::translations::save_translations;
+
+        let db = DbManager::from_url(":memory:");
+        db.run_migrations().expect("migrations");
+        let mut conn = db.get_connection().expect("conn");
+        let dl = create_download(&mut conn, "2026-01-01T00:00:00Z", "completed").unwrap();
+
+        let data = Translations {
+            Texts: TranslationTexts {
+                LanguageID: 5,
+                SkillLevels: SkillLevelList {
+                    Levels: vec![TranslationItem {
+                        Value: 20,
+                        Text: "divin".to_string(),
+                    }],
+                },
+                ..Default::default()
+            },
+        };
+        save_translations(&mut conn, &data, dl).unwrap();
+
+        assert_eq!(translated_skill_level(&mut conn, 5, 20), "divin");
+        assert_eq!(translated_skill_level(&mut conn, 5, 23), "divin");
+        // Not cached: level or language
+        assert_eq!(translated_skill_level(&mut conn, 5, 14), "supernatural");
+        assert_eq!(translated_skill_level(&mut conn, 2, 20), "divine");
+
+        let denominations = SkillDenominations::for_language(&mut conn, 5);
+        assert_eq!(denominations.tooltip(20), "20 — divin");
+        assert_eq!(denominations.tooltip(14), "14 — supernatural");
+        let builtin = SkillDenominations::for_language(&mut conn, 2);
+        assert_eq!(builtin.tooltip(20), "20 — divine");
+    }
+}
@@ -1,8 +1,10 @@
 pub mod components;
 pub mod context_object;
 pub mod controllers;
+pub mod denomination;
 pub mod oauth_dialog;
 pub mod player_display;
 pub mod player_object;
-
+pub mod style;
+pub mod team_display;
 pub mod team_object;
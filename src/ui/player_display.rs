@@ -19,19 +19,25 @@
  */
 
 use crate::chpp::model::Player;
+use crate::db::manager::DbManager;
+use crate::rating::types::PlayerSkill;
+use crate::utils::number_format::{format_number, NumberFormat};
+use chrono::{DateTime, Utc};
 use gettextrs::gettext;
-use num_format::{Buffer, SystemLocale};
+use num_format::CustomFormat;
 
 // Wraps the Player object for display purposes (Decorator pattern)
 
 pub struct PlayerDisplay {
     pub name: String,
     pub flag: String,
+    pub gender: String,
     pub number: String,
     pub age: String,
     pub form: String,
     pub tsi: String,
     pub salary: String,
+    pub value: String,
     pub specialty: String,
     pub xp: String,
     pub leadership: String,
@@ -42,17 +48,20 @@ pub struct PlayerDisplay {
     pub injured: String,
     pub cards: String,
     pub mother_club: String,
+    pub bonus: String,
+    pub deadline: String,
     pub _mother_club_bonus: bool,
 }
 
 impl PlayerDisplay {
-    pub fn new(p: &Player, locale: &SystemLocale, preferred_position: Option<&str>) -> Self {
+    pub fn new(p: &Player, number_format: &NumberFormat, preferred_position: Option<&str>) -> Self {
         // translators: Player full name format. {first} = given name, {last} = family name.
         // Swap to "{last} {first}" for cultures where family name comes first.
         let name = gettext("{first} {last}")
             .replace("{first}", &p.FirstName)
             .replace("{last}", &p.LastName);
         let flag = p.Flag.clone().unwrap_or_else(|| "🏳️".to_string());
+        let gender = gender_emoji(p.GenderID).to_string();
         let number = p
             .PlayerNumber
             .map(|n| n.to_string())
@@ -60,28 +69,23 @@ impl PlayerDisplay {
         let age = format!("{}.{}", p.Age, p.AgeDays.unwrap_or(0));
         let form = p.PlayerForm.to_string();
 
-        // TSI formatted with locale (thousands separators)
-        let mut buf_tsi = Buffer::default();
-        buf_tsi.write_formatted(&p.TSI, locale);
-        let tsi = buf_tsi.as_str().to_string();
+        // TSI formatted with the preferred thousands separator
+        let tsi = format_number(p.TSI.into(), number_format);
 
-        let mut buf_salary = Buffer::default();
-        buf_salary.write_formatted(&p.Salary, locale);
+        let salary = format_number(p.Salary.into(), number_format);
         // FIXME: this should use the currency symbol of the country the team is in.
         // FIXME: Also, depending on locale, symbol may be before or after.
         // FIXME: Amount in SEK, should be converted to local currency using currencies table.
-        let salary = format!("{} €", buf_salary.as_str());
+        let salary = format!("{} €", salary);
 
+        let value = tsi_per_salary(p)
+            .map(|v| format!("{:.2}", v))
+            .unwrap_or_else(|| "-".to_string());
+
+        // The list leaves the column blank for players without a specialty.
         let specialty = match p.Specialty {
-            Some(0) => gettext(""),
-            Some(1) => gettext("Technical"),
-            Some(2) => gettext("Quick"),
-            Some(3) => gettext("Powerful"),
-            Some(4) => gettext("Unpredictable"),
-            Some(5) => gettext("Head specialist"),
-            Some(6) => gettext("Resilient"),
-            Some(8) => gettext("Support"),
-            _ => "".to_string(),
+            Some(0) | None => String::new(),
+            code => specialty_name(code),
         };
 
         let xp = p.Experience.to_string();
@@ -112,13 +116,7 @@ impl PlayerDisplay {
             .map(|s| s.StaminaSkill.to_string())
             .unwrap_or_else(|| "-".to_string());
 
-        let injured = match p.InjuryLevel {
-            Some(0) => "🩹".to_string(),
-            // translators: Injury duration abbreviation, e.g. "3w" = 3 weeks out.
-            // {n} is the number of weeks. Replace "w" with the local abbreviation.
-            Some(i) if i > 0 => gettext("🚑 {n}w").replace("{n}", &i.to_string()),
-            _ => "".to_string(),
-        };
+        let injured = format_injury(p.InjuryLevel).unwrap_or_default();
 
         let cards = match p.Cards {
             Some(1) => "🟨".to_string(),
@@ -135,14 +133,26 @@ impl PlayerDisplay {
             "".to_string()
         };
 
+        let bonus = match crate::rating::strength::form_style_bonus(p) {
+            0 => String::new(),
+            b => format!("+{}.{:02}", b / 100, b % 100),
+        };
+
+        let deadline = p
+            .transfer_deadline()
+            .map(|deadline| format_countdown(deadline, Utc::now()))
+            .unwrap_or_default();
+
         Self {
             name,
             flag,
+            gender,
             number,
             age,
             form,
             tsi,
             salary,
+            value,
             specialty,
             xp,
             leadership,
@@ -153,11 +163,136 @@ impl PlayerDisplay {
             injured,
             cards,
             mother_club,
+            bonus,
+            deadline,
             _mother_club_bonus: p.MotherClubBonus,
         }
     }
 }
 
+/// Time left until a transfer deadline: days and hours ("2d 4h"), hours and
+/// minutes ("3h 20m"), or only minutes in the last hour. "expired" once the
+/// deadline has passed.
+pub fn format_countdown(deadline: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let remaining = deadline - now;
+    if remaining <= chrono::Duration::zero() {
+        return gettext("expired");
+    }
+    let days = remaining.num_days();
+    let hours = remaining.num_hours() % 24;
+    let minutes = remaining.num_minutes() % 60;
+    if days > 0 {
+        // translators: Time left on a transfer, e.g. "2d 4h".
+        gettext("{days}d {hours}h")
+            .replace("{days}", &days.to_string())
+            .replace("{hours}", &hours.to_string())
+    } else if hours > 0 {
+        // translators: Time left on a transfer, e.g. "3h 20m".
+        gettext("{hours}h {minutes}m")
+            .replace("{hours}", &hours.to_string())
+            .replace("{minutes}", &minutes.to_string())
+    } else {
+        // Round the last minute up so an open auction never shows "0m".
+        // translators: Time left on a transfer, e.g. "45m".
+        gettext("{minutes}m").replace("{minutes}", &minutes.max(1).to_string())
+    }
+}
+
+/// TSI earned per unit of salary, a rough measure of how much a player is
+/// worth for what they cost. `None` when the salary is zero.
+pub fn tsi_per_salary(player: &Player) -> Option<f64> {
+    if player.Salary == 0 {
+        None
+    } else {
+        Some(player.TSI as f64 / player.Salary as f64)
+    }
+}
+
+/// Number format for displayed values that follows the system locale. See
+/// [`NumberFormat::custom_format`] for the fallbacks.
+pub fn display_locale() -> CustomFormat {
+    NumberFormat::System.custom_format()
+}
+
+/// Number format chosen in the preferences, or the system one when it is
+/// unset or cannot be read.
+pub fn preferred_number_format() -> NumberFormat {
+    let db = DbManager::new();
+    let Ok(mut conn) = db.get_connection() else {
+        return NumberFormat::System;
+    };
+    crate::db::preferences::get_number_format(&mut conn).unwrap_or_else(|e| {
+        log::warn!("Could not read number format: {}", e);
+        NumberFormat::System
+    })
+}
+
+/// Skills that matter most for a CHPP player category, most important
+/// first: 1 keeper, 2 wing back, 3 central defender, 4 winger, 5 inner
+/// midfielder, 6 forward. Other categories have no primary skills.
+pub fn primary_skills_for(category: Option<u32>) -> &'static [PlayerSkill] {
+    match category {
+        Some(1) => &[PlayerSkill::Keeper, PlayerSkill::Defending],
+        Some(2) => &[PlayerSkill::Defending, PlayerSkill::Winger],
+        Some(3) => &[PlayerSkill::Defending, PlayerSkill::Playmaking],
+        Some(4) => &[PlayerSkill::Winger, PlayerSkill::Playmaking],
+        Some(5) => &[PlayerSkill::Playmaking, PlayerSkill::Passing],
+        Some(6) => &[PlayerSkill::Scoring, PlayerSkill::Passing],
+        _ => &[],
+    }
+}
+
+/// Name of a CHPP specialty code: 0 none, 1 technical, 2 quick, 3 powerful,
+/// 4 unpredictable, 5 head specialist, 6 resilient, 8 support. Code 7 is not
+/// used by Hattrick; it and unknown codes map to an empty string.
+pub fn specialty_name(code: Option<u32>) -> String {
+    match code {
+        Some(0) => gettext("No specialty"),
+        Some(1) => gettext("Technical"),
+        Some(2) => gettext("Quick"),
+        Some(3) => gettext("Powerful"),
+        Some(4) => gettext("Unpredictable"),
+        Some(5) => gettext("Head specialist"),
+        Some(6) => gettext("Resilient"),
+        Some(8) => gettext("Support"),
+        _ => String::new(),
+    }
+}
+
+/// Injury indicator for a player's `InjuryLevel`: `None` when healthy (-1 or
+/// unknown), a plaster when bruised (0), and the weeks out otherwise.
+pub fn format_injury(level: Option<i32>) -> Option<String> {
+    match level {
+        Some(0) => Some("🩹".to_string()),
+        // translators: Injury duration abbreviation, e.g. "3w" = 3 weeks out.
+        // {n} is the number of weeks. Replace "w" with the local abbreviation.
+        Some(i) if i > 0 => Some(gettext("🚑 {n}w").replace("{n}", &i.to_string())),
+        _ => None,
+    }
+}
+
+/// Emoji shown for a player's `GenderID` (1 = male, 2 = female).
+pub fn gender_emoji(gender_id: Option<u32>) -> &'static str {
+    match gender_id {
+        Some(2) => "♀️", // Female
+        _ => "♂️",       // Male (default)
+    }
+}
+
+/// A player's statement to display, trimmed, or `None` when there is
+/// nothing worth showing.
+pub fn statement_text(statement: Option<&str>) -> Option<&str> {
+    statement.map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// Player page on hattrick.org.
+pub fn player_profile_url(player_id: u32) -> String {
+    format!(
+        "https://www.hattrick.org/Club/Players/Player.aspx?playerId={}",
+        player_id
+    )
+}
+
 pub fn translate_position_id(id: u32) -> String {
     match id {
         100 => gettext("Keeper"),
@@ -209,6 +344,7 @@ pub fn translate_position_id(id: u32) -> String {
 mod tests {
     use super::*;
     use crate::chpp::model::{LastMatch, Player, PlayerSkills};
+    use chrono::TimeZone;
 
     fn create_dummy_player() -> Player {
         Player {
@@ -269,6 +405,7 @@ mod tests {
             MatchesCurrentTeam: None,
             GoalsCurrentTeam: None,
             AssistsCurrentTeam: None,
+            TransferDetails: None,
             LastMatch: Some(LastMatch {
                 Date: "2023-01-01".to_string(),
                 MatchId: 100,
@@ -282,16 +419,14 @@ mod tests {
 
     #[test]
     fn test_player_display_formatting() {
-        // Use C locale for predictable output (no separators vs comma/dot ambiguity in tests)
-        // Or we can assume strict output given SystemLocale::from_name("C")
-        let locale = SystemLocale::from_name("C").unwrap();
+        // No grouping for predictable output (no separators vs comma/dot ambiguity in tests)
         let p = create_dummy_player();
-        let display = PlayerDisplay::new(&p, &locale, None);
+        let display = PlayerDisplay::new(&p, &NumberFormat::None, None);
 
         assert_eq!(display.name, "John Doe");
         assert_eq!(display.number, "10");
         assert_eq!(display.age, "20.10");
-        assert_eq!(display.tsi, "10000"); // C locale has no separators
+        assert_eq!(display.tsi, "10000"); // no separators
         assert_eq!(display.salary, "50000 €");
         // gettext might return English or translation, but in unit test environment usually defaults to msgid if not initialized
         // Assuming "Quick" for ID 2
@@ -300,10 +435,63 @@ mod tests {
 
         assert_eq!(display.xp, "3");
         assert_eq!(display.mother_club, "🌟");
+        assert_eq!(display.bonus, "+0.97");
         assert_eq!(display.injured, "🚑 1w");
         assert_eq!(display.cards, "🟨");
         assert_eq!(display.stamina, "7");
         assert_eq!(display.last_pos, "Keeper");
+        assert_eq!(display.gender, "♂️");
+    }
+
+    #[test]
+    fn test_tsi_per_salary() {
+        let mut p = create_dummy_player();
+        assert_eq!(tsi_per_salary(&p), Some(0.2));
+
+        p.Salary = 0;
+        assert_eq!(tsi_per_salary(&p), None);
+        let locale = NumberFormat::None;
+        assert_eq!(PlayerDisplay::new(&p, &locale, None).value, "-");
+
+        // Homegrown players on a youth contract: high TSI, minimum wage.
+        p.TSI = 250_000;
+        p.Salary = 250;
+        assert_eq!(tsi_per_salary(&p), Some(1000.0));
+        assert_eq!(PlayerDisplay::new(&p, &locale, None).value, "1000.00");
+    }
+
+    #[test]
+    fn test_format_injury() {
+        assert_eq!(format_injury(Some(-1)), None);
+        assert_eq!(format_injury(None), None);
+        assert_eq!(format_injury(Some(0)).as_deref(), Some("🩹"));
+        assert_eq!(format_injury(Some(3)).as_deref(), Some("🚑 3w"));
+    }
+
+    #[test]
+    fn test_gender_emoji() {
+        assert_eq!(gender_emoji(Some(1)), "♂️");
+        assert_eq!(gender_emoji(Some(2)), "♀️");
+        assert_eq!(gender_emoji(None), "♂️");
+    }
+
+    #[test]
+    fn test_player_profile_url() {
+        assert_eq!(
+            player_profile_url(123456789),
+            "https://www.hattrick.org/Club/Players/Player.aspx?playerId=123456789"
+        );
+    }
+
+    #[test]
+    fn test_statement_text() {
+        assert_eq!(statement_text(None), None);
+        assert_eq!(statement_text(Some("")), None);
+        assert_eq!(statement_text(Some("  \n\t ")), None);
+        assert_eq!(
+            statement_text(Some("  Here to win trophies.\n")),
+            Some("Here to win trophies.")
+        );
     }
 
     #[test]
@@ -313,9 +501,107 @@ mod tests {
         // We'll skip complex locale verification to avoid environment flakiness,
         // relying on num-format's own tests for correctness.
         // Just verify it doesn't crash.
-        let locale =
-            SystemLocale::default().unwrap_or_else(|_| SystemLocale::from_name("C").unwrap());
+        let locale = NumberFormat::System;
         let p = create_dummy_player();
         let _display = PlayerDisplay::new(&p, &locale, None);
     }
+
+    #[test]
+    fn test_format_countdown_future_deadline() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let deadline = now + chrono::Duration::days(2) + chrono::Duration::hours(4);
+        assert_eq!(format_countdown(deadline, now), "2d 4h");
+
+        let deadline = now + chrono::Duration::hours(3) + chrono::Duration::minutes(20);
+        assert_eq!(format_countdown(deadline, now), "3h 20m");
+    }
+
+    #[test]
+    fn test_format_countdown_imminent_deadline() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            format_countdown(now + chrono::Duration::minutes(45), now),
+            "45m"
+        );
+        assert_eq!(
+            format_countdown(now + chrono::Duration::seconds(20), now),
+            "1m"
+        );
+    }
+
+    #[test]
+    fn test_format_countdown_passed_deadline() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(format_countdown(now, now), "expired");
+        assert_eq!(
+            format_countdown(now - chrono::Duration::hours(1), now),
+            "expired"
+        );
+    }
+
+    #[test]
+    fn test_primary_skills_for_keeper() {
+        assert_eq!(
+            primary_skills_for(Some(1)),
+            &[PlayerSkill::Keeper, PlayerSkill::Defending]
+        );
+    }
+
+    #[test]
+    fn test_primary_skills_for_forward() {
+        assert_eq!(
+            primary_skills_for(Some(6)),
+            &[PlayerSkill::Scoring, PlayerSkill::Passing]
+        );
+    }
+
+    #[test]
+    fn test_primary_skills_for_unknown_category() {
+        assert!(primary_skills_for(None).is_empty());
+        assert!(primary_skills_for(Some(0)).is_empty());
+        assert!(primary_skills_for(Some(7)).is_empty());
+    }
+
+    #[test]
+    fn test_specialty_name_codes() {
+        assert_eq!(specialty_name(Some(0)), "No specialty");
+        assert_eq!(specialty_name(Some(1)), "Technical");
+        assert_eq!(specialty_name(Some(2)), "Quick");
+        assert_eq!(specialty_name(Some(3)), "Powerful");
+        assert_eq!(specialty_name(Some(4)), "Unpredictable");
+        assert_eq!(specialty_name(Some(5)), "Head specialist");
+        assert_eq!(specialty_name(Some(6)), "Resilient");
+        assert_eq!(specialty_name(Some(7)), "");
+        assert_eq!(specialty_name(Some(8)), "Support");
+        assert_eq!(specialty_name(None), "");
+    }
+
+    #[test]
+    fn test_list_specialty_matches_specialty_name() {
+        let locale = NumberFormat::System;
+        for code in 1..=8 {
+            let p = Player {
+                Specialty: Some(code),
+                ..create_dummy_player()
+            };
+            let display = PlayerDisplay::new(&p, &locale, None);
+            assert_eq!(display.specialty, specialty_name(Some(code)));
+        }
+        let p = Player {
+            Specialty: Some(0),
+            ..create_dummy_player()
+        };
+        assert_eq!(PlayerDisplay::new(&p, &locale, None).specialty, "");
+    }
+
+    #[test]
+    fn test_display_locale_groups_digits() {
+        let locale = display_locale();
+        let mut buf = num_format::Buffer::default();
+        buf.write_formatted(&1_000_000, &locale);
+        let formatted = buf.as_str();
+        assert!(formatted.len() > "1000000".len(), "{}", formatted);
+        let digits: String = formatted.chars().filter(|c| c.is_ascii_digit()).collect();
+        assert_eq!(digits, "1000000");
+    }
 }
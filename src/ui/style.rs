@@ -0,0 +1,93 @@
+/* style.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use gtk::{gdk, glib};
+use log::{debug, info};
+use std::path::{Path, PathBuf};
+
+/// Bundled stylesheet, compiled into the binary.
+const BUNDLED_CSS: &str = include_str!("../style.css");
+
+/// Name of the optional user stylesheet inside the app config directory.
+const USER_CSS_FILE: &str = "style.css";
+
+/// Path of the user stylesheet under `config_dir`, e.g.
+/// `~/.config/nutmeg/style.css` for the XDG config directory.
+pub fn user_stylesheet_path_in(config_dir: &Path) -> PathBuf {
+    config_dir.join("nutmeg").join(USER_CSS_FILE)
+}
+
+/// Path of the user stylesheet in the current user's config directory.
+pub fn user_stylesheet_path() -> PathBuf {
+    user_stylesheet_path_in(&glib::user_config_dir())
+}
+
+/// Register the bundled stylesheet and, when present, the user stylesheet on
+/// the default display. The user stylesheet sits one priority level above the
+/// bundled one, so it can redefine colours such as `@mother_club_bg`.
+///
+/// Returns the providers that were registered, bundled first.
+pub fn load_css_providers() -> Vec<gtk::CssProvider> {
+    let Some(display) = gdk::Display::default() else {
+        debug!("No default display, skipping CSS providers");
+        return Vec::new();
+    };
+
+    let bundled = gtk::CssProvider::new();
+    bundled.load_from_string(BUNDLED_CSS);
+    gtk::style_context_add_provider_for_display(
+        &display,
+        &bundled,
+        gtk::STYLE_PROVIDER_PRIORITY_APPLICATION,
+    );
+    let mut providers = vec![bundled];
+
+    let user_path = user_stylesheet_path();
+    if user_path.is_file() {
+        info!("Loading user stylesheet from {}", user_path.display());
+        let user = gtk::CssProvider::new();
+        user.load_from_path(&user_path);
+        gtk::style_context_add_provider_for_display(
+            &display,
+            &user,
+            gtk::STYLE_PROVIDER_PRIORITY_APPLICATION + 1,
+        );
+        providers.push(user);
+    } else {
+        debug!("No user stylesheet at {}", user_path.display());
+    }
+
+    providers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_stylesheet_path_in_config_dir() {
+        let path = user_stylesheet_path_in(Path::new("/home/manager/.config"));
+        assert_eq!(
+            path,
+            PathBuf::from("/home/manager/.config/nutmeg/style.css")
+        );
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,184 @@
+/* team_display.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use crate::chpp::model::{Cup, HofPlayer};
+use gettextrs::{gettext, ngettext};
+use gtk::gdk;
+
+/// One-line summary of a team's progress in its cup, shown in the team info
+/// panel.
+pub fn cup_status_text(cup: &Cup) -> String {
+    if cup.StillInCup != Some(true) {
+        return gettext("Eliminated");
+    }
+    match cup.MatchRoundsLeft {
+        Some(n) => ngettext(
+            "Still in cup — {n} round left",
+            "Still in cup — {n} rounds left",
+            n,
+        )
+        .replace("{n}", &n.to_string()),
+        None => gettext("Still in cup"),
+    }
+}
+
+/// Guestbook line of the team info panel and tooltip.
+pub fn guestbook_text(items: u32) -> String {
+    ngettext("{n} guestbook entry", "{n} guestbook entries", items)
+        .replace("{n}", &items.to_string())
+}
+
+/// Name of a Hall of Fame player, with their nickname when they have one:
+/// `First "Nick" Last`.
+pub fn hof_player_name(player: &HofPlayer) -> String {
+    match player.NickName.as_deref().filter(|n| !n.is_empty()) {
+        Some(nick) => format!("{} \"{}\" {}", player.FirstName, nick, player.LastName),
+        None => format!("{} {}", player.FirstName, player.LastName),
+    }
+}
+
+/// Peak skills of a Hall of Fame player, strongest first, e.g.
+/// "Keeper 17 · Set Pieces 14". Skills Hattrick did not send are left out.
+pub fn hof_peak_skills(player: &HofPlayer) -> String {
+    let mut skills: Vec<(String, u32)> = [
+        (gettext("Keeper"), player.KeeperSkill),
+        (gettext("Defending"), player.DefenderSkill),
+        (gettext("Playmaking"), player.PlaymakerSkill),
+        (gettext("Winger"), player.WingerSkill),
+        (gettext("Passing"), player.PassingSkill),
+        (gettext("Scoring"), player.ScorerSkill),
+        (gettext("Set Pieces"), player.SetPiecesSkill),
+    ]
+    .into_iter()
+    .filter_map(|(name, level)| level.map(|level| (name, level)))
+    .collect();
+    // Stable sort, so equal skills keep the order above.
+    skills.sort_by(|a, b| b.1.cmp(&a.1));
+    skills
+        .iter()
+        .map(|(name, level)| format!("{} {}", name, level))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+/// Parse a kit colour as sent by Hattrick: six hex digits, usually without
+/// a leading `#` (e.g. "288032"). Anything else gives `None`.
+pub fn parse_hex_color(s: &str) -> Option<gdk::RGBA> {
+    let hex = s.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let (r, g, b) = (channel(0)?, channel(2)?, channel(4)?);
+    Some(gdk::RGBA::new(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        1.0,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cup(still_in_cup: Option<bool>, rounds_left: Option<u32>) -> Cup {
+        Cup {
+            StillInCup: still_in_cup,
+            MatchRoundsLeft: rounds_left,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_guestbook_text() {
+        assert_eq!(guestbook_text(0), "0 guestbook entries");
+        assert_eq!(guestbook_text(1), "1 guestbook entry");
+        assert_eq!(guestbook_text(42), "42 guestbook entries");
+    }
+
+    #[test]
+    fn test_cup_status_text_still_in_cup() {
+        assert_eq!(
+            cup_status_text(&cup(Some(true), Some(3))),
+            "Still in cup — 3 rounds left"
+        );
+        assert_eq!(
+            cup_status_text(&cup(Some(true), Some(1))),
+            "Still in cup — 1 round left"
+        );
+        assert_eq!(cup_status_text(&cup(Some(true), None)), "Still in cup");
+    }
+
+    #[test]
+    fn test_cup_status_text_eliminated() {
+        assert_eq!(cup_status_text(&cup(Some(false), Some(3))), "Eliminated");
+        assert_eq!(cup_status_text(&cup(None, None)), "Eliminated");
+    }
+
+    #[test]
+    fn test_hof_player_name() {
+        let mut player = HofPlayer {
+            FirstName: "Declan".to_string(),
+            LastName: "Doyle".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(hof_player_name(&player), "Declan Doyle");
+        player.NickName = Some(String::new());
+        assert_eq!(hof_player_name(&player), "Declan Doyle");
+        player.NickName = Some("The Wall".to_string());
+        assert_eq!(hof_player_name(&player), "Declan \"The Wall\" Doyle");
+    }
+
+    #[test]
+    fn test_hof_peak_skills_strongest_first() {
+        let player = HofPlayer {
+            KeeperSkill: Some(14),
+            DefenderSkill: Some(3),
+            SetPiecesSkill: Some(17),
+            PassingSkill: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            hof_peak_skills(&player),
+            "Set Pieces 17 · Keeper 14 · Defending 3 · Passing 3"
+        );
+        assert_eq!(hof_peak_skills(&HofPlayer::default()), "");
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        let green = parse_hex_color("288032").expect("valid colour");
+        assert_eq!(
+            (green.red(), green.green(), green.blue()),
+            (40.0 / 255.0, 128.0 / 255.0, 50.0 / 255.0)
+        );
+        assert_eq!(green.alpha(), 1.0);
+
+        let white = parse_hex_color("ffffff").expect("valid colour");
+        assert_eq!((white.red(), white.green(), white.blue()), (1.0, 1.0, 1.0));
+        assert!(parse_hex_color("#FFFFFF").is_some());
+
+        assert!(parse_hex_color("").is_none());
+        assert!(parse_hex_color("xyz").is_none());
+        assert!(parse_hex_color("fff").is_none());
+        assert!(parse_hex_color("12345g").is_none());
+    }
+}
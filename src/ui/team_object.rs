@@ -7,6 +7,9 @@ pub struct TeamData {
     pub id: u32,
     pub name: String,
     pub logo_url: Option<String>,
+    /// Kit colours as stored from `TeamColors`: (background, primary).
+    pub colors: Option<(String, String)>,
+    pub guestbook_items: Option<u32>,
 }
 
 mod imp {
@@ -31,11 +34,9 @@ glib::wrapper! {
 }
 
 impl TeamObject {
-    pub fn new(id: u32, name: String, logo_url: Option<String>) -> Self {
+    pub fn new(data: TeamData) -> Self {
         let obj: Self = glib::Object::new();
-        obj.imp()
-            .data
-            .replace(Some(TeamData { id, name, logo_url }));
+        obj.imp().data.replace(Some(data));
         obj
     }
 
@@ -0,0 +1,146 @@
+/* hattrick_time.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Utc};
+
+// Hattrick runs on Swedish time: CET, or CEST during EU summer time.
+
+/// Last Sunday of `month`, at 01:00 UTC, when EU summer time starts
+/// (March) or ends (October).
+fn summer_time_switch(year: i32, month: u32) -> DateTime<Utc> {
+    let last_day = NaiveDate::from_ymd_opt(year, month, 31).expect("month has 31 days");
+    let sunday = last_day - Duration::days(last_day.weekday().num_days_from_sunday() as i64);
+    sunday.and_hms_opt(1, 0, 0).expect("valid time").and_utc()
+}
+
+/// How far Hattrick time is ahead of UTC at `at`.
+pub fn hattrick_offset(at: DateTime<Utc>) -> Duration {
+    if at >= summer_time_switch(at.year(), 3) && at < summer_time_switch(at.year(), 10) {
+        Duration::hours(2)
+    } else {
+        Duration::hours(1)
+    }
+}
+
+/// The instant a Hattrick wall-clock time refers to. In the autumn hour
+/// that happens twice, the earlier one is taken.
+pub fn from_hattrick_time(local: NaiveDateTime) -> DateTime<Utc> {
+    let summer = (local - Duration::hours(2)).and_utc();
+    if hattrick_offset(summer) == Duration::hours(2) {
+        summer
+    } else {
+        (local - Duration::hours(1)).and_utc()
+    }
+}
+
+/// Hattrick wall-clock time at `at`.
+pub fn to_hattrick_time(at: DateTime<Utc>) -> NaiveDateTime {
+    at.naive_utc() + hattrick_offset(at)
+}
+
+/// Parse a date as CHPP sends it, `YYYY-MM-DD HH:MM:SS` in Hattrick time.
+pub fn parse_hattrick_time(text: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(from_hattrick_time)
+}
+
+/// Number of the Hattrick week holding `at`, counted from an arbitrary
+/// Monday. Weeks start at midnight on Monday, Hattrick time.
+pub fn hattrick_week(at: DateTime<Utc>) -> i64 {
+    let monday = NaiveDate::from_ymd_opt(1970, 1, 5).expect("valid date");
+    (to_hattrick_time(at).date() - monday)
+        .num_days()
+        .div_euclid(7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_hattrick_time_in_winter_and_summer() {
+        assert_eq!(
+            parse_hattrick_time("2024-03-03 18:30:00"),
+            Some(utc("2024-03-03T17:30:00Z"))
+        );
+        assert_eq!(
+            parse_hattrick_time("2024-07-14 18:30:00"),
+            Some(utc("2024-07-14T16:30:00Z"))
+        );
+        assert_eq!(parse_hattrick_time("not a date"), None);
+    }
+
+    #[test]
+    fn test_summer_time_switches() {
+        // 2024: summer time from 31 March to 27 October.
+        assert_eq!(
+            hattrick_offset(utc("2024-03-31T00:59:59Z")),
+            Duration::hours(1)
+        );
+        assert_eq!(
+            hattrick_offset(utc("2024-03-31T01:00:00Z")),
+            Duration::hours(2)
+        );
+        assert_eq!(
+            hattrick_offset(utc("2024-10-27T00:59:59Z")),
+            Duration::hours(2)
+        );
+        assert_eq!(
+            hattrick_offset(utc("2024-10-27T01:00:00Z")),
+            Duration::hours(1)
+        );
+
+        // The repeated autumn hour reads as its first occurrence.
+        assert_eq!(
+            parse_hattrick_time("2024-10-27 02:30:00"),
+            Some(utc("2024-10-27T00:30:00Z"))
+        );
+        assert_eq!(
+            parse_hattrick_time("2024-10-27 03:00:00"),
+            Some(utc("2024-10-27T02:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_hattrick_week_starts_on_monday_midnight() {
+        // Midnight on Monday 15 January 2024 is 23:00 UTC on the Sunday.
+        let sunday = hattrick_week(utc("2024-01-14T22:59:59Z"));
+        assert_eq!(hattrick_week(utc("2024-01-14T23:00:00Z")), sunday + 1);
+        assert_eq!(hattrick_week(utc("2024-01-21T22:59:59Z")), sunday + 1);
+        // In summer, at 22:00 UTC.
+        let summer = hattrick_week(utc("2024-07-14T21:59:59Z"));
+        assert_eq!(hattrick_week(utc("2024-07-14T22:00:00Z")), summer + 1);
+    }
+
+    #[test]
+    fn test_to_hattrick_time_round_trips() {
+        for text in ["2024-01-15T10:00:00Z", "2024-08-01T22:45:00Z"] {
+            let at = utc(text);
+            assert_eq!(from_hattrick_time(to_hattrick_time(at)), at);
+        }
+    }
+}
@@ -1,2 +1,5 @@
+#[cfg(feature = "gui")]
 pub mod image;
+pub mod hattrick_time;
+pub mod number_format;
 pub mod secret;
@@ -0,0 +1,161 @@
+/* number_format.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+use num_format::{Buffer, CustomFormat, Grouping, SystemLocale};
+
+/// Digit grouping used for displayed numbers such as TSI and salary.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// Follow the system locale.
+    #[default]
+    System,
+    Comma,
+    Dot,
+    Space,
+    None,
+}
+
+impl NumberFormat {
+    pub const ALL: [NumberFormat; 5] = [
+        NumberFormat::System,
+        NumberFormat::Comma,
+        NumberFormat::Dot,
+        NumberFormat::Space,
+        NumberFormat::None,
+    ];
+
+    /// Name under which the format is stored in the preferences.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberFormat::System => "system",
+            NumberFormat::Comma => "comma",
+            NumberFormat::Dot => "dot",
+            NumberFormat::Space => "space",
+            NumberFormat::None => "none",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<NumberFormat> {
+        Self::ALL.into_iter().find(|format| format.as_str() == name)
+    }
+
+    /// `num_format` format for this grouping. The system locale falls back
+    /// to the "C" locale, then to comma grouping, and never panics. Locales
+    /// that do not group digits (such as "C") get comma grouping so large
+    /// values stay readable.
+    pub fn custom_format(&self) -> CustomFormat {
+        let (grouping, separator, decimal) = match self {
+            NumberFormat::System => {
+                return SystemLocale::default()
+                    .or_else(|_| SystemLocale::from_name("C"))
+                    .ok()
+                    .and_then(|locale| custom_format_from(&locale))
+                    .unwrap_or_else(|| NumberFormat::Comma.custom_format());
+            }
+            NumberFormat::Comma => (Grouping::Standard, ",", "."),
+            NumberFormat::Dot => (Grouping::Standard, ".", ","),
+            NumberFormat::Space => (Grouping::Standard, " ", "."),
+            NumberFormat::None => (Grouping::Posix, "", "."),
+        };
+        CustomFormat::builder()
+            .decimal(decimal)
+            .grouping(grouping)
+            .separator(separator)
+            .build()
+            .unwrap_or_default()
+    }
+}
+
+fn custom_format_from(locale: &SystemLocale) -> Option<CustomFormat> {
+    let (grouping, separator) = match locale.grouping() {
+        Grouping::Posix => (Grouping::Standard, ","),
+        grouping => (grouping, locale.separator()),
+    };
+    CustomFormat::builder()
+        .decimal(locale.decimal())
+        .grouping(grouping)
+        .infinity(locale.infinity())
+        .minus_sign(locale.minus_sign())
+        .nan(locale.nan())
+        .plus_sign(locale.plus_sign())
+        .separator(separator)
+        .build()
+        .ok()
+}
+
+/// Format a whole number with the digit grouping of `fmt`.
+pub fn format_number(n: u64, fmt: &NumberFormat) -> String {
+    let mut buf = Buffer::default();
+    buf.write_formatted(&n, &fmt.custom_format());
+    buf.as_str().to_string()
+}
+
+/// Format `value` rounded to one decimal with the grouping and decimal
+/// separator of `fmt`, dropping the decimal when it is zero.
+pub fn format_decimal(value: f64, fmt: &NumberFormat) -> String {
+    let format = fmt.custom_format();
+    let tenths = (value * 10.0).round() as i64;
+    let mut buf = Buffer::default();
+    buf.write_formatted(&(tenths / 10), &format);
+    match (tenths % 10).abs() {
+        0 => buf.as_str().to_string(),
+        fraction => format!("{}{}{}", buf.as_str(), format.decimal(), fraction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number_groupings() {
+        assert_eq!(format_number(1_000_000, &NumberFormat::Comma), "1,000,000");
+        assert_eq!(format_number(1_000_000, &NumberFormat::Dot), "1.000.000");
+        assert_eq!(format_number(1_000_000, &NumberFormat::Space), "1 000 000");
+        assert_eq!(format_number(1_000_000, &NumberFormat::None), "1000000");
+        assert_eq!(format_number(999, &NumberFormat::Comma), "999");
+    }
+
+    #[test]
+    fn test_format_decimal_uses_the_decimal_separator() {
+        assert_eq!(format_decimal(1234.5, &NumberFormat::Comma), "1,234.5");
+        assert_eq!(format_decimal(1234.5, &NumberFormat::Dot), "1.234,5");
+        assert_eq!(format_decimal(1234.5, &NumberFormat::None), "1234.5");
+        assert_eq!(format_decimal(1234.0, &NumberFormat::Dot), "1.234");
+        // Rounds rather than printing a second separator.
+        assert_eq!(format_decimal(1234.96, &NumberFormat::Comma), "1,235");
+    }
+
+    #[test]
+    fn test_format_number_system_groups_digits() {
+        let formatted = format_number(1_000_000, &NumberFormat::System);
+        assert!(formatted.len() > "1000000".len(), "{}", formatted);
+        let digits: String = formatted.chars().filter(|c| c.is_ascii_digit()).collect();
+        assert_eq!(digits, "1000000");
+    }
+
+    #[test]
+    fn test_number_format_names_round_trip() {
+        for format in NumberFormat::ALL {
+            assert_eq!(NumberFormat::from_name(format.as_str()), Some(format));
+        }
+        assert_eq!(NumberFormat::from_name("semicolon"), None);
+    }
+}
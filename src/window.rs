@@ -20,21 +20,37 @@
 * SPDX-License-Identifier: GPL-3.0-or-later
 */
 
+use crate::chpp::model::Player;
+use crate::db::download_entries::{DataFreshness, DownloadEntryRow, STALE_DATA_AGE};
+use crate::error::NutmegError;
+use crate::export::markdown::export_squad_markdown;
 use crate::service::secret::SecretStorageService;
+use crate::service::sync::SyncService;
+use gettextrs::{gettext, ngettext};
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
 use gtk::{gdk, gio, glib, CompositeTemplate, TemplateChild};
-use log::info;
+use log::{info, warn};
+use std::cell::Cell;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::rating::ui::page::FormationOptimiserWidget;
 // use crate::training::ui::page::TrainingPlannerPage;
+use crate::ui::components::cash_chart::CashChart;
 use crate::ui::context_object::ContextObject;
+use crate::ui::controllers::squad_tab::SquadTabController;
+use crate::ui::player_display::{player_profile_url, preferred_number_format};
 use crate::ui::player_object::PlayerObject;
+use crate::ui::team_display::{
+    cup_status_text, guestbook_text, hof_peak_skills, hof_player_name, parse_hex_color,
+};
 use crate::ui::team_object::TeamObject;
+use crate::utils::number_format::NumberFormat;
 
 use crate::opponent_analysis::ui::OpponentAnalysis;
 use crate::series::ui::page::SeriesPage;
+use crate::squad::ui::keyboard::{squad_key_action, SquadKeyAction};
 use crate::squad::ui::player_details::SquadPlayerDetails;
 use crate::squad::ui::player_list::SquadPlayerList;
 mod imp {
@@ -59,6 +75,42 @@ mod imp {
         #[template_child]
         pub team_sync: TemplateChild<gtk::Button>,
 
+        #[template_child]
+        pub sync_spinner: TemplateChild<gtk::Spinner>,
+
+        #[template_child]
+        pub last_sync_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub season_week_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub achievements_badge: TemplateChild<gtk::Label>,
+
+        #[template_child]
+        pub team_info_box: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub team_info_name: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub team_info_cup: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub team_info_guestbook: TemplateChild<gtk::Label>,
+
+        #[template_child]
+        pub stale_data_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub stale_data_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub stale_data_sync: TemplateChild<gtk::Button>,
+
+        #[template_child]
+        pub cash_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub cash_chart_box: TemplateChild<gtk::Box>,
+
+        #[template_child]
+        pub hof_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub hof_list: TemplateChild<gtk::ListBox>,
+
         // https://docs.gtk.org/gtk4/class.Revealer.html
         #[template_child]
         pub sync_revealer: TemplateChild<gtk::Revealer>,
@@ -124,8 +176,9 @@ mod imp {
             let controller = crate::ui::controllers::main_controller::MainController::new(
                 obj.imp().context_object.clone(),
             );
-            obj.imp().main_controller.replace(Some(controller.clone()));
-            controller.refresh_all_teams();
+            obj.imp().main_controller.replace(Some(controller));
+            obj.reload_teams(None);
+            obj.refresh_last_sync_label();
 
             /*
             // Inject ContextObject into sub-pages
@@ -136,14 +189,8 @@ mod imp {
 
             obj.setup_actions();
 
-            // Load CSS
-            let provider = gtk::CssProvider::new();
-            provider.load_from_data(include_str!("style.css"));
-            gtk::style_context_add_provider_for_display(
-                &gdk::Display::default().unwrap(),
-                &provider,
-                gtk::STYLE_PROVIDER_PRIORITY_APPLICATION,
-            );
+            // Load CSS: bundled stylesheet plus the optional user override.
+            crate::ui::style::load_css_providers();
 
             obj.set_maximized(true);
         }
@@ -173,6 +220,337 @@ impl NutmegWindow {
     // We keep these empty implementations around if they are called recursively from signals
     // but they no longer perform any logic themselves. We will prune them shortly.
 
+    /// Reload the team dropdown, keeping `preferred` (or else the last
+    /// selected team) selected when it is still in the list.
+    fn reload_teams(&self, preferred: Option<u32>) {
+        use crate::ui::controllers::main_controller::select_team_index;
+
+        let Some(ctrl) = self.imp().main_controller.borrow().clone() else {
+            return;
+        };
+        // Read the preference first: replacing the model selects the first
+        // team, which would overwrite it.
+        let preferred = preferred.or_else(|| ctrl.last_selected_team());
+        let teams = ctrl.refresh_all_teams();
+        if !teams.is_empty() {
+            self.imp()
+                .combo_teams
+                .set_selected(select_team_index(&teams, preferred) as u32);
+        }
+    }
+
+    /// Refresh the data from CHPP, showing progress in the status bar. With
+    /// `reauthenticate`, the OAuth flow runs first to replace the stored
+    /// access token.
+    fn start_sync(&self, reauthenticate: bool) {
+        let imp = self.imp();
+        let context = imp.context_object.clone();
+
+        // Disable button
+        imp.team_sync.set_sensitive(false);
+        imp.sync_spinner.set_spinning(true);
+
+        // Show status bar
+        imp.sync_revealer.set_reveal_child(true);
+        imp.sync_progress_bar.set_fraction(0.0);
+        imp.sync_status_label.set_label("Starting sync...");
+
+        // Channel for progress updates
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<(f64, String)>();
+
+        // Handle progress updates on main thread
+        let progress_bar = imp.sync_progress_bar.clone();
+        let status_label = imp.sync_status_label.clone();
+
+        glib::MainContext::default().spawn_local(async move {
+            while let Some((p, msg)) = receiver.recv().await {
+                progress_bar.set_fraction(p);
+                status_label.set_label(&msg);
+            }
+        });
+
+        // Keep weak ref to update UI later
+        let window_weak_completion = self.downgrade();
+
+        glib::MainContext::default().spawn_local(async move {
+            // Delegate to SyncController
+            use crate::ui::controllers::sync::{PostSyncAction, SyncController};
+            let action = if reauthenticate {
+                SyncController::reauthenticate(
+                    window_weak_completion.clone(),
+                    context.clone(),
+                    sender,
+                )
+                .await
+            } else {
+                SyncController::perform_sync(
+                    window_weak_completion.clone(),
+                    context.clone(),
+                    sender,
+                )
+                .await
+            };
+
+            // UI Cleanup
+            if let Some(win) = window_weak_completion.upgrade() {
+                let imp = win.imp();
+                imp.sync_spinner.set_spinning(false);
+
+                if action == PostSyncAction::OpenSetup {
+                    if let Some(app) = win.application() {
+                        crate::setup_window::SetupWindow::new(&app).present();
+                        win.close();
+                    }
+                    return;
+                }
+
+                // Delay hiding the status bar slightly so user sees result
+                glib::timeout_future_seconds(2).await;
+
+                imp.sync_revealer.set_reveal_child(false);
+                imp.team_sync.set_sensitive(true);
+                win.refresh_last_sync_label();
+
+                if let PostSyncAction::Reload { team_id } = action {
+                    win.reload_teams(Some(team_id));
+                    context.refresh_from_db();
+                }
+            }
+        });
+    }
+
+    /// Update the header label with the time of the last completed sync,
+    /// and warn with a banner when that data is old or missing.
+    fn refresh_last_sync_label(&self) {
+        let db = crate::db::manager::DbManager::new();
+        let age = db.get_connection().and_then(|mut conn| {
+            crate::db::download_entries::data_age(&mut conn, chrono::Utc::now())
+        });
+
+        let text = match age {
+            Ok(Some(age)) => format!("Last synced: {}", format_time_ago(age)),
+            Ok(None) => "Never synced".to_string(),
+            Err(ref e) => {
+                log::warn!("Could not read last sync time: {}", e);
+                String::new()
+            }
+        };
+        self.imp().last_sync_label.set_label(&text);
+
+        let banner = match age {
+            Ok(age) => match DataFreshness::from_age(age, STALE_DATA_AGE) {
+                DataFreshness::Fresh => None,
+                DataFreshness::Stale(age) => Some(
+                    gettext("This data was last synced {ago} and may be out of date.")
+                        .replace("{ago}", &format_time_ago(age)),
+                ),
+                DataFreshness::NeverSynced => Some(gettext(
+                    "No data has been downloaded yet. Sync to fetch your team.",
+                )),
+            },
+            Err(_) => None,
+        };
+        let imp = self.imp();
+        if let Some(message) = &banner {
+            imp.stale_data_label.set_label(message);
+        }
+        imp.stale_data_revealer.set_reveal_child(banner.is_some());
+    }
+
+    /// Update the header label with the selected team's league season and week.
+    fn refresh_season_week_label(&self, team_id: Option<u32>) {
+        let season_week = team_id.and_then(|team_id| {
+            let db = crate::db::manager::DbManager::new();
+            let mut conn = db.get_connection().ok()?;
+            let team = crate::db::teams::get_team(&mut conn, team_id).ok()??;
+            let league_id = team.League?.LeagueID;
+            let league = crate::db::teams::get_latest_league(&mut conn, league_id)
+                .map_err(|e| log::warn!("Could not read league {}: {}", league_id, e))
+                .ok()??;
+            let synced_at = crate::db::teams::get_league_synced_at(&mut conn, league_id)
+                .map_err(|e| log::warn!("Could not read league {} sync time: {}", league_id, e))
+                .ok()??;
+            crate::chpp::model::current_season_week(&league, synced_at, chrono::Utc::now())
+        });
+
+        let text = match season_week {
+            Some((season, week)) => gettext("Season {season}, week {week}")
+                .replace("{season}", &season.to_string())
+                .replace("{week}", &week.to_string()),
+            None => String::new(),
+        };
+        self.imp().season_week_label.set_label(&text);
+    }
+
+    /// Show how many achievements the manager of the selected team has
+    /// earned, with their points in the tooltip. Hidden when none are stored.
+    fn refresh_achievements_badge(&self, team_id: Option<u32>) {
+        let achievements = team_id.and_then(|team_id| {
+            let db = crate::db::manager::DbManager::new();
+            let mut conn = db.get_connection().ok()?;
+            let user_id =
+                crate::db::teams::get_user_id_for_team(&mut conn, team_id as i32).ok()?? as u32;
+            let count = crate::db::achievements::get_achievements(&mut conn, user_id)
+                .map_err(|e| log::warn!("Could not read achievements: {}", e))
+                .ok()?
+                .len() as u32;
+            let points = crate::db::achievements::total_achievement_points(&mut conn, user_id)
+                .map_err(|e| log::warn!("Could not sum achievement points: {}", e))
+                .ok()?;
+            Some((count, points))
+        });
+
+        let badge = &self.imp().achievements_badge;
+        match achievements {
+            Some((count, points)) if count > 0 => {
+                badge.set_label(&format!("🏆 {}", count));
+                let count_text = ngettext("{n} achievement", "{n} achievements", count)
+                    .replace("{n}", &count.to_string());
+                let points_text =
+                    ngettext("{n} point", "{n} points", points).replace("{n}", &points.to_string());
+                badge.set_tooltip_text(Some(&format!("{} · {}", count_text, points_text)));
+                badge.set_visible(true);
+            }
+            _ => badge.set_visible(false),
+        }
+    }
+
+    /// Fill the team info panel under the header from the selected team's
+    /// latest download, hiding it when no team is stored.
+    fn refresh_team_info(&self, team_id: Option<u32>) {
+        let team = team_id.and_then(|team_id| {
+            let db = crate::db::manager::DbManager::new();
+            let mut conn = db.get_connection().ok()?;
+            crate::db::teams::get_team(&mut conn, team_id)
+                .map_err(|e| log::warn!("Could not read team {}: {}", team_id, e))
+                .ok()?
+        });
+
+        let imp = self.imp();
+        let Some(team) = team else {
+            imp.team_info_box.set_visible(false);
+            return;
+        };
+
+        imp.team_info_name.set_label(&team.TeamName);
+
+        let cup = team.Cup.as_ref().map(|cup| match &cup.CupName {
+            Some(name) => format!("{}: {}", name, cup_status_text(cup)),
+            None => cup_status_text(cup),
+        });
+        imp.team_info_cup
+            .set_label(cup.as_deref().unwrap_or_default());
+        imp.team_info_cup.set_visible(cup.is_some());
+
+        let guestbook = team
+            .Guestbook
+            .as_ref()
+            .map(|g| guestbook_text(g.NumberOfGuestbookItems));
+        imp.team_info_guestbook
+            .set_label(guestbook.as_deref().unwrap_or_default());
+        imp.team_info_guestbook.set_visible(guestbook.is_some());
+
+        imp.team_info_box.set_visible(true);
+    }
+
+    /// Redraw the Finances tab from the team's stored economy snapshots.
+    fn refresh_finances(&self, team_id: Option<u32>) {
+        let history = team_id
+            .and_then(|team_id| {
+                let db = crate::db::manager::DbManager::new();
+                let mut conn = db.get_connection().ok()?;
+                crate::db::economy::cash_history(&mut conn, team_id)
+                    .map_err(|e| log::warn!("Could not read cash history: {}", e))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        let imp = self.imp();
+        while let Some(child) = imp.cash_chart_box.first_child() {
+            imp.cash_chart_box.remove(&child);
+        }
+
+        match history.last() {
+            Some(&(_, cash)) => {
+                let mut buf = num_format::Buffer::default();
+                buf.write_formatted(&cash, &preferred_number_format().custom_format());
+                imp.cash_label
+                    .set_label(&gettext("Cash: {cash}").replace("{cash}", buf.as_str()));
+                imp.cash_chart_box.append(&CashChart::create(&history));
+            }
+            None => {
+                imp.cash_label
+                    .set_label(&gettext("No finances recorded yet. Sync to add one."));
+            }
+        }
+    }
+
+    /// Redraw the Hall of Fame tab from the team's latest stored list.
+    fn refresh_hall_of_fame(&self, team_id: Option<u32>) {
+        let hof = team_id
+            .and_then(|team_id| {
+                let db = crate::db::manager::DbManager::new();
+                let mut conn = db.get_connection().ok()?;
+                crate::db::hof_players::get_hof_players(&mut conn, team_id)
+                    .map_err(|e| log::warn!("Could not read Hall of Fame: {}", e))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        let imp = self.imp();
+        imp.hof_list.remove_all();
+        imp.hof_list.set_visible(!hof.is_empty());
+
+        if hof.is_empty() {
+            imp.hof_label
+                .set_label(&gettext("No players in the Hall of Fame yet."));
+            return;
+        }
+        imp.hof_label.set_label(
+            &ngettext(
+                "{n} player in the Hall of Fame",
+                "{n} players in the Hall of Fame",
+                hof.len() as u32,
+            )
+            .replace("{n}", &hof.len().to_string()),
+        );
+
+        for player in &hof {
+            let row = gtk::Box::builder()
+                .orientation(gtk::Orientation::Vertical)
+                .spacing(4)
+                .margin_start(12)
+                .margin_end(12)
+                .margin_top(6)
+                .margin_bottom(6)
+                .build();
+
+            let name = gtk::Label::builder()
+                .label(hof_player_name(player))
+                .halign(gtk::Align::Start)
+                .css_classes(["heading"])
+                .build();
+            row.append(&name);
+
+            // HofDate is "YYYY-MM-DD HH:MM:SS"; the day is enough here.
+            let hof_date = player.HofDate.split(' ').next().unwrap_or_default();
+            let mut details = gettext("Inducted {date}").replace("{date}", hof_date);
+            let skills = hof_peak_skills(player);
+            if !skills.is_empty() {
+                details = format!("{} · {}", details, skills);
+            }
+            let details = gtk::Label::builder()
+                .label(details)
+                .halign(gtk::Align::Start)
+                .wrap(true)
+                .css_classes(["dim-label"])
+                .build();
+            row.append(&details);
+
+            imp.hof_list.append(&row);
+        }
+    }
+
     fn setup_bindings(&self) {
         let imp = self.imp();
         let model = &imp.context_object;
@@ -194,6 +572,12 @@ impl NutmegWindow {
         model.connect_notify_local(Some("data-loaded"), move |m, _| {
             let win_imp = window.imp();
 
+            window.refresh_season_week_label(m.selected_team().map(|t| t.team_data().id));
+            window.refresh_team_info(m.selected_team().map(|t| t.team_data().id));
+            window.refresh_achievements_badge(m.selected_team().map(|t| t.team_data().id));
+            window.refresh_finances(m.selected_team().map(|t| t.team_data().id));
+            window.refresh_hall_of_fame(m.selected_team().map(|t| t.team_data().id));
+
             // Extract the snapshot
             let league_opt = m.league_details();
             let matches_opt = m.matches();
@@ -229,16 +613,23 @@ impl NutmegWindow {
             .sync_create()
             .build();
 
-        // Bind ContextObject players to TreeView model (inside PlayerList)
-        model
-            .bind_property("players", &imp.player_list.tree_view(), "model")
-            .sync_create()
-            .build();
+        // Show ContextObject players in the PlayerList, which wraps them
+        // in its filter and sort models.
+        let player_list = imp.player_list.clone();
+        model.connect_notify_local(Some("players"), move |model, _| {
+            let store = model.property::<Option<gtk::ListStore>>("players");
+            player_list.set_players(store.as_ref());
+        });
+        let store = model.property::<Option<gtk::ListStore>>("players");
+        imp.player_list.set_players(store.as_ref());
     }
 
     fn setup_team_dropdown_factory(&self, factory: &gtk::SignalListItemFactory) {
         factory.connect_setup(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let Some(item) = item.downcast_ref::<gtk::ListItem>() else {
+                log::warn!("Team dropdown factory received a non-ListItem");
+                return;
+            };
             let hbox = gtk::Box::new(gtk::Orientation::Horizontal, 8);
             hbox.set_margin_start(4);
             hbox.set_margin_end(4);
@@ -249,6 +640,13 @@ impl NutmegWindow {
             logo.set_pixel_size(24);
             hbox.append(&logo);
 
+            // Kit colour swatch: background colour on the left, primary on the right.
+            let swatch = gtk::DrawingArea::new();
+            swatch.set_content_width(16);
+            swatch.set_content_height(16);
+            swatch.set_valign(gtk::Align::Center);
+            hbox.append(&swatch);
+
             let label = gtk::Label::new(None);
             label.set_xalign(0.0);
             hbox.append(&label);
@@ -257,29 +655,57 @@ impl NutmegWindow {
         });
 
         factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let team_obj = item.item().and_downcast::<TeamObject>().unwrap();
-            let hbox = item.child().and_downcast::<gtk::Box>().unwrap();
-
-            let logo = hbox
-                .first_child()
-                .unwrap()
-                .downcast::<gtk::Image>()
-                .unwrap();
-            let label = logo
-                .next_sibling()
-                .unwrap()
-                .downcast::<gtk::Label>()
-                .unwrap();
+            let Some(item) = item.downcast_ref::<gtk::ListItem>() else {
+                return;
+            };
+            let Some(team_obj) = item.item().and_downcast::<TeamObject>() else {
+                return;
+            };
+            let Some((logo, label)) = item
+                .child()
+                .and_downcast::<gtk::Box>()
+                .as_ref()
+                .and_then(logo_and_label)
+            else {
+                log::warn!("Team dropdown row does not have the expected logo and label");
+                return;
+            };
+            let swatch = logo.next_sibling().and_downcast::<gtk::DrawingArea>();
 
             let team_data = team_obj.team_data();
 
+            let colors = team_data.colors.as_ref().and_then(|(background, primary)| {
+                Some((parse_hex_color(background)?, parse_hex_color(primary)?))
+            });
+            if let Some(swatch) = &swatch {
+                swatch.set_visible(colors.is_some());
+                if let Some((background, primary)) = colors {
+                    swatch.set_draw_func(move |_, cr, width, height| {
+                        let half = width as f64 / 2.0;
+                        for (rgba, x) in [(background, 0.0), (primary, half)] {
+                            cr.set_source_rgba(
+                                rgba.red() as f64,
+                                rgba.green() as f64,
+                                rgba.blue() as f64,
+                                rgba.alpha() as f64,
+                            );
+                            cr.rectangle(x, 0.0, half, height as f64);
+                            let _ = cr.fill();
+                        }
+                    });
+                }
+            }
+
             let markup = format!(
                 "{} <span foreground='gray'>({})</span>",
                 glib::markup_escape_text(&team_data.name),
                 team_data.id
             );
             label.set_markup(&markup);
+            if let Some(row) = label.parent() {
+                let info = team_data.guestbook_items.map(guestbook_text);
+                row.set_tooltip_text(info.as_deref());
+            }
 
             if let Some(mut url) = team_data.logo_url {
                 if url.starts_with("//") {
@@ -304,24 +730,215 @@ impl NutmegWindow {
 
     fn update_optimiser_players(&self, list_store: Option<gtk::ListStore>) {
         if let Some(store) = list_store {
-            let mut players = Vec::new();
-            if let Some(iter) = store.iter_first() {
-                loop {
-                    #[allow(deprecated)]
-                    let obj_val = store.get_value(&iter, 18);
-                    if let Ok(player_obj) = obj_val.get::<PlayerObject>() {
-                        players.push(player_obj.player().clone());
-                    }
-                    if !store.iter_next(&iter) {
-                        break;
-                    }
-                }
-            }
+            let players = players_in_store(&store);
             info!("Updating optimiser with {} players", players.len());
+            let summary = crate::squad::summary::squad_summary(&players);
+            self.imp().player_list.set_summary(Some(&summary));
             self.imp().optimiser.set_players(players);
         } else {
+            self.imp().player_list.set_summary(None);
             self.imp().optimiser.set_players(Vec::new());
         }
+        self.refresh_country_breakdown();
+    }
+
+    /// Nationality spread of the selected team's squad, under the summary.
+    fn refresh_country_breakdown(&self) {
+        let team_id = self
+            .imp()
+            .context_object
+            .selected_team()
+            .map(|t| t.team_data().id);
+        let breakdown = team_id
+            .and_then(|team_id| {
+                let db = crate::db::manager::DbManager::new();
+                let mut conn = db.get_connection().ok()?;
+                crate::db::teams::players_by_country(&mut conn, team_id)
+                    .map_err(|e| warn!("Could not count players by country: {}", e))
+                    .ok()
+            })
+            .unwrap_or_default();
+        self.imp().player_list.set_country_breakdown(&breakdown);
+    }
+
+    /// Re-fetch one player's details and update their row in place.
+    fn refresh_player(&self, player_id: u32) {
+        let context = self.imp().context_object.clone();
+        let Some(team_id) = context.selected_team().map(|t| t.team_data().id) else {
+            return;
+        };
+
+        glib::MainContext::default().spawn_local(async move {
+            let sync = SyncService::new(Arc::new(crate::db::manager::DbManager::new()));
+            match sync.refresh_player(team_id, player_id).await {
+                Ok(player) => {
+                    if let Some(store) = context.property::<Option<gtk::ListStore>>("players") {
+                        let db = crate::db::manager::DbManager::new();
+                        let trends = db
+                            .get_connection()
+                            .and_then(|mut conn| {
+                                crate::db::diff::latest_player_trends(&mut conn, team_id)
+                            })
+                            .unwrap_or_else(|e| {
+                                warn!("Could not compute trend for player {}: {}", player_id, e);
+                                Default::default()
+                            });
+                        SquadTabController::update_player_row(
+                            &store,
+                            &player,
+                            trends.get(&player_id),
+                        );
+                    }
+                    info!("Refreshed player {}", player_id);
+                }
+                Err(e) => warn!("Failed to refresh player {}: {}", player_id, e),
+            }
+        });
+    }
+
+    /// List every endpoint fetched by the most recent download with its
+    /// status, so a partial sync can be diagnosed without reading logs.
+    fn show_download_report(&self) {
+        use crate::db::download_entries::{get_download_entries, get_latest_download_id};
+        use diesel::OptionalExtension;
+
+        let db = crate::db::manager::DbManager::new();
+        let entries = db.get_connection().and_then(|mut conn| {
+            match get_latest_download_id(&mut conn).optional() {
+                Ok(Some(download_id)) => get_download_entries(&mut conn, download_id),
+                Ok(None) => Ok(Vec::new()),
+                Err(e) => Err(NutmegError::Db(e.to_string())),
+            }
+        });
+
+        let list = gtk::ListBox::new();
+        list.set_selection_mode(gtk::SelectionMode::None);
+        list.add_css_class("boxed-list");
+
+        match entries {
+            Ok(entries) if entries.is_empty() => {
+                let empty = gettext("Nothing has been synced yet.");
+                list.append(&gtk::Label::new(Some(&empty)));
+            }
+            Ok(entries) => {
+                for entry in &entries {
+                    list.append(&download_entry_row(entry));
+                }
+            }
+            Err(e) => {
+                warn!("Could not read download entries: {}", e);
+                list.append(&gtk::Label::new(Some(&e.to_string())));
+            }
+        }
+
+        let scrolled = gtk::ScrolledWindow::builder()
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .child(&list)
+            .margin_start(12)
+            .margin_end(12)
+            .margin_top(12)
+            .margin_bottom(12)
+            .build();
+
+        let report = gtk::Window::builder()
+            .transient_for(self)
+            .modal(true)
+            .title(gettext("Last Sync Report"))
+            .default_width(560)
+            .default_height(480)
+            .child(&scrolled)
+            .build();
+        report.present();
+    }
+
+    /// Right-click menu on player rows: open the player on Hattrick, copy
+    /// their ID to the clipboard, or re-fetch their details.
+    fn setup_player_context_menu(&self, view: &gtk::TreeView) {
+        let clicked_player: Rc<Cell<Option<u32>>> = Rc::new(Cell::new(None));
+
+        let actions = gio::SimpleActionGroup::new();
+
+        let open_action = gio::SimpleAction::new("open-on-hattrick", None);
+        let clicked = clicked_player.clone();
+        open_action.connect_activate(move |_, _| {
+            if let Some(player_id) = clicked.get() {
+                let url = player_profile_url(player_id);
+                if let Err(e) = open::that(&url) {
+                    warn!("Failed to open {}: {}", url, e);
+                }
+            }
+        });
+        actions.add_action(&open_action);
+
+        let copy_action = gio::SimpleAction::new("copy-id", None);
+        let clicked = clicked_player.clone();
+        let view_weak = view.downgrade();
+        copy_action.connect_activate(move |_, _| {
+            if let (Some(player_id), Some(view)) = (clicked.get(), view_weak.upgrade()) {
+                view.clipboard().set_text(&player_id.to_string());
+            }
+        });
+        actions.add_action(&copy_action);
+
+        let refresh_action = gio::SimpleAction::new("refresh", None);
+        let clicked = clicked_player.clone();
+        let window_weak = self.downgrade();
+        refresh_action.connect_activate(move |_, _| {
+            if let (Some(player_id), Some(window)) = (clicked.get(), window_weak.upgrade()) {
+                window.refresh_player(player_id);
+            }
+        });
+        actions.add_action(&refresh_action);
+
+        view.insert_action_group("player", Some(&actions));
+
+        let menu = gio::Menu::new();
+        menu.append(
+            Some(&gettext("Open on Hattrick")),
+            Some("player.open-on-hattrick"),
+        );
+        menu.append(Some(&gettext("Copy player ID")), Some("player.copy-id"));
+        menu.append(
+            Some(&gettext("Refresh this player")),
+            Some("player.refresh"),
+        );
+
+        let popover = gtk::PopoverMenu::from_model(Some(&menu));
+        popover.set_parent(view);
+        popover.set_has_arrow(false);
+
+        let gesture = gtk::GestureClick::new();
+        gesture.set_button(gdk::BUTTON_SECONDARY);
+        let view_clone = view.clone();
+        gesture.connect_pressed(move |_, _, x, y| {
+            let (bx, by) = view_clone.convert_widget_to_bin_window_coords(x as i32, y as i32);
+            let Some((Some(path), _, _, _)) = view_clone.path_at_pos(bx, by) else {
+                return;
+            };
+            // Keep a multi-selection when right-clicking one of its rows.
+            let selection = view_clone.selection();
+            if !selection.path_is_selected(&path) {
+                selection.unselect_all();
+                selection.select_path(&path);
+            }
+
+            let Some(model) = view_clone.model() else {
+                return;
+            };
+            let Some(iter) = model.iter(&path) else {
+                return;
+            };
+            #[allow(deprecated)]
+            let player_obj = model.get_value(&iter, 18).get::<PlayerObject>().ok();
+            let Some(player_obj) = player_obj else {
+                return;
+            };
+            clicked_player.set(Some(player_obj.player().PlayerID));
+
+            popover.set_pointing_to(Some(&gdk::Rectangle::new(x as i32, y as i32, 1, 1)));
+            popover.popup();
+        });
+        view.add_controller(gesture);
     }
 
     fn setup_signals(&self) {
@@ -335,17 +952,23 @@ impl NutmegWindow {
         // Needed for manual update of details
         let player_details = imp.player_details.clone();
 
+        let player_list = imp.player_list.clone();
         selection.connect_changed(move |selection| {
             #[allow(deprecated)]
-            if let Some((model, iter)) = selection.selected() {
+            let (paths, model) = selection.selected_rows();
+            #[allow(deprecated)]
+            let iters: Vec<gtk::TreeIter> = paths.iter().filter_map(|p| model.iter(p)).collect();
+
+            #[allow(deprecated)]
+            if let [iter] = iters.as_slice() {
                 #[allow(deprecated)]
-                let obj_val = model.get_value(&iter, 18);
+                let obj_val = model.get_value(iter, 18);
 
                 let player_obj = obj_val.get::<PlayerObject>().ok();
 
                 // Get preferred position from column 11
                 #[allow(deprecated)]
-                let best_pos_val = model.get_value(&iter, 11);
+                let best_pos_val = model.get_value(iter, 11);
                 let best_pos = best_pos_val.get::<String>().ok();
 
                 // Update ContextObject (for other consumers)
@@ -357,65 +980,66 @@ impl NutmegWindow {
                 context_object.set_selected_player(None);
                 player_details.set_player(None, None);
             }
-        });
-
-        // Sync Handler
-        let window_weak = self.downgrade();
-        let context_object_clone = imp.context_object.clone();
-        imp.team_sync.connect_clicked(move |_| {
-            let window = match window_weak.upgrade() {
-                Some(w) => w,
-                None => return,
-            };
-
-            let imp = window.imp();
-            let context = context_object_clone.clone();
 
-            // Disable button
-            imp.team_sync.set_sensitive(false);
-
-            // Show status bar
-            imp.sync_revealer.set_reveal_child(true);
-            imp.sync_progress_bar.set_fraction(0.0);
-            imp.sync_status_label.set_label("Starting sync...");
-
-            // Channel for progress updates
-            let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<(f64, String)>();
-
-            // Handle progress updates on main thread
-            let progress_bar = imp.sync_progress_bar.clone();
-            let status_label = imp.sync_status_label.clone();
+            // Exactly two selected players open the comparison panel.
+            #[allow(deprecated)]
+            let players: Vec<_> = iters
+                .iter()
+                .filter_map(|iter| model.get_value(iter, 18).get::<PlayerObject>().ok())
+                .map(|obj| obj.player())
+                .collect();
+            match players.as_slice() {
+                [a, b] => player_list.set_comparison(Some((a, b))),
+                _ => player_list.set_comparison(None),
+            }
+        });
 
-            glib::MainContext::default().spawn_local(async move {
-                while let Some((p, msg)) = receiver.recv().await {
-                    progress_bar.set_fraction(p);
-                    status_label.set_label(&msg);
+        // Keyboard handling: Enter shows the details, "/" starts searching
+        // Capture phase so this runs before the tree view's own typeahead.
+        let key_controller = gtk::EventControllerKey::new();
+        key_controller.set_propagation_phase(gtk::PropagationPhase::Capture);
+        let player_details = imp.player_details.clone();
+        let view_clone = view.clone();
+        key_controller.connect_key_pressed(move |_, key, _, modifiers| {
+            // Leave keys alone while the interactive search entry has focus.
+            if !view_clone.has_focus() {
+                return glib::Propagation::Proceed;
+            }
+            match squad_key_action(key, modifiers) {
+                Some(SquadKeyAction::ShowDetails) => {
+                    if view_clone.selection().count_selected_rows() > 0 {
+                        player_details.set_visible(true);
+                        player_details.grab_focus();
+                    }
+                    glib::Propagation::Stop
                 }
-            });
-
-            // Keep weak ref to update UI later
-            let window_weak_completion = window.downgrade();
-
-            glib::MainContext::default().spawn_local(async move {
-                // Delegate to SyncController
-                use crate::ui::controllers::sync::SyncController;
-                SyncController::perform_sync(window_weak_completion.clone(), context, sender).await;
-
-                // UI Cleanup
-                if let Some(win) = window_weak_completion.upgrade() {
-                    let imp = win.imp();
+                Some(SquadKeyAction::FocusSearch) => {
+                    view_clone.emit_by_name::<bool>("start-interactive-search", &[]);
+                    glib::Propagation::Stop
+                }
+                None => glib::Propagation::Proceed,
+            }
+        });
+        view.add_controller(key_controller);
 
-                    // Delay hiding the status bar slightly so user sees result
-                    glib::timeout_future_seconds(2).await;
+        self.setup_player_context_menu(&view);
 
-                    imp.sync_revealer.set_reveal_child(false);
-                    imp.team_sync.set_sensitive(true);
+        // Sync Handler
+        let window_weak = self.downgrade();
+        imp.team_sync.connect_clicked(move |_| {
+            if let Some(window) = window_weak.upgrade() {
+                window.start_sync(false);
+            }
+        });
 
-                    if let Some(ctrl) = win.imp().main_controller.borrow().as_ref() {
-                        ctrl.refresh_all_teams();
-                    }
+        let window_weak = self.downgrade();
+        imp.stale_data_sync.connect_clicked(move |_| {
+            if let Some(window) = window_weak.upgrade() {
+                // Same guard as the sync button: one refresh at a time.
+                if window.imp().team_sync.is_sensitive() {
+                    window.start_sync(false);
                 }
-            });
+            }
         });
 
         let context = imp.context_object.clone();
@@ -475,9 +1099,7 @@ impl NutmegWindow {
                                 Ok(()) => {
                                     log::info!("Database cleared successfully");
                                     // Reload teams to show empty state
-                                    if let Some(ctrl) = win.imp().main_controller.borrow().as_ref() {
-                                        ctrl.refresh_all_teams();
-                                    }
+                                    win.reload_teams(None);
 
                                     let success_dialog = gtk::MessageDialog::builder()
                                         .transient_for(&win)
@@ -517,6 +1139,66 @@ impl NutmegWindow {
             })
             .build();
 
+        // Action: compact-database
+        let compact_db_action = gio::ActionEntry::builder("compact-database")
+            .activate(move |window: &Self, _, _| {
+                use crate::db::download_entries::DEFAULT_DOWNLOADS_TO_KEEP;
+
+                let dialog = gtk::MessageDialog::builder()
+                    .transient_for(window)
+                    .modal(true)
+                    .message_type(gtk::MessageType::Question)
+                    .buttons(gtk::ButtonsType::OkCancel)
+                    .text("Compact Database?")
+                    .secondary_text(format!("This will delete all but the {} most recent syncs and shrink the database file. History older than that will be lost.", DEFAULT_DOWNLOADS_TO_KEEP))
+                    .build();
+
+                let window_weak = window.downgrade();
+                dialog.connect_response(move |dialog, response| {
+                    if response == gtk::ResponseType::Ok {
+                        if let Some(win) = window_weak.upgrade() {
+                            let db = crate::db::manager::DbManager::new();
+                            let (message_type, text, secondary) =
+                                match db.prune_old_downloads(DEFAULT_DOWNLOADS_TO_KEEP) {
+                                    Ok(removed) => {
+                                        log::info!("Pruned {} old downloads", removed);
+                                        crate::db::team_cache::team_cache().invalidate();
+                                        (
+                                            gtk::MessageType::Info,
+                                            "Database Compacted",
+                                            format!("Removed {} old syncs.", removed),
+                                        )
+                                    }
+                                    Err(e) => {
+                                        log::error!("Failed to compact database: {}", e);
+                                        (
+                                            gtk::MessageType::Error,
+                                            "Failed to Compact Database",
+                                            format!("Error: {}", e),
+                                        )
+                                    }
+                                };
+                            let result_dialog = gtk::MessageDialog::builder()
+                                .transient_for(&win)
+                                .modal(true)
+                                .message_type(message_type)
+                                .buttons(gtk::ButtonsType::Ok)
+                                .text(text)
+                                .secondary_text(secondary)
+                                .build();
+                            result_dialog.connect_response(|dialog, _| {
+                                dialog.close();
+                            });
+                            result_dialog.present();
+                        }
+                    }
+                    dialog.close();
+                });
+
+                dialog.present();
+            })
+            .build();
+
         // Action: delete-secrets
         let delete_secrets_action = gio::ActionEntry::builder("delete-secrets")
             .activate(move |window: &Self, _, _| {
@@ -573,7 +1255,161 @@ impl NutmegWindow {
             })
             .build();
 
-        self.add_action_entries([clear_db_action, delete_secrets_action]);
+        // Action: reauthenticate
+        let reauthenticate_action = gio::ActionEntry::builder("reauthenticate")
+            .activate(move |window: &Self, _, _| {
+                // Same guard as the sync button: one refresh at a time.
+                if window.imp().team_sync.is_sensitive() {
+                    window.start_sync(true);
+                }
+            })
+            .build();
+
+        // Action: download-report
+        let download_report_action = gio::ActionEntry::builder("download-report")
+            .activate(move |window: &Self, _, _| {
+                window.show_download_report();
+            })
+            .build();
+
+        // Action: copy-squad-markdown
+        let copy_markdown_action = gio::ActionEntry::builder("copy-squad-markdown")
+            .activate(move |window: &Self, _, _| {
+                let players = window
+                    .imp()
+                    .context_object
+                    .property::<Option<gtk::ListStore>>("players")
+                    .map(|store| players_in_store(&store))
+                    .unwrap_or_default();
+                window
+                    .clipboard()
+                    .set_text(&export_squad_markdown(&players));
+                info!("Copied {} players as Markdown", players.len());
+            })
+            .build();
+
+        // Action: number-format (stateful, one radio item per format)
+        let number_format_action = gio::ActionEntry::builder("number-format")
+            .parameter_type(Some(glib::VariantTy::STRING))
+            .state(preferred_number_format().as_str().to_variant())
+            .activate(move |window: &Self, action, param| {
+                let Some(format) = param
+                    .and_then(|p| p.str())
+                    .and_then(NumberFormat::from_name)
+                else {
+                    return;
+                };
+                let db = crate::db::manager::DbManager::new();
+                if let Err(e) = db.get_connection().and_then(|mut conn| {
+                    crate::db::preferences::set_number_format(&mut conn, format)
+                }) {
+                    log::error!("Failed to save number format: {}", e);
+                    return;
+                }
+                action.set_state(&format.as_str().to_variant());
+                // Redraw TSI, salaries and cash with the new grouping.
+                window.imp().context_object.refresh_from_db();
+            })
+            .build();
+
+        self.add_action_entries([
+            clear_db_action,
+            compact_db_action,
+            delete_secrets_action,
+            reauthenticate_action,
+            copy_markdown_action,
+            download_report_action,
+            number_format_action,
+        ]);
+    }
+}
+
+/// Endpoint and version on the left, status on the right, and the error
+/// underneath when the call failed.
+fn download_entry_row(entry: &DownloadEntryRow) -> gtk::Box {
+    let row = gtk::Box::new(gtk::Orientation::Vertical, 4);
+    row.set_margin_start(12);
+    row.set_margin_end(12);
+    row.set_margin_top(6);
+    row.set_margin_bottom(6);
+
+    let header = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+    let endpoint = gtk::Label::new(Some(&entry.endpoint));
+    endpoint.add_css_class("heading");
+    header.append(&endpoint);
+
+    let version = gtk::Label::new(Some(&format!("v{}", entry.version)));
+    version.add_css_class("dim-label");
+    version.set_hexpand(true);
+    version.set_xalign(0.0);
+    header.append(&version);
+
+    let status = gtk::Label::new(Some(&entry.status));
+    status.add_css_class(if entry.status == "success" {
+        "success"
+    } else {
+        "error"
+    });
+    status.set_tooltip_text(Some(&entry.fetched_date));
+    header.append(&status);
+    row.append(&header);
+
+    if let Some(message) = &entry.error_message {
+        let error = gtk::Label::new(Some(message));
+        error.set_wrap(true);
+        error.set_xalign(0.0);
+        error.set_selectable(true);
+        error.add_css_class("caption");
+        row.append(&error);
+    }
+
+    row
+}
+
+/// The players behind the rows of a squad list store, in store order.
+fn players_in_store(store: &gtk::ListStore) -> Vec<Player> {
+    let mut players = Vec::new();
+    if let Some(iter) = store.iter_first() {
+        loop {
+            #[allow(deprecated)]
+            let obj_val = store.get_value(&iter, 18);
+            if let Ok(player_obj) = obj_val.get::<PlayerObject>() {
+                players.push(player_obj.player().clone());
+            }
+            if !store.iter_next(&iter) {
+                break;
+            }
+        }
+    }
+    players
+}
+
+/// The logo and name label of a team dropdown row built by
+/// `setup_team_dropdown_factory`, or `None` if the row has another layout.
+fn logo_and_label(hbox: &gtk::Box) -> Option<(gtk::Image, gtk::Label)> {
+    let logo = hbox.first_child().and_downcast::<gtk::Image>()?;
+    let label = hbox.last_child().and_downcast::<gtk::Label>()?;
+    Some((logo, label))
+}
+
+/// Render an elapsed duration as a short human-readable string, e.g. "5 minutes ago".
+fn format_time_ago(elapsed: chrono::Duration) -> String {
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {} ago", unit)
+        } else {
+            format!("{} {}s ago", n, unit)
+        }
+    };
+
+    if elapsed.num_seconds() < 60 {
+        "just now".to_string()
+    } else if elapsed.num_minutes() < 60 {
+        plural(elapsed.num_minutes(), "minute")
+    } else if elapsed.num_hours() < 24 {
+        plural(elapsed.num_hours(), "hour")
+    } else {
+        plural(elapsed.num_days(), "day")
     }
 }
 
@@ -581,6 +1417,23 @@ impl NutmegWindow {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_time_ago() {
+        assert_eq!(format_time_ago(chrono::Duration::seconds(-5)), "just now");
+        assert_eq!(format_time_ago(chrono::Duration::seconds(30)), "just now");
+        assert_eq!(
+            format_time_ago(chrono::Duration::minutes(1)),
+            "1 minute ago"
+        );
+        assert_eq!(
+            format_time_ago(chrono::Duration::minutes(45)),
+            "45 minutes ago"
+        );
+        assert_eq!(format_time_ago(chrono::Duration::hours(3)), "3 hours ago");
+        assert_eq!(format_time_ago(chrono::Duration::days(1)), "1 day ago");
+        assert_eq!(format_time_ago(chrono::Duration::days(12)), "12 days ago");
+    }
+
     #[test]
     #[ignore = "requires a GTK display and must run on the main thread"]
     fn test_combo_teams_has_factory() {
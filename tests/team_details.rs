@@ -0,0 +1,48 @@
+/* team_details.rs
+ *
+ * Copyright 2026 Sébastien Le Callonnec
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ * SPDX-License-Identifier: GPL-3.0-or-later
+ */
+
+//! Exercises the public library API without the GTK front end.
+
+use nutmeg::chpp::model::HattrickData;
+use std::path::PathBuf;
+
+fn load_fixture(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "resources", name]
+        .iter()
+        .collect();
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {}", path.display(), e))
+}
+
+#[test]
+fn test_parse_team_details_via_public_api() {
+    let xml = load_fixture("teamdetails_announcements.xml");
+
+    let data: HattrickData =
+        serde_xml_rs::from_str(&xml).expect("Failed to deserialize HattrickData data");
+
+    assert_eq!(data.User.Loginname, "tychobrailleur");
+    assert_eq!(data.Teams.Teams.len(), 1);
+
+    let team = &data.Teams.Teams[0];
+    assert_eq!(team.TeamID, "1000");
+    assert_eq!(team.TeamName, "Test Team A");
+    assert_eq!(team.IsPrimaryClub, Some(true));
+}